@@ -0,0 +1,75 @@
+//! JS bridge for `uicp:host/kv`.
+//! Installs `globalThis.uicp.kv` with `get`/`set`/`delete`/`keys` before the bundle runs. Host
+//! `err` strings are thrown as JS `Error`s so applets can `try`/`catch` quota failures.
+
+use boa_engine::{
+    js_string,
+    object::{builtins::JsArray, ObjectInitializer},
+    property::Attribute,
+    Context, JsArgs, JsNativeError, JsResult, JsString, JsValue, NativeFunction,
+};
+
+use crate::bindings::uicp::host::kv;
+
+fn arg_string(args: &[JsValue], index: usize, context: &mut Context) -> JsResult<String> {
+    Ok(args
+        .get_or_undefined(index)
+        .to_string(context)?
+        .to_std_string_escaped())
+}
+
+fn host_error(message: String) -> boa_engine::JsError {
+    JsNativeError::error().with_message(message).into()
+}
+
+fn kv_get(_this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let key = arg_string(args, 0, context)?;
+    match kv::get(&key).map_err(host_error)? {
+        Some(value) => Ok(JsString::from(value.as_str()).into()),
+        None => Ok(JsValue::null()),
+    }
+}
+
+fn kv_set(_this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let key = arg_string(args, 0, context)?;
+    let value = arg_string(args, 1, context)?;
+    kv::set(&key, &value).map_err(host_error)?;
+    Ok(JsValue::undefined())
+}
+
+fn kv_delete(_this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let key = arg_string(args, 0, context)?;
+    Ok(kv::delete(&key).map_err(host_error)?.into())
+}
+
+fn kv_keys(_this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    // NOTE: A missing prefix lists every key rather than keys starting with "undefined".
+    let prefix = if args.get_or_undefined(0).is_undefined() {
+        String::new()
+    } else {
+        arg_string(args, 0, context)?
+    };
+    let keys = kv::keys(&prefix).map_err(host_error)?;
+    let values = keys
+        .iter()
+        .map(|key| JsValue::from(JsString::from(key.as_str())));
+    Ok(JsArray::from_iter(values, context).into())
+}
+
+/// Define `globalThis.uicp.kv`. Values are strings; callers JSON-encode structured state.
+pub(crate) fn install(context: &mut Context) -> JsResult<()> {
+    let kv_object = ObjectInitializer::new(context)
+        .function(NativeFunction::from_fn_ptr(kv_get), js_string!("get"), 1)
+        .function(NativeFunction::from_fn_ptr(kv_set), js_string!("set"), 2)
+        .function(
+            NativeFunction::from_fn_ptr(kv_delete),
+            js_string!("delete"),
+            1,
+        )
+        .function(NativeFunction::from_fn_ptr(kv_keys), js_string!("keys"), 1)
+        .build();
+    let host_object = ObjectInitializer::new(context)
+        .property(js_string!("kv"), kv_object, Attribute::READONLY)
+        .build();
+    context.register_global_property(js_string!("uicp"), host_object, Attribute::READONLY)
+}
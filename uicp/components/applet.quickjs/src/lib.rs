@@ -1,21 +1,28 @@
-//! applet.quickjs@0.1.0 component entrypoint.
+//! applet.quickjs@0.3.0 component entrypoint.
 //! Executes bundled JavaScript (compiled via build-applet.mjs) inside a sandboxed interpreter.
+//! WHY: The bundle is evaluated once by `load` and its context is kept for the lifetime of the
+//! instance, so hosts that hold the instance (per-window applet sessions) pay evaluation once.
 
 #![allow(clippy::missing_errors_doc)]
 
 mod bindings;
+mod host_kv;
+
+use std::cell::RefCell;
 
-use base64::engine::general_purpose::STANDARD as BASE64_ENGINE;
-use base64::Engine as _;
 use bindings::export;
 #[allow(unused_imports)]
 use bindings::exports;
 use bindings::exports::uicp::applet_script::script::Guest;
-use boa_engine::{context::ContextBuilder, Source};
-use once_cell::sync::OnceCell;
+use boa_engine::{context::ContextBuilder, Context, Source};
 
-const ENV_SOURCE_B64: &str = "UICP_SCRIPT_SOURCE_B64";
 const EXPORT_GLOBAL: &str = "__uicpApplet";
+
+thread_local! {
+    // INVARIANT: Components are single-threaded; the context lives until `dispose` or the next
+    // `load`, and is never observed half-initialized because `load` swaps it in only on success.
+    static APPLET: RefCell<Option<Context>> = const { RefCell::new(None) };
+}
 
 #[derive(Clone, Copy)]
 enum ScriptFunc<'a> {
@@ -28,71 +35,75 @@ enum ScriptFunc<'a> {
     },
 }
 
+impl ScriptFunc<'_> {
+    fn name(self) -> &'static str {
+        match self {
+            ScriptFunc::Init => "init",
+            ScriptFunc::Render { .. } => "render",
+            ScriptFunc::OnEvent { .. } => "onEvent",
+        }
+    }
+}
+
 struct Component;
 
 impl Component {
-    fn script_source() -> Result<&'static str, String> {
-        static SOURCE: OnceCell<String> = OnceCell::new();
-        SOURCE
-            .get_or_try_init(|| {
-                let encoded = std::env::var(ENV_SOURCE_B64)
-                    .map_err(|_| format!("JS source missing (expected {ENV_SOURCE_B64} env var)"))?;
-                let decoded = BASE64_ENGINE
-                    .decode(encoded.trim())
-                    .map_err(|err| format!("invalid base64 in {ENV_SOURCE_B64}: {err}"))?;
-                String::from_utf8(decoded)
-                    .map_err(|err| format!("JS source not utf8 after decode: {err}"))
-            })
-            .map(|s| s.as_str())
-    }
-
-    fn call(func: ScriptFunc<'_>) -> Result<String, String> {
-        let source = Self::script_source()?;
+    fn load_source(source: &str) -> Result<(), String> {
         let mut context = ContextBuilder::new().build().map_err(|err| {
             format!(
                 "E-UICP-0600: failed to construct JS context: {err}",
             )
         })?;
 
+        // WHY: Installed before evaluation so bundle top-level code can read persisted state.
+        host_kv::install(&mut context)
+            .map_err(|err| format!("E-UICP-0600: installing host bindings failed: {err}"))?;
+
         context
             .eval(Source::from_bytes(source.as_bytes()))
             .map_err(|err| format!("E-UICP-0601: evaluating bundled script failed: {err}"))?;
 
-        let (fn_name, args) = match func {
-            ScriptFunc::Init => ("init", Vec::new()),
-            ScriptFunc::Render { state } => (
-                "render",
-                vec![Self::js_string_literal(state)],
-            ),
+        APPLET.with(|slot| *slot.borrow_mut() = Some(context));
+        Ok(())
+    }
+
+    fn drop_context() {
+        APPLET.with(|slot| slot.borrow_mut().take());
+    }
+
+    fn call(func: ScriptFunc<'_>) -> Result<String, String> {
+        APPLET.with(|slot| {
+            let mut slot = slot.borrow_mut();
+            let context = slot
+                .as_mut()
+                .ok_or_else(|| "E-UICP-0605: no applet loaded; call load first".to_string())?;
+            Self::call_loaded(context, func)
+        })
+    }
+
+    fn call_loaded(context: &mut Context, func: ScriptFunc<'_>) -> Result<String, String> {
+        let args = match func {
+            ScriptFunc::Init => Vec::new(),
+            ScriptFunc::Render { state } => vec![Self::js_string_literal(state)],
             ScriptFunc::OnEvent {
                 action,
                 payload,
                 state,
-            } => (
-                "onEvent",
-                vec![
-                    Self::js_string_literal(action),
-                    Self::js_string_literal(payload),
-                    Self::js_string_literal(state),
-                ],
-            ),
+            } => vec![
+                Self::js_string_literal(action),
+                Self::js_string_literal(payload),
+                Self::js_string_literal(state),
+            ],
         };
 
-        let call_expr = Self::build_call_expr(fn_name, &args);
+        let call_expr = Self::build_call_expr(func.name(), &args);
 
         let value = context
             .eval(Source::from_bytes(call_expr.as_bytes()))
-            .map_err(|err| {
-                let func_name = match func {
-                    ScriptFunc::Init => "init",
-                    ScriptFunc::Render { .. } => "render",
-                    ScriptFunc::OnEvent { .. } => "onEvent",
-                };
-                format!("E-UICP-0602: calling {func_name} failed: {err}")
-            })?;
+            .map_err(|err| format!("E-UICP-0602: calling {} failed: {err}", func.name()))?;
 
         value
-            .to_string(&mut context)
+            .to_string(context)
             .map_err(|err| format!("E-UICP-0603: stringify JS result failed: {err}"))
             .map(|s| s.to_std_string().unwrap_or_default())
     }
@@ -127,6 +138,10 @@ impl Component {
 }
 
 impl Guest for Component {
+    fn load(source: String) -> Result<(), String> {
+        Self::load_source(&source)
+    }
+
     fn render(state: String) -> Result<String, String> {
         Self::call(ScriptFunc::Render { state: &state })
     }
@@ -142,6 +157,10 @@ impl Guest for Component {
     fn init() -> Result<String, String> {
         Self::call(ScriptFunc::Init)
     }
+
+    fn dispose() {
+        Self::drop_context();
+    }
 }
 
 export!(Component);
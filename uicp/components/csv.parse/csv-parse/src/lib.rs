@@ -1,4 +1,4 @@
-//! csv.parse@1.2.0 component entrypoint.
+//! csv.parse@1.3.0 component entrypoint.
 //! WHY: Keep component logic self-contained so bindgen exports stay minimal.
 
 #![allow(clippy::missing_errors_doc)]
@@ -7,124 +7,709 @@ mod bindings;
 
 use base64::engine::general_purpose::STANDARD as BASE64_ENGINE;
 use base64::Engine as _;
-use bindings::exports::uicp::task_csv_parse::csv::{Guest, Rows};
-use csv::{ReaderBuilder, Trim};
+use bindings::exports::uicp::task_csv_parse::csv::{Cell, Encoding, Guest, Options, Rows};
+use bindings::uicp::host::control;
+use bindings::wasi::clocks::monotonic_clock;
+use bindings::wasi::io::streams::OutputStream;
+use ciborium::ser::into_writer;
+use ciborium::value::{Integer, Value};
+use csv::{ByteRecord, ReaderBuilder, StringRecord, Trim};
 use percent_encoding::percent_decode_str;
 
 const ERROR_DATA_URI: &str = "E-UICP-0701";
 const ERROR_UTF8: &str = "E-UICP-0702";
 const ERROR_CSV: &str = "E-UICP-0703";
+const ERROR_LIMIT: &str = "E-UICP-0711";
+
+const PARTIAL_KIND_PROGRESS: u8 = 0;
+const PARTIAL_KIND_ROWS: u8 = 1;
+const PARTIAL_KIND_TOTAL: u8 = 2;
+// WHY: `blocking-write-and-flush` accepts at most 4096 bytes per call.
+const MAX_FRAME_BYTES: usize = 4096;
+// Upper bound on the envelope and `{offset, rows}` wrapper around the encoded rows.
+const FRAME_OVERHEAD_BYTES: usize = 64;
+const CANCEL_CHECK_ROWS: usize = 100;
+
+const DELIMITERS: [u8; 4] = [b',', b';', b'\t', b'|'];
+const QUOTES: [u8; 2] = [b'"', b'\''];
+const SNIFF_SAMPLE_BYTES: usize = 64 * 1024;
+const SNIFF_SAMPLE_ROWS: usize = 20;
+const INFER_SAMPLE_ROWS: usize = 100;
 
 struct Component;
 
 impl Guest for Component {
-    fn run(job_id: String, input: String, has_header: bool) -> Result<Rows, String> {
+    fn run(job_id: String, input: String, options: Options) -> Result<Rows, String> {
         // WHY: Preserve deterministic failures keyed by job for host telemetry.
-        let decoded = decode_input(&job_id, &input)?;
-        parse_csv(&job_id, &decoded, has_header)
+        let (text, encoding) =
+            decode_input(&job_id, &input, &options.encodings, options.max_bytes)?;
+        let dialect = sniff_dialect(&text, options.delimiter, options.quote, encoding);
+
+        let mut sink = control::open_partial_sink(&job_id);
+        let mut seq = 0u32;
+        emit_dialect(&mut sink, &job_id, &mut seq, &dialect);
+
+        let batch = match options.batch_rows {
+            0 => CANCEL_CHECK_ROWS,
+            n => n as usize,
+        };
+        let rows = parse_csv(&job_id, &text, &dialect, &options, batch, |offset, rows| {
+            if options.batch_rows > 0 {
+                emit_rows(&mut sink, &job_id, &mut seq, offset, rows);
+            }
+            if should_cancel(&job_id) {
+                return Err("cancelled".to_string());
+            }
+            Ok(())
+        })?;
+
+        let total = rows.len() - usize::from(options.has_header && !rows.is_empty());
+        emit_total(&mut sink, &job_id, &mut seq, total as u64);
+        Ok(rows)
     }
 }
 
 bindings::export!(Component with_types_in bindings);
 
-// WHY: Normalize CSV source across plain strings and data URIs.
-fn decode_input(job_id: &str, raw: &str) -> Result<String, String> {
-    if let Some(rest) = raw.strip_prefix("data:") {
-        return decode_data_uri(job_id, rest);
-    }
-    Ok(trim_bom(raw))
+// WHY: Host cancellation may be cooperative (user) or deadline-driven.
+fn should_cancel(job_id: &str) -> bool {
+    control::should_cancel(job_id) || control::remaining_ms(job_id) == 0
 }
 
-// INVARIANT: Successful result never contains a UTF-8 BOM prefix.
-fn trim_bom(source: &str) -> String {
-    const BOM: char = '\u{FEFF}';
-    if source.starts_with(BOM) {
-        source.trim_start_matches(BOM).to_string()
-    } else {
-        source.to_string()
-    }
+/// Separator, quote byte and source encoding the rows were parsed with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Dialect {
+    delimiter: u8,
+    quote: u8,
+    encoding: Encoding,
 }
 
-fn decode_data_uri(job_id: &str, rest: &str) -> Result<String, String> {
+// WHY: Normalize CSV source across plain strings and data URIs.
+// INVARIANT: Successful result never contains a byte-order mark.
+fn decode_input(
+    job_id: &str,
+    raw: &str,
+    fallbacks: &[Encoding],
+    max_bytes: Option<u64>,
+) -> Result<(String, Encoding), String> {
+    let Some(rest) = raw.strip_prefix("data:") else {
+        check_max_bytes(job_id, raw.len(), max_bytes)?;
+        return Ok((trim_bom(raw), Encoding::Utf8));
+    };
     let comma = rest.find(',').ok_or_else(|| {
         format!("{ERROR_DATA_URI}: job {job_id}: data URI missing comma separator")
     })?;
     let (meta, payload) = rest.split_at(comma);
     let body = &payload[1..];
     let lower_meta = meta.to_ascii_lowercase();
-    if lower_meta.contains(";base64") {
+    let bytes: Vec<u8> = if lower_meta.split(';').any(|p| p == "base64") {
         BASE64_ENGINE
             .decode(body.trim())
-            .map_err(|err| format!("{ERROR_DATA_URI}: job {job_id}: base64 decode failed: {err}"))
-            .and_then(|bytes| {
-                String::from_utf8(bytes)
-                    .map(|s| trim_bom(s.as_str()))
-                    .map_err(|err| {
-                        format!("{ERROR_UTF8}: job {job_id}: base64 bytes not utf-8: {err}")
-                    })
-            })
+            .map_err(|err| format!("{ERROR_DATA_URI}: job {job_id}: base64 decode failed: {err}"))?
     } else {
-        percent_decode_str(body)
-            .decode_utf8()
-            .map(|cow| trim_bom(cow.as_ref()))
-            .map_err(|err| format!("{ERROR_UTF8}: job {job_id}: percent-decoding failed: {err}"))
+        percent_decode_str(body).collect()
+    };
+    check_max_bytes(job_id, bytes.len(), max_bytes)?;
+    let charset = lower_meta
+        .split(';')
+        .find_map(|p| p.trim().strip_prefix("charset="))
+        .and_then(encoding_from_label);
+    decode_bytes(job_id, &bytes, charset, fallbacks)
+}
+
+fn trim_bom(source: &str) -> String {
+    const BOM: char = '\u{FEFF}';
+    source.trim_start_matches(BOM).to_string()
+}
+
+fn check_max_bytes(job_id: &str, len: usize, max_bytes: Option<u64>) -> Result<(), String> {
+    match max_bytes {
+        Some(max) if len as u64 > max => Err(format!(
+            "{ERROR_LIMIT}: job {job_id}: max-bytes limit exceeded ({len} > {max})"
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn encoding_from_label(label: &str) -> Option<Encoding> {
+    match label.trim_matches('"') {
+        "utf-8" | "utf8" => Some(Encoding::Utf8),
+        "utf-16le" => Some(Encoding::Utf16le),
+        "utf-16be" => Some(Encoding::Utf16be),
+        "iso-8859-1" | "latin1" | "latin-1" => Some(Encoding::Latin1),
+        "windows-1252" | "cp1252" => Some(Encoding::Windows1252),
+        _ => None,
+    }
+}
+
+fn encoding_label(encoding: Encoding) -> &'static str {
+    match encoding {
+        Encoding::Utf8 => "utf-8",
+        Encoding::Utf16le => "utf-16le",
+        Encoding::Utf16be => "utf-16be",
+        Encoding::Latin1 => "iso-8859-1",
+        Encoding::Windows1252 => "windows-1252",
+    }
+}
+
+// WHY: A byte-order mark is authoritative; otherwise the declared charset, UTF-8 and the caller's
+// fallbacks are tried in that order and the first clean decode wins.
+fn decode_bytes(
+    job_id: &str,
+    bytes: &[u8],
+    charset: Option<Encoding>,
+    fallbacks: &[Encoding],
+) -> Result<(String, Encoding), String> {
+    let boms: [(&[u8], Encoding); 3] = [
+        (b"\xEF\xBB\xBF", Encoding::Utf8),
+        (b"\xFF\xFE", Encoding::Utf16le),
+        (b"\xFE\xFF", Encoding::Utf16be),
+    ];
+    for (bom, encoding) in boms {
+        if let Some(rest) = bytes.strip_prefix(bom) {
+            return decode_as(rest, encoding)
+                .map(|text| (text, encoding))
+                .ok_or_else(|| {
+                    format!(
+                        "{ERROR_UTF8}: job {job_id}: bytes after {} byte-order mark do not decode",
+                        encoding_label(encoding)
+                    )
+                });
+        }
+    }
+
+    let mut tried: Vec<Encoding> = Vec::new();
+    for encoding in charset
+        .into_iter()
+        .chain([Encoding::Utf8])
+        .chain(fallbacks.iter().copied())
+    {
+        if tried.contains(&encoding) {
+            continue;
+        }
+        tried.push(encoding);
+        if let Some(text) = decode_as(bytes, encoding) {
+            return Ok((text, encoding));
+        }
+    }
+    let labels: Vec<&str> = tried.into_iter().map(encoding_label).collect();
+    Err(format!(
+        "{ERROR_UTF8}: job {job_id}: input is not valid {}",
+        labels.join(" or ")
+    ))
+}
+
+fn decode_as(bytes: &[u8], encoding: Encoding) -> Option<String> {
+    match encoding {
+        Encoding::Utf8 => std::str::from_utf8(bytes).ok().map(str::to_string),
+        Encoding::Utf16le | Encoding::Utf16be => {
+            let pairs = bytes.chunks_exact(2);
+            if !pairs.remainder().is_empty() {
+                return None;
+            }
+            let units: Vec<u16> = pairs
+                .map(|pair| match encoding {
+                    Encoding::Utf16le => u16::from_le_bytes([pair[0], pair[1]]),
+                    _ => u16::from_be_bytes([pair[0], pair[1]]),
+                })
+                .collect();
+            String::from_utf16(&units).ok()
+        }
+        Encoding::Latin1 => Some(bytes.iter().map(|&b| char::from(b)).collect()),
+        Encoding::Windows1252 => Some(bytes.iter().map(|&b| windows_1252_char(b)).collect()),
+    }
+}
+
+// NOTE: 0x80..=0x9F differ from Latin-1; the five unassigned bytes map to C1 controls (WHATWG).
+fn windows_1252_char(byte: u8) -> char {
+    const HIGH: [char; 32] = [
+        '\u{20AC}', '\u{0081}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}',
+        '\u{2021}', '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{008D}',
+        '\u{017D}', '\u{008F}', '\u{0090}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}',
+        '\u{2022}', '\u{2013}', '\u{2014}', '\u{02DC}', '\u{2122}', '\u{0161}', '\u{203A}',
+        '\u{0153}', '\u{009D}', '\u{017E}', '\u{0178}',
+    ];
+    match byte {
+        0x80..=0x9F => HIGH[usize::from(byte - 0x80)],
+        _ => char::from(byte),
+    }
+}
+
+// WHY: Score every candidate dialect on a leading sample; the winner splits the most rows into
+// the same number (> 1) of fields. Ties go to more fields opened by the quote byte, then to more
+// fields, then to candidate order, so plain comma/double-quote files keep the RFC 4180 defaults.
+fn sniff_dialect(
+    text: &str,
+    delimiter: Option<u8>,
+    quote: Option<u8>,
+    encoding: Encoding,
+) -> Dialect {
+    let delimiters: Vec<u8> = delimiter.map_or_else(|| DELIMITERS.to_vec(), |d| vec![d]);
+    let quotes: Vec<u8> = quote.map_or_else(|| QUOTES.to_vec(), |q| vec![q]);
+    let sample = sniff_sample(text);
+
+    let mut best = Dialect {
+        delimiter: delimiters[0],
+        quote: quotes[0],
+        encoding,
+    };
+    let mut best_score = (0, 0, 0);
+    for &q in &quotes {
+        for &d in &delimiters {
+            let score = score_dialect(sample, d, q);
+            if score.2 > 1 && score > best_score {
+                best_score = score;
+                best = Dialect {
+                    delimiter: d,
+                    quote: q,
+                    encoding,
+                };
+            }
+        }
+    }
+    best
+}
+
+// INVARIANT: The sample ends on a line boundary whenever the input is longer than the budget.
+fn sniff_sample(text: &str) -> &str {
+    if text.len() <= SNIFF_SAMPLE_BYTES {
+        return text;
+    }
+    let mut end = SNIFF_SAMPLE_BYTES;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    let head = &text[..end];
+    head.rfind('\n').map_or(head, |nl| &head[..nl])
+}
+
+/// (rows with the modal field count, fields opened by the quote byte, modal field count).
+fn score_dialect(sample: &str, delimiter: u8, quote: u8) -> (usize, usize, usize) {
+    let mut reader = ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .delimiter(delimiter)
+        .quote(quote)
+        .from_reader(sample.as_bytes());
+    let mut counts: Vec<usize> = Vec::new();
+    let mut record = ByteRecord::new();
+    while counts.len() < SNIFF_SAMPLE_ROWS {
+        match reader.read_byte_record(&mut record) {
+            Ok(true) => counts.push(record.len()),
+            _ => break,
+        }
+    }
+    let mut modal = (0, 0);
+    for &fields in &counts {
+        let rows = counts.iter().filter(|&&c| c == fields).count();
+        modal = modal.max((rows, fields));
+    }
+
+    let quoted = sample
+        .lines()
+        .take(SNIFF_SAMPLE_ROWS)
+        .flat_map(|line| line.split(char::from(delimiter)))
+        .filter(|field| field.trim_start().as_bytes().first() == Some(&quote))
+        .count();
+    (modal.0, quoted, modal.1)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum ColumnType {
+    Boolean,
+    Int,
+    Float,
+    Text,
+}
+
+// INVARIANT: Header cells are always text; only data rows feed inference.
+fn infer_column_types(rows: &[Vec<String>]) -> Vec<ColumnType> {
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    (0..width)
+        .map(|col| {
+            let mut ty: Option<ColumnType> = None;
+            for value in rows.iter().filter_map(|r| r.get(col)) {
+                let value = value.trim();
+                if value.is_empty() {
+                    continue;
+                }
+                let seen = classify(value);
+                ty = Some(match (ty, seen) {
+                    (None, seen) => seen,
+                    (Some(a), b) if a == b => a,
+                    (Some(ColumnType::Int), ColumnType::Float)
+                    | (Some(ColumnType::Float), ColumnType::Int) => ColumnType::Float,
+                    _ => ColumnType::Text,
+                });
+                if ty == Some(ColumnType::Text) {
+                    break;
+                }
+            }
+            ty.unwrap_or(ColumnType::Text)
+        })
+        .collect()
+}
+
+fn classify(value: &str) -> ColumnType {
+    if parse_bool(value).is_some() {
+        ColumnType::Boolean
+    } else if parse_int(value).is_some() {
+        ColumnType::Int
+    } else if parse_float(value).is_some() {
+        ColumnType::Float
+    } else {
+        ColumnType::Text
+    }
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    if value.eq_ignore_ascii_case("true") {
+        Some(true)
+    } else if value.eq_ignore_ascii_case("false") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+// WHY: Leading zeros mark identifiers (zip codes, account numbers); keep them as text.
+fn has_leading_zero(value: &str) -> bool {
+    let digits = value.trim_start_matches(['+', '-']);
+    digits.len() > 1 && digits.starts_with('0') && !digits.starts_with("0.")
+}
+
+fn parse_int(value: &str) -> Option<i64> {
+    if has_leading_zero(value) {
+        return None;
+    }
+    value.parse().ok()
+}
+
+fn parse_float(value: &str) -> Option<f64> {
+    if has_leading_zero(value) || !value.bytes().any(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    value.parse::<f64>().ok().filter(|f| f.is_finite())
+}
+
+// INVARIANT: A value that does not fit its column type stays text rather than being dropped.
+fn convert_cell(raw: String, ty: ColumnType) -> Cell {
+    if ty == ColumnType::Text {
+        return Cell::Text(raw);
+    }
+    let value = raw.trim();
+    if value.is_empty() {
+        return Cell::Null;
     }
+    let typed = match ty {
+        ColumnType::Boolean => parse_bool(value).map(Cell::Boolean),
+        ColumnType::Int => parse_int(value)
+            .map(Cell::Int)
+            .or_else(|| parse_float(value).map(Cell::Float)),
+        ColumnType::Float => parse_float(value).map(Cell::Float),
+        ColumnType::Text => None,
+    };
+    typed.unwrap_or(Cell::Text(raw))
+}
+
+fn convert_row(row: Vec<String>, types: &[ColumnType]) -> Vec<Cell> {
+    row.into_iter()
+        .enumerate()
+        .map(|(col, raw)| convert_cell(raw, types.get(col).copied().unwrap_or(ColumnType::Text)))
+        .collect()
 }
 
 // WHY: csv crate enforces RFC 4180 rules and surfaces granular errors.
 // INVARIANT: Returned rows retain original ordering; header row is preserved when requested.
-fn parse_csv(job_id: &str, csv_text: &str, has_header: bool) -> Result<Rows, String> {
+// `on_batch(offset, rows)` sees each run of `batch` finished rows (offset indexes the returned
+// rows, header included); with `infer_types` nothing is released before the inference sample.
+fn parse_csv(
+    job_id: &str,
+    csv_text: &str,
+    dialect: &Dialect,
+    options: &Options,
+    batch: usize,
+    mut on_batch: impl FnMut(usize, &[Vec<Cell>]) -> Result<(), String>,
+) -> Result<Rows, String> {
     let mut reader = ReaderBuilder::new()
-        .has_headers(has_header)
+        .has_headers(false)
         .flexible(true)
         .trim(Trim::None)
+        .delimiter(dialect.delimiter)
+        .quote(dialect.quote)
         .from_reader(csv_text.as_bytes());
 
     let mut rows: Rows = Vec::new();
-    if has_header {
-        let headers = reader.headers().map_err(|err| {
-            format!("{ERROR_CSV}: job {job_id}: failed to read header row: {err}")
-        })?;
-        rows.push(headers.iter().map(|cell| cell.to_string()).collect());
-    }
+    let mut pending: Vec<Vec<String>> = Vec::new();
+    let mut types: Option<Vec<ColumnType>> = (!options.infer_types).then(Vec::new);
+    let mut header_pending = options.has_header;
+    let mut data_rows = 0usize;
+    let mut emitted = 0usize;
+    let mut record = StringRecord::new();
 
-    for record in reader.records() {
-        let rec =
-            record.map_err(|err| format!("{ERROR_CSV}: job {job_id}: row parse failed: {err}"))?;
-        rows.push(rec.iter().map(|cell| cell.to_string()).collect());
+    while reader
+        .read_record(&mut record)
+        .map_err(|err| format!("{ERROR_CSV}: job {job_id}: row parse failed: {err}"))?
+    {
+        let fields: Vec<String> = record.iter().map(str::to_string).collect();
+        if header_pending {
+            header_pending = false;
+            rows.push(fields.into_iter().map(Cell::Text).collect());
+            continue;
+        }
+        data_rows += 1;
+        if let Some(max) = options.max_rows {
+            if data_rows > max as usize {
+                return Err(format!(
+                    "{ERROR_LIMIT}: job {job_id}: max-rows limit exceeded (more than {max} data rows)"
+                ));
+            }
+        }
+        pending.push(fields);
+        if types.is_none() && pending.len() >= INFER_SAMPLE_ROWS {
+            types = Some(infer_column_types(&pending));
+        }
+        if let Some(types) = &types {
+            rows.extend(pending.drain(..).map(|row| convert_row(row, types)));
+        }
+        if rows.len() - emitted >= batch {
+            on_batch(emitted, &rows[emitted..])?;
+            emitted = rows.len();
+        }
     }
 
+    let types = types.unwrap_or_else(|| infer_column_types(&pending));
+    rows.extend(pending.drain(..).map(|row| convert_row(row, &types)));
+    if rows.len() > emitted {
+        on_batch(emitted, &rows[emitted..])?;
+    }
     Ok(rows)
 }
 
+fn cell_value(cell: &Cell) -> Value {
+    match cell {
+        Cell::Null => Value::Null,
+        Cell::Boolean(b) => Value::Bool(*b),
+        Cell::Int(i) => Value::Integer(Integer::from(*i)),
+        Cell::Float(f) => Value::Float(*f),
+        Cell::Text(s) => Value::Text(s.clone()),
+    }
+}
+
+fn encoded_len(value: &Value) -> usize {
+    let mut out = Vec::new();
+    let _ = into_writer(value, &mut out);
+    out.len()
+}
+
+// WHY: Each frame must fit one blocking write, so rows are packed greedily by encoded size. A
+// row too wide for a frame on its own is left out of the preview; `offset` lets the UI spot it.
+fn pack_row_frames(offset: usize, rows: &[Vec<Cell>]) -> Vec<(usize, Vec<Value>)> {
+    let budget = MAX_FRAME_BYTES - FRAME_OVERHEAD_BYTES;
+    let mut frames: Vec<(usize, Vec<Value>)> = Vec::new();
+    let mut current: Option<(usize, Vec<Value>, usize)> = None;
+    for (idx, row) in rows.iter().enumerate() {
+        let value = Value::Array(row.iter().map(cell_value).collect());
+        let len = encoded_len(&value);
+        if len > budget {
+            if let Some((start, values, _)) = current.take() {
+                frames.push((start, values));
+            }
+            continue;
+        }
+        match &mut current {
+            Some((_, values, used)) if *used + len <= budget => {
+                values.push(value);
+                *used += len;
+            }
+            _ => {
+                if let Some((start, values, _)) = current.take() {
+                    frames.push((start, values));
+                }
+                current = Some((offset + idx, vec![value], len));
+            }
+        }
+    }
+    if let Some((start, values, _)) = current {
+        frames.push((start, values));
+    }
+    frames
+}
+
+fn emit_rows(
+    sink: &mut OutputStream,
+    job_id: &str,
+    seq: &mut u32,
+    offset: usize,
+    rows: &[Vec<Cell>],
+) {
+    for (start, values) in pack_row_frames(offset, rows) {
+        let payload = Value::Map(vec![
+            (
+                Value::Text("offset".into()),
+                Value::Integer(Integer::from(start as u64)),
+            ),
+            (Value::Text("rows".into()), Value::Array(values)),
+        ]);
+        emit_partial(
+            sink,
+            seq,
+            PARTIAL_KIND_ROWS,
+            now_microseconds(),
+            Some(payload),
+            job_id,
+        );
+    }
+}
+
+fn emit_dialect(sink: &mut OutputStream, job_id: &str, seq: &mut u32, dialect: &Dialect) {
+    let payload = Value::Map(vec![
+        (
+            Value::Text("delimiter".into()),
+            Value::Text(char::from(dialect.delimiter).to_string()),
+        ),
+        (
+            Value::Text("quote".into()),
+            Value::Text(char::from(dialect.quote).to_string()),
+        ),
+        (
+            Value::Text("encoding".into()),
+            Value::Text(encoding_label(dialect.encoding).into()),
+        ),
+    ]);
+    emit_partial(
+        sink,
+        seq,
+        PARTIAL_KIND_PROGRESS,
+        now_microseconds(),
+        Some(payload),
+        job_id,
+    );
+}
+
+fn emit_total(sink: &mut OutputStream, job_id: &str, seq: &mut u32, total: u64) {
+    let payload = Value::Map(vec![(
+        Value::Text("total".into()),
+        Value::Integer(Integer::from(total)),
+    )]);
+    emit_partial(
+        sink,
+        seq,
+        PARTIAL_KIND_TOTAL,
+        now_microseconds(),
+        Some(payload),
+        job_id,
+    );
+}
+
+fn now_microseconds() -> u64 {
+    monotonic_clock::now() / 1_000_000
+}
+
+fn emit_partial(
+    sink: &mut OutputStream,
+    seq: &mut u32,
+    kind: u8,
+    timestamp: u64,
+    payload: Option<Value>,
+    job_id: &str,
+) {
+    let frame = cbor_envelope(kind, bump_seq(seq), timestamp, payload);
+    // WHY: Blocking write ensures ordering; treat failures as fatal traps to obey fail-loud.
+    if let Err(err) = sink.blocking_write_and_flush(&frame) {
+        panic!("E-UICP-0801: job {job_id}: partial emission failed: {err:?}");
+    }
+}
+
+fn bump_seq(seq: &mut u32) -> u32 {
+    let next = seq.wrapping_add(1);
+    *seq = next;
+    next
+}
+
+fn cbor_envelope(kind: u8, seq: u32, timestamp: u64, payload: Option<Value>) -> Vec<u8> {
+    let mut entries: Vec<(Value, Value)> = vec![
+        (
+            Value::Integer(Integer::from(1u8)),
+            Value::Integer(Integer::from(kind)),
+        ),
+        (
+            Value::Integer(Integer::from(2u32)),
+            Value::Integer(Integer::from(seq)),
+        ),
+        (
+            Value::Integer(Integer::from(3u64)),
+            Value::Integer(Integer::from(timestamp)),
+        ),
+    ];
+    if let Some(p) = payload {
+        entries.push((Value::Integer(Integer::from(4u8)), p));
+    }
+    let map = Value::Map(entries);
+    let mut out = Vec::new();
+    let _ = into_writer(&map, &mut out);
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn options(has_header: bool) -> Options {
+        Options {
+            has_header,
+            delimiter: None,
+            quote: None,
+            encodings: Vec::new(),
+            infer_types: false,
+            max_rows: None,
+            max_bytes: None,
+            batch_rows: 0,
+        }
+    }
+
+    fn parse(content: &str, options: &Options) -> Result<Rows, String> {
+        let dialect = sniff_dialect(content, options.delimiter, options.quote, Encoding::Utf8);
+        parse_csv(
+            "job-test",
+            content,
+            &dialect,
+            options,
+            usize::MAX,
+            |_, _| Ok(()),
+        )
+    }
+
+    // NOTE: Generated `Cell` has no PartialEq; compare through Debug.
+    fn assert_rows(rows: &Rows, expected: &[&[Cell]]) {
+        assert_eq!(format!("{rows:?}"), format!("{expected:?}"));
+    }
+
+    fn text(s: &str) -> Cell {
+        Cell::Text(s.to_string())
+    }
+
     #[test]
     fn parses_plain_csv_with_header() {
         let content = "name,qty\nalpha,1\nbravo,2\n";
-        let rows = parse_csv("job-test", content, true).expect("csv rows");
-        assert_eq!(
-            rows,
-            vec![
-                vec!["name".to_string(), "qty".to_string()],
-                vec!["alpha".to_string(), "1".to_string()],
-                vec!["bravo".to_string(), "2".to_string()]
-            ]
+        let rows = parse(content, &options(true)).expect("csv rows");
+        assert_rows(
+            &rows,
+            &[
+                &[text("name"), text("qty")],
+                &[text("alpha"), text("1")],
+                &[text("bravo"), text("2")],
+            ],
         );
     }
 
     #[test]
     fn parses_plain_csv_without_header() {
         let content = "alpha,1\nbravo,2\n";
-        let rows = parse_csv("job-test", content, false).expect("csv rows");
-        assert_eq!(
-            rows,
-            vec![
-                vec!["alpha".to_string(), "1".to_string()],
-                vec!["bravo".to_string(), "2".to_string()]
-            ]
+        let rows = parse(content, &options(false)).expect("csv rows");
+        assert_rows(
+            &rows,
+            &[&[text("alpha"), text("1")], &[text("bravo"), text("2")]],
         );
     }
 
@@ -132,20 +717,186 @@ mod tests {
     fn decodes_base64_data_uri() {
         let data = BASE64_ENGINE.encode("name,qty\nalpha,1\n");
         let uri = format!("data:text/csv;base64,{data}");
-        let decoded = decode_input("job-b64", &uri).expect("data uri");
+        let (decoded, _) = decode_input("job-b64", &uri, &[], None).expect("data uri");
         assert_eq!(decoded, "name,qty\nalpha,1\n");
     }
 
     #[test]
     fn decodes_percent_encoded_data_uri() {
         let uri = "data:text/csv,name%2Cqty%0Aalpha%2C1%0A";
-        let decoded = decode_input("job-pct", uri).expect("data uri");
+        let (decoded, _) = decode_input("job-pct", uri, &[], None).expect("data uri");
         assert_eq!(decoded, "name,qty\nalpha,1\n");
     }
 
     #[test]
     fn rejects_broken_data_uri() {
-        let err = decode_input("job-err", "data:text/csv;base64").unwrap_err();
+        let err = decode_input("job-err", "data:text/csv;base64", &[], None).unwrap_err();
         assert!(err.contains(ERROR_DATA_URI));
     }
+
+    #[test]
+    fn sniffs_delimiter_and_quote() {
+        let cases: [(&str, u8, u8); 4] = [
+            ("a;b;c\n1;2,5;3\n4;5;6\n", b';', b'"'),
+            ("a\tb\n\"x,y\"\t2\n", b'\t', b'"'),
+            ("a|b|c\n1|2|3\n", b'|', b'"'),
+            ("'a,b',c\n'd,e',f\n", b',', b'\''),
+        ];
+        for (content, delimiter, quote) in cases {
+            let dialect = sniff_dialect(content, None, None, Encoding::Utf8);
+            assert_eq!(
+                (dialect.delimiter, dialect.quote),
+                (delimiter, quote),
+                "{content:?}"
+            );
+        }
+        // Single-column input falls back to the defaults.
+        let dialect = sniff_dialect("alpha\nbravo\n", None, None, Encoding::Utf8);
+        assert_eq!((dialect.delimiter, dialect.quote), (b',', b'"'));
+        // An explicit delimiter is never overridden.
+        let dialect = sniff_dialect("a;b\n1;2\n", Some(b','), None, Encoding::Utf8);
+        assert_eq!(dialect.delimiter, b',');
+    }
+
+    #[test]
+    fn falls_back_through_encodings() {
+        let latin = BASE64_ENGINE.encode(b"caf\xe9,\x80\n");
+        let uri = format!("data:text/csv;base64,{latin}");
+        let err = decode_input("job-enc", &uri, &[], None).unwrap_err();
+        assert!(err.contains(ERROR_UTF8), "{err}");
+
+        let (text, encoding) =
+            decode_input("job-enc", &uri, &[Encoding::Windows1252], None).expect("cp1252");
+        assert_eq!(
+            (text.as_str(), encoding),
+            ("café,€\n", Encoding::Windows1252)
+        );
+
+        let charset = format!("data:text/csv;charset=ISO-8859-1;base64,{latin}");
+        let (text, encoding) = decode_input("job-enc", &charset, &[], None).expect("latin-1");
+        assert_eq!(
+            (text.as_str(), encoding),
+            ("café,\u{80}\n", Encoding::Latin1)
+        );
+
+        let utf16: Vec<u8> = [0xFF, 0xFE]
+            .into_iter()
+            .chain("a,b\n".encode_utf16().flat_map(u16::to_le_bytes))
+            .collect();
+        let uri = format!("data:text/csv;base64,{}", BASE64_ENGINE.encode(utf16));
+        let (text, encoding) = decode_input("job-enc", &uri, &[], None).expect("utf-16");
+        assert_eq!((text.as_str(), encoding), ("a,b\n", Encoding::Utf16le));
+    }
+
+    #[test]
+    fn infers_column_types() {
+        let mut opts = options(true);
+        opts.infer_types = true;
+        let content = "id,zip,price,ok,note\n1,02134,2.5,true,x\n2,10001,3,FALSE,\n,,,,7\n";
+        let rows = parse(content, &opts).expect("csv rows");
+        assert_rows(
+            &rows,
+            &[
+                &[
+                    text("id"),
+                    text("zip"),
+                    text("price"),
+                    text("ok"),
+                    text("note"),
+                ],
+                &[
+                    Cell::Int(1),
+                    text("02134"),
+                    Cell::Float(2.5),
+                    Cell::Boolean(true),
+                    text("x"),
+                ],
+                &[
+                    Cell::Int(2),
+                    text("10001"),
+                    Cell::Float(3.0),
+                    Cell::Boolean(false),
+                    text(""),
+                ],
+                &[Cell::Null, text(""), Cell::Null, Cell::Null, text("7")],
+            ],
+        );
+    }
+
+    #[test]
+    fn enforces_limits() {
+        let mut opts = options(true);
+        opts.max_rows = Some(2);
+        assert!(parse("h\n1\n2\n", &opts).is_ok());
+        let err = parse("h\n1\n2\n3\n", &opts).unwrap_err();
+        assert!(
+            err.starts_with(ERROR_LIMIT) && err.contains("limit exceeded"),
+            "{err}"
+        );
+
+        let err = decode_input("job-max", "a,b\n1,2\n", &[], Some(4)).unwrap_err();
+        assert!(
+            err.starts_with(ERROR_LIMIT) && err.contains("max-bytes"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn releases_batches_in_order() {
+        let mut opts = options(true);
+        let content: String = std::iter::once("n".to_string())
+            .chain((0..250).map(|i| i.to_string()))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let dialect = sniff_dialect(&content, None, None, Encoding::Utf8);
+
+        let mut seen: Vec<(usize, usize)> = Vec::new();
+        let rows = parse_csv("job-b", &content, &dialect, &opts, 100, |offset, rows| {
+            seen.push((offset, rows.len()));
+            Ok(())
+        })
+        .expect("csv rows");
+        assert_eq!(rows.len(), 251);
+        assert_eq!(seen, vec![(0, 100), (100, 100), (200, 51)]);
+
+        // Inference holds rows back until its sample is complete.
+        opts.infer_types = true;
+        seen.clear();
+        parse_csv("job-b", &content, &dialect, &opts, 10, |offset, rows| {
+            seen.push((offset, rows.len()));
+            Ok(())
+        })
+        .expect("csv rows");
+        assert_eq!(seen.first(), Some(&(0, INFER_SAMPLE_ROWS + 1)));
+
+        let err = parse_csv("job-b", &content, &dialect, &opts, 10, |_, _| {
+            Err("cancelled".to_string())
+        })
+        .unwrap_err();
+        assert_eq!(err, "cancelled");
+    }
+
+    #[test]
+    fn packs_frames_within_write_budget() {
+        let narrow: Vec<Vec<Cell>> = (0..1000).map(|i| vec![Cell::Int(i), text("row")]).collect();
+        let wide = vec![vec![text(&"x".repeat(MAX_FRAME_BYTES))]];
+        let rows: Vec<Vec<Cell>> = narrow.iter().chain(&wide).chain(&narrow).cloned().collect();
+
+        let frames = pack_row_frames(7, &rows);
+        assert!(frames.len() > 2);
+        assert_eq!(frames[0].0, 7);
+        let packed: usize = frames.iter().map(|(_, values)| values.len()).sum();
+        assert_eq!(packed, 2000, "wide row is skipped");
+        for (start, values) in frames {
+            let payload = Value::Map(vec![
+                (
+                    Value::Text("offset".into()),
+                    Value::Integer(Integer::from(start as u64)),
+                ),
+                (Value::Text("rows".into()), Value::Array(values)),
+            ]);
+            let frame = cbor_envelope(PARTIAL_KIND_ROWS, u32::MAX, u64::MAX, Some(payload));
+            assert!(frame.len() <= MAX_FRAME_BYTES, "frame len {}", frame.len());
+        }
+    }
 }
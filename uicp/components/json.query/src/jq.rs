@@ -0,0 +1,1864 @@
+//! jq subset: paths, `.[]`, slices, `|`, `,`, literals, array/object construction, arithmetic,
+//! comparisons, `and`/`or`/`//`, `if`/`elif`/`else`, postfix `?` and the builtins in
+//! [`BUILTINS`]. Variables, `reduce`/`foreach`, `def`, `try`/`catch` and assignment operators are
+//! rejected at parse time.
+
+use std::cmp::Ordering;
+
+use serde_json::{Map, Value};
+
+use crate::bindings::exports::uicp::task_json_query::query::Error;
+use crate::{
+    as_f64, compare_values, number, type_name, values_equal, Ticker, MAX_EXPRESSION_DEPTH,
+};
+
+/// Builtins by name and arity; checked at parse time so typos surface as invalid expressions.
+const BUILTINS: &[(&str, usize)] = &[
+    ("empty", 0),
+    ("error", 0),
+    ("error", 1),
+    ("not", 0),
+    ("length", 0),
+    ("keys", 0),
+    ("keys_unsorted", 0),
+    ("values", 0),
+    ("add", 0),
+    ("any", 0),
+    ("any", 1),
+    ("all", 0),
+    ("all", 1),
+    ("first", 0),
+    ("first", 1),
+    ("last", 0),
+    ("last", 1),
+    ("limit", 2),
+    ("range", 1),
+    ("range", 2),
+    ("reverse", 0),
+    ("sort", 0),
+    ("sort_by", 1),
+    ("group_by", 1),
+    ("unique", 0),
+    ("unique_by", 1),
+    ("min", 0),
+    ("max", 0),
+    ("min_by", 1),
+    ("max_by", 1),
+    ("flatten", 0),
+    ("flatten", 1),
+    ("to_entries", 0),
+    ("from_entries", 0),
+    ("with_entries", 1),
+    ("select", 1),
+    ("map", 1),
+    ("map_values", 1),
+    ("has", 1),
+    ("contains", 1),
+    ("recurse", 0),
+    ("recurse", 1),
+    ("type", 0),
+    ("tostring", 0),
+    ("tonumber", 0),
+    ("tojson", 0),
+    ("fromjson", 0),
+    ("ascii_downcase", 0),
+    ("ascii_upcase", 0),
+    ("floor", 0),
+    ("ceil", 0),
+    ("abs", 0),
+    ("join", 1),
+    ("split", 1),
+    ("test", 1),
+    ("startswith", 1),
+    ("endswith", 1),
+    ("ltrimstr", 1),
+    ("rtrimstr", 1),
+];
+
+const KEYWORDS: &[&str] = &[
+    "then", "elif", "else", "end", "and", "or", "as", "def", "reduce", "foreach", "try", "catch",
+    "label", "import", "include",
+];
+
+// NOTE: Longest operators first so `//` and `==` win over `/` and `=`-less prefixes.
+const OPERATORS: &[&str] = &[
+    "//", "==", "!=", "<=", ">=", "|", ",", "<", ">", "+", "-", "*", "/", "%", "[", "]", "{", "}",
+    "(", ")", ":", ";", "?",
+];
+
+// INVARIANT: Left-associative chains (`|`, `,`, `//`, `and`, `or`, arithmetic, postfix suffixes)
+// are stored flat and evaluated iteratively, so AST depth only grows with bracket nesting, which
+// the parser caps at MAX_EXPRESSION_DEPTH.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Ast {
+    Identity,
+    RecurseAll,
+    Literal(Value),
+    Array(Option<Box<Ast>>),
+    Object(Vec<(ObjectKey, Ast)>),
+    Postfix(Box<Ast>, Vec<Suffix>),
+    Pipe(Vec<Ast>),
+    Comma(Vec<Ast>),
+    Alternative(Vec<Ast>),
+    Or(Vec<Ast>),
+    And(Vec<Ast>),
+    Compare(Box<Ast>, CompareOp, Box<Ast>),
+    Arith(Box<Ast>, Vec<(ArithOp, Ast)>),
+    Neg(Box<Ast>),
+    If(Vec<(Ast, Ast)>, Option<Box<Ast>>),
+    Call(String, Vec<Ast>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ObjectKey {
+    Name(String),
+    Expr(Ast),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Suffix {
+    Field(String),
+    Index(Ast),
+    Slice(Option<Ast>, Option<Ast>),
+    Iterate,
+    Try,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+}
+
+impl CompareOp {
+    fn holds(self, ordering: Ordering) -> bool {
+        match self {
+            CompareOp::Eq => ordering.is_eq(),
+            CompareOp::Ne => ordering.is_ne(),
+            CompareOp::Lt => ordering.is_lt(),
+            CompareOp::Lte => ordering.is_le(),
+            CompareOp::Gt => ordering.is_gt(),
+            CompareOp::Gte => ordering.is_ge(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ArithOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Mod,
+}
+
+impl ArithOp {
+    fn verb(self) -> &'static str {
+        match self {
+            ArithOp::Add => "added",
+            ArithOp::Sub => "subtracted",
+            ArithOp::Mul => "multiplied",
+            ArithOp::Div => "divided",
+            ArithOp::Mod => "divided (remainder)",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Dot,
+    DotDot,
+    Field(String),
+    Ident(String),
+    Number(serde_json::Number),
+    Str(String),
+    Op(&'static str),
+}
+
+pub(crate) fn parse(expression: &str) -> Result<Ast, String> {
+    let tokens = tokenize(expression)?;
+    if tokens.is_empty() {
+        return Err("expression is empty".into());
+    }
+    let mut parser = Parser {
+        tokens,
+        pos: 0,
+        depth: 0,
+    };
+    let ast = parser.pipe()?;
+    if parser.pos < parser.tokens.len() {
+        return Err(parser.error("expected end of expression"));
+    }
+    Ok(ast)
+}
+
+fn tokenize(src: &str) -> Result<Vec<(Token, usize)>, String> {
+    let bytes = src.as_bytes();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let start = pos;
+        let b = bytes[pos];
+        let token = if b.is_ascii_whitespace() {
+            pos += 1;
+            continue;
+        } else if b == b'#' {
+            while pos < bytes.len() && bytes[pos] != b'\n' {
+                pos += 1;
+            }
+            continue;
+        } else if b == b'.' {
+            pos += 1;
+            match bytes.get(pos) {
+                Some(b'.') => {
+                    pos += 1;
+                    Token::DotDot
+                }
+                Some(c) if is_ident_start(*c) => Token::Field(lex_ident(bytes, &mut pos)),
+                Some(b'"') => Token::Field(lex_string(src, &mut pos)?),
+                _ => Token::Dot,
+            }
+        } else if is_ident_start(b) {
+            Token::Ident(lex_ident(bytes, &mut pos))
+        } else if b.is_ascii_digit() {
+            Token::Number(lex_number(src, &mut pos)?)
+        } else if b == b'"' {
+            Token::Str(lex_string(src, &mut pos)?)
+        } else if let Some(op) = OPERATORS
+            .iter()
+            .find(|op| bytes[pos..].starts_with(op.as_bytes()))
+        {
+            pos += op.len();
+            Token::Op(op)
+        } else if b == b'$' {
+            return Err(format!("variables are not supported (offset {pos})"));
+        } else if b == b'=' {
+            return Err(format!(
+                "assignment operators are not supported (offset {pos})"
+            ));
+        } else {
+            return Err(format!("unexpected character at offset {pos}"));
+        };
+        tokens.push((token, start));
+    }
+    Ok(tokens)
+}
+
+fn is_ident_start(b: u8) -> bool {
+    b.is_ascii_alphabetic() || b == b'_'
+}
+
+fn lex_ident(bytes: &[u8], pos: &mut usize) -> String {
+    let start = *pos;
+    while bytes
+        .get(*pos)
+        .is_some_and(|b| b.is_ascii_alphanumeric() || *b == b'_')
+    {
+        *pos += 1;
+    }
+    String::from_utf8_lossy(&bytes[start..*pos]).into_owned()
+}
+
+fn lex_number(src: &str, pos: &mut usize) -> Result<serde_json::Number, String> {
+    let bytes = src.as_bytes();
+    let start = *pos;
+    let digits = |pos: &mut usize| {
+        while bytes.get(*pos).is_some_and(u8::is_ascii_digit) {
+            *pos += 1;
+        }
+    };
+    digits(pos);
+    if bytes.get(*pos) == Some(&b'.') && bytes.get(*pos + 1).is_some_and(u8::is_ascii_digit) {
+        *pos += 1;
+        digits(pos);
+    }
+    if matches!(bytes.get(*pos), Some(b'e' | b'E')) {
+        *pos += 1;
+        if matches!(bytes.get(*pos), Some(b'+' | b'-')) {
+            *pos += 1;
+        }
+        digits(pos);
+    }
+    serde_json::from_str(&src[start..*pos]).map_err(|_| format!("invalid number at offset {start}"))
+}
+
+// WHY: jq string escapes are JSON's, so the literal is delimited here and decoded by serde_json.
+fn lex_string(src: &str, pos: &mut usize) -> Result<String, String> {
+    let bytes = src.as_bytes();
+    let start = *pos;
+    let mut i = start + 1;
+    loop {
+        match bytes.get(i) {
+            None => return Err(format!("unterminated string at offset {start}")),
+            Some(b'\\') if bytes.get(i + 1) == Some(&b'(') => {
+                return Err(format!(
+                    "string interpolation is not supported (offset {i})"
+                ))
+            }
+            Some(b'\\') => i += 2,
+            Some(b'"') => break,
+            Some(_) => i += 1,
+        }
+    }
+    *pos = i + 1;
+    serde_json::from_str(&src[start..*pos])
+        .map_err(|err| format!("invalid string at offset {start}: {err}"))
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    depth: usize,
+}
+
+impl Parser {
+    fn error(&self, what: &str) -> String {
+        match self.tokens.get(self.pos) {
+            Some((token, offset)) => {
+                format!("{what}, found {} at offset {offset}", describe(token))
+            }
+            None => format!("{what}, found end of expression"),
+        }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(token, _)| token)
+    }
+
+    fn eat_op(&mut self, op: &str) -> bool {
+        if matches!(self.peek(), Some(Token::Op(o)) if *o == op) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_op(&mut self, op: &str) -> Result<(), String> {
+        if self.eat_op(op) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{op}`")))
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        if matches!(self.peek(), Some(Token::Ident(name)) if name == keyword) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), String> {
+        if self.eat_keyword(keyword) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{keyword}`")))
+        }
+    }
+
+    fn enter(&mut self) -> Result<(), String> {
+        self.depth += 1;
+        if self.depth > MAX_EXPRESSION_DEPTH {
+            return Err(self.error("expression nests too deeply"));
+        }
+        Ok(())
+    }
+
+    fn pipe(&mut self) -> Result<Ast, String> {
+        let mut stages = vec![self.comma()?];
+        while self.eat_op("|") {
+            stages.push(self.comma()?);
+        }
+        Ok(chain(stages, Ast::Pipe))
+    }
+
+    fn comma(&mut self) -> Result<Ast, String> {
+        let mut items = vec![self.alternative()?];
+        while self.eat_op(",") {
+            items.push(self.alternative()?);
+        }
+        Ok(chain(items, Ast::Comma))
+    }
+
+    fn alternative(&mut self) -> Result<Ast, String> {
+        let mut items = vec![self.or()?];
+        while self.eat_op("//") {
+            items.push(self.or()?);
+        }
+        Ok(chain(items, Ast::Alternative))
+    }
+
+    fn or(&mut self) -> Result<Ast, String> {
+        let mut items = vec![self.and()?];
+        while self.eat_keyword("or") {
+            items.push(self.and()?);
+        }
+        Ok(chain(items, Ast::Or))
+    }
+
+    fn and(&mut self) -> Result<Ast, String> {
+        let mut items = vec![self.compare()?];
+        while self.eat_keyword("and") {
+            items.push(self.compare()?);
+        }
+        Ok(chain(items, Ast::And))
+    }
+
+    fn compare(&mut self) -> Result<Ast, String> {
+        let left = self.additive()?;
+        let Some(op) = self.compare_op() else {
+            return Ok(left);
+        };
+        let right = self.additive()?;
+        if self.compare_op().is_some() {
+            return Err(self.error("comparison operators do not chain"));
+        }
+        Ok(Ast::Compare(Box::new(left), op, Box::new(right)))
+    }
+
+    fn compare_op(&mut self) -> Option<CompareOp> {
+        let op = match self.peek()? {
+            Token::Op("==") => CompareOp::Eq,
+            Token::Op("!=") => CompareOp::Ne,
+            Token::Op("<") => CompareOp::Lt,
+            Token::Op("<=") => CompareOp::Lte,
+            Token::Op(">") => CompareOp::Gt,
+            Token::Op(">=") => CompareOp::Gte,
+            _ => return None,
+        };
+        self.pos += 1;
+        Some(op)
+    }
+
+    fn additive(&mut self) -> Result<Ast, String> {
+        self.arith(
+            &[("+", ArithOp::Add), ("-", ArithOp::Sub)],
+            Self::multiplicative,
+        )
+    }
+
+    fn multiplicative(&mut self) -> Result<Ast, String> {
+        self.arith(
+            &[
+                ("*", ArithOp::Mul),
+                ("/", ArithOp::Div),
+                ("%", ArithOp::Mod),
+            ],
+            Self::unary,
+        )
+    }
+
+    fn arith(
+        &mut self,
+        ops: &[(&str, ArithOp)],
+        operand: fn(&mut Self) -> Result<Ast, String>,
+    ) -> Result<Ast, String> {
+        let first = operand(self)?;
+        let mut rest = Vec::new();
+        while let Some(op) = ops
+            .iter()
+            .find(|(text, _)| self.eat_op(text))
+            .map(|(_, op)| *op)
+        {
+            rest.push((op, operand(self)?));
+        }
+        Ok(if rest.is_empty() {
+            first
+        } else {
+            Ast::Arith(Box::new(first), rest)
+        })
+    }
+
+    fn unary(&mut self) -> Result<Ast, String> {
+        if self.eat_op("-") {
+            self.enter()?;
+            let inner = self.unary()?;
+            self.depth -= 1;
+            return Ok(Ast::Neg(Box::new(inner)));
+        }
+        self.postfix()
+    }
+
+    fn postfix(&mut self) -> Result<Ast, String> {
+        let base = self.term()?;
+        let mut suffixes = Vec::new();
+        loop {
+            match self.peek() {
+                Some(Token::Field(name)) => {
+                    let name = name.clone();
+                    self.pos += 1;
+                    suffixes.push(Suffix::Field(name));
+                }
+                // NOTE: `.a.[0]` is accepted as `.a[0]`, as jq 1.7 does.
+                Some(Token::Dot)
+                    if matches!(self.tokens.get(self.pos + 1), Some((Token::Op("["), _))) =>
+                {
+                    self.pos += 1;
+                }
+                Some(Token::Op("[")) => {
+                    self.pos += 1;
+                    suffixes.push(self.bracket()?);
+                }
+                Some(Token::Op("?")) => {
+                    self.pos += 1;
+                    suffixes.push(Suffix::Try);
+                }
+                _ => break,
+            }
+        }
+        Ok(if suffixes.is_empty() {
+            base
+        } else {
+            Ast::Postfix(Box::new(base), suffixes)
+        })
+    }
+
+    fn bracket(&mut self) -> Result<Suffix, String> {
+        if self.eat_op("]") {
+            return Ok(Suffix::Iterate);
+        }
+        if self.eat_op(":") {
+            let to = self.pipe()?;
+            self.expect_op("]")?;
+            return Ok(Suffix::Slice(None, Some(to)));
+        }
+        let first = self.pipe()?;
+        if self.eat_op(":") {
+            let to = if self.eat_op("]") {
+                None
+            } else {
+                let to = self.pipe()?;
+                self.expect_op("]")?;
+                Some(to)
+            };
+            return Ok(Suffix::Slice(Some(first), to));
+        }
+        self.expect_op("]")?;
+        Ok(Suffix::Index(first))
+    }
+
+    fn term(&mut self) -> Result<Ast, String> {
+        self.enter()?;
+        let ast = self.term_inner()?;
+        self.depth -= 1;
+        Ok(ast)
+    }
+
+    fn term_inner(&mut self) -> Result<Ast, String> {
+        let Some(token) = self.peek().cloned() else {
+            return Err(self.error("expected a term"));
+        };
+        // WHY: Field tokens are left for `postfix` so `.a.b` parses as one suffix chain.
+        if matches!(token, Token::Field(_)) {
+            return Ok(Ast::Identity);
+        }
+        self.pos += 1;
+        match token {
+            Token::Dot => Ok(Ast::Identity),
+            Token::DotDot => Ok(Ast::RecurseAll),
+            Token::Number(n) => Ok(Ast::Literal(Value::Number(n))),
+            Token::Str(s) => Ok(Ast::Literal(Value::String(s))),
+            Token::Op("(") => {
+                let inner = self.pipe()?;
+                self.expect_op(")")?;
+                Ok(inner)
+            }
+            Token::Op("[") => {
+                if self.eat_op("]") {
+                    return Ok(Ast::Array(None));
+                }
+                let inner = self.pipe()?;
+                self.expect_op("]")?;
+                Ok(Ast::Array(Some(Box::new(inner))))
+            }
+            Token::Op("{") => self.object(),
+            Token::Ident(name) => self.ident(name),
+            _ => {
+                self.pos -= 1;
+                Err(self.error("expected a term"))
+            }
+        }
+    }
+
+    fn ident(&mut self, name: String) -> Result<Ast, String> {
+        match name.as_str() {
+            "true" => return Ok(Ast::Literal(Value::Bool(true))),
+            "false" => return Ok(Ast::Literal(Value::Bool(false))),
+            "null" => return Ok(Ast::Literal(Value::Null)),
+            "if" => return self.conditional(),
+            keyword if KEYWORDS.contains(&keyword) => {
+                self.pos -= 1;
+                return Err(self.error("expected a term"));
+            }
+            _ => {}
+        }
+        let mut args = Vec::new();
+        if self.eat_op("(") {
+            args.push(self.pipe()?);
+            while self.eat_op(";") {
+                args.push(self.pipe()?);
+            }
+            self.expect_op(")")?;
+        }
+        if !BUILTINS.contains(&(name.as_str(), args.len())) {
+            return Err(format!("{name}/{} is not defined", args.len()));
+        }
+        Ok(Ast::Call(name, args))
+    }
+
+    fn conditional(&mut self) -> Result<Ast, String> {
+        // INVARIANT: Each `elif` nests one more evaluation frame, so it counts toward the depth.
+        let mut elifs = 0;
+        let mut branches = Vec::new();
+        loop {
+            let cond = self.pipe()?;
+            self.expect_keyword("then")?;
+            let then = self.pipe()?;
+            branches.push((cond, then));
+            if !self.eat_keyword("elif") {
+                break;
+            }
+            self.enter()?;
+            elifs += 1;
+        }
+        let otherwise = if self.eat_keyword("else") {
+            Some(Box::new(self.pipe()?))
+        } else {
+            None
+        };
+        self.expect_keyword("end")?;
+        self.depth -= elifs;
+        Ok(Ast::If(branches, otherwise))
+    }
+
+    fn object(&mut self) -> Result<Ast, String> {
+        let mut entries = Vec::new();
+        if self.eat_op("}") {
+            return Ok(Ast::Object(entries));
+        }
+        loop {
+            let key = match self.peek().cloned() {
+                Some(Token::Ident(name) | Token::Str(name)) => {
+                    self.pos += 1;
+                    ObjectKey::Name(name)
+                }
+                Some(Token::Op("(")) => {
+                    self.pos += 1;
+                    let expr = self.pipe()?;
+                    self.expect_op(")")?;
+                    ObjectKey::Expr(expr)
+                }
+                _ => return Err(self.error("expected an object key")),
+            };
+            let value = if self.eat_op(":") {
+                self.alternative()?
+            } else {
+                match &key {
+                    ObjectKey::Name(name) => {
+                        Ast::Postfix(Box::new(Ast::Identity), vec![Suffix::Field(name.clone())])
+                    }
+                    ObjectKey::Expr(_) => return Err(self.error("expected `:`")),
+                }
+            };
+            entries.push((key, value));
+            if self.eat_op("}") {
+                return Ok(Ast::Object(entries));
+            }
+            self.expect_op(",")?;
+        }
+    }
+}
+
+fn chain(mut items: Vec<Ast>, wrap: fn(Vec<Ast>) -> Ast) -> Ast {
+    if items.len() == 1 {
+        items.remove(0)
+    } else {
+        wrap(items)
+    }
+}
+
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Dot => "`.`".into(),
+        Token::DotDot => "`..`".into(),
+        Token::Field(name) => format!("`.{name}`"),
+        Token::Ident(name) => format!("`{name}`"),
+        Token::Number(n) => format!("`{n}`"),
+        Token::Str(_) => "string".into(),
+        Token::Op(op) => format!("`{op}`"),
+    }
+}
+
+/// Every output of `filter` applied to `document`, in jq generator order.
+pub(crate) fn evaluate(
+    filter: &Ast,
+    document: Value,
+    ticker: &mut Ticker<'_>,
+) -> Result<Vec<Value>, Error> {
+    eval(filter, &document, ticker)
+}
+
+type Outputs = Result<Vec<Value>, Error>;
+
+fn fail<T>(message: impl Into<String>) -> Result<T, Error> {
+    Err(Error::Eval(message.into()))
+}
+
+fn one(value: Value) -> Outputs {
+    Ok(vec![value])
+}
+
+fn truthy(value: &Value) -> bool {
+    !matches!(value, Value::Null | Value::Bool(false))
+}
+
+fn eval(ast: &Ast, input: &Value, t: &mut Ticker<'_>) -> Outputs {
+    match ast {
+        Ast::Identity => one(input.clone()),
+        Ast::RecurseAll => recurse(input, None, t),
+        Ast::Literal(value) => one(value.clone()),
+        Ast::Array(None) => one(Value::Array(Vec::new())),
+        Ast::Array(Some(inner)) => one(Value::Array(eval(inner, input, t)?)),
+        Ast::Object(entries) => construct_object(entries, input, t),
+        Ast::Postfix(base, suffixes) => eval_postfix(base, suffixes, input, t),
+        Ast::Pipe(stages) => {
+            let mut values = vec![input.clone()];
+            for stage in stages {
+                let mut next = Vec::new();
+                for value in &values {
+                    next.extend(eval(stage, value, t)?);
+                }
+                values = next;
+            }
+            Ok(values)
+        }
+        Ast::Comma(items) => {
+            let mut out = Vec::new();
+            for item in items {
+                out.extend(eval(item, input, t)?);
+            }
+            Ok(out)
+        }
+        // WHY: jq suppresses errors on the left of `//` and only falls through when no truthy
+        // output was produced; the last operand's outputs (and errors) pass through as-is.
+        Ast::Alternative(items) => {
+            for item in &items[..items.len() - 1] {
+                match eval(item, input, t) {
+                    Ok(values) => {
+                        let kept: Vec<Value> = values.into_iter().filter(truthy).collect();
+                        if !kept.is_empty() {
+                            return Ok(kept);
+                        }
+                    }
+                    Err(Error::Eval(_)) => {}
+                    Err(err) => return Err(err),
+                }
+            }
+            eval(&items[items.len() - 1], input, t)
+        }
+        Ast::Or(items) | Ast::And(items) => {
+            let short_circuit = matches!(ast, Ast::Or(_));
+            let mut results: Vec<bool> = eval(&items[0], input, t)?.iter().map(truthy).collect();
+            for item in &items[1..] {
+                let mut next = Vec::new();
+                for result in results {
+                    if result == short_circuit {
+                        next.push(result);
+                    } else {
+                        next.extend(eval(item, input, t)?.iter().map(truthy));
+                    }
+                }
+                results = next;
+            }
+            Ok(results.into_iter().map(Value::Bool).collect())
+        }
+        Ast::Compare(left, op, right) => {
+            let rights = eval(right, input, t)?;
+            let lefts = eval(left, input, t)?;
+            let mut out = Vec::with_capacity(rights.len() * lefts.len());
+            for r in &rights {
+                for l in &lefts {
+                    out.push(Value::Bool(op.holds(compare_values(l, r))));
+                }
+            }
+            Ok(out)
+        }
+        // NOTE: jq iterates the right operand in the outer loop: `(1,2) + (10,20)` is 11,12,21,22.
+        Ast::Arith(first, rest) => {
+            let mut acc = eval(first, input, t)?;
+            for (op, operand) in rest {
+                let rights = eval(operand, input, t)?;
+                let mut next = Vec::with_capacity(rights.len() * acc.len());
+                for r in &rights {
+                    for l in &acc {
+                        next.push(arith(*op, l, r)?);
+                    }
+                }
+                acc = next;
+            }
+            Ok(acc)
+        }
+        Ast::Neg(inner) => eval(inner, input, t)?
+            .iter()
+            .map(|value| match value {
+                Value::Number(n) => Ok(number(-as_f64(n))),
+                other => fail(format!("{} cannot be negated", type_name(other))),
+            })
+            .collect(),
+        Ast::If(branches, otherwise) => eval_if(branches, otherwise.as_deref(), input, t),
+        Ast::Call(name, args) => eval_call(name, args, input, t),
+    }
+}
+
+fn eval_if(
+    branches: &[(Ast, Ast)],
+    otherwise: Option<&Ast>,
+    input: &Value,
+    t: &mut Ticker<'_>,
+) -> Outputs {
+    let Some(((cond, then), rest)) = branches.split_first() else {
+        return match otherwise {
+            Some(ast) => eval(ast, input, t),
+            None => one(input.clone()),
+        };
+    };
+    let mut out = Vec::new();
+    for value in eval(cond, input, t)? {
+        if truthy(&value) {
+            out.extend(eval(then, input, t)?);
+        } else {
+            out.extend(eval_if(rest, otherwise, input, t)?);
+        }
+    }
+    Ok(out)
+}
+
+fn eval_postfix(base: &Ast, suffixes: &[Suffix], input: &Value, t: &mut Ticker<'_>) -> Outputs {
+    let mut current = eval(base, input, t);
+    for suffix in suffixes {
+        current = match (suffix, current) {
+            // INVARIANT: `?` only swallows evaluation errors; cancellation always propagates.
+            (Suffix::Try, Err(Error::Eval(_))) => Ok(Vec::new()),
+            (Suffix::Try, other) => other,
+            (_, Ok(values)) => apply_suffix(suffix, values, input, t),
+            (_, Err(err)) => Err(err),
+        };
+    }
+    current
+}
+
+// NOTE: Index and slice expressions see the term's input (`.`), not the value being indexed.
+fn apply_suffix(suffix: &Suffix, values: Vec<Value>, input: &Value, t: &mut Ticker<'_>) -> Outputs {
+    let mut out = Vec::with_capacity(values.len());
+    match suffix {
+        Suffix::Field(name) => {
+            let key = Value::String(name.clone());
+            for value in &values {
+                out.push(index_value(value, &key)?);
+            }
+        }
+        Suffix::Index(expr) => {
+            let keys = eval(expr, input, t)?;
+            for value in &values {
+                for key in &keys {
+                    out.push(index_value(value, key)?);
+                }
+            }
+        }
+        Suffix::Slice(from, to) => {
+            let bound = |expr: &Option<Ast>, t: &mut Ticker<'_>| match expr {
+                Some(expr) => eval(expr, input, t),
+                None => one(Value::Null),
+            };
+            let tos = bound(to, t)?;
+            let froms = bound(from, t)?;
+            for value in &values {
+                for to in &tos {
+                    for from in &froms {
+                        out.push(slice_value(value, from, to)?);
+                    }
+                }
+            }
+        }
+        Suffix::Iterate => {
+            for value in values {
+                iterate(value, &mut out, t)?;
+            }
+        }
+        Suffix::Try => return Ok(values),
+    }
+    Ok(out)
+}
+
+fn iterate(value: Value, out: &mut Vec<Value>, t: &mut Ticker<'_>) -> Result<(), Error> {
+    match value {
+        Value::Array(items) => {
+            for item in items {
+                t.tick()?;
+                out.push(item);
+            }
+        }
+        Value::Object(map) => {
+            for (_, item) in map {
+                t.tick()?;
+                out.push(item);
+            }
+        }
+        other => return fail(format!("Cannot iterate over {}", type_name(&other))),
+    }
+    Ok(())
+}
+
+fn index_value(value: &Value, key: &Value) -> Result<Value, Error> {
+    match (value, key) {
+        (Value::Null, Value::String(_) | Value::Number(_) | Value::Null) => Ok(Value::Null),
+        (Value::Object(map), Value::String(name)) => {
+            Ok(map.get(name).cloned().unwrap_or(Value::Null))
+        }
+        (Value::Array(items), Value::Number(n)) => {
+            let len = items.len() as f64;
+            let mut idx = as_f64(n).floor();
+            if idx < 0.0 {
+                idx += len;
+            }
+            Ok(if (0.0..len).contains(&idx) {
+                items[idx as usize].clone()
+            } else {
+                Value::Null
+            })
+        }
+        (_, Value::String(name)) => {
+            fail(format!("Cannot index {} with \"{name}\"", type_name(value)))
+        }
+        _ => fail(format!(
+            "Cannot index {} with {}",
+            type_name(value),
+            type_name(key)
+        )),
+    }
+}
+
+fn slice_value(value: &Value, from: &Value, to: &Value) -> Result<Value, Error> {
+    let bound = |v: &Value| match v {
+        Value::Null => Ok(None),
+        Value::Number(n) => Ok(Some(as_f64(n))),
+        _ => fail("Start and end indices of an array slice must be numbers"),
+    };
+    let (from, to) = (bound(from)?, bound(to)?);
+    let range = |len: usize| {
+        let len_f = len as f64;
+        let clamp = |i: f64| {
+            let i = if i < 0.0 { i + len_f } else { i };
+            i.clamp(0.0, len_f) as usize
+        };
+        let start = clamp(from.map_or(0.0, f64::floor));
+        let end = clamp(to.map_or(len_f, f64::ceil));
+        start..end.max(start)
+    };
+    match value {
+        Value::Null => Ok(Value::Null),
+        Value::Array(items) => Ok(Value::Array(items[range(items.len())].to_vec())),
+        Value::String(s) => {
+            let chars: Vec<char> = s.chars().collect();
+            Ok(Value::String(chars[range(chars.len())].iter().collect()))
+        }
+        other => fail(format!("Cannot index {} with object", type_name(other))),
+    }
+}
+
+fn construct_object(entries: &[(ObjectKey, Ast)], input: &Value, t: &mut Ticker<'_>) -> Outputs {
+    let mut objects = vec![Map::new()];
+    for (key, value) in entries {
+        let keys = match key {
+            ObjectKey::Name(name) => vec![Value::String(name.clone())],
+            ObjectKey::Expr(expr) => eval(expr, input, t)?,
+        };
+        let values = eval(value, input, t)?;
+        let mut next = Vec::with_capacity(objects.len() * keys.len() * values.len());
+        for object in &objects {
+            for key in &keys {
+                let Value::String(key) = key else {
+                    return fail(format!(
+                        "Object keys must be strings, not {}",
+                        type_name(key)
+                    ));
+                };
+                for value in &values {
+                    let mut object = object.clone();
+                    object.insert(key.clone(), value.clone());
+                    next.push(object);
+                }
+            }
+        }
+        objects = next;
+    }
+    Ok(objects.into_iter().map(Value::Object).collect())
+}
+
+// INVARIANT: Pre-order and iterative, so `..` over deep documents cannot exhaust the stack.
+fn recurse(input: &Value, step: Option<&Ast>, t: &mut Ticker<'_>) -> Outputs {
+    let mut out = Vec::new();
+    let mut stack = vec![input.clone()];
+    while let Some(value) = stack.pop() {
+        let children = match step {
+            Some(step) => eval(step, &value, t)?,
+            None => match &value {
+                Value::Array(_) | Value::Object(_) => {
+                    let mut children = Vec::new();
+                    iterate(value.clone(), &mut children, t)?;
+                    children
+                }
+                _ => Vec::new(),
+            },
+        };
+        stack.extend(children.into_iter().rev());
+        out.push(value);
+    }
+    Ok(out)
+}
+
+fn arith(op: ArithOp, l: &Value, r: &Value) -> Result<Value, Error> {
+    match (op, l, r) {
+        (ArithOp::Add, Value::Null, v) | (ArithOp::Add, v, Value::Null) => Ok(v.clone()),
+        (_, Value::Number(a), Value::Number(b)) => numeric(op, as_f64(a), as_f64(b)),
+        (ArithOp::Add, Value::String(a), Value::String(b)) => Ok(Value::String(format!("{a}{b}"))),
+        (ArithOp::Add, Value::Array(a), Value::Array(b)) => {
+            Ok(Value::Array(a.iter().chain(b).cloned().collect()))
+        }
+        (ArithOp::Add, Value::Object(a), Value::Object(b)) => {
+            let mut merged = a.clone();
+            merged.extend(b.clone());
+            Ok(Value::Object(merged))
+        }
+        (ArithOp::Sub, Value::Array(a), Value::Array(b)) => Ok(Value::Array(
+            a.iter()
+                .filter(|x| !b.iter().any(|y| values_equal(x, y)))
+                .cloned()
+                .collect(),
+        )),
+        (ArithOp::Mul, Value::String(s), Value::Number(n))
+        | (ArithOp::Mul, Value::Number(n), Value::String(s)) => {
+            let n = as_f64(n);
+            Ok(if n > 0.0 {
+                Value::String(s.repeat(n.ceil() as usize))
+            } else {
+                Value::Null
+            })
+        }
+        (ArithOp::Mul, Value::Object(a), Value::Object(b)) => {
+            Ok(Value::Object(deep_merge(a.clone(), b)))
+        }
+        (ArithOp::Div, Value::String(a), Value::String(b)) => Ok(split_string(a, b)),
+        _ => fail(format!(
+            "{} and {} cannot be {}",
+            type_name(l),
+            type_name(r),
+            op.verb()
+        )),
+    }
+}
+
+fn numeric(op: ArithOp, a: f64, b: f64) -> Result<Value, Error> {
+    Ok(match op {
+        ArithOp::Add => number(a + b),
+        ArithOp::Sub => number(a - b),
+        ArithOp::Mul => number(a * b),
+        ArithOp::Div if b == 0.0 => {
+            return fail(format!(
+                "{a} and {b} cannot be divided because the divisor is zero"
+            ))
+        }
+        ArithOp::Div => number(a / b),
+        // NOTE: jq truncates both operands to integers; the sign follows the dividend.
+        ArithOp::Mod => {
+            let (a, b) = (a as i64, b as i64);
+            if b == 0 {
+                return fail(format!(
+                    "{a} and {b} cannot be divided because the divisor is zero"
+                ));
+            }
+            Value::from(a.wrapping_rem(b))
+        }
+    })
+}
+
+fn deep_merge(mut base: Map<String, Value>, overlay: &Map<String, Value>) -> Map<String, Value> {
+    for (key, value) in overlay {
+        match (base.get_mut(key), value) {
+            (Some(Value::Object(existing)), Value::Object(incoming)) => {
+                *existing = deep_merge(std::mem::take(existing), incoming);
+            }
+            _ => {
+                base.insert(key.clone(), value.clone());
+            }
+        }
+    }
+    base
+}
+
+fn split_string(s: &str, separator: &str) -> Value {
+    if s.is_empty() {
+        return Value::Array(Vec::new());
+    }
+    if separator.is_empty() {
+        return Value::Array(s.chars().map(|c| Value::String(c.to_string())).collect());
+    }
+    Value::Array(
+        s.split(separator)
+            .map(|part| Value::String(part.into()))
+            .collect(),
+    )
+}
+
+/// Applies `f` to `input` once per output of `arg`; jq evaluates value arguments as generators.
+fn with_arg(
+    arg: &Ast,
+    input: &Value,
+    t: &mut Ticker<'_>,
+    f: impl Fn(&Value) -> Result<Value, Error>,
+) -> Outputs {
+    eval(arg, input, t)?.iter().map(f).collect()
+}
+
+/// `(key, item)` pairs for the `*_by` builtins; the key is the array of `f`'s outputs.
+fn keyed(input: &Value, f: &Ast, t: &mut Ticker<'_>) -> Result<Vec<(Value, Value)>, Error> {
+    let Value::Array(items) = input else {
+        return fail(format!(
+            "{} cannot be sorted, as it is not an array",
+            type_name(input)
+        ));
+    };
+    let mut pairs = Vec::with_capacity(items.len());
+    for item in items {
+        t.tick()?;
+        pairs.push((Value::Array(eval(f, item, t)?), item.clone()));
+    }
+    pairs.sort_by(|a, b| compare_values(&a.0, &b.0));
+    Ok(pairs)
+}
+
+fn grouped(input: &Value, f: &Ast, t: &mut Ticker<'_>) -> Result<Vec<Vec<Value>>, Error> {
+    let mut groups: Vec<(Value, Vec<Value>)> = Vec::new();
+    for (key, item) in keyed(input, f, t)? {
+        match groups.last_mut() {
+            Some((last, group)) if values_equal(last, &key) => group.push(item),
+            _ => groups.push((key, vec![item])),
+        }
+    }
+    Ok(groups.into_iter().map(|(_, group)| group).collect())
+}
+
+fn sorted(input: &Value) -> Result<Vec<Value>, Error> {
+    let Value::Array(items) = input else {
+        return fail(format!(
+            "{} cannot be sorted, as it is not an array",
+            type_name(input)
+        ));
+    };
+    let mut items = items.clone();
+    items.sort_by(compare_values);
+    Ok(items)
+}
+
+fn length(input: &Value) -> Result<Value, Error> {
+    Ok(match input {
+        Value::Null => Value::from(0),
+        Value::Bool(_) => return fail("boolean has no length"),
+        Value::Number(n) => number(as_f64(n).abs()),
+        Value::String(s) => Value::from(s.chars().count()),
+        Value::Array(items) => Value::from(items.len()),
+        Value::Object(map) => Value::from(map.len()),
+    })
+}
+
+fn keys(input: &Value) -> Result<Value, Error> {
+    match input {
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            Ok(Value::Array(
+                keys.into_iter().map(|k| Value::String(k.clone())).collect(),
+            ))
+        }
+        Value::Array(items) => Ok(Value::Array((0..items.len()).map(Value::from).collect())),
+        other => fail(format!("{} has no keys", type_name(other))),
+    }
+}
+
+fn to_entries(input: &Value) -> Result<Value, Error> {
+    let Value::Object(map) = input else {
+        return fail(format!("{} has no keys", type_name(input)));
+    };
+    Ok(Value::Array(
+        map.iter()
+            .map(|(key, value)| {
+                let mut entry = Map::new();
+                entry.insert("key".into(), Value::String(key.clone()));
+                entry.insert("value".into(), value.clone());
+                Value::Object(entry)
+            })
+            .collect(),
+    ))
+}
+
+fn from_entries(input: &Value) -> Result<Value, Error> {
+    let Value::Array(entries) = input else {
+        return fail(format!("Cannot iterate over {}", type_name(input)));
+    };
+    let mut out = Map::new();
+    for entry in entries {
+        let Value::Object(entry) = entry else {
+            return fail(format!("Cannot index {} with \"key\"", type_name(entry)));
+        };
+        let pick = |names: &[&str]| names.iter().find_map(|name| entry.get(*name));
+        let key = match pick(&["key", "k", "name", "Name", "K", "Key"]) {
+            Some(Value::String(s)) => s.clone(),
+            Some(v @ (Value::Number(_) | Value::Bool(_))) => v.to_string(),
+            other => {
+                return fail(format!(
+                    "Cannot use {} as object key",
+                    other.map_or("null", type_name)
+                ))
+            }
+        };
+        let value = pick(&["value", "v", "Value"])
+            .cloned()
+            .unwrap_or(Value::Null);
+        out.insert(key, value);
+    }
+    Ok(Value::Object(out))
+}
+
+fn flatten(input: &Value, depth: f64) -> Result<Value, Error> {
+    fn walk(items: &[Value], depth: f64, out: &mut Vec<Value>) {
+        for item in items {
+            match item {
+                Value::Array(inner) if depth > 0.0 => walk(inner, depth - 1.0, out),
+                other => out.push(other.clone()),
+            }
+        }
+    }
+    if depth < 0.0 {
+        return fail("flatten depth must not be negative");
+    }
+    let Value::Array(items) = input else {
+        return fail(format!("Cannot iterate over {}", type_name(input)));
+    };
+    let mut out = Vec::new();
+    walk(items, depth, &mut out);
+    Ok(Value::Array(out))
+}
+
+// NOTE: jq containment: substrings for strings, element-wise "some element contains" for arrays,
+// key-wise for objects, equality otherwise; mismatched kinds are an error.
+fn contains(a: &Value, b: &Value) -> Result<bool, Error> {
+    match (a, b) {
+        (Value::String(a), Value::String(b)) => Ok(a.contains(b.as_str())),
+        (Value::Array(a), Value::Array(b)) => {
+            for needle in b {
+                let mut found = false;
+                for hay in a {
+                    if type_name(hay) == type_name(needle) && contains(hay, needle)? {
+                        found = true;
+                        break;
+                    }
+                }
+                if !found {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+        (Value::Object(a), Value::Object(b)) => {
+            for (key, needle) in b {
+                match a.get(key) {
+                    Some(hay) if contains(hay, needle)? => {}
+                    _ => return Ok(false),
+                }
+            }
+            Ok(true)
+        }
+        _ if type_name(a) == type_name(b) => Ok(values_equal(a, b)),
+        _ => fail(format!(
+            "{} and {} cannot have their containment checked",
+            type_name(a),
+            type_name(b)
+        )),
+    }
+}
+
+fn error_message(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn string_pair<'a>(
+    input: &'a Value,
+    arg: &'a Value,
+    name: &str,
+) -> Result<(&'a str, &'a str), Error> {
+    match (input, arg) {
+        (Value::String(a), Value::String(b)) => Ok((a, b)),
+        _ => fail(format!("{name}() requires string inputs")),
+    }
+}
+
+fn numeric_input(input: &Value, name: &str) -> Result<f64, Error> {
+    match input {
+        Value::Number(n) => Ok(as_f64(n)),
+        other => fail(format!("{} ({name}) number required", type_name(other))),
+    }
+}
+
+fn eval_call(name: &str, args: &[Ast], input: &Value, t: &mut Ticker<'_>) -> Outputs {
+    match (name, args) {
+        ("empty", []) => Ok(Vec::new()),
+        ("error", []) => fail(error_message(input)),
+        ("error", [message]) => match eval(message, input, t)?.first() {
+            Some(message) => fail(error_message(message)),
+            None => Ok(Vec::new()),
+        },
+        ("not", []) => one(Value::Bool(!truthy(input))),
+        ("length", []) => one(length(input)?),
+        ("keys" | "keys_unsorted", []) => one(keys(input)?),
+        ("values", []) => Ok(if input.is_null() {
+            Vec::new()
+        } else {
+            vec![input.clone()]
+        }),
+        ("add", []) => {
+            let items = match input {
+                Value::Null => return one(Value::Null),
+                Value::Object(map) => map.values().cloned().collect(),
+                Value::Array(items) => items.clone(),
+                other => return fail(format!("Cannot iterate over {}", type_name(other))),
+            };
+            let mut acc = Value::Null;
+            for item in &items {
+                acc = arith(ArithOp::Add, &acc, item)?;
+            }
+            one(acc)
+        }
+        ("any" | "all", _) => {
+            let mut items = Vec::new();
+            iterate(input.clone(), &mut items, t)?;
+            let mut flags = Vec::with_capacity(items.len());
+            for item in &items {
+                match args.first() {
+                    Some(f) => flags.extend(eval(f, item, t)?.iter().map(truthy)),
+                    None => flags.push(truthy(item)),
+                }
+            }
+            let result = if name == "any" {
+                flags.into_iter().any(|flag| flag)
+            } else {
+                flags.into_iter().all(|flag| flag)
+            };
+            one(Value::Bool(result))
+        }
+        ("first", []) => one(index_value(input, &Value::from(0))?),
+        ("last", []) => one(index_value(input, &Value::from(-1))?),
+        ("first", [f]) => Ok(eval(f, input, t)?.into_iter().take(1).collect()),
+        ("last", [f]) => Ok(eval(f, input, t)?.pop().into_iter().collect()),
+        ("limit", [n, f]) => {
+            let mut out = Vec::new();
+            for n in eval(n, input, t)? {
+                let n = numeric_input(&n, "limit")?;
+                if n > 0.0 {
+                    out.extend(eval(f, input, t)?.into_iter().take(n as usize));
+                }
+            }
+            Ok(out)
+        }
+        ("range", [upto]) => {
+            let mut out = Vec::new();
+            for upto in eval(upto, input, t)? {
+                range(0.0, numeric_input(&upto, "range")?, &mut out, t)?;
+            }
+            Ok(out)
+        }
+        ("range", [from, upto]) => {
+            let uptos = eval(upto, input, t)?;
+            let mut out = Vec::new();
+            for from in eval(from, input, t)? {
+                for upto in &uptos {
+                    range(
+                        numeric_input(&from, "range")?,
+                        numeric_input(upto, "range")?,
+                        &mut out,
+                        t,
+                    )?;
+                }
+            }
+            Ok(out)
+        }
+        ("reverse", []) => one(match input {
+            Value::Null => Value::Array(Vec::new()),
+            Value::Array(items) => Value::Array(items.iter().rev().cloned().collect()),
+            Value::String(s) => Value::String(s.chars().rev().collect()),
+            other => return fail(format!("Cannot reverse {}", type_name(other))),
+        }),
+        ("sort", []) => one(Value::Array(sorted(input)?)),
+        ("unique", []) => {
+            let mut items = sorted(input)?;
+            items.dedup_by(|a, b| values_equal(a, b));
+            one(Value::Array(items))
+        }
+        ("min", []) => one(sorted(input)?.into_iter().next().unwrap_or(Value::Null)),
+        ("max", []) => one(sorted(input)?.pop().unwrap_or(Value::Null)),
+        ("sort_by", [f]) => one(Value::Array(
+            keyed(input, f, t)?
+                .into_iter()
+                .map(|(_, item)| item)
+                .collect(),
+        )),
+        ("group_by", [f]) => one(Value::Array(
+            grouped(input, f, t)?
+                .into_iter()
+                .map(Value::Array)
+                .collect(),
+        )),
+        ("unique_by", [f]) => one(Value::Array(
+            grouped(input, f, t)?
+                .into_iter()
+                .filter_map(|group| group.into_iter().next())
+                .collect(),
+        )),
+        ("min_by", [f]) => one(keyed(input, f, t)?
+            .into_iter()
+            .next()
+            .map_or(Value::Null, |(_, item)| item)),
+        ("max_by", [f]) => one(keyed(input, f, t)?
+            .pop()
+            .map_or(Value::Null, |(_, item)| item)),
+        ("flatten", []) => one(flatten(input, f64::INFINITY)?),
+        ("flatten", [depth]) => with_arg(depth, input, t, |depth| {
+            flatten(input, numeric_input(depth, "flatten")?)
+        }),
+        ("to_entries", []) => one(to_entries(input)?),
+        ("from_entries", []) => one(from_entries(input)?),
+        ("with_entries", [f]) => {
+            let Value::Array(entries) = to_entries(input)? else {
+                return fail("to_entries did not produce an array");
+            };
+            let mut mapped = Vec::with_capacity(entries.len());
+            for entry in &entries {
+                t.tick()?;
+                mapped.extend(eval(f, entry, t)?);
+            }
+            one(from_entries(&Value::Array(mapped))?)
+        }
+        ("select", [f]) => Ok(eval(f, input, t)?
+            .iter()
+            .filter(|value| truthy(value))
+            .map(|_| input.clone())
+            .collect()),
+        ("map", [f]) => {
+            let mut items = Vec::new();
+            iterate(input.clone(), &mut items, t)?;
+            let mut out = Vec::with_capacity(items.len());
+            for item in &items {
+                out.extend(eval(f, item, t)?);
+            }
+            one(Value::Array(out))
+        }
+        // NOTE: As in jq 1.7, the first output of `f` replaces each value; no output drops it.
+        ("map_values", [f]) => match input {
+            Value::Object(map) => {
+                let mut out = Map::new();
+                for (key, value) in map {
+                    t.tick()?;
+                    if let Some(first) = eval(f, value, t)?.into_iter().next() {
+                        out.insert(key.clone(), first);
+                    }
+                }
+                one(Value::Object(out))
+            }
+            Value::Array(items) => {
+                let mut out = Vec::with_capacity(items.len());
+                for item in items {
+                    t.tick()?;
+                    out.extend(eval(f, item, t)?.into_iter().take(1));
+                }
+                one(Value::Array(out))
+            }
+            other => fail(format!("Cannot iterate over {}", type_name(other))),
+        },
+        ("has", [key]) => with_arg(key, input, t, |key| match (input, key) {
+            (Value::Object(map), Value::String(k)) => Ok(Value::Bool(map.contains_key(k))),
+            (Value::Array(items), Value::Number(n)) => {
+                let idx = as_f64(n);
+                Ok(Value::Bool(idx >= 0.0 && idx < items.len() as f64))
+            }
+            _ => fail(format!(
+                "Cannot check whether {} has a {} key",
+                type_name(input),
+                type_name(key)
+            )),
+        }),
+        ("contains", [needle]) => with_arg(needle, input, t, |needle| {
+            Ok(Value::Bool(contains(input, needle)?))
+        }),
+        ("recurse", []) => recurse(input, None, t),
+        ("recurse", [f]) => recurse(input, Some(f), t),
+        ("type", []) => one(Value::String(type_name(input).into())),
+        ("tostring", []) => one(match input {
+            Value::String(_) => input.clone(),
+            other => Value::String(other.to_string()),
+        }),
+        ("tonumber", []) => one(match input {
+            Value::Number(_) => input.clone(),
+            Value::String(s) => match s.trim().parse::<f64>() {
+                Ok(f) if f.is_finite() => number(f),
+                _ => return fail(format!("Cannot parse '{s}' as a number")),
+            },
+            other => return fail(format!("{} cannot be parsed as a number", type_name(other))),
+        }),
+        ("tojson", []) => one(Value::String(input.to_string())),
+        ("fromjson", []) => match input {
+            Value::String(s) => serde_json::from_str(s)
+                .map(|value| vec![value])
+                .map_err(|err| Error::Eval(format!("{s} (while parsing '{s}'): {err}"))),
+            other => fail(format!("{} cannot be parsed as JSON", type_name(other))),
+        },
+        ("ascii_downcase" | "ascii_upcase", []) => match input {
+            Value::String(s) if name == "ascii_downcase" => {
+                one(Value::String(s.to_ascii_lowercase()))
+            }
+            Value::String(s) => one(Value::String(s.to_ascii_uppercase())),
+            _ => fail(format!("{name} input must be a string")),
+        },
+        ("floor", []) => one(number(numeric_input(input, name)?.floor())),
+        ("ceil", []) => one(number(numeric_input(input, name)?.ceil())),
+        ("abs", []) => one(number(numeric_input(input, name)?.abs())),
+        ("join", [separator]) => with_arg(separator, input, t, |separator| {
+            let (Value::Array(items), Value::String(separator)) = (input, separator) else {
+                return fail(format!(
+                    "Cannot join {} with {}",
+                    type_name(input),
+                    type_name(separator)
+                ));
+            };
+            let mut parts = Vec::with_capacity(items.len());
+            for item in items {
+                parts.push(match item {
+                    Value::Null => String::new(),
+                    Value::String(s) => s.clone(),
+                    Value::Number(_) | Value::Bool(_) => item.to_string(),
+                    other => return fail(format!("Cannot join with {}", type_name(other))),
+                });
+            }
+            Ok(Value::String(parts.join(separator)))
+        }),
+        ("split", [separator]) => with_arg(separator, input, t, |separator| {
+            let (s, separator) = string_pair(input, separator, name)?;
+            Ok(split_string(s, separator))
+        }),
+        ("test", [pattern]) => with_arg(pattern, input, t, |pattern| {
+            let (s, pattern) = string_pair(input, pattern, name)?;
+            let regex = regex_lite::Regex::new(pattern)
+                .map_err(|err| Error::Eval(format!("{pattern} is not a valid regex: {err}")))?;
+            Ok(Value::Bool(regex.is_match(s)))
+        }),
+        ("startswith", [prefix]) => with_arg(prefix, input, t, |prefix| {
+            let (s, prefix) = string_pair(input, prefix, name)?;
+            Ok(Value::Bool(s.starts_with(prefix)))
+        }),
+        ("endswith", [suffix]) => with_arg(suffix, input, t, |suffix| {
+            let (s, suffix) = string_pair(input, suffix, name)?;
+            Ok(Value::Bool(s.ends_with(suffix)))
+        }),
+        ("ltrimstr" | "rtrimstr", [affix]) => with_arg(affix, input, t, |affix| {
+            let (Value::String(s), Value::String(affix)) = (input, affix) else {
+                return Ok(input.clone());
+            };
+            let trimmed = if name == "ltrimstr" {
+                s.strip_prefix(affix.as_str())
+            } else {
+                s.strip_suffix(affix.as_str())
+            };
+            Ok(Value::String(trimmed.unwrap_or(s).to_string()))
+        }),
+        _ => fail(format!("{name}/{} is not defined", args.len())),
+    }
+}
+
+// NOTE: Each output ticks so unbounded ranges stay cancellable.
+fn range(from: f64, upto: f64, out: &mut Vec<Value>, t: &mut Ticker<'_>) -> Result<(), Error> {
+    let mut i = from;
+    while i < upto {
+        t.tick()?;
+        out.push(number(i));
+        i += 1.0;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn run(expression: &str, document: Value) -> Result<Vec<Value>, Error> {
+        let filter = parse(expression).unwrap_or_else(|err| panic!("{expression}: {err}"));
+        let mut ticker = Ticker {
+            visited: 0,
+            on_item: Box::new(|_| Ok(())),
+        };
+        evaluate(&filter, document, &mut ticker)
+    }
+
+    fn check(expression: &str, document: Value, expected: Vec<Value>) {
+        match run(expression, document) {
+            Ok(out) => assert_eq!(out, expected, "{expression}"),
+            Err(err) => panic!("{expression}: {err:?}"),
+        }
+    }
+
+    #[test]
+    fn paths_iteration_and_slices() {
+        let doc = json!({"a": {"b": [1, 2, 3, 4]}, "k": "b", "s": "héllo"});
+        check(".a.b[0]", doc.clone(), vec![json!(1)]);
+        check(".a.b[-1]", doc.clone(), vec![json!(4)]);
+        check(".a.b[1:3]", doc.clone(), vec![json!([2, 3])]);
+        check(".a.b[:-2]", doc.clone(), vec![json!([1, 2])]);
+        check(".s[1:3]", doc.clone(), vec![json!("él")]);
+        check(".a[.k] | length", doc.clone(), vec![json!(4)]);
+        check(
+            r#"."a"."b"[]"#,
+            doc.clone(),
+            vec![json!(1), json!(2), json!(3), json!(4)],
+        );
+        check(".a.[\"b\"][2]", doc.clone(), vec![json!(3)]);
+        check(".missing.deeper", doc.clone(), vec![json!(null)]);
+        check(".a.b[10]", doc.clone(), vec![json!(null)]);
+    }
+
+    #[test]
+    fn pipes_commas_and_construction() {
+        let doc = json!({"user": "ada", "titles": ["x", "y"], "n": 2});
+        check(".user, .n", doc.clone(), vec![json!("ada"), json!(2)]);
+        check(
+            "[.titles[] | ascii_upcase]",
+            doc.clone(),
+            vec![json!(["X", "Y"])],
+        );
+        check(
+            "{user, title: .titles[]}",
+            doc.clone(),
+            vec![
+                json!({"user": "ada", "title": "x"}),
+                json!({"user": "ada", "title": "y"}),
+            ],
+        );
+        check(
+            r#"{(.user): .n, "n2": (.n * 2)}"#,
+            doc.clone(),
+            vec![json!({"ada": 2, "n2": 4})],
+        );
+        check(
+            "(1, 2) + (10, 20)",
+            json!(null),
+            vec![json!(11), json!(12), json!(21), json!(22)],
+        );
+    }
+
+    #[test]
+    fn arithmetic_follows_jq() {
+        check("1 + 2 * 3 - 4 / 2", json!(null), vec![json!(5)]);
+        check("7 % 3, -7 % 3", json!(null), vec![json!(1), json!(-1)]);
+        check(".a + .b", json!({"a": [1], "b": [2]}), vec![json!([1, 2])]);
+        check(". - [2]", json!([1, 2, 3, 2]), vec![json!([1, 3])]);
+        check(
+            "{a: {b: 1}} * {a: {c: 2}}",
+            json!(null),
+            vec![json!({"a": {"b": 1, "c": 2}})],
+        );
+        check(r#""a,b" / ",""#, json!(null), vec![json!(["a", "b"])]);
+        check(r#""ab" * 2"#, json!(null), vec![json!("abab")]);
+        check("null + 1", json!(null), vec![json!(1)]);
+        check("0.1 + 0.2 > 0.3", json!(null), vec![json!(true)]);
+        assert!(matches!(run("1 / 0", json!(null)), Err(Error::Eval(_))));
+        assert!(matches!(
+            run(r#"1 + "a""#, json!(null)),
+            Err(Error::Eval(_))
+        ));
+    }
+
+    #[test]
+    fn conditionals_logic_and_alternatives() {
+        check(
+            r#".[] | if . > 2 then "big" elif . == 2 then "two" else "small" end"#,
+            json!([1, 2, 3]),
+            vec![json!("small"), json!("two"), json!("big")],
+        );
+        check("if . then 1 end", json!(false), vec![json!(false)]);
+        check(
+            "true and (true, false)",
+            json!(null),
+            vec![json!(true), json!(false)],
+        );
+        check(
+            "(true, false) or false",
+            json!(null),
+            vec![json!(true), json!(false)],
+        );
+        check(".a // .b // 3", json!({"b": false}), vec![json!(3)]);
+        check(".a // 1", json!({"a": 0}), vec![json!(0)]);
+        check("(.[] | .x) // \"none\"", json!([1]), vec![json!("none")]);
+        check("[.[] | .a?]", json!([1, {"a": 2}]), vec![json!([2])]);
+        check("[.[] | not]", json!([null, 0]), vec![json!([true, false])]);
+    }
+
+    #[test]
+    fn builtins_cover_common_transforms() {
+        let people = json!([
+            {"name": "b", "age": 30, "team": "x"},
+            {"name": "a", "age": 25, "team": "y"},
+            {"name": "c", "age": 30, "team": "x"}
+        ]);
+        check(
+            "map(.name) | join(\"-\")",
+            people.clone(),
+            vec![json!("b-a-c")],
+        );
+        check(
+            "sort_by(.age) | map(.name)",
+            people.clone(),
+            vec![json!(["a", "b", "c"])],
+        );
+        check(
+            "group_by(.team) | map(length)",
+            people.clone(),
+            vec![json!([2, 1])],
+        );
+        check("unique_by(.age) | length", people.clone(), vec![json!(2)]);
+        check(
+            "max_by(.age).name, min_by(.age).name",
+            people.clone(),
+            vec![json!("c"), json!("a")],
+        );
+        check(
+            "map(select(.age > 26)) | length",
+            people.clone(),
+            vec![json!(2)],
+        );
+        check(
+            "[.[].age] | add / length",
+            people.clone(),
+            vec![json!(85.0 / 3.0)],
+        );
+        check(
+            "to_entries | map(.key)",
+            json!({"b": 1, "a": 2}),
+            vec![json!(["a", "b"])],
+        );
+        check(
+            "with_entries(select(.value > 1))",
+            json!({"b": 1, "a": 2}),
+            vec![json!({"a": 2})],
+        );
+        check(
+            "keys, length",
+            json!({"b": 1, "a": 2}),
+            vec![json!(["a", "b"]), json!(2)],
+        );
+        check(
+            "[range(3)], [range(2; 4)]",
+            json!(null),
+            vec![json!([0, 1, 2]), json!([2, 3])],
+        );
+        check(
+            "[limit(2; .[])], first(.[]), last",
+            json!([5, 6, 7]),
+            vec![json!([5, 6]), json!(5), json!(7)],
+        );
+        check(
+            "flatten, flatten(1)",
+            json!([1, [2, [3]]]),
+            vec![json!([1, 2, 3]), json!([1, 2, [3]])],
+        );
+        check(
+            "sort, unique, min, max",
+            json!([3, 1, 3]),
+            vec![json!([1, 3, 3]), json!([1, 3]), json!(1), json!(3)],
+        );
+        check(
+            "[.[] | type]",
+            json!([null, true, 1, "s", [], {}]),
+            vec![json!([
+                "null", "boolean", "number", "string", "array", "object"
+            ])],
+        );
+        check(
+            r#"test("^a.c$"), startswith("ab"), endswith("c"), ltrimstr("a"), split("b")"#,
+            json!("abc"),
+            vec![
+                json!(true),
+                json!(true),
+                json!(true),
+                json!("bc"),
+                json!(["a", "c"]),
+            ],
+        );
+        check(
+            r#"contains({a: [1]}), has("b")"#,
+            json!({"a": [1, 2]}),
+            vec![json!(true), json!(false)],
+        );
+        check(
+            "tojson, (tojson | fromjson), (\"12\" | tonumber), (3.7 | floor, ceil)",
+            json!([1]),
+            vec![json!("[1]"), json!([1]), json!(12), json!(3), json!(4)],
+        );
+        check(
+            "[recurse | select(type == \"number\")]",
+            json!({"a": [1, {"b": 2}]}),
+            vec![json!([1, 2])],
+        );
+        check("[..] | length", json!({"a": [1, {"b": 2}]}), vec![json!(5)]);
+        check("map_values(. + 1)", json!({"a": 1}), vec![json!({"a": 2})]);
+        check("[.[] | values]", json!([1, null]), vec![json!([1])]);
+        assert!(
+            matches!(run(r#"error("boom")"#, json!(null)), Err(Error::Eval(msg)) if msg == "boom")
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_syntax() {
+        for bad in [
+            "",
+            ".a |",
+            ". as $x | $x",
+            ".a |= 1",
+            "reduce .[] as $x (0; . + $x)",
+            "nosuch",
+            "length(1)",
+            "\"\\(1)\"",
+            "1 < 2 < 3",
+            "{(.a)}",
+            ".[",
+            "if . then 1",
+        ] {
+            assert!(parse(bad).is_err(), "{bad}");
+        }
+        let deep = format!("{}1{}", "[".repeat(80), "]".repeat(80));
+        assert!(parse(&deep).is_err());
+        let shallow = format!("{}1{}", "[".repeat(10), "]".repeat(10));
+        assert!(parse(&shallow).is_ok());
+        let long_chain = vec![".a"; 5000].join("");
+        assert!(
+            parse(&long_chain).is_ok(),
+            "flat chains are not depth-limited"
+        );
+    }
+}
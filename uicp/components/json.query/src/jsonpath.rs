@@ -0,0 +1,740 @@
+//! JSONPath (RFC 9535 subset): `$`, `.name`, `['name']`, `[index]`, `[start:end:step]`,
+//! `*`, unions, `..` descent and `?` filters with `@`/`$` paths, comparisons, `&&`, `||`, `!`.
+
+use serde_json::Value;
+
+use crate::bindings::exports::uicp::task_json_query::query::Error;
+use crate::{as_f64, compare_values, values_equal, Ticker, MAX_EXPRESSION_DEPTH};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Path {
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Segment {
+    descendant: bool,
+    selectors: Vec<Selector>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Selector {
+    Name(String),
+    Index(i64),
+    Wildcard,
+    Slice(Option<i64>, Option<i64>, Option<i64>),
+    Filter(Filter),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Filter {
+    Or(Vec<Filter>),
+    And(Vec<Filter>),
+    Not(Box<Filter>),
+    Exists(Operand),
+    Compare(Operand, CompareOp, Operand),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Operand {
+    Literal(Value),
+    /// `@` (relative) or `$` (absolute) path; compares as its single node, if any.
+    Query {
+        absolute: bool,
+        path: Path,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+}
+
+pub(crate) fn parse(expression: &str) -> Result<Path, String> {
+    let mut parser = Parser {
+        src: expression.as_bytes(),
+        pos: 0,
+        depth: 0,
+    };
+    parser.skip_ws();
+    if !parser.eat(b'$') {
+        return Err("JSONPath must start with `$`".into());
+    }
+    let path = parser.segments()?;
+    parser.skip_ws();
+    if parser.pos < parser.src.len() {
+        return Err(parser.error("unexpected trailing input"));
+    }
+    Ok(path)
+}
+
+struct Parser<'a> {
+    src: &'a [u8],
+    pos: usize,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, msg: &str) -> String {
+        format!("{msg} at offset {}", self.pos)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.src.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, b: u8) -> bool {
+        if self.peek() == Some(b) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_str(&mut self, s: &str) -> bool {
+        if self.src[self.pos..].starts_with(s.as_bytes()) {
+            self.pos += s.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, b: u8) -> Result<(), String> {
+        self.skip_ws();
+        if self.eat(b) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{}`", char::from(b))))
+        }
+    }
+
+    fn skip_ws(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn segments(&mut self) -> Result<Path, String> {
+        let mut segments = Vec::new();
+        loop {
+            if self.eat_str("..") {
+                let selectors = match self.peek() {
+                    Some(b'[') => self.bracket()?,
+                    Some(b'*') => {
+                        self.pos += 1;
+                        vec![Selector::Wildcard]
+                    }
+                    _ => vec![Selector::Name(self.name()?)],
+                };
+                segments.push(Segment {
+                    descendant: true,
+                    selectors,
+                });
+            } else if self.eat(b'.') {
+                let selector = if self.eat(b'*') {
+                    Selector::Wildcard
+                } else {
+                    Selector::Name(self.name()?)
+                };
+                segments.push(Segment {
+                    descendant: false,
+                    selectors: vec![selector],
+                });
+            } else if self.peek() == Some(b'[') {
+                let selectors = self.bracket()?;
+                segments.push(Segment {
+                    descendant: false,
+                    selectors,
+                });
+            } else {
+                return Ok(Path { segments });
+            }
+        }
+    }
+
+    fn name(&mut self) -> Result<String, String> {
+        let start = self.pos;
+        while let Some(b) = self.peek() {
+            if b.is_ascii_alphanumeric() || b == b'_' || b == b'-' || b >= 0x80 {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        if start == self.pos {
+            return Err(self.error("expected member name"));
+        }
+        // SAFETY: Boundaries sit on ASCII bytes or the ends of the original `&str`.
+        Ok(String::from_utf8_lossy(&self.src[start..self.pos]).into_owned())
+    }
+
+    fn bracket(&mut self) -> Result<Vec<Selector>, String> {
+        self.expect(b'[')?;
+        let mut selectors = vec![self.selector()?];
+        loop {
+            self.skip_ws();
+            if self.eat(b']') {
+                return Ok(selectors);
+            }
+            self.expect(b',')?;
+            selectors.push(self.selector()?);
+        }
+    }
+
+    fn selector(&mut self) -> Result<Selector, String> {
+        self.skip_ws();
+        match self.peek() {
+            Some(b'\'' | b'"') => Ok(Selector::Name(self.string()?)),
+            Some(b'*') => {
+                self.pos += 1;
+                Ok(Selector::Wildcard)
+            }
+            Some(b'?') => {
+                self.pos += 1;
+                Ok(Selector::Filter(self.filter_or()?))
+            }
+            _ => {
+                let start = self.int_opt()?;
+                self.skip_ws();
+                if !self.eat(b':') {
+                    return start
+                        .map(Selector::Index)
+                        .ok_or_else(|| self.error("expected selector"));
+                }
+                let end = self.int_opt()?;
+                self.skip_ws();
+                let step = if self.eat(b':') {
+                    self.int_opt()?
+                } else {
+                    None
+                };
+                Ok(Selector::Slice(start, end, step))
+            }
+        }
+    }
+
+    fn int_opt(&mut self) -> Result<Option<i64>, String> {
+        self.skip_ws();
+        let start = self.pos;
+        self.eat(b'-');
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.pos += 1;
+        }
+        let text = std::str::from_utf8(&self.src[start..self.pos]).unwrap_or("");
+        match text {
+            "" => Ok(None),
+            "-" => Err(self.error("expected digits")),
+            _ => text
+                .parse()
+                .map(Some)
+                .map_err(|_| self.error("integer out of range")),
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        let quote = self.peek().ok_or_else(|| self.error("expected string"))?;
+        self.pos += 1;
+        let mut out: Vec<u8> = Vec::new();
+        loop {
+            let b = self
+                .peek()
+                .ok_or_else(|| self.error("unterminated string"))?;
+            self.pos += 1;
+            match b {
+                _ if b == quote => break,
+                b'\\' => {
+                    let esc = self
+                        .peek()
+                        .ok_or_else(|| self.error("unterminated escape"))?;
+                    self.pos += 1;
+                    match esc {
+                        b'n' => out.push(b'\n'),
+                        b't' => out.push(b'\t'),
+                        b'r' => out.push(b'\r'),
+                        b'/' | b'\\' | b'\'' | b'"' => out.push(esc),
+                        _ => return Err(self.error("unsupported escape")),
+                    }
+                }
+                _ => out.push(b),
+            }
+        }
+        String::from_utf8(out).map_err(|_| self.error("string is not UTF-8"))
+    }
+
+    fn enter(&mut self) -> Result<(), String> {
+        self.depth += 1;
+        if self.depth > MAX_EXPRESSION_DEPTH {
+            return Err(self.error("filter nests too deeply"));
+        }
+        Ok(())
+    }
+
+    fn filter_or(&mut self) -> Result<Filter, String> {
+        self.enter()?;
+        let mut terms = vec![self.filter_and()?];
+        loop {
+            self.skip_ws();
+            if !self.eat_str("||") {
+                break;
+            }
+            terms.push(self.filter_and()?);
+        }
+        self.depth -= 1;
+        Ok(if terms.len() == 1 {
+            terms.remove(0)
+        } else {
+            Filter::Or(terms)
+        })
+    }
+
+    fn filter_and(&mut self) -> Result<Filter, String> {
+        let mut terms = vec![self.filter_unary()?];
+        loop {
+            self.skip_ws();
+            if !self.eat_str("&&") {
+                break;
+            }
+            terms.push(self.filter_unary()?);
+        }
+        Ok(if terms.len() == 1 {
+            terms.remove(0)
+        } else {
+            Filter::And(terms)
+        })
+    }
+
+    fn filter_unary(&mut self) -> Result<Filter, String> {
+        self.skip_ws();
+        if self.peek() == Some(b'!') && self.src.get(self.pos + 1) != Some(&b'=') {
+            self.pos += 1;
+            self.enter()?;
+            let inner = self.filter_unary()?;
+            self.depth -= 1;
+            return Ok(Filter::Not(Box::new(inner)));
+        }
+        if self.eat(b'(') {
+            let inner = self.filter_or()?;
+            self.expect(b')')?;
+            return Ok(inner);
+        }
+        let left = self.operand()?;
+        self.skip_ws();
+        let op = [
+            ("==", CompareOp::Eq),
+            ("!=", CompareOp::Ne),
+            ("<=", CompareOp::Lte),
+            (">=", CompareOp::Gte),
+            ("<", CompareOp::Lt),
+            (">", CompareOp::Gt),
+        ]
+        .into_iter()
+        .find(|(text, _)| self.eat_str(text))
+        .map(|(_, op)| op);
+        match op {
+            Some(op) => Ok(Filter::Compare(left, op, self.operand()?)),
+            None if matches!(left, Operand::Query { .. }) => Ok(Filter::Exists(left)),
+            None => Err(self.error("a literal needs a comparison")),
+        }
+    }
+
+    fn operand(&mut self) -> Result<Operand, String> {
+        self.skip_ws();
+        match self.peek() {
+            Some(b'@') | Some(b'$') => {
+                let absolute = self.peek() == Some(b'$');
+                self.pos += 1;
+                self.enter()?;
+                let path = self.segments()?;
+                self.depth -= 1;
+                Ok(Operand::Query { absolute, path })
+            }
+            Some(b'\'' | b'"') => Ok(Operand::Literal(Value::String(self.string()?))),
+            _ => {
+                for (word, value) in [
+                    ("true", Value::Bool(true)),
+                    ("false", Value::Bool(false)),
+                    ("null", Value::Null),
+                ] {
+                    if self.eat_str(word) {
+                        return Ok(Operand::Literal(value));
+                    }
+                }
+                self.number().map(Operand::Literal)
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<Value, String> {
+        let start = self.pos;
+        while matches!(
+            self.peek(),
+            Some(b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E')
+        ) {
+            self.pos += 1;
+        }
+        let text = std::str::from_utf8(&self.src[start..self.pos]).unwrap_or("");
+        serde_json::from_str::<serde_json::Number>(text)
+            .map(Value::Number)
+            .map_err(|_| self.error("expected a literal or a path"))
+    }
+}
+
+/// Every node `path` selects, in document order (object members in key order).
+pub(crate) fn evaluate(
+    path: &Path,
+    document: &Value,
+    ticker: &mut Ticker<'_>,
+) -> Result<Vec<Value>, Error> {
+    Ok(select(path, document, document, ticker)?
+        .into_iter()
+        .cloned()
+        .collect())
+}
+
+fn select<'a>(
+    path: &Path,
+    root: &'a Value,
+    start: &'a Value,
+    ticker: &mut Ticker<'_>,
+) -> Result<Vec<&'a Value>, Error> {
+    let mut nodes = vec![start];
+    for segment in &path.segments {
+        let mut next = Vec::new();
+        for node in nodes {
+            if segment.descendant {
+                for inner in descendants(node, ticker)? {
+                    apply_selectors(&segment.selectors, root, inner, &mut next, ticker)?;
+                }
+            } else {
+                apply_selectors(&segment.selectors, root, node, &mut next, ticker)?;
+            }
+        }
+        nodes = next;
+    }
+    Ok(nodes)
+}
+
+// INVARIANT: Pre-order (node before its children), iterative so deep documents cannot overflow.
+fn descendants<'a>(node: &'a Value, ticker: &mut Ticker<'_>) -> Result<Vec<&'a Value>, Error> {
+    let mut out = Vec::new();
+    let mut stack = vec![node];
+    while let Some(current) = stack.pop() {
+        out.push(current);
+        match current {
+            Value::Array(items) => {
+                for item in items.iter().rev() {
+                    ticker.tick()?;
+                    stack.push(item);
+                }
+            }
+            Value::Object(map) => {
+                for item in map.values().rev() {
+                    ticker.tick()?;
+                    stack.push(item);
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(out)
+}
+
+fn apply_selectors<'a>(
+    selectors: &[Selector],
+    root: &'a Value,
+    node: &'a Value,
+    out: &mut Vec<&'a Value>,
+    ticker: &mut Ticker<'_>,
+) -> Result<(), Error> {
+    for selector in selectors {
+        match (selector, node) {
+            (Selector::Name(name), Value::Object(map)) => out.extend(map.get(name)),
+            (Selector::Index(i), Value::Array(items)) => {
+                out.extend(normalize_index(*i, items.len()).and_then(|i| items.get(i)))
+            }
+            (Selector::Wildcard, Value::Array(items)) => {
+                for item in items {
+                    ticker.tick()?;
+                    out.push(item);
+                }
+            }
+            (Selector::Wildcard, Value::Object(map)) => {
+                for item in map.values() {
+                    ticker.tick()?;
+                    out.push(item);
+                }
+            }
+            (Selector::Slice(start, end, step), Value::Array(items)) => {
+                for i in slice_indices(items.len(), *start, *end, *step) {
+                    out.push(&items[i]);
+                }
+            }
+            (Selector::Filter(filter), Value::Array(items)) => {
+                for item in items {
+                    ticker.tick()?;
+                    if test(filter, root, item, ticker)? {
+                        out.push(item);
+                    }
+                }
+            }
+            (Selector::Filter(filter), Value::Object(map)) => {
+                for item in map.values() {
+                    ticker.tick()?;
+                    if test(filter, root, item, ticker)? {
+                        out.push(item);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn normalize_index(i: i64, len: usize) -> Option<usize> {
+    let len = i64::try_from(len).ok()?;
+    let idx = if i < 0 { len + i } else { i };
+    (0..len).contains(&idx).then_some(idx as usize)
+}
+
+// NOTE: RFC 9535 slice semantics: defaults depend on the step sign and step 0 selects nothing.
+fn slice_indices(
+    len: usize,
+    start: Option<i64>,
+    end: Option<i64>,
+    step: Option<i64>,
+) -> Vec<usize> {
+    let len = len as i64;
+    let step = step.unwrap_or(1);
+    let clamp = |v: i64| if v < 0 { (len + v).max(-1) } else { v.min(len) };
+    let mut out = Vec::new();
+    if step > 0 {
+        let (lo, hi) = (
+            clamp(start.unwrap_or(0)).max(0),
+            clamp(end.unwrap_or(len)).max(0),
+        );
+        let mut i = lo;
+        while i < hi {
+            out.push(i as usize);
+            i += step;
+        }
+    } else if step < 0 {
+        let lo = end.map_or(-1, clamp);
+        let mut i = start.map_or(len - 1, |s| clamp(s).min(len - 1));
+        while i > lo {
+            out.push(i as usize);
+            i += step;
+        }
+    }
+    out
+}
+
+fn test(
+    filter: &Filter,
+    root: &Value,
+    node: &Value,
+    ticker: &mut Ticker<'_>,
+) -> Result<bool, Error> {
+    Ok(match filter {
+        Filter::Or(terms) => {
+            for term in terms {
+                if test(term, root, node, ticker)? {
+                    return Ok(true);
+                }
+            }
+            false
+        }
+        Filter::And(terms) => {
+            for term in terms {
+                if !test(term, root, node, ticker)? {
+                    return Ok(false);
+                }
+            }
+            true
+        }
+        Filter::Not(inner) => !test(inner, root, node, ticker)?,
+        Filter::Exists(operand) => match operand {
+            Operand::Query { absolute, path } => {
+                let start = if *absolute { root } else { node };
+                !select(path, root, start, ticker)?.is_empty()
+            }
+            Operand::Literal(_) => false,
+        },
+        Filter::Compare(left, op, right) => {
+            let l = operand_value(left, root, node, ticker)?;
+            let r = operand_value(right, root, node, ticker)?;
+            compare(l, *op, r)
+        }
+    })
+}
+
+/// A literal, or the single node a query selects; `None` when it selects zero or several nodes.
+fn operand_value<'a>(
+    operand: &'a Operand,
+    root: &'a Value,
+    node: &'a Value,
+    ticker: &mut Ticker<'_>,
+) -> Result<Option<&'a Value>, Error> {
+    match operand {
+        Operand::Literal(v) => Ok(Some(v)),
+        Operand::Query { absolute, path } => {
+            let start = if *absolute { root } else { node };
+            let nodes = select(path, root, start, ticker)?;
+            Ok(match nodes.as_slice() {
+                [single] => Some(*single),
+                _ => None,
+            })
+        }
+    }
+}
+
+// WHY: Ordering comparisons only hold between two numbers or two strings (RFC 9535 §2.3.5.2.2);
+// two empty operands are equal, as the RFC requires.
+fn compare(l: Option<&Value>, op: CompareOp, r: Option<&Value>) -> bool {
+    let equal = match (l, r) {
+        (None, None) => true,
+        (Some(a), Some(b)) => values_equal(a, b),
+        _ => false,
+    };
+    let ordering = match (l, r) {
+        (Some(Value::Number(a)), Some(Value::Number(b))) => as_f64(a).partial_cmp(&as_f64(b)),
+        (Some(a @ Value::String(_)), Some(b @ Value::String(_))) => Some(compare_values(a, b)),
+        _ => None,
+    };
+    match op {
+        CompareOp::Eq => equal,
+        CompareOp::Ne => !equal,
+        CompareOp::Lt => ordering.is_some_and(|o| o.is_lt()),
+        CompareOp::Lte => equal || ordering.is_some_and(|o| o.is_lt()),
+        CompareOp::Gt => ordering.is_some_and(|o| o.is_gt()),
+        CompareOp::Gte => equal || ordering.is_some_and(|o| o.is_gt()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn query(expression: &str, document: &Value) -> Vec<Value> {
+        let path = parse(expression).expect("parse");
+        let mut ticker = Ticker {
+            visited: 0,
+            on_item: Box::new(|_| Ok(())),
+        };
+        evaluate(&path, document, &mut ticker).expect("evaluate")
+    }
+
+    fn store() -> Value {
+        json!({
+            "store": {
+                "book": [
+                    {"category": "reference", "author": "Rees", "price": 8.95},
+                    {"category": "fiction", "author": "Waugh", "price": 12.99},
+                    {"category": "fiction", "author": "Melville", "price": 8.99, "isbn": "0-553"},
+                    {"category": "fiction", "author": "Tolkien", "price": 22.99, "isbn": "0-395"}
+                ],
+                "bicycle": {"color": "red", "price": 399}
+            }
+        })
+    }
+
+    #[test]
+    fn selects_members_indices_and_wildcards() {
+        let doc = store();
+        assert_eq!(query("$.store.bicycle.color", &doc), vec![json!("red")]);
+        assert_eq!(
+            query("$['store']['book'][-1].author", &doc),
+            vec![json!("Tolkien")]
+        );
+        assert_eq!(
+            query("$.store.book[0,2].author", &doc),
+            vec![json!("Rees"), json!("Melville")]
+        );
+        assert_eq!(query("$.store.book[*].price", &doc).len(), 4);
+        assert_eq!(
+            query("$.store.*.price", &doc),
+            vec![json!(399)],
+            "only the bicycle has a direct price; members iterate in key order"
+        );
+        assert!(query("$.missing.path", &doc).is_empty());
+    }
+
+    #[test]
+    fn slices_like_rfc_9535() {
+        let doc = json!([0, 1, 2, 3, 4, 5]);
+        assert_eq!(query("$[1:3]", &doc), vec![json!(1), json!(2)]);
+        assert_eq!(query("$[:2]", &doc), vec![json!(0), json!(1)]);
+        assert_eq!(query("$[-2:]", &doc), vec![json!(4), json!(5)]);
+        assert_eq!(query("$[::2]", &doc), vec![json!(0), json!(2), json!(4)]);
+        assert_eq!(query("$[::-2]", &doc), vec![json!(5), json!(3), json!(1)]);
+        assert_eq!(query("$[4:1:-1]", &doc), vec![json!(4), json!(3), json!(2)]);
+        assert!(query("$[::0]", &doc).is_empty());
+    }
+
+    #[test]
+    fn descends_recursively() {
+        let doc = store();
+        assert_eq!(
+            query("$..author", &doc),
+            vec![
+                json!("Rees"),
+                json!("Waugh"),
+                json!("Melville"),
+                json!("Tolkien")
+            ]
+        );
+        assert_eq!(query("$..price", &doc).len(), 5);
+        assert_eq!(query("$..book[2].isbn", &doc), vec![json!("0-553")]);
+    }
+
+    #[test]
+    fn filters_with_comparisons_and_logic() {
+        let doc = store();
+        assert_eq!(
+            query("$.store.book[?(@.price < 10)].author", &doc),
+            vec![json!("Rees"), json!("Melville")]
+        );
+        assert_eq!(
+            query("$..book[?@.isbn && @.price > 20].author", &doc),
+            vec![json!("Tolkien")]
+        );
+        assert_eq!(
+            query(
+                "$..book[?(@.category == 'reference' || !@.isbn)].author",
+                &doc
+            ),
+            vec![json!("Rees"), json!("Waugh")]
+        );
+        assert_eq!(
+            query("$..book[?@.price >= $.store.book[1].price].author", &doc),
+            vec![json!("Waugh"), json!("Tolkien")]
+        );
+        assert_eq!(query("$..book[?@.missing == null]", &doc).len(), 0);
+    }
+
+    #[test]
+    fn rejects_malformed_paths() {
+        for bad in [
+            "",
+            "store",
+            "$.",
+            "$[",
+            "$['a'",
+            "$[?(@.a ==)]",
+            "$[?1]",
+            "$.a b",
+        ] {
+            assert!(parse(bad).is_err(), "{bad}");
+        }
+        let deep = format!("$[?{}@.a{}]", "(".repeat(80), ")".repeat(80));
+        assert!(parse(&deep).is_err());
+    }
+}
@@ -0,0 +1,340 @@
+//! json.query@0.1.0 component entrypoint.
+//! WHY: Keep host coordination contained for maintainable bindings upgrades.
+
+#![allow(clippy::missing_errors_doc)]
+
+#[allow(warnings)]
+mod bindings;
+mod jq;
+mod jsonpath;
+
+use std::cmp::Ordering;
+
+use bindings::exports::uicp::task_json_query::query::{Error, Guest, Input, Language};
+use bindings::uicp::host::control;
+use bindings::wasi::clocks::monotonic_clock;
+use bindings::wasi::io::streams::OutputStream;
+use ciborium::ser::into_writer;
+use ciborium::value::{Integer, Value as CborValue};
+use serde_json::Value;
+
+const PARTIAL_KIND_PROGRESS: u8 = 0;
+const PARTIAL_KIND_TOTAL: u8 = 2;
+const PROGRESS_EVERY_ITEMS: usize = 1000;
+/// Nesting limit for parsed expressions; keeps evaluation recursion off the guest stack limit.
+pub(crate) const MAX_EXPRESSION_DEPTH: usize = 64;
+
+struct Component;
+
+impl Guest for Component {
+    fn run(job_id: String, input: Input) -> Result<String, Error> {
+        run_json_query(job_id, input)
+    }
+}
+
+bindings::exports::uicp::task_json_query::query::__export_uicp_task_json_query_query_0_1_0_cabi!(
+    Component with_types_in bindings::exports::uicp::task_json_query::query
+);
+
+// WHY: Separate orchestration from trait impl for easier unit testing.
+fn run_json_query(job_id: String, input: Input) -> Result<String, Error> {
+    let mut sink = control::open_partial_sink(&job_id);
+    let mut seq = 0u32;
+
+    let results = execute(&input, |visited| {
+        if visited % PROGRESS_EVERY_ITEMS == 0 {
+            emit_progress(&mut sink, &job_id, &mut seq, visited as u64);
+            if should_cancel(&job_id) {
+                return Err(Error::Cancelled);
+            }
+        }
+        Ok(())
+    })?;
+
+    emit_total(&mut sink, &job_id, &mut seq, results.len() as u64);
+    serde_json::to_string(&Value::Array(results))
+        .map_err(|err| Error::Eval(format!("serialize results: {err}")))
+}
+
+// WHY: Host cancellation may be cooperative (user) or deadline-driven.
+fn should_cancel(job_id: &str) -> bool {
+    control::should_cancel(job_id) || control::remaining_ms(job_id) == 0
+}
+
+/// Parses the document and expression, then evaluates. `on_item` is called with a running count
+/// every time an array element or object member is visited (for progress and cancellation).
+fn execute(
+    input: &Input,
+    on_item: impl FnMut(usize) -> Result<(), Error>,
+) -> Result<Vec<Value>, Error> {
+    let document: Value = serde_json::from_str(&input.document)
+        .map_err(|err| Error::Eval(format!("document is not valid JSON: {err}")))?;
+    let mut ticker = Ticker {
+        visited: 0,
+        on_item: Box::new(on_item),
+    };
+    match input.language {
+        Language::Jsonpath => {
+            let path = jsonpath::parse(&input.expression).map_err(Error::InvalidExpression)?;
+            jsonpath::evaluate(&path, &document, &mut ticker)
+        }
+        Language::Jq => {
+            let filter = jq::parse(&input.expression).map_err(Error::InvalidExpression)?;
+            jq::evaluate(&filter, document, &mut ticker)
+        }
+    }
+}
+
+/// Progress/cancellation hook threaded through both evaluators.
+pub(crate) struct Ticker<'a> {
+    visited: usize,
+    on_item: Box<dyn FnMut(usize) -> Result<(), Error> + 'a>,
+}
+
+impl Ticker<'_> {
+    pub(crate) fn tick(&mut self) -> Result<(), Error> {
+        self.visited += 1;
+        (self.on_item)(self.visited)
+    }
+}
+
+/// jq ordering: null < false < true < numbers < strings < arrays < objects. Arrays compare
+/// element-wise; objects compare sorted key sets first, then values key by key.
+pub(crate) fn compare_values(a: &Value, b: &Value) -> Ordering {
+    fn rank(v: &Value) -> u8 {
+        match v {
+            Value::Null => 0,
+            Value::Bool(false) => 1,
+            Value::Bool(true) => 2,
+            Value::Number(_) => 3,
+            Value::String(_) => 4,
+            Value::Array(_) => 5,
+            Value::Object(_) => 6,
+        }
+    }
+    match (a, b) {
+        (Value::Number(x), Value::Number(y)) => as_f64(x).total_cmp(&as_f64(y)),
+        (Value::String(x), Value::String(y)) => x.cmp(y),
+        (Value::Array(x), Value::Array(y)) => x
+            .iter()
+            .zip(y)
+            .map(|(l, r)| compare_values(l, r))
+            .find(|o| o.is_ne())
+            .unwrap_or_else(|| x.len().cmp(&y.len())),
+        (Value::Object(x), Value::Object(y)) => x.keys().cmp(y.keys()).then_with(|| {
+            x.values()
+                .zip(y.values())
+                .map(|(l, r)| compare_values(l, r))
+                .find(|o| o.is_ne())
+                .unwrap_or(Ordering::Equal)
+        }),
+        _ => rank(a).cmp(&rank(b)),
+    }
+}
+
+/// Structural equality with numbers compared by value (`1 == 1.0`).
+pub(crate) fn values_equal(a: &Value, b: &Value) -> bool {
+    compare_values(a, b).is_eq()
+}
+
+pub(crate) fn as_f64(n: &serde_json::Number) -> f64 {
+    n.as_f64().unwrap_or(f64::NAN)
+}
+
+// INVARIANT: Integral results within the exact f64 range stay integers so output is stable
+// whether a number came from the document or from arithmetic; non-finite values become null.
+pub(crate) fn number(f: f64) -> Value {
+    const EXACT: f64 = 9_007_199_254_740_992.0; // 2^53
+    if f.fract() == 0.0 && f.abs() <= EXACT {
+        Value::from(f as i64)
+    } else {
+        serde_json::Number::from_f64(f).map_or(Value::Null, Value::Number)
+    }
+}
+
+pub(crate) fn type_name(v: &Value) -> &'static str {
+    match v {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn emit_progress(sink: &mut OutputStream, job_id: &str, seq: &mut u32, processed: u64) {
+    let timestamp = now_microseconds();
+    let payload = CborValue::Map(vec![(
+        CborValue::Text("processed".into()),
+        CborValue::Integer(Integer::from(processed)),
+    )]);
+    emit_partial(
+        sink,
+        seq,
+        PARTIAL_KIND_PROGRESS,
+        timestamp,
+        Some(payload),
+        job_id,
+    );
+}
+
+fn emit_total(sink: &mut OutputStream, job_id: &str, seq: &mut u32, total: u64) {
+    let timestamp = now_microseconds();
+    let payload = CborValue::Map(vec![(
+        CborValue::Text("total".into()),
+        CborValue::Integer(Integer::from(total)),
+    )]);
+    emit_partial(
+        sink,
+        seq,
+        PARTIAL_KIND_TOTAL,
+        timestamp,
+        Some(payload),
+        job_id,
+    );
+}
+
+fn now_microseconds() -> u64 {
+    monotonic_clock::now() / 1_000_000
+}
+
+fn emit_partial(
+    sink: &mut OutputStream,
+    seq: &mut u32,
+    kind: u8,
+    timestamp: u64,
+    payload: Option<CborValue>,
+    job_id: &str,
+) {
+    let frame = cbor_envelope(kind, bump_seq(seq), timestamp, payload);
+    // WHY: Blocking write ensures ordering; treat failures as fatal traps to obey fail-loud.
+    if let Err(err) = sink.blocking_write_and_flush(&frame) {
+        panic!("E-UICP-0801: job {job_id}: partial emission failed: {err:?}");
+    }
+}
+
+fn bump_seq(seq: &mut u32) -> u32 {
+    let next = seq.wrapping_add(1);
+    *seq = next;
+    next
+}
+
+fn cbor_envelope(kind: u8, seq: u32, timestamp: u64, payload: Option<CborValue>) -> Vec<u8> {
+    let mut entries: Vec<(CborValue, CborValue)> = vec![
+        (
+            CborValue::Integer(Integer::from(1u8)),
+            CborValue::Integer(Integer::from(kind)),
+        ),
+        (
+            CborValue::Integer(Integer::from(2u32)),
+            CborValue::Integer(Integer::from(seq)),
+        ),
+        (
+            CborValue::Integer(Integer::from(3u64)),
+            CborValue::Integer(Integer::from(timestamp)),
+        ),
+    ];
+    if let Some(p) = payload {
+        entries.push((CborValue::Integer(Integer::from(4u8)), p));
+    }
+    let map = CborValue::Map(entries);
+    let mut out = Vec::new();
+    let _ = into_writer(&map, &mut out);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn input(language: Language, expression: &str, document: Value) -> Input {
+        Input {
+            document: document.to_string(),
+            language,
+            expression: expression.to_string(),
+        }
+    }
+
+    #[test]
+    fn reports_progress_and_honours_cancellation() {
+        let doc = Value::Array((0..2500).map(Value::from).collect());
+        let mut seen = Vec::new();
+        let out = execute(
+            &input(
+                Language::Jq,
+                "[.[] | select(. % 2 == 0)] | length",
+                doc.clone(),
+            ),
+            |n| {
+                if n % PROGRESS_EVERY_ITEMS == 0 {
+                    seen.push(n);
+                }
+                Ok(())
+            },
+        )
+        .expect("query");
+        assert_eq!(out, vec![json!(1250)]);
+        assert_eq!(seen, vec![1000, 2000]);
+
+        let err = execute(&input(Language::Jsonpath, "$[*]", doc), |n| {
+            if n == 10 {
+                Err(Error::Cancelled)
+            } else {
+                Ok(())
+            }
+        })
+        .unwrap_err();
+        assert!(matches!(err, Error::Cancelled));
+    }
+
+    #[test]
+    fn classifies_errors() {
+        let doc = json!({"a": 1});
+        let err = execute(&input(Language::Jq, ".a |", doc.clone()), |_| Ok(())).unwrap_err();
+        assert!(matches!(err, Error::InvalidExpression(_)), "{err:?}");
+        let err = execute(&input(Language::Jsonpath, "a.b", doc.clone()), |_| Ok(())).unwrap_err();
+        assert!(matches!(err, Error::InvalidExpression(_)), "{err:?}");
+        let err = execute(&input(Language::Jq, ".a[0]", doc), |_| Ok(())).unwrap_err();
+        assert!(matches!(err, Error::Eval(_)), "{err:?}");
+
+        let bad_doc = Input {
+            document: "{".into(),
+            language: Language::Jq,
+            expression: ".".into(),
+        };
+        assert!(matches!(execute(&bad_doc, |_| Ok(())), Err(Error::Eval(_))));
+    }
+
+    #[test]
+    fn orders_values_like_jq() {
+        let mut values = vec![
+            json!({"a": 1}),
+            json!("b"),
+            json!([1]),
+            json!(2.5),
+            json!(true),
+            json!(null),
+            json!(false),
+            json!(1),
+        ];
+        values.sort_by(compare_values);
+        assert_eq!(
+            values,
+            vec![
+                json!(null),
+                json!(false),
+                json!(true),
+                json!(1),
+                json!(2.5),
+                json!("b"),
+                json!([1]),
+                json!({"a": 1}),
+            ]
+        );
+        assert!(values_equal(&json!(1), &json!(1.0)));
+        assert_eq!(number(3.0), json!(3));
+        assert_eq!(number(f64::INFINITY), json!(null));
+    }
+}
@@ -0,0 +1,141 @@
+//! markdown.render@0.1.0 component entrypoint.
+//! WHY: Rendering runs in the sandbox; sanitization stays on the host so a single HTML policy
+//! covers every source of window markup.
+
+#[allow(warnings)]
+mod bindings;
+
+use bindings::exports::uicp::task_markdown_render::render::{Guest, Options};
+use pulldown_cmark::{html, CowStr, Event, Options as ParseOptions, Parser};
+
+const TASK_DONE: &str = "<span class=\"task-list-marker\" data-checked=\"true\">\u{2611}</span> ";
+const TASK_OPEN: &str = "<span class=\"task-list-marker\" data-checked=\"false\">\u{2610}</span> ";
+
+struct Component;
+
+impl Guest for Component {
+    fn run(_job_id: String, markdown: String, options: Options) -> Result<String, String> {
+        Ok(render_markdown(&markdown, options))
+    }
+}
+
+bindings::exports::uicp::task_markdown_render::render::__export_uicp_task_markdown_render_render_0_1_0_cabi!(
+    Component with_types_in bindings::exports::uicp::task_markdown_render::render
+);
+
+fn parse_options(options: Options) -> ParseOptions {
+    let mut flags = ParseOptions::empty();
+    if options.gfm {
+        flags.insert(
+            ParseOptions::ENABLE_TABLES
+                | ParseOptions::ENABLE_STRIKETHROUGH
+                | ParseOptions::ENABLE_TASKLISTS,
+        );
+    }
+    if options.smart_punctuation {
+        flags.insert(ParseOptions::ENABLE_SMART_PUNCTUATION);
+    }
+    flags
+}
+
+fn render_markdown(markdown: &str, options: Options) -> String {
+    let raw_html = options.raw_html;
+    let events = Parser::new_ext(markdown, parse_options(options)).map(|event| match event {
+        // WHY: Disabled raw HTML is shown as escaped text rather than dropped, so nothing the
+        // author wrote silently disappears.
+        Event::Html(markup) | Event::InlineHtml(markup) if !raw_html => Event::Text(markup),
+        // WHY: Checkbox inputs fall outside the host HTML policy; render the marker as a glyph.
+        Event::TaskListMarker(done) => {
+            Event::InlineHtml(CowStr::Borrowed(if done { TASK_DONE } else { TASK_OPEN }))
+        }
+        other => other,
+    });
+    let mut out = String::with_capacity(markdown.len() + markdown.len() / 2);
+    html::push_html(&mut out, events);
+    align_table_cells(out)
+}
+
+// WHY: The host policy strips `style`, so column alignment is carried by the `align` attribute
+// it allows instead of pulldown-cmark's inline `text-align` style.
+fn align_table_cells(html: String) -> String {
+    if !html.contains("style=\"text-align: ") {
+        return html;
+    }
+    let mut out = html;
+    for cell in ["th", "td"] {
+        for align in ["left", "center", "right"] {
+            out = out.replace(
+                &format!("<{cell} style=\"text-align: {align}\">"),
+                &format!("<{cell} align=\"{align}\">"),
+            );
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn opts(gfm: bool, smart_punctuation: bool, raw_html: bool) -> Options {
+        Options {
+            gfm,
+            smart_punctuation,
+            raw_html,
+        }
+    }
+
+    #[test]
+    fn renders_commonmark_and_code_fences() {
+        let html = render_markdown(
+            "# Title\n\nSome *em* and `code`.\n\n```rust\nfn main() {}\n```\n",
+            opts(true, false, true),
+        );
+        assert_eq!(
+            html,
+            "<h1>Title</h1>\n<p>Some <em>em</em> and <code>code</code>.</p>\n\
+             <pre><code class=\"language-rust\">fn main() {}\n</code></pre>\n"
+        );
+    }
+
+    #[test]
+    fn gfm_tables_strikethrough_and_task_lists() {
+        let md = "| a | b |\n|:--|--:|\n| 1 | 2 |\n\n~~gone~~\n\n- [x] done\n- [ ] open\n";
+        let html = render_markdown(md, opts(true, false, true));
+        assert!(html.contains("<th align=\"left\">a</th>"), "{html}");
+        assert!(html.contains("<td align=\"right\">2</td>"), "{html}");
+        assert!(!html.contains("style="), "{html}");
+        assert!(html.contains("<del>gone</del>"), "{html}");
+        assert!(html.contains(TASK_DONE) && html.contains(TASK_OPEN), "{html}");
+        assert!(!html.contains("<input"), "{html}");
+
+        let plain = render_markdown(md, opts(false, false, true));
+        assert!(!plain.contains("<table>"), "{plain}");
+        assert!(plain.contains("~~gone~~"), "{plain}");
+    }
+
+    #[test]
+    fn raw_html_passes_through_or_is_escaped() {
+        let md = "<div class=\"x\">hi</div>\n\ntext <b>bold</b>\n";
+        let passed = render_markdown(md, opts(true, false, true));
+        assert!(passed.contains("<div class=\"x\">hi</div>"), "{passed}");
+        assert!(passed.contains("<b>bold</b>"), "{passed}");
+
+        let escaped = render_markdown(md, opts(true, false, false));
+        assert!(escaped.contains("&lt;div class=\"x\"&gt;hi&lt;/div&gt;"), "{escaped}");
+        assert!(escaped.contains("&lt;b&gt;bold&lt;/b&gt;"), "{escaped}");
+    }
+
+    #[test]
+    fn smart_punctuation_is_opt_in() {
+        let md = "\"quoted\" -- wait...\n";
+        assert_eq!(
+            render_markdown(md, opts(true, true, true)),
+            "<p>\u{201c}quoted\u{201d} \u{2013} wait\u{2026}</p>\n"
+        );
+        assert_eq!(
+            render_markdown(md, opts(true, false, true)),
+            "<p>\"quoted\" -- wait...</p>\n"
+        );
+    }
+}
@@ -114,6 +114,31 @@ pub mod uicp {
             #[doc(hidden)]
             static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
             use super::super::super::_rt;
+            /// Typed cell; JSON null/bool/integer/number/string map onto these cases one-to-one.
+            #[derive(Clone)]
+            pub enum Cell {
+                Null,
+                Boolean(bool),
+                Int(i64),
+                Float(f64),
+                Text(_rt::String),
+            }
+            impl ::core::fmt::Debug for Cell {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        Cell::Null => f.debug_tuple("Cell::Null").finish(),
+                        Cell::Boolean(e) => {
+                            f.debug_tuple("Cell::Boolean").field(e).finish()
+                        }
+                        Cell::Int(e) => f.debug_tuple("Cell::Int").field(e).finish(),
+                        Cell::Float(e) => f.debug_tuple("Cell::Float").field(e).finish(),
+                        Cell::Text(e) => f.debug_tuple("Cell::Text").field(e).finish(),
+                    }
+                }
+            }
             #[derive(Clone)]
             pub struct Filter {
                 pub col: u32,
@@ -130,11 +155,193 @@ pub mod uicp {
                         .finish()
                 }
             }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum CompareOp {
+                Eq,
+                Ne,
+                Lt,
+                Lte,
+                Gt,
+                Gte,
+                Contains,
+                Regex,
+            }
+            impl ::core::fmt::Debug for CompareOp {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        CompareOp::Eq => f.debug_tuple("CompareOp::Eq").finish(),
+                        CompareOp::Ne => f.debug_tuple("CompareOp::Ne").finish(),
+                        CompareOp::Lt => f.debug_tuple("CompareOp::Lt").finish(),
+                        CompareOp::Lte => f.debug_tuple("CompareOp::Lte").finish(),
+                        CompareOp::Gt => f.debug_tuple("CompareOp::Gt").finish(),
+                        CompareOp::Gte => f.debug_tuple("CompareOp::Gte").finish(),
+                        CompareOp::Contains => {
+                            f.debug_tuple("CompareOp::Contains").finish()
+                        }
+                        CompareOp::Regex => f.debug_tuple("CompareOp::Regex").finish(),
+                    }
+                }
+            }
+            impl CompareOp {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> CompareOp {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => CompareOp::Eq,
+                        1 => CompareOp::Ne,
+                        2 => CompareOp::Lt,
+                        3 => CompareOp::Lte,
+                        4 => CompareOp::Gt,
+                        5 => CompareOp::Gte,
+                        6 => CompareOp::Contains,
+                        7 => CompareOp::Regex,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[derive(Clone)]
+            pub struct Predicate {
+                pub col: u32,
+                pub op: CompareOp,
+                pub value: Cell,
+                pub case_insensitive: bool,
+            }
+            impl ::core::fmt::Debug for Predicate {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("Predicate")
+                        .field("col", &self.col)
+                        .field("op", &self.op)
+                        .field("value", &self.value)
+                        .field("case-insensitive", &self.case_insensitive)
+                        .finish()
+                }
+            }
+            /// WIT has no recursive types, so the predicate tree is flattened in post-order: `all`/`any`
+            /// reference earlier entries by index and the last entry is the root. Empty means no filter.
+            #[derive(Clone)]
+            pub enum WhereNode {
+                Leaf(Predicate),
+                All(_rt::Vec<u32>),
+                Any(_rt::Vec<u32>),
+            }
+            impl ::core::fmt::Debug for WhereNode {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        WhereNode::Leaf(e) => {
+                            f.debug_tuple("WhereNode::Leaf").field(e).finish()
+                        }
+                        WhereNode::All(e) => {
+                            f.debug_tuple("WhereNode::All").field(e).finish()
+                        }
+                        WhereNode::Any(e) => {
+                            f.debug_tuple("WhereNode::Any").field(e).finish()
+                        }
+                    }
+                }
+            }
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct OrderKey {
+                pub col: u32,
+                pub descending: bool,
+            }
+            impl ::core::fmt::Debug for OrderKey {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("OrderKey")
+                        .field("col", &self.col)
+                        .field("descending", &self.descending)
+                        .finish()
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum AggregateOp {
+                Count,
+                Sum,
+                Min,
+                Max,
+                Avg,
+            }
+            impl ::core::fmt::Debug for AggregateOp {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        AggregateOp::Count => {
+                            f.debug_tuple("AggregateOp::Count").finish()
+                        }
+                        AggregateOp::Sum => f.debug_tuple("AggregateOp::Sum").finish(),
+                        AggregateOp::Min => f.debug_tuple("AggregateOp::Min").finish(),
+                        AggregateOp::Max => f.debug_tuple("AggregateOp::Max").finish(),
+                        AggregateOp::Avg => f.debug_tuple("AggregateOp::Avg").finish(),
+                    }
+                }
+            }
+            impl AggregateOp {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> AggregateOp {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => AggregateOp::Count,
+                        1 => AggregateOp::Sum,
+                        2 => AggregateOp::Min,
+                        3 => AggregateOp::Max,
+                        4 => AggregateOp::Avg,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[derive(Clone)]
+            pub struct Aggregate {
+                pub op: AggregateOp,
+                pub col: Option<u32>,
+                pub label: _rt::String,
+            }
+            impl ::core::fmt::Debug for Aggregate {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("Aggregate")
+                        .field("op", &self.op)
+                        .field("col", &self.col)
+                        .field("label", &self.label)
+                        .finish()
+                }
+            }
+            /// Stages run in order: where-contains + predicates, group-by/aggregates, order-by,
+            /// offset/limit, select. With group-by or aggregates, order-by and select index the grouped row
+            /// (group-by cells followed by aggregate values).
             #[derive(Clone)]
             pub struct Input {
-                pub rows: _rt::Vec<_rt::Vec<_rt::String>>,
+                pub rows: _rt::Vec<_rt::Vec<Cell>>,
+                pub has_header: bool,
                 pub select: _rt::Vec<u32>,
                 pub where_contains: Option<Filter>,
+                pub predicates: _rt::Vec<WhereNode>,
+                pub order_by: _rt::Vec<OrderKey>,
+                pub group_by: _rt::Vec<u32>,
+                pub aggregates: _rt::Vec<Aggregate>,
+                pub limit: Option<u32>,
+                pub offset: u32,
             }
             impl ::core::fmt::Debug for Input {
                 fn fmt(
@@ -143,12 +350,19 @@ pub mod uicp {
                 ) -> ::core::fmt::Result {
                     f.debug_struct("Input")
                         .field("rows", &self.rows)
+                        .field("has-header", &self.has_header)
                         .field("select", &self.select)
                         .field("where-contains", &self.where_contains)
+                        .field("predicates", &self.predicates)
+                        .field("order-by", &self.order_by)
+                        .field("group-by", &self.group_by)
+                        .field("aggregates", &self.aggregates)
+                        .field("limit", &self.limit)
+                        .field("offset", &self.offset)
                         .finish()
                 }
             }
-            pub type Output = _rt::Vec<_rt::Vec<_rt::String>>;
+            pub type Output = _rt::Vec<_rt::Vec<Cell>>;
         }
     }
 }
@@ -1618,33 +1832,24 @@ pub mod exports {
                 use super::super::super::super::_rt;
                 pub type Input = super::super::super::super::uicp::task_table_query::types::Input;
                 pub type Output = super::super::super::super::uicp::task_table_query::types::Output;
-                #[repr(u8)]
-                #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+                #[derive(Clone)]
                 pub enum Error {
                     Cancelled,
-                }
-                impl Error {
-                    pub fn name(&self) -> &'static str {
-                        match self {
-                            Error::Cancelled => "cancelled",
-                        }
-                    }
-                    pub fn message(&self) -> &'static str {
-                        match self {
-                            Error::Cancelled => "",
-                        }
-                    }
+                    InvalidQuery(_rt::String),
                 }
                 impl ::core::fmt::Debug for Error {
                     fn fmt(
                         &self,
                         f: &mut ::core::fmt::Formatter<'_>,
                     ) -> ::core::fmt::Result {
-                        f.debug_struct("Error")
-                            .field("code", &(*self as i32))
-                            .field("name", &self.name())
-                            .field("message", &self.message())
-                            .finish()
+                        match self {
+                            Error::Cancelled => {
+                                f.debug_tuple("Error::Cancelled").finish()
+                            }
+                            Error::InvalidQuery(e) => {
+                                f.debug_tuple("Error::InvalidQuery").field(e).finish()
+                            }
+                        }
                     }
                 }
                 impl ::core::fmt::Display for Error {
@@ -1652,185 +1857,492 @@ pub mod exports {
                         &self,
                         f: &mut ::core::fmt::Formatter<'_>,
                     ) -> ::core::fmt::Result {
-                        write!(f, "{} (error {})", self.name(), * self as i32)
+                        write!(f, "{:?}", self)
                     }
                 }
                 impl std::error::Error for Error {}
-                impl Error {
-                    #[doc(hidden)]
-                    pub unsafe fn _lift(val: u8) -> Error {
-                        if !cfg!(debug_assertions) {
-                            return ::core::mem::transmute(val);
-                        }
-                        match val {
-                            0 => Error::Cancelled,
-                            _ => panic!("invalid enum discriminant"),
-                        }
-                    }
-                }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_run_cabi<T: Guest>(
-                    arg0: *mut u8,
-                    arg1: usize,
-                    arg2: *mut u8,
-                    arg3: usize,
-                    arg4: *mut u8,
-                    arg5: usize,
-                    arg6: i32,
-                    arg7: i32,
-                    arg8: *mut u8,
-                    arg9: usize,
-                ) -> *mut u8 {
+                pub unsafe fn _export_run_cabi<T: Guest>(arg0: *mut u8) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let len0 = arg1;
-                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-                    let base7 = arg2;
-                    let len7 = arg3;
-                    let mut result7 = _rt::Vec::with_capacity(len7);
-                    for i in 0..len7 {
-                        let base = base7
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len2 = l1;
+                    let bytes2 = _rt::Vec::from_raw_parts(l0.cast(), len2, len2);
+                    let l3 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l4 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base16 = l3;
+                    let len16 = l4;
+                    let mut result16 = _rt::Vec::with_capacity(len16);
+                    for i in 0..len16 {
+                        let base = base16
                             .add(i * (2 * ::core::mem::size_of::<*const u8>()));
-                        let e7 = {
-                            let l1 = *base.add(0).cast::<*mut u8>();
-                            let l2 = *base
+                        let e16 = {
+                            let l5 = *base.add(0).cast::<*mut u8>();
+                            let l6 = *base
                                 .add(::core::mem::size_of::<*const u8>())
                                 .cast::<usize>();
-                            let base6 = l1;
-                            let len6 = l2;
-                            let mut result6 = _rt::Vec::with_capacity(len6);
-                            for i in 0..len6 {
-                                let base = base6
-                                    .add(i * (2 * ::core::mem::size_of::<*const u8>()));
-                                let e6 = {
-                                    let l3 = *base.add(0).cast::<*mut u8>();
-                                    let l4 = *base
-                                        .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<usize>();
-                                    let len5 = l4;
-                                    let bytes5 = _rt::Vec::from_raw_parts(
-                                        l3.cast(),
-                                        len5,
-                                        len5,
-                                    );
-                                    _rt::string_lift(bytes5)
+                            let base15 = l5;
+                            let len15 = l6;
+                            let mut result15 = _rt::Vec::with_capacity(len15);
+                            for i in 0..len15 {
+                                let base = base15
+                                    .add(i * (8 + 2 * ::core::mem::size_of::<*const u8>()));
+                                let e15 = {
+                                    let l7 = i32::from(*base.add(0).cast::<u8>());
+                                    use super::super::super::super::uicp::task_table_query::types::Cell as V14;
+                                    let v14 = match l7 {
+                                        0 => V14::Null,
+                                        1 => {
+                                            let e14 = {
+                                                let l8 = i32::from(*base.add(8).cast::<u8>());
+                                                _rt::bool_lift(l8 as u8)
+                                            };
+                                            V14::Boolean(e14)
+                                        }
+                                        2 => {
+                                            let e14 = {
+                                                let l9 = *base.add(8).cast::<i64>();
+                                                l9
+                                            };
+                                            V14::Int(e14)
+                                        }
+                                        3 => {
+                                            let e14 = {
+                                                let l10 = *base.add(8).cast::<f64>();
+                                                l10
+                                            };
+                                            V14::Float(e14)
+                                        }
+                                        n => {
+                                            debug_assert_eq!(n, 4, "invalid enum discriminant");
+                                            let e14 = {
+                                                let l11 = *base.add(8).cast::<*mut u8>();
+                                                let l12 = *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>();
+                                                let len13 = l12;
+                                                let bytes13 = _rt::Vec::from_raw_parts(
+                                                    l11.cast(),
+                                                    len13,
+                                                    len13,
+                                                );
+                                                _rt::string_lift(bytes13)
+                                            };
+                                            V14::Text(e14)
+                                        }
+                                    };
+                                    v14
                                 };
-                                result6.push(e6);
+                                result15.push(e15);
                             }
                             _rt::cabi_dealloc(
-                                base6,
-                                len6 * (2 * ::core::mem::size_of::<*const u8>()),
-                                ::core::mem::size_of::<*const u8>(),
+                                base15,
+                                len15 * (8 + 2 * ::core::mem::size_of::<*const u8>()),
+                                8,
+                            );
+                            result15
+                        };
+                        result16.push(e16);
+                    }
+                    _rt::cabi_dealloc(
+                        base16,
+                        len16 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0.add(4 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                    );
+                    let l18 = *arg0
+                        .add(5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l19 = *arg0
+                        .add(6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len20 = l19;
+                    let l21 = i32::from(
+                        *arg0.add(7 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                    );
+                    let l26 = *arg0
+                        .add(11 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l27 = *arg0
+                        .add(12 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base47 = l26;
+                    let len47 = l27;
+                    let mut result47 = _rt::Vec::with_capacity(len47);
+                    for i in 0..len47 {
+                        let base = base47
+                            .add(i * (32 + 2 * ::core::mem::size_of::<*const u8>()));
+                        let e47 = {
+                            let l28 = i32::from(*base.add(0).cast::<u8>());
+                            use super::super::super::super::uicp::task_table_query::types::WhereNode as V46;
+                            let v46 = match l28 {
+                                0 => {
+                                    let e46 = {
+                                        let l29 = *base.add(8).cast::<i32>();
+                                        let l30 = i32::from(*base.add(12).cast::<u8>());
+                                        let l31 = i32::from(*base.add(16).cast::<u8>());
+                                        use super::super::super::super::uicp::task_table_query::types::Cell as V38;
+                                        let v38 = match l31 {
+                                            0 => V38::Null,
+                                            1 => {
+                                                let e38 = {
+                                                    let l32 = i32::from(*base.add(24).cast::<u8>());
+                                                    _rt::bool_lift(l32 as u8)
+                                                };
+                                                V38::Boolean(e38)
+                                            }
+                                            2 => {
+                                                let e38 = {
+                                                    let l33 = *base.add(24).cast::<i64>();
+                                                    l33
+                                                };
+                                                V38::Int(e38)
+                                            }
+                                            3 => {
+                                                let e38 = {
+                                                    let l34 = *base.add(24).cast::<f64>();
+                                                    l34
+                                                };
+                                                V38::Float(e38)
+                                            }
+                                            n => {
+                                                debug_assert_eq!(n, 4, "invalid enum discriminant");
+                                                let e38 = {
+                                                    let l35 = *base.add(24).cast::<*mut u8>();
+                                                    let l36 = *base
+                                                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    let len37 = l36;
+                                                    let bytes37 = _rt::Vec::from_raw_parts(
+                                                        l35.cast(),
+                                                        len37,
+                                                        len37,
+                                                    );
+                                                    _rt::string_lift(bytes37)
+                                                };
+                                                V38::Text(e38)
+                                            }
+                                        };
+                                        let l39 = i32::from(
+                                            *base
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        super::super::super::super::uicp::task_table_query::types::Predicate {
+                                            col: l29 as u32,
+                                            op: super::super::super::super::uicp::task_table_query::types::CompareOp::_lift(
+                                                l30 as u8,
+                                            ),
+                                            value: v38,
+                                            case_insensitive: _rt::bool_lift(l39 as u8),
+                                        }
+                                    };
+                                    V46::Leaf(e46)
+                                }
+                                1 => {
+                                    let e46 = {
+                                        let l40 = *base.add(8).cast::<*mut u8>();
+                                        let l41 = *base
+                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len42 = l41;
+                                        _rt::Vec::from_raw_parts(l40.cast(), len42, len42)
+                                    };
+                                    V46::All(e46)
+                                }
+                                n => {
+                                    debug_assert_eq!(n, 2, "invalid enum discriminant");
+                                    let e46 = {
+                                        let l43 = *base.add(8).cast::<*mut u8>();
+                                        let l44 = *base
+                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len45 = l44;
+                                        _rt::Vec::from_raw_parts(l43.cast(), len45, len45)
+                                    };
+                                    V46::Any(e46)
+                                }
+                            };
+                            v46
+                        };
+                        result47.push(e47);
+                    }
+                    _rt::cabi_dealloc(
+                        base47,
+                        len47 * (32 + 2 * ::core::mem::size_of::<*const u8>()),
+                        8,
+                    );
+                    let l48 = *arg0
+                        .add(13 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l49 = *arg0
+                        .add(14 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base52 = l48;
+                    let len52 = l49;
+                    let mut result52 = _rt::Vec::with_capacity(len52);
+                    for i in 0..len52 {
+                        let base = base52.add(i * 8);
+                        let e52 = {
+                            let l50 = *base.add(0).cast::<i32>();
+                            let l51 = i32::from(*base.add(4).cast::<u8>());
+                            super::super::super::super::uicp::task_table_query::types::OrderKey {
+                                col: l50 as u32,
+                                descending: _rt::bool_lift(l51 as u8),
+                            }
+                        };
+                        result52.push(e52);
+                    }
+                    _rt::cabi_dealloc(base52, len52 * 8, 4);
+                    let l53 = *arg0
+                        .add(15 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l54 = *arg0
+                        .add(16 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len55 = l54;
+                    let l56 = *arg0
+                        .add(17 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l57 = *arg0
+                        .add(18 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base64 = l56;
+                    let len64 = l57;
+                    let mut result64 = _rt::Vec::with_capacity(len64);
+                    for i in 0..len64 {
+                        let base = base64
+                            .add(i * (8 + 3 * ::core::mem::size_of::<*const u8>()));
+                        let e64 = {
+                            let l58 = i32::from(*base.add(0).cast::<u8>());
+                            let l59 = i32::from(*base.add(4).cast::<u8>());
+                            let l61 = *base
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l62 = *base
+                                .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len63 = l62;
+                            let bytes63 = _rt::Vec::from_raw_parts(
+                                l61.cast(),
+                                len63,
+                                len63,
                             );
-                            result6
+                            super::super::super::super::uicp::task_table_query::types::Aggregate {
+                                op: super::super::super::super::uicp::task_table_query::types::AggregateOp::_lift(
+                                    l58 as u8,
+                                ),
+                                col: match l59 {
+                                    0 => None,
+                                    1 => {
+                                        let e = {
+                                            let l60 = *base.add(8).cast::<i32>();
+                                            l60 as u32
+                                        };
+                                        Some(e)
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                },
+                                label: _rt::string_lift(bytes63),
+                            }
                         };
-                        result7.push(e7);
+                        result64.push(e64);
                     }
                     _rt::cabi_dealloc(
-                        base7,
-                        len7 * (2 * ::core::mem::size_of::<*const u8>()),
+                        base64,
+                        len64 * (8 + 3 * ::core::mem::size_of::<*const u8>()),
                         ::core::mem::size_of::<*const u8>(),
                     );
-                    let len8 = arg5;
-                    let result10 = T::run(
-                        _rt::string_lift(bytes0),
+                    let l65 = i32::from(
+                        *arg0.add(19 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                    );
+                    let l67 = *arg0
+                        .add(8 + 19 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let result68 = T::run(
+                        _rt::string_lift(bytes2),
                         super::super::super::super::uicp::task_table_query::types::Input {
-                            rows: result7,
-                            select: _rt::Vec::from_raw_parts(arg4.cast(), len8, len8),
-                            where_contains: match arg6 {
+                            rows: result16,
+                            has_header: _rt::bool_lift(l17 as u8),
+                            select: _rt::Vec::from_raw_parts(l18.cast(), len20, len20),
+                            where_contains: match l21 {
                                 0 => None,
                                 1 => {
                                     let e = {
-                                        let len9 = arg9;
-                                        let bytes9 = _rt::Vec::from_raw_parts(
-                                            arg8.cast(),
-                                            len9,
-                                            len9,
+                                        let l22 = *arg0
+                                            .add(8 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let l23 = *arg0
+                                            .add(9 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>();
+                                        let l24 = *arg0
+                                            .add(10 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len25 = l24;
+                                        let bytes25 = _rt::Vec::from_raw_parts(
+                                            l23.cast(),
+                                            len25,
+                                            len25,
                                         );
                                         super::super::super::super::uicp::task_table_query::types::Filter {
-                                            col: arg7 as u32,
-                                            needle: _rt::string_lift(bytes9),
+                                            col: l22 as u32,
+                                            needle: _rt::string_lift(bytes25),
                                         }
                                     };
                                     Some(e)
                                 }
                                 _ => _rt::invalid_enum_discriminant(),
                             },
+                            predicates: result47,
+                            order_by: result52,
+                            group_by: _rt::Vec::from_raw_parts(l53.cast(), len55, len55),
+                            aggregates: result64,
+                            limit: match l65 {
+                                0 => None,
+                                1 => {
+                                    let e = {
+                                        let l66 = *arg0
+                                            .add(4 + 19 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        l66 as u32
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            offset: l67 as u32,
                         },
                     );
-                    let ptr11 = (&raw mut _RET_AREA.0).cast::<u8>();
-                    match result10 {
+                    _rt::cabi_dealloc(
+                        arg0,
+                        8 + 20 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let ptr69 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result68 {
                         Ok(e) => {
-                            *ptr11.add(0).cast::<u8>() = (0i32) as u8;
-                            let vec14 = e;
-                            let len14 = vec14.len();
-                            let layout14 = _rt::alloc::Layout::from_size_align_unchecked(
-                                vec14.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                            *ptr69.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec73 = e;
+                            let len73 = vec73.len();
+                            let layout73 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec73.len() * (2 * ::core::mem::size_of::<*const u8>()),
                                 ::core::mem::size_of::<*const u8>(),
                             );
-                            let result14 = if layout14.size() != 0 {
-                                let ptr = _rt::alloc::alloc(layout14).cast::<u8>();
+                            let result73 = if layout73.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout73).cast::<u8>();
                                 if ptr.is_null() {
-                                    _rt::alloc::handle_alloc_error(layout14);
+                                    _rt::alloc::handle_alloc_error(layout73);
                                 }
                                 ptr
                             } else {
                                 ::core::ptr::null_mut()
                             };
-                            for (i, e) in vec14.into_iter().enumerate() {
-                                let base = result14
+                            for (i, e) in vec73.into_iter().enumerate() {
+                                let base = result73
                                     .add(i * (2 * ::core::mem::size_of::<*const u8>()));
                                 {
-                                    let vec13 = e;
-                                    let len13 = vec13.len();
-                                    let layout13 = _rt::alloc::Layout::from_size_align_unchecked(
-                                        vec13.len() * (2 * ::core::mem::size_of::<*const u8>()),
-                                        ::core::mem::size_of::<*const u8>(),
+                                    let vec72 = e;
+                                    let len72 = vec72.len();
+                                    let layout72 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec72.len() * (8 + 2 * ::core::mem::size_of::<*const u8>()),
+                                        8,
                                     );
-                                    let result13 = if layout13.size() != 0 {
-                                        let ptr = _rt::alloc::alloc(layout13).cast::<u8>();
+                                    let result72 = if layout72.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout72).cast::<u8>();
                                         if ptr.is_null() {
-                                            _rt::alloc::handle_alloc_error(layout13);
+                                            _rt::alloc::handle_alloc_error(layout72);
                                         }
                                         ptr
                                     } else {
                                         ::core::ptr::null_mut()
                                     };
-                                    for (i, e) in vec13.into_iter().enumerate() {
-                                        let base = result13
-                                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                    for (i, e) in vec72.into_iter().enumerate() {
+                                        let base = result72
+                                            .add(i * (8 + 2 * ::core::mem::size_of::<*const u8>()));
                                         {
-                                            let vec12 = (e.into_bytes()).into_boxed_slice();
-                                            let ptr12 = vec12.as_ptr().cast::<u8>();
-                                            let len12 = vec12.len();
-                                            ::core::mem::forget(vec12);
-                                            *base
-                                                .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<usize>() = len12;
-                                            *base.add(0).cast::<*mut u8>() = ptr12.cast_mut();
+                                            use super::super::super::super::uicp::task_table_query::types::Cell as V71;
+                                            match e {
+                                                V71::Null => {
+                                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                                }
+                                                V71::Boolean(e) => {
+                                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                                    *base.add(8).cast::<u8>() = (match e {
+                                                        true => 1,
+                                                        false => 0,
+                                                    }) as u8;
+                                                }
+                                                V71::Int(e) => {
+                                                    *base.add(0).cast::<u8>() = (2i32) as u8;
+                                                    *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                                }
+                                                V71::Float(e) => {
+                                                    *base.add(0).cast::<u8>() = (3i32) as u8;
+                                                    *base.add(8).cast::<f64>() = _rt::as_f64(e);
+                                                }
+                                                V71::Text(e) => {
+                                                    *base.add(0).cast::<u8>() = (4i32) as u8;
+                                                    let vec70 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr70 = vec70.as_ptr().cast::<u8>();
+                                                    let len70 = vec70.len();
+                                                    ::core::mem::forget(vec70);
+                                                    *base
+                                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len70;
+                                                    *base.add(8).cast::<*mut u8>() = ptr70.cast_mut();
+                                                }
+                                            }
                                         }
                                     }
                                     *base
                                         .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<usize>() = len13;
-                                    *base.add(0).cast::<*mut u8>() = result13;
+                                        .cast::<usize>() = len72;
+                                    *base.add(0).cast::<*mut u8>() = result72;
                                 }
                             }
-                            *ptr11
+                            *ptr69
                                 .add(2 * ::core::mem::size_of::<*const u8>())
-                                .cast::<usize>() = len14;
-                            *ptr11
+                                .cast::<usize>() = len73;
+                            *ptr69
                                 .add(::core::mem::size_of::<*const u8>())
-                                .cast::<*mut u8>() = result14;
+                                .cast::<*mut u8>() = result73;
                         }
                         Err(e) => {
-                            *ptr11.add(0).cast::<u8>() = (1i32) as u8;
-                            *ptr11
-                                .add(::core::mem::size_of::<*const u8>())
-                                .cast::<u8>() = (e.clone() as i32) as u8;
+                            *ptr69.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                Error::Cancelled => {
+                                    *ptr69
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                }
+                                Error::InvalidQuery(e) => {
+                                    *ptr69
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    let vec74 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr74 = vec74.as_ptr().cast::<u8>();
+                                    let len74 = vec74.len();
+                                    ::core::mem::forget(vec74);
+                                    *ptr69
+                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len74;
+                                    *ptr69
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr74.cast_mut();
+                                }
+                            }
                         }
                     };
-                    ptr11
+                    ptr69
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
@@ -1844,75 +2356,97 @@ pub mod exports {
                             let l2 = *arg0
                                 .add(2 * ::core::mem::size_of::<*const u8>())
                                 .cast::<usize>();
-                            let base8 = l1;
-                            let len8 = l2;
-                            for i in 0..len8 {
-                                let base = base8
+                            let base9 = l1;
+                            let len9 = l2;
+                            for i in 0..len9 {
+                                let base = base9
                                     .add(i * (2 * ::core::mem::size_of::<*const u8>()));
                                 {
                                     let l3 = *base.add(0).cast::<*mut u8>();
                                     let l4 = *base
                                         .add(::core::mem::size_of::<*const u8>())
                                         .cast::<usize>();
-                                    let base7 = l3;
-                                    let len7 = l4;
-                                    for i in 0..len7 {
-                                        let base = base7
-                                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                    let base8 = l3;
+                                    let len8 = l4;
+                                    for i in 0..len8 {
+                                        let base = base8
+                                            .add(i * (8 + 2 * ::core::mem::size_of::<*const u8>()));
                                         {
-                                            let l5 = *base.add(0).cast::<*mut u8>();
-                                            let l6 = *base
-                                                .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<usize>();
-                                            _rt::cabi_dealloc(l5, l6, 1);
+                                            let l5 = i32::from(*base.add(0).cast::<u8>());
+                                            match l5 {
+                                                0 => {}
+                                                1 => {}
+                                                2 => {}
+                                                3 => {}
+                                                _ => {
+                                                    let l6 = *base.add(8).cast::<*mut u8>();
+                                                    let l7 = *base
+                                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    _rt::cabi_dealloc(l6, l7, 1);
+                                                }
+                                            }
                                         }
                                     }
                                     _rt::cabi_dealloc(
-                                        base7,
-                                        len7 * (2 * ::core::mem::size_of::<*const u8>()),
-                                        ::core::mem::size_of::<*const u8>(),
+                                        base8,
+                                        len8 * (8 + 2 * ::core::mem::size_of::<*const u8>()),
+                                        8,
                                     );
                                 }
                             }
                             _rt::cabi_dealloc(
-                                base8,
-                                len8 * (2 * ::core::mem::size_of::<*const u8>()),
+                                base9,
+                                len9 * (2 * ::core::mem::size_of::<*const u8>()),
                                 ::core::mem::size_of::<*const u8>(),
                             );
                         }
-                        _ => {}
+                        _ => {
+                            let l10 = i32::from(
+                                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                            );
+                            match l10 {
+                                0 => {}
+                                _ => {
+                                    let l11 = *arg0
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l12 = *arg0
+                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l11, l12, 1);
+                                }
+                            }
+                        }
                     }
                 }
                 pub trait Guest {
                     fn run(job_id: _rt::String, input: Input) -> Result<Output, Error>;
                 }
                 #[doc(hidden)]
-                macro_rules! __export_uicp_task_table_query_table_0_1_0_cabi {
+                macro_rules! __export_uicp_task_table_query_table_0_2_0_cabi {
                     ($ty:ident with_types_in $($path_to_types:tt)*) => {
                         const _ : () = { #[unsafe (export_name =
-                        "uicp:task-table-query/table@0.1.0#run")] unsafe extern "C" fn
-                        export_run(arg0 : * mut u8, arg1 : usize, arg2 : * mut u8, arg3 :
-                        usize, arg4 : * mut u8, arg5 : usize, arg6 : i32, arg7 : i32,
-                        arg8 : * mut u8, arg9 : usize,) -> * mut u8 { unsafe {
-                        $($path_to_types)*:: _export_run_cabi::<$ty > (arg0, arg1, arg2,
-                        arg3, arg4, arg5, arg6, arg7, arg8, arg9) } } #[unsafe
+                        "uicp:task-table-query/table@0.2.0#run")] unsafe extern "C" fn
+                        export_run(arg0 : * mut u8,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_run_cabi::<$ty > (arg0) } } #[unsafe
                         (export_name =
-                        "cabi_post_uicp:task-table-query/table@0.1.0#run")] unsafe extern
+                        "cabi_post_uicp:task-table-query/table@0.2.0#run")] unsafe extern
                         "C" fn _post_return_run(arg0 : * mut u8,) { unsafe {
                         $($path_to_types)*:: __post_return_run::<$ty > (arg0) } } };
                     };
                 }
                 #[doc(hidden)]
-                pub(crate) use __export_uicp_task_table_query_table_0_1_0_cabi;
+                pub(crate) use __export_uicp_task_table_query_table_0_2_0_cabi;
                 #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
                 #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
                 struct _RetArea(
                     [::core::mem::MaybeUninit<
                         u8,
-                    >; 3 * ::core::mem::size_of::<*const u8>()],
+                    >; 4 * ::core::mem::size_of::<*const u8>()],
                 );
                 static mut _RET_AREA: _RetArea = _RetArea(
-                    [::core::mem::MaybeUninit::uninit(); 3
+                    [::core::mem::MaybeUninit::uninit(); 4
                         * ::core::mem::size_of::<*const u8>()],
                 );
             }
@@ -2058,6 +2592,23 @@ mod _rt {
         let layout = alloc::Layout::from_size_align_unchecked(size, align);
         alloc::dealloc(ptr, layout);
     }
+    pub fn as_f64<T: AsF64>(t: T) -> f64 {
+        t.as_f64()
+    }
+    pub trait AsF64 {
+        fn as_f64(self) -> f64;
+    }
+    impl<'a, T: Copy + AsF64> AsF64 for &'a T {
+        fn as_f64(self) -> f64 {
+            (*self).as_f64()
+        }
+    }
+    impl AsF64 for f64 {
+        #[inline]
+        fn as_f64(self) -> f64 {
+            self as f64
+        }
+    }
     extern crate alloc as alloc_crate;
 }
 /// Generates `#[unsafe(no_mangle)]` functions to export the specified type as
@@ -2084,21 +2635,19 @@ macro_rules! __export_task_impl {
     };
     ($ty:ident with_types_in $($path_to_types_root:tt)*) => {
         $($path_to_types_root)*::
-        exports::uicp::task_table_query::table::__export_uicp_task_table_query_table_0_1_0_cabi!($ty
+        exports::uicp::task_table_query::table::__export_uicp_task_table_query_table_0_2_0_cabi!($ty
         with_types_in $($path_to_types_root)*:: exports::uicp::task_table_query::table);
     };
 }
 #[doc(inline)]
 pub(crate) use __export_task_impl as export;
 #[cfg(target_arch = "wasm32")]
-#[unsafe(
-    link_section = "component-type:wit-bindgen:0.41.0:uicp:task-table-query@0.1.0:task:encoded world"
-)]
+#[unsafe(link_section = "component-type:wit-bindgen:0.41.0:uicp:task-table-query@0.2.0:task:encoded world")]
 #[doc(hidden)]
 #[allow(clippy::octal_escapes)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 2005] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xda\x0e\x01A\x02\x01\
-A\x14\x01B\x04\x04\0\x05error\x03\x01\x01h\0\x01@\x01\x04self\x01\0s\x04\0\x1d[m\
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 2445] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\x92\x12\x01A\x02\x01\
+A\x15\x01B\x04\x04\0\x05error\x03\x01\x01h\0\x01@\x01\x04self\x01\0s\x04\0\x1d[m\
 ethod]error.to-debug-string\x01\x02\x03\0\x13wasi:io/error@0.2.8\x05\0\x01B\x0a\x04\
 \0\x08pollable\x03\x01\x01h\0\x01@\x01\x04self\x01\0\x7f\x04\0\x16[method]pollab\
 le.ready\x01\x02\x01@\x01\x04self\x01\x01\0\x04\0\x16[method]pollable.block\x01\x03\
@@ -2130,17 +2679,27 @@ ock@0.2.3\x05\x05\x02\x03\0\x02\x0doutput-stream\x01B\x0a\x02\x03\x02\x01\x06\x0
 \0\x0doutput-stream\x03\0\0\x01i\x01\x01@\x01\x03jobs\0\x02\x04\0\x11open-partia\
 l-sink\x01\x03\x01@\x01\x03jobs\0\x7f\x04\0\x0dshould-cancel\x01\x04\x01@\x01\x03\
 jobs\0y\x04\0\x0bdeadline-ms\x01\x05\x04\0\x0cremaining-ms\x01\x05\x03\0\x17uicp\
-:host/control@1.0.0\x05\x07\x01B\x0a\x01r\x02\x03coly\x06needles\x04\0\x06filter\
-\x03\0\0\x01ps\x01p\x02\x01py\x01k\x01\x01r\x03\x04rows\x03\x06select\x04\x0ewhe\
-re-contains\x05\x04\0\x05input\x03\0\x06\x01p\x02\x04\0\x06output\x03\0\x08\x03\0\
-!uicp:task-table-query/types@0.1.0\x05\x08\x02\x03\0\x05\x06filter\x02\x03\0\x05\
-\x05input\x02\x03\0\x05\x06output\x01B\x0b\x02\x03\x02\x01\x09\x04\0\x06filter\x03\
-\0\0\x02\x03\x02\x01\x0a\x04\0\x05input\x03\0\x02\x02\x03\x02\x01\x0b\x04\0\x06o\
-utput\x03\0\x04\x01m\x01\x09cancelled\x04\0\x05error\x03\0\x06\x01j\x01\x05\x01\x07\
-\x01@\x02\x06job-ids\x05input\x03\0\x08\x04\0\x03run\x01\x09\x04\0!uicp:task-tab\
-le-query/table@0.1.0\x05\x0c\x04\0\x20uicp:task-table-query/task@0.1.0\x04\0\x0b\
-\x0a\x01\0\x04task\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-compon\
-ent\x070.227.1\x10wit-bindgen-rust\x060.41.0";
+:host/control@1.0.0\x05\x07\x01B\x1c\x01q\x05\x04null\0\0\x07boolean\x01\x7f\0\x03\
+int\x01x\0\x05float\x01u\0\x04text\x01s\0\x04\0\x04cell\x03\0\0\x01r\x02\x03coly\
+\x06needles\x04\0\x06filter\x03\0\x02\x01m\x08\x02eq\x02ne\x02lt\x03lte\x02gt\x03\
+gte\x08contains\x05regex\x04\0\x0acompare-op\x03\0\x04\x01r\x04\x03coly\x02op\x05\
+\x05value\x01\x10case-insensitive\x7f\x04\0\x09predicate\x03\0\x06\x01py\x01q\x03\
+\x04leaf\x01\x07\0\x03all\x01\x08\0\x03any\x01\x08\0\x04\0\x0awhere-node\x03\0\x09\
+\x01r\x02\x03coly\x0adescending\x7f\x04\0\x09order-key\x03\0\x0b\x01m\x05\x05cou\
+nt\x03sum\x03min\x03max\x03avg\x04\0\x0caggregate-op\x03\0\x0d\x01ky\x01r\x03\x02\
+op\x0e\x03col\x0f\x05labels\x04\0\x09aggregate\x03\0\x10\x01p\x01\x01p\x12\x01k\x03\
+\x01p\x0a\x01p\x0c\x01p\x11\x01r\x0a\x04rows\x13\x0ahas-header\x7f\x06select\x08\
+\x0ewhere-contains\x14\x0apredicates\x15\x08order-by\x16\x08group-by\x08\x0aaggr\
+egates\x17\x05limit\x0f\x06offsety\x04\0\x05input\x03\0\x18\x01p\x12\x04\0\x06ou\
+tput\x03\0\x1a\x03\0!uicp:task-table-query/types@0.2.0\x05\x08\x02\x03\0\x05\x04\
+cell\x02\x03\0\x05\x06filter\x02\x03\0\x05\x05input\x02\x03\0\x05\x06output\x01B\
+\x0d\x02\x03\x02\x01\x09\x04\0\x04cell\x03\0\0\x02\x03\x02\x01\x0a\x04\0\x06filt\
+er\x03\0\x02\x02\x03\x02\x01\x0b\x04\0\x05input\x03\0\x04\x02\x03\x02\x01\x0c\x04\
+\0\x06output\x03\0\x06\x01q\x02\x09cancelled\0\0\x0dinvalid-query\x01s\0\x04\0\x05\
+error\x03\0\x08\x01j\x01\x07\x01\x09\x01@\x02\x06job-ids\x05input\x05\0\x0a\x04\0\
+\x03run\x01\x0b\x04\0!uicp:task-table-query/table@0.2.0\x05\x0d\x04\0\x20uicp:ta\
+sk-table-query/task@0.2.0\x04\0\x0b\x0a\x01\0\x04task\x03\0\0\0G\x09producers\x01\
+\x0cprocessed-by\x02\x0dwit-component\x070.227.1\x10wit-bindgen-rust\x060.41.0";
 #[inline(never)]
 #[doc(hidden)]
 pub fn __link_custom_section_describing_imports() {
@@ -1,4 +1,4 @@
-//! table.query@0.1.0 component entrypoint.
+//! table.query@0.2.0 component entrypoint.
 //! WHY: Keep host coordination contained for maintainable bindings upgrades.
 
 #![allow(clippy::missing_errors_doc)]
@@ -6,8 +6,15 @@
 #[allow(warnings)]
 mod bindings;
 
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
 use bindings::exports::uicp::task_table_query::table::{Error, Guest, Input, Output};
 use bindings::uicp::host::control;
+use bindings::uicp::task_table_query::types::{
+    Aggregate, AggregateOp, Cell, CompareOp, OrderKey, WhereNode,
+};
 use bindings::wasi::clocks::monotonic_clock;
 use bindings::wasi::io::streams::OutputStream;
 use ciborium::ser::into_writer;
@@ -15,6 +22,7 @@ use ciborium::value::{Integer, Value};
 
 const PARTIAL_KIND_PROGRESS: u8 = 0;
 const PARTIAL_KIND_TOTAL: u8 = 2;
+const PROGRESS_EVERY_ROWS: usize = 100;
 
 type FilterSpec = (usize, String);
 
@@ -26,69 +34,520 @@ impl Guest for Component {
     }
 }
 
-bindings::exports::uicp::task_table_query::table::__export_uicp_task_table_query_table_0_1_0_cabi!(
+bindings::exports::uicp::task_table_query::table::__export_uicp_task_table_query_table_0_2_0_cabi!(
     Component with_types_in bindings::exports::uicp::task_table_query::table
 );
 
 // WHY: Separate orchestration from trait impl for easier unit testing.
 fn run_table_query(job_id: String, input: Input) -> Result<Output, Error> {
+    let mut sink = control::open_partial_sink(&job_id);
+    let mut seq = 0u32;
+
+    let (output, total) = execute(input, |idx| {
+        if idx % PROGRESS_EVERY_ROWS == 0 {
+            emit_progress(&mut sink, &job_id, &mut seq, idx as u64);
+        }
+        if should_cancel(&job_id) {
+            return Err(Error::Cancelled);
+        }
+        Ok(())
+    })?;
+
+    emit_total(&mut sink, &job_id, &mut seq, total as u64);
+
+    Ok(output)
+}
+
+// WHY: Host cancellation may be cooperative (user) or deadline-driven.
+fn should_cancel(job_id: &str) -> bool {
+    control::should_cancel(job_id) || control::remaining_ms(job_id) == 0
+}
+
+/// Runs every query stage: filter, group/aggregate, order, offset/limit, project.
+/// `on_row` is called once per scanned data row (for progress and cancellation); the returned
+/// count excludes the header row.
+fn execute(
+    input: Input,
+    mut on_row: impl FnMut(usize) -> Result<(), Error>,
+) -> Result<(Output, usize), Error> {
     let Input {
-        rows,
+        mut rows,
+        has_header,
         select,
         where_contains,
+        predicates,
+        order_by,
+        group_by,
+        aggregates,
+        limit,
+        offset,
     } = input;
 
-    let select_indices: Vec<usize> = select.into_iter().map(|idx| idx as usize).collect();
+    let predicates = compile_predicates(predicates)?;
+    if let Some(agg) = aggregates
+        .iter()
+        .find(|a| a.col.is_none() && a.op != AggregateOp::Count)
+    {
+        return Err(Error::InvalidQuery(format!(
+            "aggregate '{}' requires a column",
+            agg.label
+        )));
+    }
     let filter = where_contains.map(|f| (f.col as usize, f.needle));
+    let header = if has_header && !rows.is_empty() {
+        Some(rows.remove(0))
+    } else {
+        None
+    };
 
-    let mut sink = control::open_partial_sink(&job_id);
-    let mut seq = 0u32;
+    let mut kept = Vec::with_capacity(rows.len());
+    let mut scratch = Vec::with_capacity(predicates.len());
+    for (idx, row) in rows.into_iter().enumerate() {
+        on_row(idx)?;
+        if matches_contains(&row, filter.as_ref()) && predicates.matches(&row, &mut scratch) {
+            kept.push(row);
+        }
+    }
 
-    let filtered_rows = filter_rows(rows, filter.as_ref());
-    let mut projected: Output = Vec::with_capacity(filtered_rows.len());
-    for (idx, row) in filtered_rows.into_iter().enumerate() {
-        let cells = project_row(&row, &select_indices);
-        projected.push(cells);
+    let (mut rows, header) = if group_by.is_empty() && aggregates.is_empty() {
+        (kept, header)
+    } else {
+        let header = header.map(|h| grouped_header(&h, &group_by, &aggregates));
+        (group_rows(kept, &group_by, &aggregates), header)
+    };
 
-        if idx % 100 == 0 {
-            emit_progress(&mut sink, &job_id, &mut seq, idx as u64);
+    sort_rows(&mut rows, &order_by);
+
+    let select: Vec<usize> = select.into_iter().map(|idx| idx as usize).collect();
+    let window = rows
+        .iter()
+        .skip(offset as usize)
+        .take(limit.map_or(usize::MAX, |l| l as usize));
+    let mut projected: Output = Vec::with_capacity(rows.len() + 1);
+    if let Some(h) = header {
+        projected.push(project_row(&h, &select));
+    }
+    projected.extend(window.map(|row| project_row(row, &select)));
+    let total = projected.len() - usize::from(has_header && !projected.is_empty());
+
+    Ok((projected, total))
+}
+
+/// Legacy single-column substring filter (`where-contains`); rows missing the column never match.
+fn matches_contains(row: &[Cell], filter: Option<&FilterSpec>) -> bool {
+    match filter {
+        Some((col, needle)) => row
+            .get(*col)
+            .map(|cell| render(cell).contains(needle.as_str()))
+            .unwrap_or(false),
+        None => true,
+    }
+}
+
+enum Test {
+    Compare {
+        op: CompareOp,
+        value: Cell,
+        case_insensitive: bool,
+    },
+    Contains {
+        needle: String,
+        case_insensitive: bool,
+    },
+    Regex(regex_lite::Regex),
+}
+
+enum Node {
+    Leaf { col: usize, test: Test },
+    All(Vec<usize>),
+    Any(Vec<usize>),
+}
+
+/// Post-order predicate list from the host; the last node is the root.
+struct Predicates(Vec<Node>);
+
+fn compile_predicates(nodes: Vec<WhereNode>) -> Result<Predicates, Error> {
+    let mut out = Vec::with_capacity(nodes.len());
+    for (idx, node) in nodes.into_iter().enumerate() {
+        // INVARIANT: children precede their parent, which rules out cycles and lets `matches`
+        // evaluate the list in one forward pass.
+        let children = |list: Vec<u32>| -> Result<Vec<usize>, Error> {
+            list.into_iter()
+                .map(|c| c as usize)
+                .map(|c| {
+                    if c < idx {
+                        Ok(c)
+                    } else {
+                        Err(Error::InvalidQuery(format!(
+                            "where node {idx} references node {c}, which does not precede it"
+                        )))
+                    }
+                })
+                .collect()
+        };
+        out.push(match node {
+            WhereNode::All(list) => Node::All(children(list)?),
+            WhereNode::Any(list) => Node::Any(children(list)?),
+            WhereNode::Leaf(p) => {
+                let test = match (p.op, p.value) {
+                    (CompareOp::Contains, Cell::Text(needle)) => Test::Contains {
+                        needle: if p.case_insensitive {
+                            needle.to_lowercase()
+                        } else {
+                            needle
+                        },
+                        case_insensitive: p.case_insensitive,
+                    },
+                    (CompareOp::Regex, Cell::Text(pattern)) => Test::Regex(
+                        regex_lite::RegexBuilder::new(&pattern)
+                            .case_insensitive(p.case_insensitive)
+                            .build()
+                            .map_err(|e| {
+                                Error::InvalidQuery(format!("invalid regex '{pattern}': {e}"))
+                            })?,
+                    ),
+                    (CompareOp::Contains | CompareOp::Regex, _) => {
+                        return Err(Error::InvalidQuery(format!(
+                            "where node {idx}: contains/regex need a text value"
+                        )));
+                    }
+                    (op, value) => Test::Compare {
+                        op,
+                        value,
+                        case_insensitive: p.case_insensitive,
+                    },
+                };
+                Node::Leaf {
+                    col: p.col as usize,
+                    test,
+                }
+            }
+        });
+    }
+    Ok(Predicates(out))
+}
+
+impl Predicates {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// An empty list matches every row. `scratch` holds per-node results and is reused per row.
+    fn matches(&self, row: &[Cell], scratch: &mut Vec<bool>) -> bool {
+        scratch.clear();
+        for node in &self.0 {
+            let hit = match node {
+                Node::All(children) => children.iter().all(|&c| scratch[c]),
+                Node::Any(children) => children.iter().any(|&c| scratch[c]),
+                Node::Leaf { col, test } => {
+                    leaf_matches(row.get(*col).unwrap_or(&Cell::Null), test)
+                }
+            };
+            scratch.push(hit);
         }
+        scratch.last().copied().unwrap_or(true)
+    }
+}
 
-        if should_cancel(&job_id) {
-            return Err(Error::Cancelled);
+fn leaf_matches(cell: &Cell, test: &Test) -> bool {
+    match test {
+        Test::Contains {
+            needle,
+            case_insensitive,
+        } => {
+            let text = render(cell);
+            if *case_insensitive {
+                text.to_lowercase().contains(needle.as_str())
+            } else {
+                text.contains(needle.as_str())
+            }
+        }
+        Test::Regex(re) => re.is_match(&render(cell)),
+        Test::Compare {
+            op,
+            value,
+            case_insensitive,
+        } => {
+            let ord = compare_cells(cell, value, *case_insensitive);
+            match op {
+                CompareOp::Eq => ord == Some(Ordering::Equal),
+                CompareOp::Ne => ord != Some(Ordering::Equal),
+                CompareOp::Lt => ord == Some(Ordering::Less),
+                CompareOp::Lte => matches!(ord, Some(Ordering::Less | Ordering::Equal)),
+                CompareOp::Gt => ord == Some(Ordering::Greater),
+                CompareOp::Gte => matches!(ord, Some(Ordering::Greater | Ordering::Equal)),
+                CompareOp::Contains | CompareOp::Regex => false,
+            }
         }
     }
+}
 
-    emit_total(&mut sink, &job_id, &mut seq, projected.len() as u64);
+/// Predicate comparison. Numbers compare numerically, and text that parses as a number compares
+/// against numeric literals so CSV-sourced columns can be range-filtered. Incomparable kinds
+/// (e.g. text vs boolean, anything vs null) yield `None`, which only `ne` accepts.
+fn compare_cells(a: &Cell, b: &Cell, case_insensitive: bool) -> Option<Ordering> {
+    match (a, b) {
+        (Cell::Null, Cell::Null) => Some(Ordering::Equal),
+        (Cell::Null, _) | (_, Cell::Null) => None,
+        (Cell::Boolean(x), Cell::Boolean(y)) => Some(x.cmp(y)),
+        (Cell::Int(x), Cell::Int(y)) => Some(x.cmp(y)),
+        (Cell::Text(x), Cell::Text(y)) if case_insensitive => {
+            Some(x.to_lowercase().cmp(&y.to_lowercase()))
+        }
+        (Cell::Text(x), Cell::Text(y)) => Some(x.cmp(y)),
+        _ => numeric(a)?.partial_cmp(&numeric(b)?),
+    }
+}
 
-    Ok(projected)
+fn numeric(cell: &Cell) -> Option<f64> {
+    match cell {
+        Cell::Int(i) => Some(*i as f64),
+        Cell::Float(f) => Some(*f),
+        Cell::Text(s) => s.trim().parse::<f64>().ok(),
+        Cell::Null | Cell::Boolean(_) => None,
+    }
 }
 
-// WHY: Host cancellation may be cooperative (user) or deadline-driven.
-fn should_cancel(job_id: &str) -> bool {
-    control::should_cancel(job_id) || control::remaining_ms(job_id) == 0
+/// Text form used by `contains`, `regex` and `where-contains`.
+fn render(cell: &Cell) -> Cow<'_, str> {
+    match cell {
+        Cell::Null => Cow::Borrowed(""),
+        Cell::Boolean(b) => Cow::Borrowed(if *b { "true" } else { "false" }),
+        Cell::Int(i) => Cow::Owned(i.to_string()),
+        Cell::Float(f) => Cow::Owned(f.to_string()),
+        Cell::Text(s) => Cow::Borrowed(s.as_str()),
+    }
 }
 
-// WHY: Split selection so filter + projection logic stays pure for tests.
-fn filter_rows(rows: Vec<Vec<String>>, filter: Option<&FilterSpec>) -> Vec<Vec<String>> {
-    match filter {
-        Some((col, needle)) => rows
-            .into_iter()
-            .filter(|row| {
-                row.get(*col)
-                    .map(|cell| cell.contains(needle))
-                    .unwrap_or(false)
+/// Total order for sorting and min/max: null < boolean < number < text. Text that parses as a
+/// finite number sorts with the numbers so CSV columns order numerically.
+#[derive(PartialEq, PartialOrd)]
+enum SortKey<'a> {
+    Null,
+    Boolean(bool),
+    Number(TotalF64),
+    Text(&'a str),
+}
+
+#[derive(PartialEq)]
+struct TotalF64(f64);
+
+impl PartialOrd for TotalF64 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.0.total_cmp(&other.0))
+    }
+}
+
+fn sort_key(cell: &Cell) -> SortKey<'_> {
+    match cell {
+        Cell::Null => SortKey::Null,
+        Cell::Boolean(b) => SortKey::Boolean(*b),
+        // NOTE: integers beyond 2^53 lose precision here; ordering among them may tie.
+        Cell::Int(i) => SortKey::Number(TotalF64(*i as f64)),
+        Cell::Float(f) => SortKey::Number(TotalF64(*f)),
+        Cell::Text(s) => match s.trim().parse::<f64>() {
+            Ok(n) if n.is_finite() => SortKey::Number(TotalF64(n)),
+            _ => SortKey::Text(s.as_str()),
+        },
+    }
+}
+
+fn sort_order(a: &Cell, b: &Cell) -> Ordering {
+    sort_key(a)
+        .partial_cmp(&sort_key(b))
+        .unwrap_or(Ordering::Equal)
+}
+
+// INVARIANT: Stable sort, so rows equal on every key keep their input order.
+fn sort_rows(rows: &mut [Vec<Cell>], keys: &[OrderKey]) {
+    if keys.is_empty() {
+        return;
+    }
+    rows.sort_by(|a, b| {
+        keys.iter()
+            .map(|k| {
+                let col = k.col as usize;
+                let ord = sort_order(
+                    a.get(col).unwrap_or(&Cell::Null),
+                    b.get(col).unwrap_or(&Cell::Null),
+                );
+                if k.descending {
+                    ord.reverse()
+                } else {
+                    ord
+                }
             })
-            .collect(),
-        None => rows,
+            .find(|ord| ord.is_ne())
+            .unwrap_or(Ordering::Equal)
+    });
+}
+
+/// Hashable form of a group-by cell; grouping is by exact typed value.
+#[derive(PartialEq, Eq, Hash)]
+enum GroupKey {
+    Null,
+    Boolean(bool),
+    Int(i64),
+    Float(u64),
+    Text(String),
+}
+
+impl From<&Cell> for GroupKey {
+    fn from(cell: &Cell) -> Self {
+        match cell {
+            Cell::Null => Self::Null,
+            Cell::Boolean(b) => Self::Boolean(*b),
+            Cell::Int(i) => Self::Int(*i),
+            Cell::Float(f) => Self::Float(f.to_bits()),
+            Cell::Text(s) => Self::Text(s.clone()),
+        }
+    }
+}
+
+enum Acc {
+    Count(i64),
+    Sum {
+        int: Option<i64>,
+        float: f64,
+        seen: bool,
+    },
+    Min(Option<Cell>),
+    Max(Option<Cell>),
+    Avg {
+        total: f64,
+        n: u64,
+    },
+}
+
+impl Acc {
+    fn new(op: AggregateOp) -> Self {
+        match op {
+            AggregateOp::Count => Self::Count(0),
+            AggregateOp::Sum => Self::Sum {
+                int: Some(0),
+                float: 0.0,
+                seen: false,
+            },
+            AggregateOp::Min => Self::Min(None),
+            AggregateOp::Max => Self::Max(None),
+            AggregateOp::Avg => Self::Avg { total: 0.0, n: 0 },
+        }
+    }
+
+    /// `cell` is `None` for `count` without a column; nulls are skipped by every other aggregate.
+    fn add(&mut self, cell: Option<&Cell>) {
+        match self {
+            Self::Count(n) => {
+                if !matches!(cell, Some(Cell::Null)) {
+                    *n += 1;
+                }
+            }
+            Self::Sum { int, float, seen } => {
+                let Some(cell) = cell else { return };
+                let exact = match cell {
+                    Cell::Int(i) => Some(*i),
+                    Cell::Text(s) => s.trim().parse::<i64>().ok(),
+                    _ => None,
+                };
+                let Some(value) = exact.map(|i| i as f64).or_else(|| numeric(cell)) else {
+                    return;
+                };
+                *seen = true;
+                *float += value;
+                // WHY: stay integral while every input is an integer and the sum fits in i64.
+                *int = match (*int, exact) {
+                    (Some(acc), Some(i)) => acc.checked_add(i),
+                    _ => None,
+                };
+            }
+            Self::Min(best) => keep_extreme(best, cell, Ordering::Less),
+            Self::Max(best) => keep_extreme(best, cell, Ordering::Greater),
+            Self::Avg { total, n } => {
+                if let Some(value) = cell.and_then(numeric) {
+                    *total += value;
+                    *n += 1;
+                }
+            }
+        }
+    }
+
+    fn finish(self) -> Cell {
+        match self {
+            Self::Count(n) => Cell::Int(n),
+            Self::Sum { seen: false, .. } => Cell::Null,
+            Self::Sum { int: Some(i), .. } => Cell::Int(i),
+            Self::Sum { float, .. } => Cell::Float(float),
+            Self::Min(best) | Self::Max(best) => best.unwrap_or(Cell::Null),
+            Self::Avg { n: 0, .. } => Cell::Null,
+            Self::Avg { total, n } => Cell::Float(total / n as f64),
+        }
+    }
+}
+
+fn keep_extreme(best: &mut Option<Cell>, cell: Option<&Cell>, want: Ordering) {
+    let Some(cell) = cell.filter(|c| !matches!(c, Cell::Null)) else {
+        return;
+    };
+    if best.as_ref().is_none_or(|b| sort_order(cell, b) == want) {
+        *best = Some(cell.clone());
     }
 }
 
-// INVARIANT: Returned projection maintains selection order; missing cells become "".
-fn project_row(row: &[String], select: &[usize]) -> Vec<String> {
+/// Collapses rows into one row per distinct group-by key (first-seen order): the group-by cells
+/// followed by one value per aggregate. Without group-by columns every row lands in one group, so
+/// a table-wide aggregate returns exactly one row even when no rows matched.
+fn group_rows(rows: Vec<Vec<Cell>>, group_by: &[u32], aggregates: &[Aggregate]) -> Vec<Vec<Cell>> {
+    let mut index: HashMap<Vec<GroupKey>, usize> = HashMap::new();
+    let mut groups: Vec<(Vec<Cell>, Vec<Acc>)> = Vec::new();
+    if group_by.is_empty() {
+        groups.push((
+            Vec::new(),
+            aggregates.iter().map(|a| Acc::new(a.op)).collect(),
+        ));
+    }
+    for row in &rows {
+        let cells: Vec<Cell> = group_by
+            .iter()
+            .map(|&c| row.get(c as usize).cloned().unwrap_or(Cell::Null))
+            .collect();
+        let slot = if group_by.is_empty() {
+            0
+        } else {
+            let key: Vec<GroupKey> = cells.iter().map(GroupKey::from).collect();
+            *index.entry(key).or_insert_with(|| {
+                groups.push((cells, aggregates.iter().map(|a| Acc::new(a.op)).collect()));
+                groups.len() - 1
+            })
+        };
+        for (acc, agg) in groups[slot].1.iter_mut().zip(aggregates) {
+            match agg.col {
+                Some(c) => acc.add(Some(row.get(c as usize).unwrap_or(&Cell::Null))),
+                None => acc.add(None),
+            }
+        }
+    }
+    groups
+        .into_iter()
+        .map(|(mut cells, accs)| {
+            cells.extend(accs.into_iter().map(Acc::finish));
+            cells
+        })
+        .collect()
+}
+
+fn grouped_header(header: &[Cell], group_by: &[u32], aggregates: &[Aggregate]) -> Vec<Cell> {
+    group_by
+        .iter()
+        .map(|&c| header.get(c as usize).cloned().unwrap_or(Cell::Null))
+        .chain(aggregates.iter().map(|a| Cell::Text(a.label.clone())))
+        .collect()
+}
+
+// INVARIANT: Returned projection maintains selection order; missing cells become null.
+fn project_row(row: &[Cell], select: &[usize]) -> Vec<Cell> {
     let mut out = Vec::with_capacity(select.len());
     for &idx in select {
-        out.push(row.get(idx).cloned().unwrap_or_default());
+        out.push(row.get(idx).cloned().unwrap_or(Cell::Null));
     }
     out
 }
@@ -177,24 +636,256 @@ fn cbor_envelope(kind: u8, seq: u32, timestamp: u64, payload: Option<Value>) ->
 #[cfg(test)]
 mod tests {
     use super::*;
+    use bindings::uicp::task_table_query::types::{Filter, Predicate};
+
+    fn text(s: &str) -> Cell {
+        Cell::Text(s.into())
+    }
+
+    fn input(rows: Vec<Vec<Cell>>, select: &[u32]) -> Input {
+        Input {
+            rows,
+            has_header: false,
+            select: select.to_vec(),
+            where_contains: None,
+            predicates: Vec::new(),
+            order_by: Vec::new(),
+            group_by: Vec::new(),
+            aggregates: Vec::new(),
+            limit: None,
+            offset: 0,
+        }
+    }
+
+    fn run(input: Input) -> Output {
+        execute(input, |_| Ok(())).expect("query").0
+    }
+
+    // NOTE: generated `Cell` has no `PartialEq`; compare through its `Debug` form.
+    fn assert_rows(actual: &[Vec<Cell>], expected: &[Vec<Cell>]) {
+        assert_eq!(format!("{actual:?}"), format!("{expected:?}"));
+    }
+
+    fn leaf(col: u32, op: CompareOp, value: Cell) -> WhereNode {
+        WhereNode::Leaf(Predicate {
+            col,
+            op,
+            value,
+            case_insensitive: false,
+        })
+    }
+
+    fn people() -> Vec<Vec<Cell>> {
+        vec![
+            vec![text("name"), text("city"), text("age")],
+            vec![text("alice"), text("Austin"), text("30")],
+            vec![text("bob"), text("boston"), Cell::Int(25)],
+            vec![text("carol"), text("chicago"), Cell::Float(41.5)],
+            vec![text("dave"), text("austin"), Cell::Null],
+        ]
+    }
 
     #[test]
-    fn filter_rows_applies_contains() {
-        let rows = vec![
-            vec!["header".into(), "city".into()],
-            vec!["alice".into(), "austin".into()],
-            vec!["bob".into(), "boston".into()],
-            vec!["carol".into(), "chicago".into()],
-        ];
-        let filter = (1usize, "bo".to_string());
-        let filtered = filter_rows(rows, Some(&filter));
-        assert_eq!(filtered, vec![vec!["bob".into(), "boston".into()]]);
+    fn where_contains_still_filters_and_projects() {
+        let mut q = input(people(), &[0, 1]);
+        q.where_contains = Some(Filter {
+            col: 1,
+            needle: "bo".into(),
+        });
+        assert_rows(&run(q), &[vec![text("bob"), text("boston")]]);
     }
 
     #[test]
     fn project_row_respects_selection() {
-        let row = vec!["name".into(), "city".into(), "zip".into()];
+        let row = vec![text("name"), text("city"), text("zip")];
         let projected = project_row(&row, &[0, 2, 5]);
-        assert_eq!(projected, vec!["name".into(), "zip".into(), "".into()]);
+        assert_rows(&[projected], &[vec![text("name"), text("zip"), Cell::Null]]);
+    }
+
+    #[test]
+    fn predicate_tree_combines_and_or_range_and_regex() {
+        // (city ~ /^aus/i) OR (age >= 26 AND age < 45), over the header-less data rows.
+        let mut q = input(people()[1..].to_vec(), &[0]);
+        q.predicates = vec![
+            WhereNode::Leaf(Predicate {
+                col: 1,
+                op: CompareOp::Regex,
+                value: text("^aus"),
+                case_insensitive: true,
+            }),
+            leaf(2, CompareOp::Gte, Cell::Int(26)),
+            leaf(2, CompareOp::Lt, Cell::Int(45)),
+            WhereNode::All(vec![1, 2]),
+            WhereNode::Any(vec![0, 3]),
+        ];
+        assert_rows(
+            &run(q),
+            &[vec![text("alice")], vec![text("carol")], vec![text("dave")]],
+        );
+    }
+
+    #[test]
+    fn case_insensitive_equality_and_contains() {
+        let mut q = input(people()[1..].to_vec(), &[0]);
+        q.predicates = vec![WhereNode::Leaf(Predicate {
+            col: 1,
+            op: CompareOp::Eq,
+            value: text("AUSTIN"),
+            case_insensitive: true,
+        })];
+        assert_rows(&run(q), &[vec![text("alice")], vec![text("dave")]]);
+
+        let mut q = input(people()[1..].to_vec(), &[0]);
+        q.predicates = vec![WhereNode::Leaf(Predicate {
+            col: 1,
+            op: CompareOp::Contains,
+            value: text("OST"),
+            case_insensitive: true,
+        })];
+        assert_rows(&run(q), &[vec![text("bob")]]);
+    }
+
+    #[test]
+    fn malformed_predicates_are_rejected() {
+        let mut q = input(people(), &[0]);
+        q.predicates = vec![WhereNode::All(vec![0])];
+        assert!(matches!(
+            execute(q, |_| Ok(())),
+            Err(Error::InvalidQuery(_))
+        ));
+
+        let mut q = input(people(), &[0]);
+        q.predicates = vec![leaf(0, CompareOp::Regex, text("("))];
+        assert!(matches!(
+            execute(q, |_| Ok(())),
+            Err(Error::InvalidQuery(_))
+        ));
+    }
+
+    #[test]
+    fn header_is_kept_out_of_filters_and_sorted_rows() {
+        let mut q = input(people(), &[0, 2]);
+        q.has_header = true;
+        q.order_by = vec![
+            OrderKey {
+                col: 2,
+                descending: true,
+            },
+            OrderKey {
+                col: 0,
+                descending: false,
+            },
+        ];
+        q.offset = 1;
+        q.limit = Some(2);
+        let (out, total) = execute(q, |_| Ok(())).expect("query");
+        assert_rows(
+            &out,
+            &[
+                vec![text("name"), text("age")],
+                vec![text("alice"), text("30")],
+                vec![text("bob"), Cell::Int(25)],
+            ],
+        );
+        assert_eq!(total, 2);
+    }
+
+    #[test]
+    fn group_by_with_aggregates() {
+        let rows = vec![
+            vec![text("dept"), text("salary")],
+            vec![text("eng"), Cell::Int(10)],
+            vec![text("ops"), text("5")],
+            vec![text("eng"), Cell::Float(2.5)],
+            vec![text("ops"), Cell::Null],
+        ];
+        let agg = |op, col: Option<u32>, label: &str| Aggregate {
+            op,
+            col,
+            label: label.into(),
+        };
+        let mut q = input(rows, &[0, 1, 2, 3, 4, 5]);
+        q.has_header = true;
+        q.group_by = vec![0];
+        q.aggregates = vec![
+            agg(AggregateOp::Count, None, "count"),
+            agg(AggregateOp::Sum, Some(1), "sum(salary)"),
+            agg(AggregateOp::Min, Some(1), "min(salary)"),
+            agg(AggregateOp::Max, Some(1), "max(salary)"),
+            agg(AggregateOp::Avg, Some(1), "avg(salary)"),
+        ];
+        q.order_by = vec![OrderKey {
+            col: 1,
+            descending: true,
+        }];
+        assert_rows(
+            &run(q),
+            &[
+                vec![
+                    text("dept"),
+                    text("count"),
+                    text("sum(salary)"),
+                    text("min(salary)"),
+                    text("max(salary)"),
+                    text("avg(salary)"),
+                ],
+                vec![
+                    text("eng"),
+                    Cell::Int(2),
+                    Cell::Float(12.5),
+                    Cell::Float(2.5),
+                    Cell::Int(10),
+                    Cell::Float(6.25),
+                ],
+                vec![
+                    text("ops"),
+                    Cell::Int(2),
+                    Cell::Int(5),
+                    text("5"),
+                    text("5"),
+                    Cell::Float(5.0),
+                ],
+            ],
+        );
+    }
+
+    #[test]
+    fn table_wide_aggregate_returns_one_row_even_when_empty() {
+        let mut q = input(Vec::new(), &[0, 1]);
+        q.aggregates = vec![
+            Aggregate {
+                op: AggregateOp::Count,
+                col: None,
+                label: "count".into(),
+            },
+            Aggregate {
+                op: AggregateOp::Sum,
+                col: Some(0),
+                label: "sum".into(),
+            },
+        ];
+        assert_rows(&run(q), &[vec![Cell::Int(0), Cell::Null]]);
+    }
+
+    #[test]
+    fn on_row_sees_every_data_row_and_can_cancel() {
+        let mut seen = Vec::new();
+        let mut q = input(people(), &[0]);
+        q.has_header = true;
+        execute(q, |idx| {
+            seen.push(idx);
+            Ok(())
+        })
+        .expect("query");
+        assert_eq!(seen, vec![0, 1, 2, 3]);
+
+        let cancelled = execute(input(people(), &[0]), |idx| {
+            if idx == 2 {
+                Err(Error::Cancelled)
+            } else {
+                Ok(())
+            }
+        });
+        assert!(matches!(cancelled, Err(Error::Cancelled)));
     }
 }
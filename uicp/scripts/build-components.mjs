@@ -10,35 +10,92 @@ const repoRoot = resolve(here, '..');
 const modulesDir = process.env.UICP_MODULES_DIR || join(repoRoot, 'src-tauri', 'modules');
 
 const components = [
+  // WHY: Each version is its own crate; rebuilding one never overwrites another's world.
   {
     name: 'csv.parse',
     version: '1.2.0',
-    dir: join(repoRoot, 'components', 'csv.parse'),
+    dir: join(repoRoot, 'components', 'csv.parse', 'csv-parse-1.2'),
     outs: [
       join(
         repoRoot,
         'components',
         'csv.parse',
+        'csv-parse-1.2',
         'target',
         'wasm32-wasi',
         'release',
-        'uicp_task_csv_parse.wasm',
+        'csv_parse.wasm',
       ),
       join(
         repoRoot,
         'components',
         'csv.parse',
+        'csv-parse-1.2',
         'target',
         'wasm32-wasip1',
         'release',
-        'uicp_task_csv_parse.wasm',
+        'csv_parse.wasm',
       ),
     ],
     filename: 'csv.parse@1.2.0.wasm',
   },
+  {
+    name: 'csv.parse',
+    version: '1.3.0',
+    dir: join(repoRoot, 'components', 'csv.parse', 'csv-parse'),
+    outs: [
+      join(
+        repoRoot,
+        'components',
+        'csv.parse',
+        'csv-parse',
+        'target',
+        'wasm32-wasi',
+        'release',
+        'csv_parse.wasm',
+      ),
+      join(
+        repoRoot,
+        'components',
+        'csv.parse',
+        'csv-parse',
+        'target',
+        'wasm32-wasip1',
+        'release',
+        'csv_parse.wasm',
+      ),
+    ],
+    filename: 'csv.parse@1.3.0.wasm',
+  },
   {
     name: 'table.query',
     version: '0.1.0',
+    dir: join(repoRoot, 'components', 'table.query-0.1'),
+    outs: [
+      join(
+        repoRoot,
+        'components',
+        'table.query-0.1',
+        'target',
+        'wasm32-wasi',
+        'release',
+        'uicp_task_table_query.wasm',
+      ),
+      join(
+        repoRoot,
+        'components',
+        'table.query-0.1',
+        'target',
+        'wasm32-wasip1',
+        'release',
+        'uicp_task_table_query.wasm',
+      ),
+    ],
+    filename: 'table.query@0.1.0.wasm',
+  },
+  {
+    name: 'table.query',
+    version: '0.2.0',
     dir: join(repoRoot, 'components', 'table.query'),
     outs: [
       join(
@@ -60,11 +117,63 @@ const components = [
         'uicp_task_table_query.wasm',
       ),
     ],
-    filename: 'table.query@0.1.0.wasm',
+    filename: 'table.query@0.2.0.wasm',
   },
   {
-    name: 'applet.quickjs',
+    name: 'json.query',
     version: '0.1.0',
+    dir: join(repoRoot, 'components', 'json.query'),
+    outs: [
+      join(
+        repoRoot,
+        'components',
+        'json.query',
+        'target',
+        'wasm32-wasi',
+        'release',
+        'json_query.wasm',
+      ),
+      join(
+        repoRoot,
+        'components',
+        'json.query',
+        'target',
+        'wasm32-wasip1',
+        'release',
+        'json_query.wasm',
+      ),
+    ],
+    filename: 'json.query@0.1.0.wasm',
+  },
+  {
+    name: 'markdown.render',
+    version: '0.1.0',
+    dir: join(repoRoot, 'components', 'markdown.render'),
+    outs: [
+      join(
+        repoRoot,
+        'components',
+        'markdown.render',
+        'target',
+        'wasm32-wasi',
+        'release',
+        'markdown_render.wasm',
+      ),
+      join(
+        repoRoot,
+        'components',
+        'markdown.render',
+        'target',
+        'wasm32-wasip1',
+        'release',
+        'markdown_render.wasm',
+      ),
+    ],
+    filename: 'markdown.render@0.1.0.wasm',
+  },
+  {
+    name: 'applet.quickjs',
+    version: '0.3.0',
     dir: join(repoRoot, 'components', 'applet.quickjs'),
     outs: [
       join(
@@ -86,7 +195,7 @@ const components = [
         'applet_quickjs.wasm',
       ),
     ],
-    filename: 'applet.quickjs@0.1.0.wasm',
+    filename: 'applet.quickjs@0.3.0.wasm',
   },
 ];
 
@@ -57,14 +57,20 @@ function privateKeyFromSeed(seed) {
   });
 }
 
-function canonicalMessage(task, version, digestHex) {
+// Mirrors registry::canonical_imports: sorted, de-duplicated, comma-joined.
+function canonicalImports(imports) {
+  if (!Array.isArray(imports)) return null;
+  return [...new Set(imports.map((s) => String(s).trim()))].sort().join(",");
+}
+
+function canonicalMessage(task, version, digestHex, imports, signedAt) {
   if (!/^[0-9a-f]{64}$/i.test(digestHex)) {
     throw new Error(
       `digest_sha256 must be 64 hex characters (got ${digestHex.length})`
     );
   }
   const digest = Buffer.from(digestHex, "hex");
-  return Buffer.concat([
+  const parts = [
     Buffer.from("UICP-MODULE\0", "utf8"),
     Buffer.from(`task=${task}`, "utf8"),
     Buffer.from([0]),
@@ -72,7 +78,16 @@ function canonicalMessage(task, version, digestHex) {
     Buffer.from([0]),
     Buffer.from("sha256=", "utf8"),
     digest,
-  ]);
+  ];
+  const importList = canonicalImports(imports);
+  if (importList !== null) {
+    parts.push(Buffer.from([0]), Buffer.from(`imports=${importList}`, "utf8"));
+  }
+  // signed_at is covered so key expiry cannot be dodged by backdating it.
+  if (signedAt !== undefined) {
+    parts.push(Buffer.from([0]), Buffer.from(`signed_at=${signedAt}`, "utf8"));
+  }
+  return Buffer.concat(parts);
 }
 
 async function main() {
@@ -109,14 +124,21 @@ async function main() {
     process.exit(1);
   }
 
-  const message = canonicalMessage(task, version, entry.digest_sha256);
+  const signedAt = Math.floor(Date.now() / 1000);
+  const message = canonicalMessage(
+    task,
+    version,
+    entry.digest_sha256,
+    entry.imports,
+    signedAt
+  );
   const key = privateKeyFromSeed(seed);
   const signatureBytes = edSign(null, message, key);
   const signatureB64 = Buffer.from(signatureBytes).toString("base64");
 
   entry.signature = signatureB64;
   entry.keyid = keyid;
-  entry.signed_at = Math.floor(Date.now() / 1000);
+  entry.signed_at = signedAt;
 
   await writeFile(
     manifestPath,
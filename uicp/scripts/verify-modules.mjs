@@ -65,7 +65,7 @@ function loadTrustStore() {
   return map;
 }
 
-function buildMessage(task, version, digestHex) {
+function buildMessage(task, version, digestHex, imports, signedAt) {
   const digest = Buffer.from(digestHex, 'hex');
   if (digest.length !== 32) throw new Error('digest must be 32 bytes hex');
   const parts = [
@@ -79,6 +79,14 @@ function buildMessage(task, version, digestHex) {
     Buffer.from('sha256='),
     digest,
   ];
+  // Declared import allowlists are bound into the signature (sorted, de-duplicated).
+  if (Array.isArray(imports)) {
+    const list = [...new Set(imports.map((s) => String(s).trim()))].sort().join(',');
+    parts.push(Buffer.from([0]), Buffer.from(`imports=${list}`));
+  }
+  if (Number.isInteger(signedAt)) {
+    parts.push(Buffer.from([0]), Buffer.from(`signed_at=${signedAt}`));
+  }
   return Buffer.concat(parts);
 }
 
@@ -145,8 +153,13 @@ async function main() {
             // Decode signature (base64 preferred; fallback hex)
             let sig = decodeB64OrHex(entry.signature);
             if (!sig) throw new Error('invalid signature encoding');
-            const msg = buildMessage(entry.task, entry.version, hex);
-            const ok = edVerify(null, msg, keyObj, sig);
+            const msg = buildMessage(entry.task, entry.version, hex, entry.imports, entry.signed_at);
+            // Signatures from before signed_at was covered still verify, as in the host.
+            const legacy = Number.isInteger(entry.signed_at)
+              ? buildMessage(entry.task, entry.version, hex, entry.imports)
+              : null;
+            const ok =
+              edVerify(null, msg, keyObj, sig) || (legacy !== null && edVerify(null, legacy, keyObj, sig));
             if (!ok) {
               failures.push({ filename: entry.filename, reason: 'signature_invalid' });
             }
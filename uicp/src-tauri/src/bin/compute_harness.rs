@@ -14,11 +14,12 @@ use uicp::ComputeJobSpec;
 async fn main() -> Result<()> {
     let mut args = env::args().skip(1);
     let Some(cmd) = args.next() else {
-        bail!("missing command (expected 'run')");
+        bail!("missing command (expected 'run' or 'replay')");
     };
 
     match cmd.as_str() {
         "run" => run_command(args).await?,
+        "replay" => replay_command(args).await?,
         other => bail!("unknown command: {other}"),
     }
 
@@ -82,3 +83,36 @@ async fn run_command(mut args: impl Iterator<Item = String>) -> Result<()> {
 
     Ok(())
 }
+
+/// `replay [--data-dir <dir>] <job-id>`: re-execute a logged job and print the replay report.
+/// Exits non-zero when the output hash does not reproduce.
+async fn replay_command(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut data_dir: Option<PathBuf> = None;
+    let mut job_id: Option<String> = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--data-dir" => {
+                let path = args.next().context("--data-dir requires a value")?;
+                data_dir = Some(PathBuf::from(path));
+            }
+            flag if flag.starts_with("--") => bail!("unknown flag for replay command: {flag}"),
+            id => job_id = Some(id.to_string()),
+        }
+    }
+    let job_id = job_id.context("replay requires a job id")?;
+    let data_dir =
+        data_dir.context("replay requires --data-dir holding the original action log")?;
+
+    let harness = ComputeTestHarness::with_data_dir_async(data_dir).await?;
+    let report = harness.replay_job(&job_id).await?;
+    println!("{}", serde_json::to_string(&report)?);
+    if !report.matched {
+        bail!(
+            "replay of {job_id} did not reproduce (expected {}, got {})",
+            report.expected_hash,
+            report.actual_hash.as_deref().unwrap_or("no output")
+        );
+    }
+    Ok(())
+}
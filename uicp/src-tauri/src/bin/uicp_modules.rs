@@ -0,0 +1,424 @@
+use std::{
+    collections::HashMap,
+    env, fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use uicp::compute::registry::{
+    self, ModuleEntry, ModuleManifest, ModuleProvenance, SignatureStatus,
+};
+use uicp::compute::trust_store;
+use uicp::{log_error, log_warn, parse_seed};
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            log_error(format!("uicp-modules: {err:?}"));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<()> {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    if args.is_empty() {
+        print_usage();
+        return Err(anyhow!("E-UICP-0650: missing command"));
+    }
+    let cmd = args.remove(0);
+    match cmd.as_str() {
+        "digest" => digest_cmd(&args),
+        "add" => add_cmd(&args),
+        "sign" => sign_cmd(&args),
+        "provenance" => provenance_cmd(&args),
+        "verify" => verify_cmd(&args),
+        "--help" | "-h" => {
+            print_usage();
+            Ok(())
+        }
+        other => Err(anyhow!("E-UICP-0651: unknown command '{other}'")),
+    }
+}
+
+/// `--flag value` pairs for `cmd`; `switches` are flags that take no value and map to "true".
+fn parse_flags(
+    cmd: &str,
+    args: &[String],
+    flags: &[&str],
+    switches: &[&str],
+) -> Result<HashMap<String, String>> {
+    let mut out = HashMap::new();
+    let mut idx = 0usize;
+    while idx < args.len() {
+        let flag = args[idx].as_str();
+        if switches.contains(&flag) {
+            out.insert(flag.to_string(), "true".to_string());
+        } else if flags.contains(&flag) {
+            idx += 1;
+            let value = args
+                .get(idx)
+                .with_context(|| format!("E-UICP-0652: {flag} expects a value"))?;
+            out.insert(flag.to_string(), value.clone());
+        } else {
+            bail!("E-UICP-0653: unexpected argument '{flag}' for {cmd} command");
+        }
+        idx += 1;
+    }
+    Ok(out)
+}
+
+fn required<'a>(flags: &'a HashMap<String, String>, flag: &str) -> Result<&'a str> {
+    flags
+        .get(flag)
+        .map(String::as_str)
+        .with_context(|| format!("E-UICP-0654: {flag} is required"))
+}
+
+fn optional_i64(flags: &HashMap<String, String>, flag: &str) -> Result<Option<i64>> {
+    flags
+        .get(flag)
+        .map(|raw| {
+            raw.parse::<i64>()
+                .with_context(|| format!("E-UICP-0652: {flag} expects unix seconds"))
+        })
+        .transpose()
+}
+
+fn modules_dir(flags: &HashMap<String, String>) -> Result<PathBuf> {
+    Ok(PathBuf::from(required(flags, "--dir")?))
+}
+
+fn read_manifest(dir: &Path) -> Result<ModuleManifest> {
+    registry::read_manifest_file(dir)
+        .with_context(|| format!("E-UICP-0659: read manifest in {}", dir.display()))
+}
+
+fn write_manifest(dir: &Path, manifest: &ModuleManifest) -> Result<()> {
+    registry::write_manifest_file(dir, manifest)
+        .with_context(|| format!("E-UICP-0659: write manifest in {}", dir.display()))
+}
+
+fn digest_cmd(args: &[String]) -> Result<()> {
+    if args.is_empty() {
+        bail!("E-UICP-0654: digest expects at least one file");
+    }
+    for file in args {
+        let digest = registry::file_sha256_hex(Path::new(file))
+            .with_context(|| format!("E-UICP-0659: hash {file}"))?;
+        println!("{digest}  {file}");
+    }
+    Ok(())
+}
+
+/// Record `{task}@{version}.wasm` in `manifest.json` with its current digest. `--file` copies a
+/// build artifact into the directory first. An existing entry keeps its signature only if the
+/// digest and imports are unchanged.
+fn add_cmd(args: &[String]) -> Result<()> {
+    let flags = parse_flags(
+        "add",
+        args,
+        &["--dir", "--task", "--version", "--file", "--imports"],
+        &[],
+    )?;
+    let dir = modules_dir(&flags)?;
+    let task = required(&flags, "--task")?;
+    let version = required(&flags, "--version")?;
+    // WHY: Task and version name the destination file; check them before anything is copied.
+    if !registry::is_valid_task_id(task) {
+        bail!("E-UICP-0652: --task must match [a-z0-9._-]+, got '{task}'");
+    }
+    semver::Version::parse(version)
+        .with_context(|| format!("E-UICP-0652: --version must be semver, got '{version}'"))?;
+    let filename = format!("{task}@{version}.wasm");
+    if !registry::is_clean_filename(&filename) {
+        bail!("E-UICP-0652: {filename} is not a plain file name");
+    }
+    let dest = dir.join(&filename);
+    if let Some(source) = flags.get("--file") {
+        fs::create_dir_all(&dir)
+            .with_context(|| format!("E-UICP-0659: create {}", dir.display()))?;
+        // NOTE: Copying a file onto itself truncates it, so skip when --file already is the target.
+        let same_file = matches!(
+            (fs::canonicalize(source), fs::canonicalize(&dest)),
+            (Ok(a), Ok(b)) if a == b
+        );
+        if !same_file {
+            fs::copy(source, &dest)
+                .with_context(|| format!("E-UICP-0659: copy {source} to {}", dest.display()))?;
+        }
+    }
+    let digest = registry::file_sha256_hex(&dest)
+        .with_context(|| format!("E-UICP-0659: hash {}", dest.display()))?;
+    let imports = flags.get("--imports").map(|list| {
+        list.split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    });
+
+    let mut manifest = read_manifest(&dir)?;
+    let mut entry = ModuleEntry {
+        task: task.to_string(),
+        version: version.to_string(),
+        filename,
+        digest_sha256: digest.clone(),
+        signature: None,
+        keyid: None,
+        signed_at: None,
+        imports,
+    };
+    registry::validate_manifest_entry(&entry).context("E-UICP-0652: invalid manifest entry")?;
+    match manifest
+        .entries
+        .iter_mut()
+        .find(|e| e.task == task && e.version == version)
+    {
+        Some(existing) => {
+            if existing.digest_sha256.eq_ignore_ascii_case(&digest)
+                && existing.imports == entry.imports
+            {
+                entry.signature = existing.signature.take();
+                entry.keyid = existing.keyid.take();
+                entry.signed_at = existing.signed_at;
+            }
+            *existing = entry;
+        }
+        None => manifest.entries.push(entry),
+    }
+    write_manifest(&dir, &manifest)?;
+    println!("{task}@{version}: sha256={digest}");
+    Ok(())
+}
+
+/// Sign manifest entries (all, or those matching `--task`/`--version`) after checking each file
+/// still hashes to its recorded digest. The seed comes from `--seed` or
+/// `UICP_MODULES_SIGNING_SEED` (32 bytes, base64 or hex).
+fn sign_cmd(args: &[String]) -> Result<()> {
+    let flags = parse_flags(
+        "sign",
+        args,
+        &[
+            "--dir",
+            "--keyid",
+            "--seed",
+            "--task",
+            "--version",
+            "--signed-at",
+        ],
+        &[],
+    )?;
+    let dir = modules_dir(&flags)?;
+    let keyid = required(&flags, "--keyid")?;
+    let raw_seed = flags
+        .get("--seed")
+        .cloned()
+        .or_else(|| env::var("UICP_MODULES_SIGNING_SEED").ok())
+        .context("E-UICP-0654: --seed or UICP_MODULES_SIGNING_SEED is required")?;
+    let seed = parse_seed(&raw_seed)
+        .context("E-UICP-0655: failed to parse signing seed")?
+        .context("E-UICP-0655: signing seed is empty")?;
+    let signed_at =
+        optional_i64(&flags, "--signed-at")?.unwrap_or_else(|| chrono::Utc::now().timestamp());
+    let task = flags.get("--task");
+    let version = flags.get("--version");
+
+    let mut manifest = read_manifest(&dir)?;
+    let mut signed = 0usize;
+    for entry in manifest.entries.iter_mut() {
+        if task.is_some_and(|t| *t != entry.task) || version.is_some_and(|v| *v != entry.version) {
+            continue;
+        }
+        let path = dir.join(&entry.filename);
+        let matches = registry::verify_digest(&path, &entry.digest_sha256)
+            .with_context(|| format!("E-UICP-0659: hash {}", path.display()))?;
+        if !matches {
+            bail!(
+                "E-UICP-0657: {} no longer matches its recorded digest; run `add` first",
+                entry.filename
+            );
+        }
+        registry::sign_entry(entry, &seed, Some(keyid), signed_at)
+            .with_context(|| format!("E-UICP-0655: sign {}@{}", entry.task, entry.version))?;
+        println!("signed {}@{} keyid={keyid}", entry.task, entry.version);
+        signed += 1;
+    }
+    if signed == 0 {
+        bail!("E-UICP-0656: no manifest entries matched");
+    }
+    write_manifest(&dir, &manifest)
+}
+
+/// Write `{task}@{version}.provenance.json` through `save_provenance`.
+fn provenance_cmd(args: &[String]) -> Result<()> {
+    let flags = parse_flags(
+        "provenance",
+        args,
+        &[
+            "--dir",
+            "--task",
+            "--version",
+            "--origin-url",
+            "--toolchain",
+            "--wit-world",
+            "--built-at",
+            "--source-revision",
+            "--builder",
+        ],
+        &[],
+    )?;
+    let dir = modules_dir(&flags)?;
+    let task = required(&flags, "--task")?;
+    let version = required(&flags, "--version")?;
+    if !read_manifest(&dir)?
+        .entries
+        .iter()
+        .any(|e| e.task == task && e.version == version)
+    {
+        bail!("E-UICP-0656: {task}@{version} is not in the manifest");
+    }
+    let prov = ModuleProvenance {
+        task: task.to_string(),
+        version: version.to_string(),
+        origin_url: flags.get("--origin-url").cloned(),
+        build_toolchain: flags.get("--toolchain").cloned(),
+        wit_world: flags.get("--wit-world").cloned(),
+        built_at: optional_i64(&flags, "--built-at")?,
+        source_revision: flags.get("--source-revision").cloned(),
+        builder: flags.get("--builder").cloned(),
+        metadata: None,
+    };
+    registry::save_provenance(&dir, &prov).context("E-UICP-0659: write provenance")?;
+    println!("wrote {task}@{version}.provenance.json");
+    Ok(())
+}
+
+/// Check every manifest entry: the file exists and matches its digest, the signer is neither
+/// revoked nor expired, and the signature verifies. Entries with a keyid use `--trust-store` or
+/// the managed `trust_store.json` in `--trust-dir` (default: the app data dir) and fail when
+/// neither knows it; entries without one use `--pubkey` (or `UICP_MODULES_PUBKEY`).
+fn verify_cmd(args: &[String]) -> Result<()> {
+    let flags = parse_flags(
+        "verify",
+        args,
+        &["--dir", "--pubkey", "--trust-store", "--trust-dir"],
+        &["--require-signatures"],
+    )?;
+    let dir = modules_dir(&flags)?;
+    let trust_dir = flags
+        .get("--trust-dir")
+        .map(PathBuf::from)
+        .unwrap_or_else(trust_store::default_dir);
+    let require_signatures = flags.contains_key("--require-signatures");
+    let single_key = match flags
+        .get("--pubkey")
+        .cloned()
+        .or_else(|| env::var("UICP_MODULES_PUBKEY").ok())
+    {
+        Some(raw) => {
+            Some(registry::decode_pubkey_any(&raw).context("E-UICP-0652: invalid --pubkey")?)
+        }
+        None => None,
+    };
+    let mut trust: HashMap<String, [u8; 32]> = HashMap::new();
+    if let Some(path) = flags.get("--trust-store") {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("E-UICP-0659: read trust store {path}"))?;
+        let map: HashMap<String, String> = serde_json::from_str(&text)
+            .context("E-UICP-0652: trust store must map keyid to pubkey")?;
+        for (keyid, raw) in map {
+            let key = registry::decode_pubkey_any(&raw)
+                .with_context(|| format!("E-UICP-0652: decode pubkey for keyid {keyid}"))?;
+            trust.insert(keyid, key);
+        }
+    }
+
+    let manifest = read_manifest(&dir)?;
+    let mut failures = 0usize;
+    for entry in &manifest.entries {
+        let name = format!("{}@{}", entry.task, entry.version);
+        let (ok, detail) = check_entry(
+            &dir,
+            &trust_dir,
+            entry,
+            single_key,
+            &trust,
+            require_signatures,
+        )?;
+        if !ok {
+            failures += 1;
+        }
+        println!("{} {name}: {detail}", if ok { "ok  " } else { "FAIL" });
+    }
+    println!(
+        "modules: entries={} failed={failures}",
+        manifest.entries.len()
+    );
+    if failures > 0 {
+        bail!(
+            "E-UICP-0658: {failures} of {} entries failed verification",
+            manifest.entries.len()
+        );
+    }
+    Ok(())
+}
+
+fn check_entry(
+    dir: &Path,
+    trust_dir: &Path,
+    entry: &ModuleEntry,
+    single_key: Option<[u8; 32]>,
+    trust: &HashMap<String, [u8; 32]>,
+    require_signatures: bool,
+) -> Result<(bool, String)> {
+    let path = dir.join(&entry.filename);
+    if !path.exists() {
+        return Ok((false, format!("missing {}", entry.filename)));
+    }
+    match registry::verify_digest(&path, &entry.digest_sha256) {
+        Ok(true) => {}
+        Ok(false) => return Ok((false, "digest mismatch".into())),
+        Err(err) => return Ok((false, format!("digest check failed: {err:#}"))),
+    }
+    if let Err(err) = trust_store::check_signer(trust_dir, entry) {
+        return Ok((false, format!("{err:#}")));
+    }
+    if entry.signature.is_none() {
+        return Ok((!require_signatures, "unsigned".into()));
+    }
+    let key = match entry.keyid.as_deref() {
+        Some(keyid) => match trust.get(keyid) {
+            Some(key) => Some(*key),
+            // Like the runtime, a keyid never falls back to the single `--pubkey`.
+            None => trust_store::managed_pubkey(trust_dir, keyid)?,
+        },
+        None => single_key,
+    };
+    let Some(key) = key else {
+        let keyid = entry.keyid.as_deref().unwrap_or("none");
+        return Ok((
+            !require_signatures,
+            format!("signature not checked (no key for keyid {keyid})"),
+        ));
+    };
+    Ok(match registry::verify_entry_signature(entry, &key)? {
+        SignatureStatus::Verified => (true, "digest ok, signature verified".into()),
+        SignatureStatus::Invalid => (false, "signature invalid".into()),
+        SignatureStatus::Missing => (!require_signatures, "unsigned".into()),
+    })
+}
+
+fn print_usage() {
+    log_warn("Usage:");
+    log_warn("  uicp-modules digest FILE...");
+    log_warn(
+        "  uicp-modules add --dir DIR --task TASK --version VER [--file PATH] [--imports a,b]",
+    );
+    log_warn("  uicp-modules sign --dir DIR --keyid KEYID [--seed HEX_OR_B64] [--task TASK] [--version VER] [--signed-at UNIX]");
+    log_warn("  uicp-modules provenance --dir DIR --task TASK --version VER [--origin-url URL] [--toolchain S] [--wit-world W] [--built-at UNIX] [--source-revision REV] [--builder NAME]");
+    log_warn("  uicp-modules verify --dir DIR [--pubkey HEX_OR_B64] [--trust-store FILE] [--trust-dir DIR] [--require-signatures]");
+    log_warn("  uicp-modules --help");
+}
@@ -2443,7 +2443,9 @@ pub fn is_codegen_task(task: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::security::policy::{ComputeCapabilitiesSpec, ComputeJobSpec, ComputeProvenanceSpec};
+    use crate::security::policy::{
+        ComputeCapabilitiesSpec, ComputeJobSpec, ComputePriority, ComputeProvenanceSpec,
+    };
     use serde_json::{json, Map, Value};
 
     #[test]
@@ -2947,6 +2949,7 @@ export function onEvent(action: string, payload: string, state: string) {
             golden_key: None,
             artifact_id: None,
             expect_golden: false,
+            priority: ComputePriority::Normal,
         }
     }
 }
@@ -5,18 +5,100 @@ use hmac::{Hmac, Mac};
 use sha2::Sha256;
 use tauri::{async_runtime::spawn, AppHandle, Emitter, Manager, State};
 
+use crate::compute::compute::error_codes;
 use crate::compute::compute_cache;
 use crate::compute::compute_input::canonicalize_task_input;
+use crate::compute::job_logs::{self, GuestLogEntry};
+use crate::compute::module_pins;
+use crate::compute::pipeline::{self, ComputePipelineSpec};
+use crate::compute::replay::{self, ReplayReport};
 use crate::infrastructure::core::emit_or_log;
 use crate::security::policy::enforce_compute_policy;
 use crate::security::policy::{ComputeFinalErr, ComputeJobSpec};
 use crate::AppState;
 
+/// HMAC binding a job id to its task, workspace and env hash (`UICP_REQUIRE_TOKENS`).
+fn job_token(key: &[u8], spec: &ComputeJobSpec) -> Result<String, String> {
+    let mut mac: Hmac<Sha256> = Hmac::new_from_slice(key).map_err(|e| e.to_string())?;
+    mac.update(b"UICP-TOKENv1\x00");
+    mac.update(spec.job_id.as_bytes());
+    mac.update(b"|");
+    mac.update(spec.task.as_bytes());
+    mac.update(b"|");
+    mac.update(spec.workspace_id.as_bytes());
+    mac.update(b"|");
+    mac.update(spec.provenance.env_hash.as_bytes());
+    Ok(hex::encode(mac.finalize().into_bytes()))
+}
+
+/// `UICP_REQUIRE_TOKENS` gate: the spec must carry the HMAC minted for its job id, task,
+/// workspace and env hash.
+fn check_job_token(state: &AppState, spec: &ComputeJobSpec) -> Option<ComputeFinalErr> {
+    let require_tokens = match std::env::var("UICP_REQUIRE_TOKENS") {
+        Ok(v) => matches!(v.as_str(), "1" | "true" | "TRUE" | "on" | "yes"),
+        Err(_) => false,
+    };
+    if !require_tokens {
+        return None;
+    }
+    let valid = matches!(
+        job_token(&state.job_token_key, spec),
+        Ok(expected) if spec.token.as_deref() == Some(expected.as_str())
+    );
+    if valid {
+        return None;
+    }
+    Some(ComputeFinalErr {
+        ok: false,
+        job_id: spec.job_id.clone(),
+        task: spec.task.clone(),
+        code: "Compute.CapabilityDenied".into(),
+        message: "E-UICP-0701: missing or invalid job token".into(),
+        metrics: None,
+    })
+}
+
+/// `permissions.json` gate, scoped by task name and major version (`compute:csv.parse@1`).
+fn check_compute_permissions(spec: &ComputeJobSpec) -> Option<ComputeFinalErr> {
+    let task_key = {
+        let t = spec.task.as_str();
+        if let Some(at) = t.find('@') {
+            let (name, ver) = t.split_at(at);
+            let ver = &ver[1..];
+            let major = ver.split('.').next().unwrap_or(ver);
+            format!("{}@{}", name, major)
+        } else {
+            t.to_string()
+        }
+    };
+    if crate::security::authz::allow_compute(&task_key) {
+        return None;
+    }
+    Some(ComputeFinalErr {
+        ok: false,
+        job_id: spec.job_id.clone(),
+        task: spec.task.clone(),
+        code: "PolicyDenied".into(),
+        message: format!("Denied by permissions.json (scope: compute:{})", task_key),
+        metrics: None,
+    })
+}
+
 #[tauri::command]
 pub async fn compute_call(
     window: tauri::Window,
     state: State<'_, AppState>,
     spec: ComputeJobSpec,
+) -> Result<(), String> {
+    submit_compute_job(window.app_handle(), &state, spec).await
+}
+
+/// Admit a single job: token and policy gates, cache lookup, then a scheduler permit and spawn.
+/// Outcomes are reported on `compute-result-final`; `Err` is reserved for host bookkeeping failures.
+pub(crate) async fn submit_compute_job(
+    app_handle: &AppHandle,
+    state: &AppState,
+    spec: ComputeJobSpec,
 ) -> Result<(), String> {
     #[cfg(feature = "otel_spans")]
     let _span = tracing::info_span!(
@@ -35,15 +117,29 @@ pub async fn compute_call(
         return Err(format!("Duplicate job id {}", spec.job_id));
     }
 
+    // Semver requirements and workspace pins resolve to one installed version up front.
+    let resolved_task =
+        module_pins::resolve_job_task(app_handle, &state.db_ro, &spec.workspace_id, &spec.task)
+            .await;
+
+    // The spec (minus its token) lets `compute_replay` re-execute the job later. It is logged with
+    // the resolved task so a replay runs the same version even after pins or installs change.
+    // Inputs of non-replayable jobs, and inputs over the cap, are recorded as a digest only.
+    let mut logged_spec = replay::logged_spec(&spec);
+    if let Ok(task) = &resolved_task {
+        logged_spec.task = task.clone();
+    }
     if let Err(err) = state
         .action_log
         .append_json(
-            "compute.job.submit",
+            replay::SUBMIT_KIND,
             &serde_json::json!({
                 "jobId": spec.job_id.clone(),
-                "task": spec.task.clone(),
+                "task": logged_spec.task.clone(),
+                "requestedTask": spec.task.clone(),
                 "cache": spec.cache.clone(),
                 "workspaceId": spec.workspace_id.clone(),
+                "spec": logged_spec,
                 "ts": chrono::Utc::now().timestamp_millis(),
             }),
         )
@@ -52,39 +148,33 @@ pub async fn compute_call(
         return Err(format!("Action log append failed: {err}"));
     }
 
-    let app_handle = window.app_handle().clone();
+    let app_handle = app_handle.clone();
 
-    let require_tokens = match std::env::var("UICP_REQUIRE_TOKENS") {
-        Ok(v) => matches!(v.as_str(), "1" | "true" | "TRUE" | "on" | "yes"),
-        Err(_) => false,
-    };
-    if require_tokens {
-        let expected = {
-            let key = &state.job_token_key;
-            let mut mac: Hmac<Sha256> = Hmac::new_from_slice(key).map_err(|e| e.to_string())?;
-            mac.update(b"UICP-TOKENv1\x00");
-            mac.update(spec.job_id.as_bytes());
-            mac.update(b"|");
-            mac.update(spec.task.as_bytes());
-            mac.update(b"|");
-            mac.update(spec.workspace_id.as_bytes());
-            mac.update(b"|");
-            mac.update(spec.provenance.env_hash.as_bytes());
-            let tag = mac.finalize().into_bytes();
-            hex::encode(tag)
-        };
-        let provided = spec.token.as_deref().unwrap_or("");
-        if provided != expected {
+    if let Some(deny) = check_job_token(state, &spec) {
+        emit_or_log(
+            &app_handle,
+            crate::infrastructure::events::EVENT_COMPUTE_RESULT_FINAL,
+            &deny,
+        );
+        return Ok(());
+    }
+
+    // WHY: Tokens are minted for the task the caller asked for, so swap in the resolved task only
+    // after the token check.
+    let mut spec = spec;
+    match resolved_task {
+        Ok(task) => spec.task = task,
+        Err(message) => {
             let payload = ComputeFinalErr {
                 ok: false,
                 job_id: spec.job_id.clone(),
                 task: spec.task.clone(),
-                code: "Compute.CapabilityDenied".into(),
-                message: "E-UICP-0701: missing or invalid job token".into(),
+                code: error_codes::TASK_NOT_FOUND.into(),
+                message,
                 metrics: None,
             };
             emit_or_log(
-                window.app_handle(),
+                &app_handle,
                 crate::infrastructure::events::EVENT_COMPUTE_RESULT_FINAL,
                 &payload,
             );
@@ -93,36 +183,24 @@ pub async fn compute_call(
     }
 
     // --- Host policy enforcement (coarse gate) ---
-    let task_key = {
-        let t = spec.task.as_str();
-        if let Some(at) = t.find('@') {
-            let (name, ver) = t.split_at(at);
-            let ver = &ver[1..];
-            let major = ver.split('.').next().unwrap_or(ver);
-            format!("{}@{}", name, major)
-        } else {
-            t.to_string()
-        }
-    };
-    if !crate::security::authz::allow_compute(&task_key) {
-        let payload = ComputeFinalErr {
-            ok: false,
-            job_id: spec.job_id.clone(),
-            task: spec.task.clone(),
-            code: "PolicyDenied".into(),
-            message: format!("Denied by permissions.json (scope: compute:{})", task_key),
-            metrics: None,
-        };
+    if let Some(deny) = check_compute_permissions(&spec) {
         emit_or_log(
-            window.app_handle(),
+            &app_handle,
             crate::infrastructure::events::EVENT_COMPUTE_RESULT_FINAL,
-            &payload,
+            &deny,
         );
         return Ok(());
     }
 
     // --- Policy enforcement (Non-negotiables v1) ---
-    if let Some(deny) = enforce_compute_policy(&spec) {
+    let module = crate::compute::registry::find_module(&app_handle, &spec.task)
+        .ok()
+        .flatten();
+    let is_module_task = module.is_some();
+    let declares_http = module
+        .as_ref()
+        .is_some_and(|m| crate::compute::registry::declares_import(m, "uicp:host/http"));
+    if let Some(deny) = enforce_compute_policy(&spec, declares_http) {
         emit_or_log(
             &app_handle,
             crate::infrastructure::events::EVENT_COMPUTE_RESULT_FINAL,
@@ -131,6 +209,11 @@ pub async fn compute_call(
         return Ok(());
     }
 
+    // NOTE: Jobs the cache skips are not replayable either; `compute_replay` refuses them too.
+    if !spec.cacheable() {
+        spec.replayable = false;
+    }
+
     let normalized_input = match canonicalize_task_input(&spec) {
         Ok(value) => value,
         Err(err) => {
@@ -152,10 +235,6 @@ pub async fn compute_call(
     };
 
     // Provider decision telemetry (host-owned)
-    let is_module_task = crate::compute::registry::find_module(&app_handle, &spec.task)
-        .ok()
-        .flatten()
-        .is_some();
     let provider_kind = if crate::security::policy::is_codegen_task(&spec.task) {
         "codegen"
     } else if is_module_task {
@@ -192,38 +271,9 @@ pub async fn compute_call(
 
     // Content-addressed cache lookup when enabled (normalize policy casing)
     let cache_mode = spec.cache.to_lowercase();
-    if cache_mode == "readwrite" || cache_mode == "readonly" {
-        let use_v2 = std::env::var("UICP_CACHE_V2")
-            .ok()
-            .map(|v| matches!(v.as_str(), "1" | "true" | "TRUE" | "on" | "yes"))
-            .unwrap_or(false);
-        let module_meta = crate::compute::registry::find_module(&app_handle, &spec.task)
-            .ok()
-            .flatten();
-        let invariants = {
-            let mut parts: Vec<String> = Vec::new();
-            if let Some(m) = &module_meta {
-                parts.push(format!("modsha={}", m.entry.digest_sha256));
-                parts.push(format!("modver={}", m.entry.version));
-                if let Some(world) = m.provenance.as_ref().and_then(|p| p.wit_world.clone()) {
-                    if !world.is_empty() {
-                        parts.push(format!("world={}", world));
-                    }
-                }
-                parts.push("abi=wasi-p2".to_string());
-            }
-            if let Ok(pver) = std::env::var("UICP_POLICY_VERSION") {
-                if !pver.is_empty() {
-                    parts.push(format!("policy={}", pver));
-                }
-            }
-            parts.join("|")
-        };
-        let key = if use_v2 {
-            compute_cache::compute_key_v2_plus(&spec, &normalized_input, &invariants)
-        } else {
-            compute_cache::compute_key(&spec.task, &normalized_input, &spec.provenance.env_hash)
-        };
+    // WHY: fsWrite and net-granted jobs must run for real; never answer them from cache.
+    if (cache_mode == "readwrite" || cache_mode == "readonly") && spec.cacheable() {
+        let key = compute_cache::job_cache_key(&app_handle, &spec, &normalized_input);
         if let Ok(Some(mut cached)) =
             compute_cache::lookup(&app_handle, &spec.workspace_id, &key).await
         {
@@ -274,18 +324,16 @@ pub async fn compute_call(
         .is_some();
     let permit = if is_module_task {
         state
-            .wasm_sem
-            .clone()
-            .acquire_owned()
-            .await
-            .map_err(|e| e.to_string())?
+            .compute_queues
+            .wasm
+            .acquire(spec.priority, &spec.workspace_id)
+            .await?
     } else {
         state
-            .compute_sem
-            .clone()
-            .acquire_owned()
-            .await
-            .map_err(|e| e.to_string())?
+            .compute_queues
+            .compute
+            .acquire(spec.priority, &spec.workspace_id)
+            .await?
     };
     let queue_wait_ms = queued_at
         .elapsed()
@@ -312,6 +360,117 @@ pub async fn compute_call(
     Ok(())
 }
 
+/// Run a DAG of jobs host-side. Returns once the graph is validated; node partials arrive on
+/// `compute-pipeline-partial` and the aggregate result on `compute-pipeline-final`.
+#[tauri::command]
+pub async fn compute_pipeline(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    spec: ComputePipelineSpec,
+) -> Result<(), String> {
+    #[cfg(feature = "otel_spans")]
+    let _span = tracing::info_span!(
+        "compute_pipeline",
+        pipeline_id = %spec.pipeline_id,
+        nodes = spec.nodes.len()
+    );
+    let plan = pipeline::plan(&spec)?;
+    {
+        let ongoing = state.compute_ongoing.read().await;
+        if let Some(dup) = spec
+            .nodes
+            .iter()
+            .find(|n| ongoing.contains_key(&n.job.job_id))
+        {
+            return Err(format!("Duplicate job id {}", dup.job.job_id));
+        }
+    }
+    if let Err(err) = state
+        .action_log
+        .append_json(
+            "compute.pipeline.submit",
+            &serde_json::json!({
+                "pipelineId": spec.pipeline_id.clone(),
+                "nodes": spec
+                    .nodes
+                    .iter()
+                    .map(|n| {
+                        serde_json::json!({ "id": n.id, "jobId": n.job.job_id, "task": n.job.task })
+                    })
+                    .collect::<Vec<_>>(),
+                "ts": chrono::Utc::now().timestamp_millis(),
+            }),
+        )
+        .await
+    {
+        return Err(format!("Action log append failed: {err}"));
+    }
+
+    let app_handle = window.app_handle().clone();
+    spawn(async move {
+        pipeline::run(app_handle, spec, plan, |app, job| async move {
+            let state: State<'_, AppState> = app.state();
+            submit_compute_job(&app, &state, job).await
+        })
+        .await;
+    });
+    Ok(())
+}
+
+/// Re-execute a finished job from its action-log records with the same spec, module digest and
+/// RNG seed, and report whether the output hash reproduced.
+#[tauri::command]
+pub async fn compute_replay(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    job_id: String,
+) -> Result<ReplayReport, String> {
+    #[cfg(feature = "otel_spans")]
+    let _span = tracing::info_span!("compute_replay", job_id = %job_id);
+    let recorded = replay::load_recorded_job(&state.db_ro, &job_id).await?;
+    let app_handle = window.app_handle().clone();
+    let installed_digest = crate::compute::registry::find_module(&app_handle, &recorded.spec.task)
+        .map_err(|e| {
+            format!(
+                "E-UICP-0722: resolve module for {}: {e}",
+                recorded.spec.task
+            )
+        })?
+        .map(|m| m.entry.digest_sha256);
+    let token_key = state.job_token_key;
+    let report = replay::run(
+        app_handle,
+        recorded,
+        installed_digest,
+        |app, mut job| async move {
+            job.token = Some(job_token(&token_key, &job)?);
+            let state: State<'_, AppState> = app.state();
+            submit_compute_job(&app, &state, job).await
+        },
+    )
+    .await?;
+    if let Err(err) = state
+        .action_log
+        .append_json(replay::REPLAY_KIND, &serde_json::json!(report))
+        .await
+    {
+        return Err(format!("Action log append failed: {err}"));
+    }
+    Ok(report)
+}
+
+/// Structured `uicp:host/log` entries for a job, oldest first. `min_level` keeps entries at or
+/// above that level (`trace`..`error`); `limit` keeps the most recent entries (default 500).
+#[tauri::command]
+pub async fn compute_job_logs(
+    state: State<'_, AppState>,
+    job_id: String,
+    min_level: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<GuestLogEntry>, String> {
+    job_logs::load_job_logs(&state.db_ro, &job_id, min_level.as_deref(), limit).await
+}
+
 #[tauri::command]
 pub async fn compute_cancel(
     state: State<'_, AppState>,
@@ -357,12 +516,189 @@ pub async fn compute_cancel(
                 let state: State<'_, AppState> = app_handle.state();
                 state.compute_cancel.write().await.remove(&jid);
                 state.compute_ongoing.write().await.remove(&jid);
+                state.compute_pause.write().await.remove(&jid);
             }
         }
     });
     Ok(())
 }
 
+/// Suspend a running compute job. The guest parks at its next epoch tick; paused time does not
+/// count against `timeoutMs` and is reported as `pausedMs`.
+#[tauri::command]
+pub async fn compute_pause(
+    state: State<'_, AppState>,
+    job_id: String,
+    window: tauri::Window,
+) -> Result<(), String> {
+    set_compute_paused(&state, window.app_handle(), &job_id, true).await
+}
+
+/// Resume a job suspended by `compute_pause`.
+#[tauri::command]
+pub async fn compute_resume(
+    state: State<'_, AppState>,
+    job_id: String,
+    window: tauri::Window,
+) -> Result<(), String> {
+    set_compute_paused(&state, window.app_handle(), &job_id, false).await
+}
+
+async fn set_compute_paused(
+    state: &AppState,
+    app_handle: &AppHandle,
+    job_id: &str,
+    paused: bool,
+) -> Result<(), String> {
+    #[cfg(feature = "otel_spans")]
+    tracing::info!(target = "uicp", job_id = %job_id, paused, "compute pause state requested");
+    // ERROR: E-UICP-0243 only running Wasm jobs register a pause channel (not codegen or finished jobs).
+    let Some(tx) = state.compute_pause.read().await.get(job_id).cloned() else {
+        return Err(format!(
+            "E-UICP-0243: compute job '{job_id}' is not running or cannot be paused"
+        ));
+    };
+    let was_paused = tx.send_replace(paused);
+    if was_paused != paused {
+        let event = if paused { "paused" } else { "resumed" };
+        let _ = app_handle.emit(
+            "compute-debug",
+            serde_json::json!({ "jobId": job_id, "event": event }),
+        );
+    }
+    Ok(())
+}
+
+/// Admit one applet session open or call through the gates `submit_compute_job` applies: job
+/// token, `permissions.json` scope, host policy and a `compute.job.submit` record. Returns the
+/// Wasm queue permit to hold while the guest runs.
+async fn admit_applet_session(
+    state: &AppState,
+    window_id: &str,
+    spec: &ComputeJobSpec,
+) -> Result<tokio::sync::OwnedSemaphorePermit, String> {
+    if state
+        .compute_ongoing
+        .read()
+        .await
+        .contains_key(&spec.job_id)
+    {
+        return Err(format!("Duplicate job id {}", spec.job_id));
+    }
+    // WHY: Session calls share the job audit trail, but they are not replayable jobs, so the
+    // record keeps only a digest of the input (the bundle source on open).
+    let mut session_spec = spec.clone();
+    session_spec.replayable = false;
+    let logged_spec = replay::logged_spec(&session_spec);
+    state
+        .action_log
+        .append_json(
+            replay::SUBMIT_KIND,
+            &serde_json::json!({
+                "jobId": spec.job_id.clone(),
+                "task": spec.task.clone(),
+                "requestedTask": spec.task.clone(),
+                "cache": spec.cache.clone(),
+                "workspaceId": spec.workspace_id.clone(),
+                "appletSession": window_id,
+                "spec": logged_spec,
+                "ts": chrono::Utc::now().timestamp_millis(),
+            }),
+        )
+        .await
+        .map_err(|err| format!("Action log append failed: {err}"))?;
+
+    // NOTE: Sessions never link `uicp:host/http`, so net grants are refused like undeclared ones.
+    let denied = check_job_token(state, spec)
+        .or_else(|| check_compute_permissions(spec))
+        .or_else(|| enforce_compute_policy(spec, false));
+    if let Some(deny) = denied {
+        return Err(format!("{}: {}", deny.code, deny.message));
+    }
+    state
+        .compute_queues
+        .wasm
+        .acquire(spec.priority, &spec.workspace_id)
+        .await
+}
+
+/// Open a persistent `applet.quickjs` session for a window, evaluating `spec.input.source` once.
+/// Reopening an existing window reloads the bundle in place. `uicp:host/kv` state is scoped to
+/// `spec.workspaceId` and `window_id`. `spec.memLimitMb` sizes the session's store.
+#[tauri::command]
+pub async fn applet_session_open(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    window_id: String,
+    spec: ComputeJobSpec,
+) -> Result<(), String> {
+    let _permit = admit_applet_session(&state, &window_id, &spec).await?;
+    let source = spec
+        .input
+        .get("source")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| "E-UICP-0254: applet session open requires input.source".to_string())?;
+    crate::compute::compute::applet_session_open(
+        &app,
+        &spec.workspace_id,
+        &window_id,
+        &spec.task,
+        source,
+        spec.mem_limit_mb,
+    )
+    .await
+    .map_err(|err| format!("{err:#}"))
+}
+
+/// Call `init`/`render`/`on-event` on a window's applet session. `spec.input` has the
+/// `applet.quickjs` job shape minus `source`; `spec.task` must match the open session.
+#[tauri::command]
+pub async fn applet_session_call(
+    state: State<'_, AppState>,
+    window_id: String,
+    spec: ComputeJobSpec,
+) -> Result<serde_json::Value, String> {
+    let _permit = admit_applet_session(&state, &window_id, &spec).await?;
+    crate::compute::compute::applet_session_call(&window_id, &spec.task, &spec.input)
+        .await
+        .map_err(|err| format!("{err:#}"))
+}
+
+/// Drop a window's applet session. Returns whether one was open.
+/// With `clear_state`, also deletes the window's `uicp:host/kv` entries (e.g. when it closes).
+#[tauri::command]
+pub async fn applet_session_dispose(
+    state: State<'_, AppState>,
+    window_id: String,
+    workspace_id: Option<String>,
+    clear_state: Option<bool>,
+) -> Result<bool, String> {
+    let was_open = crate::compute::compute::applet_session_dispose(&window_id);
+    if clear_state.unwrap_or(false) {
+        let ws = workspace_id.unwrap_or_else(|| "default".into());
+        state
+            .db_rw
+            .call(move |conn| Ok(crate::compute::applet_kv::clear(conn, &ws, &window_id)))
+            .await
+            .map_err(|e| format!("{e:?}"))?
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(was_open)
+}
+
+/// Queue depth per priority class and workspace for the Wasm and compute caps.
+#[tauri::command]
+pub async fn compute_queue_status(state: State<'_, AppState>) -> Result<serde_json::Value, String> {
+    let mut status = state.compute_queues.status();
+    if let Some(obj) = status.as_object_mut() {
+        obj.insert(
+            "instancePools".into(),
+            crate::compute::compute::instance_pool_stats(),
+        );
+    }
+    Ok(status)
+}
+
 #[tauri::command]
 pub async fn clear_compute_cache(
     app: AppHandle,
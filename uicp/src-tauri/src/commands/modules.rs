@@ -1,10 +1,18 @@
 //! Modules command handlers.
 
+use std::path::PathBuf;
+
 use base64::engine::general_purpose::STANDARD as BASE64_ENGINE;
 use base64::Engine as _;
 use sha2::Digest;
+use tauri::State;
 
-use crate::compute::registry::{load_manifest, modules_dir};
+use crate::compute::module_pins::{self, ModulePin};
+use crate::compute::registry::{
+    install_module_bundle, load_manifest, modules_dir, uninstall_module, InstalledModule,
+};
+use crate::compute::trust_store::{self, TrustStoreListing};
+use crate::AppState;
 
 /// Verify that all module entries listed in the manifest exist and match their digests.
 #[tauri::command]
@@ -76,6 +84,225 @@ pub async fn verify_modules(app: tauri::AppHandle) -> Result<serde_json::Value,
     }))
 }
 
+/// Install a module from a local bundle directory: `module.json` (one manifest entry), the
+/// `.wasm` it names and an optional `{task}@{version}.provenance.json`. Digest, signature and
+/// import preflight are checked before `manifest.json` changes.
+#[tauri::command]
+pub async fn modules_install(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+    path: String,
+) -> Result<InstalledModule, String> {
+    #[cfg(feature = "otel_spans")]
+    let _span = tracing::info_span!("modules_install", path = %path);
+    let dir = modules_dir(&app);
+    let bundle = PathBuf::from(path);
+    let installed = tauri::async_runtime::spawn_blocking(move || {
+        install_module_bundle(&dir, &trust_store::default_dir(), &bundle)
+    })
+    .await
+    .map_err(|e| format!("install task failed: {e}"))?
+    .map_err(|e| format!("{e:#}"))?;
+    state
+        .action_log
+        .append_json("modules.install", &serde_json::json!(installed))
+        .await
+        .map_err(|e| format!("Action log append failed: {e}"))?;
+    Ok(installed)
+}
+
+/// Remove an installed `task@version` from the manifest and delete its files.
+#[tauri::command]
+pub async fn modules_uninstall(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+    task: String,
+    version: String,
+) -> Result<(), String> {
+    #[cfg(feature = "otel_spans")]
+    let _span = tracing::info_span!("modules_uninstall", task = %task, version = %version);
+    let dir = modules_dir(&app);
+    let removed =
+        tauri::async_runtime::spawn_blocking(move || uninstall_module(&dir, &task, &version))
+            .await
+            .map_err(|e| format!("uninstall task failed: {e}"))?
+            .map_err(|e| format!("{e:#}"))?;
+    state
+        .action_log
+        .append_json(
+            "modules.uninstall",
+            &serde_json::json!({
+                "task": removed.task,
+                "version": removed.version,
+                "digestSha256": removed.digest_sha256,
+            }),
+        )
+        .await
+        .map_err(|e| format!("Action log append failed: {e}"))?;
+    Ok(())
+}
+
+/// Pin `task` to a semver requirement (`^0.1`, `=0.1.3`) for jobs in a workspace that name the
+/// task without a version. Replaces any existing pin.
+#[tauri::command]
+pub async fn modules_pin_set(
+    state: State<'_, AppState>,
+    workspace_id: Option<String>,
+    task: String,
+    requirement: String,
+) -> Result<(), String> {
+    module_pins::validate_pin(&task, &requirement)?;
+    let ws = workspace_id.unwrap_or_else(|| "default".into());
+    let record = serde_json::json!({
+        "workspaceId": ws,
+        "task": task,
+        "requirement": requirement,
+    });
+    state
+        .db_rw
+        .call(move |conn| Ok(module_pins::set_pin(conn, &ws, &task, &requirement)?))
+        .await
+        .map_err(|e| format!("E-UICP-0268: write module pin failed: {e}"))?;
+    state
+        .action_log
+        .append_json("modules.pin", &record)
+        .await
+        .map_err(|e| format!("Action log append failed: {e}"))?;
+    Ok(())
+}
+
+/// Remove a workspace pin. Returns whether one existed.
+#[tauri::command]
+pub async fn modules_pin_clear(
+    state: State<'_, AppState>,
+    workspace_id: Option<String>,
+    task: String,
+) -> Result<bool, String> {
+    let ws = workspace_id.unwrap_or_else(|| "default".into());
+    let record = serde_json::json!({ "workspaceId": ws, "task": task, "requirement": null });
+    let removed = state
+        .db_rw
+        .call(move |conn| Ok(module_pins::clear_pin(conn, &ws, &task)?))
+        .await
+        .map_err(|e| format!("E-UICP-0268: clear module pin failed: {e}"))?;
+    if removed {
+        state
+            .action_log
+            .append_json("modules.pin", &record)
+            .await
+            .map_err(|e| format!("Action log append failed: {e}"))?;
+    }
+    Ok(removed)
+}
+
+/// Version pins for a workspace, sorted by task.
+#[tauri::command]
+pub async fn modules_pins(
+    state: State<'_, AppState>,
+    workspace_id: Option<String>,
+) -> Result<Vec<ModulePin>, String> {
+    let ws = workspace_id.unwrap_or_else(|| "default".into());
+    state
+        .db_ro
+        .call(move |conn| Ok(module_pins::list_pins(conn, &ws)?))
+        .await
+        .map_err(|e| format!("E-UICP-0268: read module pins failed: {e}"))
+}
+
+/// Run a blocking trust-store change and record it as a `modules.trust` action-log entry.
+async fn apply_trust_change<T, F>(state: &AppState, op: &str, change: F) -> Result<T, String>
+where
+    T: serde::Serialize + Send + 'static,
+    F: FnOnce() -> anyhow::Result<T> + Send + 'static,
+{
+    let out = tauri::async_runtime::spawn_blocking(change)
+        .await
+        .map_err(|e| format!("trust store task failed: {e}"))?
+        .map_err(|e| format!("{e:#}"))?;
+    state
+        .action_log
+        .append_json(
+            "modules.trust",
+            &serde_json::json!({
+                "op": op,
+                "change": &out,
+                "ts": chrono::Utc::now().timestamp_millis(),
+            }),
+        )
+        .await
+        .map_err(|e| format!("Action log append failed: {e}"))?;
+    Ok(out)
+}
+
+/// Trusted module signing keys (`trust_store.json` plus `UICP_TRUST_STORE(_JSON)`) and the
+/// revocation list.
+#[tauri::command]
+pub async fn modules_trust_list() -> Result<TrustStoreListing, String> {
+    let dir = trust_store::default_dir();
+    tauri::async_runtime::spawn_blocking(move || trust_store::list(&dir))
+        .await
+        .map_err(|e| format!("trust store task failed: {e}"))?
+        .map_err(|e| format!("{e:#}"))
+}
+
+/// Trust an Ed25519 `pubkey` (base64 or hex) under `keyid`, optionally only for signatures made
+/// before `expires_at` (unix seconds).
+#[tauri::command]
+pub async fn modules_trust_add(
+    state: State<'_, AppState>,
+    keyid: String,
+    pubkey: String,
+    expires_at: Option<i64>,
+) -> Result<(), String> {
+    #[cfg(feature = "otel_spans")]
+    let _span = tracing::info_span!("modules_trust_add", keyid = %keyid);
+    let dir = trust_store::default_dir();
+    apply_trust_change(&state, "add", move || {
+        let key = trust_store::add_key(&dir, &keyid, &pubkey, expires_at)?;
+        Ok(serde_json::json!({ "keyid": keyid, "key": key }))
+    })
+    .await
+    .map(|_| ())
+}
+
+/// Replace `old_keyid` with `new_keyid`: the new key is trusted and the old one expires now, so
+/// modules it already signed keep loading.
+#[tauri::command]
+pub async fn modules_trust_rotate(
+    state: State<'_, AppState>,
+    old_keyid: String,
+    new_keyid: String,
+    pubkey: String,
+) -> Result<(), String> {
+    #[cfg(feature = "otel_spans")]
+    let _span = tracing::info_span!("modules_trust_rotate", old = %old_keyid, new = %new_keyid);
+    let dir = trust_store::default_dir();
+    apply_trust_change(&state, "rotate", move || {
+        let key = trust_store::rotate_key(&dir, &old_keyid, &new_keyid, &pubkey)?;
+        Ok(serde_json::json!({ "keyid": new_keyid, "rotatedFrom": old_keyid, "key": key }))
+    })
+    .await
+    .map(|_| ())
+}
+
+/// Revoke `keyid`: modules it signed are refused by `find_module` and `modules_install`.
+#[tauri::command]
+pub async fn modules_trust_revoke(
+    state: State<'_, AppState>,
+    keyid: String,
+    reason: Option<String>,
+) -> Result<(), String> {
+    #[cfg(feature = "otel_spans")]
+    let _span = tracing::info_span!("modules_trust_revoke", keyid = %keyid);
+    let dir = trust_store::default_dir();
+    apply_trust_change(&state, "revoke", move || {
+        let revoked = trust_store::revoke_key(&dir, &keyid, reason)?;
+        Ok(serde_json::json!({ "keyid": keyid, "revoked": revoked }))
+    })
+    .await
+    .map(|_| ())
+}
+
 /// Returns detailed module registry information with provenance for supply chain transparency.
 /// Used by the devtools panel to display "museum labels" for each module.
 #[tauri::command]
@@ -114,10 +341,15 @@ pub async fn get_modules_registry(app: tauri::AppHandle) -> Result<serde_json::V
                 "1" | "true" | "yes" | "on"
             )
         });
+    let managed_keys = trust_store::load(&trust_store::default_dir())
+        .map(|store| !store.keys.is_empty())
+        .unwrap_or(false);
     let trust_store = if std::env::var("UICP_TRUST_STORE_JSON").is_ok() {
         "inline"
     } else if std::env::var("UICP_TRUST_STORE").is_ok() {
         "file"
+    } else if managed_keys {
+        "managed"
     } else if std::env::var("UICP_MODULES_PUBKEY").is_ok() {
         "single_key"
     } else {
@@ -133,7 +365,10 @@ pub async fn get_modules_registry(app: tauri::AppHandle) -> Result<serde_json::V
 }
 
 #[tauri::command]
-pub async fn get_modules_info(app: tauri::AppHandle) -> Result<serde_json::Value, String> {
+pub async fn get_modules_info(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> Result<serde_json::Value, String> {
     #[cfg(feature = "otel_spans")]
     let _span = tracing::info_span!("get_modules_info");
     let dir = crate::compute::registry::modules_dir(&app);
@@ -147,10 +382,12 @@ pub async fn get_modules_info(app: tauri::AppHandle) -> Result<serde_json::Value
             }
         }
     }
+    let integrity = state.modules_integrity.read().await.clone();
     Ok(serde_json::json!({
         "dir": dir.display().to_string(),
         "manifest": manifest.display().to_string(),
         "hasManifest": exists,
         "entries": entries,
+        "integrity": integrity,
     }))
 }
@@ -4,9 +4,14 @@
 use tauri::{Emitter, Manager, Runtime, State};
 // use anyhow::Context;
 
+use crate::compute::compute::error_codes;
 use crate::compute::compute_cache;
 use crate::compute::compute_input::canonicalize_task_input;
+use crate::compute::job_logs::{self, GuestLogEntry};
+use crate::compute::module_pins;
+use crate::compute::pipeline::{self, ComputePipelineSpec};
 use crate::compute::registry;
+use crate::compute::replay::{self, ReplayReport};
 use crate::infrastructure::core::emit_or_log;
 use crate::infrastructure::events;
 use crate::llm::provider_cli;
@@ -31,14 +36,67 @@ pub async fn compute_call<R: Runtime>(
         return Err(format!("Duplicate job id {}", spec.job_id));
     }
 
+    let resolved_task =
+        module_pins::resolve_job_task(&app, &state.db_ro, &spec.workspace_id, &spec.task).await;
+
+    let mut logged_spec = replay::logged_spec(&spec);
+    if let Ok(task) = &resolved_task {
+        logged_spec.task = task.clone();
+    }
+    state
+        .action_log
+        .append_json(
+            replay::SUBMIT_KIND,
+            &serde_json::json!({
+                "jobId": spec.job_id.clone(),
+                "task": logged_spec.task.clone(),
+                "requestedTask": spec.task.clone(),
+                "cache": spec.cache.clone(),
+                "workspaceId": spec.workspace_id.clone(),
+                "spec": logged_spec,
+                "ts": chrono::Utc::now().timestamp_millis(),
+            }),
+        )
+        .await
+        .map_err(|err| format!("Action log append failed: {err}"))?;
+
     let app_handle = app.clone();
 
+    let mut spec = spec;
+    match resolved_task {
+        Ok(task) => spec.task = task,
+        Err(message) => {
+            let payload = ComputeFinalErr {
+                ok: false,
+                job_id: spec.job_id.clone(),
+                task: spec.task.clone(),
+                code: error_codes::TASK_NOT_FOUND.into(),
+                message,
+                metrics: None,
+            };
+            emit_or_log(&app_handle, events::EVENT_COMPUTE_RESULT_FINAL, &payload);
+            return Ok(());
+        }
+    }
+
     // --- Policy enforcement ---
-    if let Some(deny) = enforce_compute_policy(&spec) {
+    let module = crate::compute::registry::find_module(&app_handle, &spec.task)
+        .ok()
+        .flatten();
+    let is_module_task = module.is_some();
+    let declares_http = module
+        .as_ref()
+        .is_some_and(|m| crate::compute::registry::declares_import(m, "uicp:host/http"));
+    if let Some(deny) = enforce_compute_policy(&spec, declares_http) {
         emit_or_log(&app_handle, events::EVENT_COMPUTE_RESULT_FINAL, &deny);
         return Ok(());
     }
 
+    // NOTE: Jobs the cache skips are not replayable either; `compute_replay` refuses them too.
+    if !spec.cacheable() {
+        spec.replayable = false;
+    }
+
     let normalized_input = match canonicalize_task_input(&spec) {
         Ok(value) => value,
         Err(err) => {
@@ -56,10 +114,6 @@ pub async fn compute_call<R: Runtime>(
     };
 
     // Provider decision telemetry (host-owned for harness)
-    let is_module_task = crate::compute::registry::find_module(&app_handle, &spec.task)
-        .ok()
-        .flatten()
-        .is_some();
     let provider_kind = if crate::security::policy::is_codegen_task(&spec.task) {
         "codegen"
     } else if is_module_task {
@@ -96,38 +150,9 @@ pub async fn compute_call<R: Runtime>(
 
     // Cache lookup when enabled
     let cache_mode = spec.cache.to_lowercase();
-    if cache_mode == "readwrite" || cache_mode == "readonly" {
-        let use_v2 = std::env::var("UICP_CACHE_V2")
-            .ok()
-            .map(|v| matches!(v.as_str(), "1" | "true" | "TRUE" | "on" | "yes"))
-            .unwrap_or(false);
-        let module_meta = crate::compute::registry::find_module(&app_handle, &spec.task)
-            .ok()
-            .flatten();
-        let invariants = {
-            let mut parts: Vec<String> = Vec::new();
-            if let Some(m) = &module_meta {
-                parts.push(format!("modsha={}", m.entry.digest_sha256));
-                parts.push(format!("modver={}", m.entry.version));
-                if let Some(world) = m.provenance.as_ref().and_then(|p| p.wit_world.clone()) {
-                    if !world.is_empty() {
-                        parts.push(format!("world={}", world));
-                    }
-                }
-                parts.push("abi=wasi-p2".to_string());
-            }
-            if let Ok(pver) = std::env::var("UICP_POLICY_VERSION") {
-                if !pver.is_empty() {
-                    parts.push(format!("policy={}", pver));
-                }
-            }
-            parts.join("|")
-        };
-        let key = if use_v2 {
-            compute_cache::compute_key_v2_plus(&spec, &normalized_input, &invariants)
-        } else {
-            compute_cache::compute_key(&spec.task, &normalized_input, &spec.provenance.env_hash)
-        };
+    // WHY: fsWrite and net-granted jobs must run for real; never answer them from cache.
+    if (cache_mode == "readwrite" || cache_mode == "readonly") && spec.cacheable() {
+        let key = compute_cache::job_cache_key(&app_handle, &spec, &normalized_input);
         if let Ok(Some(mut cached)) =
             compute_cache::lookup(&app_handle, &spec.workspace_id, &key).await
         {
@@ -168,18 +193,16 @@ pub async fn compute_call<R: Runtime>(
         .is_some();
     let permit = if is_module_task {
         state
-            .wasm_sem
-            .clone()
-            .acquire_owned()
-            .await
-            .map_err(|e| e.to_string())?
+            .compute_queues
+            .wasm
+            .acquire(spec.priority, &spec.workspace_id)
+            .await?
     } else {
         state
-            .compute_sem
-            .clone()
-            .acquire_owned()
-            .await
-            .map_err(|e| e.to_string())?
+            .compute_queues
+            .compute
+            .acquire(spec.priority, &spec.workspace_id)
+            .await?
     };
     let queue_wait_ms = queued_at
         .elapsed()
@@ -244,12 +267,89 @@ pub async fn compute_cancel<R: Runtime>(
                 let state: State<'_, AppState> = app_handle.state();
                 state.compute_cancel.write().await.remove(&jid);
                 state.compute_ongoing.write().await.remove(&jid);
+                state.compute_pause.write().await.remove(&jid);
             }
         }
     });
     Ok(())
 }
 
+pub async fn compute_set_paused<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    state: State<'_, AppState>,
+    job_id: String,
+    paused: bool,
+) -> Result<(), String> {
+    let Some(tx) = state.compute_pause.read().await.get(&job_id).cloned() else {
+        return Err(format!(
+            "E-UICP-0243: compute job '{job_id}' is not running or cannot be paused"
+        ));
+    };
+    if tx.send_replace(paused) != paused {
+        let event = if paused { "paused" } else { "resumed" };
+        let _ = app.emit(
+            "compute-debug",
+            serde_json::json!({ "jobId": job_id, "event": event }),
+        );
+    }
+    Ok(())
+}
+
+/// Harness variant of `compute_pipeline`: runs the DAG inline and returns the aggregate payload.
+pub async fn compute_pipeline<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    spec: ComputePipelineSpec,
+) -> Result<serde_json::Value, String> {
+    let plan = pipeline::plan(&spec)?;
+    Ok(pipeline::run(app, spec, plan, |app, job| async move {
+        let state: State<'_, AppState> = app.state();
+        compute_call(app.clone(), state, job).await
+    })
+    .await)
+}
+
+/// Harness variant of `compute_replay`: token-free, since the harness `compute_call` skips token checks.
+pub async fn compute_replay<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    state: State<'_, AppState>,
+    job_id: String,
+) -> Result<ReplayReport, String> {
+    let recorded = replay::load_recorded_job(&state.db_ro, &job_id).await?;
+    let installed_digest = registry::find_module(&app, &recorded.spec.task)
+        .map_err(|e| {
+            format!(
+                "E-UICP-0722: resolve module for {}: {e}",
+                recorded.spec.task
+            )
+        })?
+        .map(|m| m.entry.digest_sha256);
+    replay::run(app, recorded, installed_digest, |app, job| async move {
+        let state: State<'_, AppState> = app.state();
+        compute_call(app.clone(), state, job).await
+    })
+    .await
+}
+
+pub async fn compute_job_logs(
+    state: State<'_, AppState>,
+    job_id: String,
+    min_level: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<GuestLogEntry>, String> {
+    job_logs::load_job_logs(&state.db_ro, &job_id, min_level.as_deref(), limit).await
+}
+
+pub async fn compute_queue_status(state: State<'_, AppState>) -> Result<serde_json::Value, String> {
+    let mut status = state.compute_queues.status();
+    if let Some(obj) = status.as_object_mut() {
+        obj.insert(
+            "instancePools".into(),
+            crate::compute::compute::instance_pool_stats(),
+        );
+    }
+    Ok(status)
+}
+
 pub async fn get_modules_info<R: Runtime>(
     app: tauri::AppHandle<R>,
 ) -> Result<serde_json::Value, String> {
@@ -0,0 +1,355 @@
+//! Host-side key/value state for script applets (`uicp:host/kv`).
+//!
+//! Each applet session gets a scope keyed by `(workspace_id, window_id)`; one-shot jobs get none. Entries live in the
+//! `applet_kv` table next to `window_content`, so they survive restarts, and every write is
+//! checked against per-scope quotas before it is applied. Guests see policy failures as
+//! `err(string)` values prefixed with a short reason (`InvalidKey`, `QuotaExceeded`, `Storage`).
+
+use anyhow::Context;
+use rusqlite::{params, Connection, OptionalExtension};
+use tokio_rusqlite::Connection as AsyncConn;
+
+/// Longest key accepted, in bytes.
+pub const MAX_KEY_BYTES: usize = 256;
+/// Largest single value accepted, in bytes.
+pub const MAX_VALUE_BYTES: usize = 64 * 1024;
+/// Most keys a single scope may hold.
+pub const MAX_KEYS_PER_SCOPE: u64 = 1_024;
+/// Total key plus value bytes a single scope may hold.
+pub const MAX_BYTES_PER_SCOPE: u64 = 1024 * 1024;
+
+pub fn ensure_applet_kv_schema(conn: &Connection) -> anyhow::Result<()> {
+    // NOTE: No foreign key to `window`; `save_workspace` rewrites window rows on every save and a
+    // cascade would wipe applet state with them.
+    conn.execute_batch(
+        r"
+        CREATE TABLE IF NOT EXISTS applet_kv (
+            workspace_id TEXT NOT NULL,
+            window_id TEXT NOT NULL,
+            key TEXT NOT NULL,
+            value TEXT NOT NULL,
+            updated_at INTEGER NOT NULL,
+            PRIMARY KEY (workspace_id, window_id, key)
+        );
+        ",
+    )
+    .context("ensure applet_kv schema")?;
+    Ok(())
+}
+
+/// The workspace/window pair an applet's entries belong to, plus the connection to reach them.
+#[derive(Clone)]
+pub struct KvScope {
+    pub workspace_id: String,
+    pub window_id: String,
+    db: AsyncConn,
+}
+
+/// Why a kv call was refused or failed; rendered into the guest-facing `err` string.
+#[derive(Debug, PartialEq, Eq)]
+pub enum KvError {
+    InvalidKey(String),
+    QuotaExceeded(String),
+    Storage(String),
+}
+
+impl std::fmt::Display for KvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KvError::InvalidKey(msg) => write!(f, "InvalidKey: {msg}"),
+            KvError::QuotaExceeded(msg) => write!(f, "QuotaExceeded: {msg}"),
+            KvError::Storage(msg) => write!(f, "Storage: {msg}"),
+        }
+    }
+}
+
+impl From<rusqlite::Error> for KvError {
+    fn from(err: rusqlite::Error) -> Self {
+        KvError::Storage(err.to_string())
+    }
+}
+
+fn check_key(key: &str) -> Result<(), KvError> {
+    if key.is_empty() {
+        return Err(KvError::InvalidKey("key must not be empty".into()));
+    }
+    if key.len() > MAX_KEY_BYTES {
+        return Err(KvError::InvalidKey(format!(
+            "key is {} bytes (max {MAX_KEY_BYTES})",
+            key.len()
+        )));
+    }
+    Ok(())
+}
+
+pub fn get(
+    conn: &Connection,
+    workspace_id: &str,
+    window_id: &str,
+    key: &str,
+) -> Result<Option<String>, KvError> {
+    check_key(key)?;
+    let value = conn
+        .query_row(
+            "SELECT value FROM applet_kv WHERE workspace_id = ?1 AND window_id = ?2 AND key = ?3",
+            params![workspace_id, window_id, key],
+            |row| row.get(0),
+        )
+        .optional()?;
+    Ok(value)
+}
+
+/// Insert or replace `key`. Quotas are checked against the scope as it would be after the write,
+/// so overwriting an existing key only counts the size difference.
+pub fn set(
+    conn: &mut Connection,
+    workspace_id: &str,
+    window_id: &str,
+    key: &str,
+    value: &str,
+) -> Result<(), KvError> {
+    check_key(key)?;
+    if value.len() > MAX_VALUE_BYTES {
+        return Err(KvError::QuotaExceeded(format!(
+            "value is {} bytes (max {MAX_VALUE_BYTES})",
+            value.len()
+        )));
+    }
+    let tx = conn.transaction()?;
+    let (keys, bytes): (u64, u64) = tx.query_row(
+        "SELECT COUNT(*), COALESCE(SUM(LENGTH(CAST(key AS BLOB)) + LENGTH(CAST(value AS BLOB))), 0)
+         FROM applet_kv WHERE workspace_id = ?1 AND window_id = ?2",
+        params![workspace_id, window_id],
+        |row| Ok((row.get::<_, i64>(0)? as u64, row.get::<_, i64>(1)? as u64)),
+    )?;
+    let existing: Option<u64> = tx
+        .query_row(
+            "SELECT LENGTH(CAST(key AS BLOB)) + LENGTH(CAST(value AS BLOB)) FROM applet_kv
+             WHERE workspace_id = ?1 AND window_id = ?2 AND key = ?3",
+            params![workspace_id, window_id, key],
+            |row| row.get::<_, i64>(0).map(|n| n as u64),
+        )
+        .optional()?;
+    let next_keys = keys + u64::from(existing.is_none());
+    let next_bytes = bytes - existing.unwrap_or(0) + (key.len() + value.len()) as u64;
+    if next_keys > MAX_KEYS_PER_SCOPE {
+        return Err(KvError::QuotaExceeded(format!(
+            "window holds {keys} keys (max {MAX_KEYS_PER_SCOPE})"
+        )));
+    }
+    if next_bytes > MAX_BYTES_PER_SCOPE {
+        return Err(KvError::QuotaExceeded(format!(
+            "write would bring window state to {next_bytes} bytes (max {MAX_BYTES_PER_SCOPE})"
+        )));
+    }
+    tx.execute(
+        "INSERT INTO applet_kv (workspace_id, window_id, key, value, updated_at)
+         VALUES (?1, ?2, ?3, ?4, ?5)
+         ON CONFLICT (workspace_id, window_id, key)
+         DO UPDATE SET value = excluded.value, updated_at = excluded.updated_at",
+        params![
+            workspace_id,
+            window_id,
+            key,
+            value,
+            chrono::Utc::now().timestamp()
+        ],
+    )?;
+    tx.commit()?;
+    Ok(())
+}
+
+/// Remove `key`. Returns whether it existed.
+pub fn delete(
+    conn: &Connection,
+    workspace_id: &str,
+    window_id: &str,
+    key: &str,
+) -> Result<bool, KvError> {
+    check_key(key)?;
+    let removed = conn.execute(
+        "DELETE FROM applet_kv WHERE workspace_id = ?1 AND window_id = ?2 AND key = ?3",
+        params![workspace_id, window_id, key],
+    )?;
+    Ok(removed > 0)
+}
+
+/// Keys starting with `prefix` (all keys when empty), sorted.
+pub fn keys(
+    conn: &Connection,
+    workspace_id: &str,
+    window_id: &str,
+    prefix: &str,
+) -> Result<Vec<String>, KvError> {
+    // WHY: Compare on `substr` instead of LIKE so `%` and `_` in prefixes stay literal.
+    let mut stmt = conn.prepare(
+        "SELECT key FROM applet_kv
+         WHERE workspace_id = ?1 AND window_id = ?2 AND substr(key, 1, length(?3)) = ?3
+         ORDER BY key",
+    )?;
+    let rows = stmt
+        .query_map(params![workspace_id, window_id, prefix], |row| row.get(0))?
+        .collect::<Result<Vec<String>, _>>()?;
+    Ok(rows)
+}
+
+/// Drop every entry in a scope. Returns how many were removed.
+pub fn clear(conn: &Connection, workspace_id: &str, window_id: &str) -> Result<usize, KvError> {
+    Ok(conn.execute(
+        "DELETE FROM applet_kv WHERE workspace_id = ?1 AND window_id = ?2",
+        params![workspace_id, window_id],
+    )?)
+}
+
+impl KvScope {
+    pub fn new(db: AsyncConn, workspace_id: &str, window_id: &str) -> Self {
+        Self {
+            workspace_id: workspace_id.to_string(),
+            window_id: window_id.to_string(),
+            db,
+        }
+    }
+
+    /// Run `op` on the write connection with this scope's ids.
+    async fn with_conn<T, F>(&self, op: F) -> Result<T, KvError>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Connection, &str, &str) -> Result<T, KvError> + Send + 'static,
+    {
+        let workspace_id = self.workspace_id.clone();
+        let window_id = self.window_id.clone();
+        self.db
+            .call(move |conn| Ok(op(conn, &workspace_id, &window_id)))
+            .await
+            .map_err(|err| KvError::Storage(err.to_string()))?
+    }
+
+    pub async fn get(&self, key: String) -> Result<Option<String>, KvError> {
+        self.with_conn(move |conn, ws, win| get(conn, ws, win, &key))
+            .await
+    }
+
+    pub async fn set(&self, key: String, value: String) -> Result<(), KvError> {
+        self.with_conn(move |conn, ws, win| set(conn, ws, win, &key, &value))
+            .await
+    }
+
+    pub async fn delete(&self, key: String) -> Result<bool, KvError> {
+        self.with_conn(move |conn, ws, win| delete(conn, ws, win, &key))
+            .await
+    }
+
+    pub async fn keys(&self, prefix: String) -> Result<Vec<String>, KvError> {
+        self.with_conn(move |conn, ws, win| keys(conn, ws, win, &prefix))
+            .await
+    }
+
+    pub async fn clear(&self) -> Result<usize, KvError> {
+        self.with_conn(|conn, ws, win| clear(conn, ws, win)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn conn() -> Connection {
+        let conn = Connection::open_in_memory().expect("open in-memory sqlite");
+        ensure_applet_kv_schema(&conn).expect("schema");
+        conn
+    }
+
+    #[test]
+    fn set_get_delete_round_trip_is_scoped_per_window() {
+        let mut conn = conn();
+        set(&mut conn, "default", "win-a", "count", "3").unwrap();
+        set(&mut conn, "default", "win-b", "count", "7").unwrap();
+        set(&mut conn, "default", "win-a", "count", "4").unwrap();
+
+        assert_eq!(
+            get(&conn, "default", "win-a", "count").unwrap().as_deref(),
+            Some("4")
+        );
+        assert_eq!(
+            get(&conn, "default", "win-b", "count").unwrap().as_deref(),
+            Some("7")
+        );
+        assert_eq!(get(&conn, "other", "win-a", "count").unwrap(), None);
+
+        assert!(delete(&conn, "default", "win-a", "count").unwrap());
+        assert!(!delete(&conn, "default", "win-a", "count").unwrap());
+        assert_eq!(
+            get(&conn, "default", "win-b", "count").unwrap().as_deref(),
+            Some("7")
+        );
+    }
+
+    #[test]
+    fn keys_filter_by_literal_prefix() {
+        let mut conn = conn();
+        for key in ["todo:1", "todo:2", "todo%x", "prefs"] {
+            set(&mut conn, "default", "win", key, "v").unwrap();
+        }
+        assert_eq!(
+            keys(&conn, "default", "win", "todo:").unwrap(),
+            vec!["todo:1".to_string(), "todo:2".to_string()]
+        );
+        assert_eq!(
+            keys(&conn, "default", "win", "todo%").unwrap(),
+            vec!["todo%x".to_string()]
+        );
+        assert_eq!(keys(&conn, "default", "win", "").unwrap().len(), 4);
+    }
+
+    #[test]
+    fn quotas_reject_oversized_keys_values_and_scopes() {
+        let mut conn = conn();
+        assert!(matches!(
+            set(&mut conn, "default", "win", "", "v"),
+            Err(KvError::InvalidKey(_))
+        ));
+        let long_key = "k".repeat(MAX_KEY_BYTES + 1);
+        assert!(matches!(
+            set(&mut conn, "default", "win", &long_key, "v"),
+            Err(KvError::InvalidKey(_))
+        ));
+        let big = "x".repeat(MAX_VALUE_BYTES + 1);
+        assert!(matches!(
+            set(&mut conn, "default", "win", "big", &big),
+            Err(KvError::QuotaExceeded(_))
+        ));
+
+        // Fill the scope to just under the byte cap, then overflow it with a new key.
+        let chunk = "x".repeat(MAX_VALUE_BYTES - 8);
+        let mut n = 0;
+        while (n + 1) * (chunk.len() + 8) <= MAX_BYTES_PER_SCOPE as usize {
+            set(&mut conn, "default", "win", &format!("chunk{n:03}"), &chunk).unwrap();
+            n += 1;
+        }
+        assert!(matches!(
+            set(&mut conn, "default", "win", "overflow", &chunk),
+            Err(KvError::QuotaExceeded(_))
+        ));
+        // Overwriting an existing key only counts the difference.
+        set(&mut conn, "default", "win", "chunk000", "small").unwrap();
+        // Other windows have their own budget.
+        set(&mut conn, "default", "win-2", "overflow", &chunk).unwrap();
+    }
+
+    #[test]
+    fn key_count_quota_applies_to_new_keys_only() {
+        let mut conn = conn();
+        for i in 0..MAX_KEYS_PER_SCOPE {
+            set(&mut conn, "default", "win", &format!("k{i}"), "").unwrap();
+        }
+        assert!(matches!(
+            set(&mut conn, "default", "win", "one-more", ""),
+            Err(KvError::QuotaExceeded(_))
+        ));
+        set(&mut conn, "default", "win", "k0", "updated").unwrap();
+        assert_eq!(
+            clear(&conn, "default", "win").unwrap() as u64,
+            MAX_KEYS_PER_SCOPE
+        );
+    }
+}
@@ -15,6 +15,18 @@ pub mod csv_parse {
     });
 }
 
+/// Typed bindings for the `uicp:task-csv-parse@1.3.0` package (parse options, typed cells).
+/// INVARIANT: 1.2 bindings above stay in place for modules built against the string-row world.
+pub mod csv_parse_v1_3 {
+    wasmtime::component::bindgen!({
+        path: "wit/csv.parse.v1_3.host.wit",
+        world: "task",
+        exports: {
+            default: async,
+        },
+    });
+}
+
 /// Typed bindings for the `uicp:task-table-query@0.1.0` package.
 /// INVARIANT: The WIT path matches the vendored component source of truth.
 pub mod table_query {
@@ -27,6 +39,42 @@ pub mod table_query {
     });
 }
 
+/// Typed bindings for the `uicp:task-table-query@0.2.0` package (typed cells).
+/// INVARIANT: 0.1 bindings above stay in place for modules built against the string-cell world.
+pub mod table_query_v0_2 {
+    wasmtime::component::bindgen!({
+        path: "wit/table.query.v0_2.host.wit",
+        world: "task",
+        exports: {
+            default: async,
+        },
+    });
+}
+
+/// Typed bindings for the `uicp:task-json-query@0.1.0` package.
+/// INVARIANT: The WIT path matches the vendored component source of truth.
+pub mod json_query {
+    wasmtime::component::bindgen!({
+        path: "wit/json.query.host.wit",
+        world: "task",
+        exports: {
+            default: async,
+        },
+    });
+}
+
+/// Typed bindings for the `uicp:task-markdown-render@0.1.0` package.
+/// INVARIANT: The WIT path matches the vendored component source of truth.
+pub mod markdown_render {
+    wasmtime::component::bindgen!({
+        path: "wit/markdown.render.host.wit",
+        world: "task",
+        exports: {
+            default: async,
+        },
+    });
+}
+
 /// Typed bindings for the `uicp:applet-script@0.1.0` world used by script applets.
 /// INVARIANT: The WIT path is the host-side source of truth for this world.
 pub mod script {
@@ -38,3 +86,15 @@ pub mod script {
         },
     });
 }
+
+/// Typed bindings for the `uicp:applet-script@0.2.0` world (`load`/`dispose`).
+/// INVARIANT: 0.1 bindings above stay in place for modules that read the bundle from the env.
+pub mod script_v0_2 {
+    wasmtime::component::bindgen!({
+        path: "wit/script.v0_2.world.wit",
+        world: "task",
+        exports: {
+            default: async,
+        },
+    });
+}
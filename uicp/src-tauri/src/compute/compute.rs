@@ -21,8 +21,14 @@ use tracing::Instrument;
 
 #[cfg(feature = "wasm_compute")]
 use crate::compute::compute_input::{
-    derive_job_seed, extract_csv_input, extract_script_input, extract_table_query_input,
-    resolve_csv_source, ScriptMode,
+    applet_uses_kv, commit_staged_writes, csv_parse_streaming, derive_job_seed, extract_csv_input,
+    extract_csv_parse_input, extract_json_query_input, extract_markdown_render_input,
+    extract_script_input, extract_table_query_input, extract_table_query_input_typed,
+    plan_fs_read_mounts, resolve_csv_source, script_loads_source, table_query_typed_cells,
+    wants_fs_write_staging, CommittedWrite, CsvEncoding, CsvParseInput, FsReadMount,
+    JsonQueryInput, JsonQueryLanguage, MarkdownRenderInput, ScriptInput, ScriptMode,
+    TableAggregateOp, TableCell, TableCompareOp, TableQuery, TableWhere, WS_FILES_GUEST_ROOT,
+    WS_SCRATCH_GUEST_ROOT,
 };
 #[cfg(feature = "wasm_compute")]
 use crate::compute::registry;
@@ -42,6 +48,7 @@ pub mod error_codes {
     pub const TASK_NOT_FOUND: &str = "Task.NotFound";
     pub const RUNTIME_FAULT: &str = "Runtime.Fault";
     pub const RESOURCE_LIMIT: &str = "Compute.Resource.Limit";
+    pub const FUEL_EXHAUSTED: &str = "Compute.Fuel.Exhausted";
     pub const IO_DENIED: &str = "IO.Denied";
 }
 
@@ -55,13 +62,45 @@ pub mod error_codes {
 #[cfg(feature = "wasm_compute")]
 mod with_runtime {
     use super::*;
+    use crate::compute::applet_kv::KvScope;
     use crate::compute::component_bindings::csv_parse::Task as CsvTask;
+    use crate::compute::component_bindings::csv_parse_v1_3::{
+        exports::uicp::task_csv_parse::csv::{
+            Cell as CsvCell, Encoding as WitCsvEncoding, Options as CsvOptions,
+        },
+        Task as StreamingCsvTask,
+    };
+    use crate::compute::component_bindings::json_query::{
+        exports::uicp::task_json_query::query::{
+            Error as JsonQueryRunError, Input as WitJsonQueryInput,
+            Language as WitJsonQueryLanguage,
+        },
+        Task as JsonQueryTask,
+    };
+    use crate::compute::component_bindings::markdown_render::{
+        exports::uicp::task_markdown_render::render::Options as WitMarkdownOptions,
+        Task as MarkdownTask,
+    };
     use crate::compute::component_bindings::script::Task as ScriptTask;
+    use crate::compute::component_bindings::script_v0_2::{
+        exports::uicp::applet_script::script::Guest as SessionScriptGuest,
+        Task as SessionScriptTask,
+    };
     use crate::compute::component_bindings::table_query::{
         exports::uicp::task_table_query::table::Error as TableRunError,
         uicp::task_table_query::types::{Filter as TableFilter, Input as TableInput},
         Task as TableTask,
     };
+    use crate::compute::component_bindings::table_query_v0_2::{
+        exports::uicp::task_table_query::table::Error as TypedTableRunError,
+        uicp::task_table_query::types::{
+            Aggregate as WitAggregate, AggregateOp as WitAggregateOp, Cell as WitCell,
+            CompareOp as WitCompareOp, Filter as TypedTableFilter, Input as TypedTableInput,
+            OrderKey as WitOrderKey, Predicate as WitPredicate, WhereNode as WitWhereNode,
+        },
+        Task as TypedTableTask,
+    };
+    use crate::compute::job_logs::{self, GuestLogEntry};
     // WHY: Bring `Context` into scope for error enrichment on Wasmtime operations.
     // SAFETY: Some build permutations may not hit the `.context()` paths; suppress unused lint.
     #[allow(unused_imports)]
@@ -71,6 +110,7 @@ mod with_runtime {
     use chrono::Utc;
     use ciborium::value::Value;
     use dashmap::DashMap;
+    use hmac::{Hmac, Mac};
     use once_cell::sync::Lazy;
     use parking_lot::Mutex;
     use pollster::block_on;
@@ -91,8 +131,11 @@ mod with_runtime {
     };
     use tokio::time::{sleep, Duration as TokioDuration};
     use wasmtime::{
-        component::{Component, Linker, Resource, ResourceTable},
-        Config, Engine, Store, StoreContextMut, StoreLimits, StoreLimitsBuilder,
+        component::{
+            Component, ComponentType, Instance, InstancePre, Lift, Linker, Lower, Resource,
+            ResourceTable,
+        },
+        Config, Engine, Store, StoreContextMut, StoreLimits, StoreLimitsBuilder, UpdateDeadline,
     };
     use wasmtime_wasi::async_trait;
     use wasmtime_wasi::p2::{
@@ -104,10 +147,13 @@ mod with_runtime {
         DirPerms, FilePerms, WasiCtx, WasiCtxBuilder, WasiCtxView, WasiView,
     };
 
+    use crate::compute::hostctx::HostCtx;
     // Import configuration constants
     use crate::config::limits::{
         DEFAULT_MEMORY_LIMIT_MB, DEFAULT_RUNTIME_FUEL, MAX_LOG_BYTES, MAX_PARTIAL_FRAME_BYTES,
     };
+    use crate::security::egress::{egress_fetch_core, EgressRequest};
+    use crate::security::policy::{net_url_granted, NetRule};
 
     const EPOCH_TICK_INTERVAL_MS: u64 = 10;
     // Max preview bytes per log frame to include in partial events
@@ -169,11 +215,84 @@ mod with_runtime {
         Shutdown,
     }
 
+    /// Percentages of `mem_limit_mb` that raise a `kind: "memory"` partial the first time linear
+    /// memory reaches them.
+    const MEM_THRESHOLDS_PCT: [u64; 3] = [50, 75, 90];
+    /// Samples retained per store; beyond this the series halves its resolution instead of growing.
+    const MEM_SERIES_CAPACITY: usize = 512;
+    /// Points reported as `memSeries` in final metrics.
+    const MEM_SERIES_POINTS: usize = 32;
+
+    /// Resource usage observed at one epoch tick.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct MemSample {
+        t_ms: u64,
+        mem_bytes: usize,
+        table_elements: usize,
+    }
+
+    /// Bounded usage time series. When full, every other sample is dropped and the sampling stride
+    /// doubles, so the series always spans the whole run at decreasing resolution.
+    #[derive(Debug)]
+    struct MemSeries {
+        samples: Vec<MemSample>,
+        stride: u64,
+        ticks: u64,
+    }
+
+    impl MemSeries {
+        fn new() -> Self {
+            Self {
+                samples: Vec::new(),
+                stride: 1,
+                ticks: 0,
+            }
+        }
+
+        fn record(&mut self, sample: MemSample) {
+            let tick = self.ticks;
+            self.ticks += 1;
+            if !tick.is_multiple_of(self.stride) {
+                return;
+            }
+            if self.samples.len() >= MEM_SERIES_CAPACITY {
+                let mut index = 0usize;
+                self.samples.retain(|_| {
+                    index += 1;
+                    index % 2 == 1
+                });
+                self.stride *= 2;
+                if !tick.is_multiple_of(self.stride) {
+                    return;
+                }
+            }
+            self.samples.push(sample);
+        }
+
+        /// At most `points` samples; each bucket keeps its highest-memory sample so peaks survive.
+        fn downsample(&self, points: usize) -> Vec<MemSample> {
+            if points == 0 || self.samples.len() <= points {
+                return self.samples.clone();
+            }
+            self.samples
+                .chunks(self.samples.len().div_ceil(points))
+                .filter_map(|chunk| chunk.iter().copied().max_by_key(|s| s.mem_bytes))
+                .collect()
+        }
+    }
+
     #[allow(dead_code)]
     #[derive(Debug)]
     struct LimitsWithPeak {
         inner: StoreLimits,
         mem_peak_bytes: usize,
+        mem_limit_bytes: usize,
+        // Current totals across all memories/tables; both only grow for the life of a store.
+        mem_bytes: usize,
+        table_elements: usize,
+        series: MemSeries,
+        // Number of `MEM_THRESHOLDS_PCT` entries already reported.
+        thresholds_reported: usize,
     }
 
     impl LimitsWithPeak {
@@ -202,6 +321,11 @@ mod with_runtime {
             Self {
                 inner,
                 mem_peak_bytes: 0,
+                mem_limit_bytes,
+                mem_bytes: 0,
+                table_elements: 0,
+                series: MemSeries::new(),
+                thresholds_reported: 0,
             }
         }
 
@@ -209,6 +333,61 @@ mod with_runtime {
             // Round up to the nearest MiB
             (self.mem_peak_bytes as u64).div_ceil(1_048_576)
         }
+
+        /// Adopt usage already materialized in a pooled store so samples and peaks match a cold start.
+        fn inherit_usage(&mut self, prior: &LimitsWithPeak) {
+            self.mem_peak_bytes = self.mem_peak_bytes.max(prior.mem_peak_bytes);
+            self.mem_bytes += prior.mem_bytes;
+            self.table_elements += prior.table_elements;
+        }
+
+        fn sample(&self, t_ms: u64) -> MemSample {
+            MemSample {
+                t_ms,
+                mem_bytes: self.mem_bytes,
+                table_elements: self.table_elements,
+            }
+        }
+
+        /// Thresholds reached since the last call, in ascending order.
+        fn take_crossed_thresholds(&mut self) -> Vec<u64> {
+            if self.mem_limit_bytes == 0 {
+                return Vec::new();
+            }
+            let used_pct =
+                (self.mem_bytes as u64).saturating_mul(100) / self.mem_limit_bytes as u64;
+            let reached = MEM_THRESHOLDS_PCT
+                .iter()
+                .take_while(|&&pct| used_pct >= pct)
+                .count();
+            let crossed =
+                MEM_THRESHOLDS_PCT[self.thresholds_reported.min(reached)..reached].to_vec();
+            self.thresholds_reported = self.thresholds_reported.max(reached);
+            crossed
+        }
+
+        /// Downsampled series plus the usage at `t_ms`, as `[{ tMs, memBytes, tableElements }]`.
+        fn series_json(&self, t_ms: u64) -> serde_json::Value {
+            let mut points = self.series.downsample(MEM_SERIES_POINTS.saturating_sub(1));
+            let last = self.sample(t_ms);
+            if points.last().map(|p| (p.mem_bytes, p.table_elements))
+                != Some((last.mem_bytes, last.table_elements))
+            {
+                points.push(last);
+            }
+            serde_json::Value::Array(
+                points
+                    .into_iter()
+                    .map(|p| {
+                        serde_json::json!({
+                            "tMs": p.t_ms,
+                            "memBytes": p.mem_bytes,
+                            "tableElements": p.table_elements,
+                        })
+                    })
+                    .collect(),
+            )
+        }
     }
 
     impl wasmtime::ResourceLimiter for LimitsWithPeak {
@@ -221,7 +400,11 @@ mod with_runtime {
             if desired > self.mem_peak_bytes {
                 self.mem_peak_bytes = desired;
             }
-            self.inner.memory_growing(current, desired, maximum)
+            let allowed = self.inner.memory_growing(current, desired, maximum)?;
+            if allowed {
+                self.mem_bytes += desired.saturating_sub(current);
+            }
+            Ok(allowed)
         }
 
         fn table_growing(
@@ -237,8 +420,11 @@ mod with_runtime {
             // allowed up to a conservative high ceiling to avoid false positives while preventing
             // pathological growth.
             const TABLE_ELEMENTS_MAX: usize = 1_048_576; // ~1M elements across tables
-            let _ = current; // not used for policy beyond telemetry
-            Ok(desired <= TABLE_ELEMENTS_MAX)
+            let allowed = desired <= TABLE_ELEMENTS_MAX;
+            if allowed {
+                self.table_elements += desired.saturating_sub(current);
+            }
+            Ok(allowed)
         }
     }
 
@@ -274,9 +460,99 @@ mod with_runtime {
         partial_rate: Arc<Mutex<RateLimiterEvents>>,
         partial_throttle_waits: Arc<AtomicU64>,
         limits: LimitsWithPeak,
+        // Whether this job reused a pre-instantiated store from the task pool
+        pool_hit: bool,
+        // Network grant for `uicp:host/http`; `None` unless the spec lists `capabilities.net`
+        net: Option<Arc<NetGrant>>,
+        // Applet state scope for `uicp:host/kv`; set only for per-window applet sessions
+        kv: Option<Arc<KvScope>>,
+        // Durable sink for `uicp:host/log` entries; `None` for internal stores
+        action_log: Option<crate::infrastructure::action_log::ActionLogHandle>,
+        // Pause signal from `compute_pause`/`compute_resume` (`None` for internal stores)
+        pause_rx: Option<tokio::sync::watch::Receiver<bool>>,
+        pause: Arc<PauseClock>,
+    }
+
+    /// Paused-time accounting for a job. The epoch callback parks the guest while the job is
+    /// paused; parked time is excluded from the deadline and reported as `pausedMs`.
+    #[derive(Default)]
+    struct PauseClock {
+        parked_since: Mutex<Option<Instant>>,
+        paused_ms: AtomicU64,
+        pauses: AtomicU64,
+    }
+
+    impl PauseClock {
+        fn park(&self) {
+            let mut since = self.parked_since.lock();
+            if since.is_none() {
+                *since = Some(Instant::now());
+                self.pauses.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        fn unpark(&self) {
+            if let Some(since) = self.parked_since.lock().take() {
+                let ms = u64::try_from(since.elapsed().as_millis()).unwrap_or(u64::MAX);
+                self.paused_ms.fetch_add(ms, Ordering::Relaxed);
+            }
+        }
+
+        /// Total paused time, including an interval still in progress.
+        fn total_ms(&self) -> u64 {
+            let current = self
+                .parked_since
+                .lock()
+                .map(|since| u64::try_from(since.elapsed().as_millis()).unwrap_or(u64::MAX))
+                .unwrap_or(0);
+            self.paused_ms
+                .load(Ordering::Relaxed)
+                .saturating_add(current)
+        }
+    }
+
+    /// URLs a job may reach through `uicp:host/http`, plus the egress context used to reach them.
+    struct NetGrant {
+        /// Parsed `capabilities.net` rules (host pattern, port and path prefix).
+        rules: Vec<NetRule>,
+        /// Rate/concurrency limiter key for egress (`compute:<task>`).
+        installed_id: String,
+        host: HostCtx,
+        requests: AtomicU64,
     }
 
     impl Ctx {
+        /// Wall-clock time since job start minus paused intervals; deadlines apply to this.
+        fn active_elapsed_ms(&self) -> u64 {
+            u64::try_from(self.started.elapsed().as_millis())
+                .unwrap_or(u64::MAX)
+                .saturating_sub(self.pause.total_ms())
+        }
+
+        /// Append a usage sample and emit a `kind: "memory"` partial per newly crossed threshold.
+        fn sample_memory(&mut self) {
+            let sample = self.limits.sample(self.active_elapsed_ms());
+            self.limits.series.record(sample);
+            for pct in self.limits.take_crossed_thresholds() {
+                let seq_no = self
+                    .partial_seq
+                    .fetch_add(1, Ordering::Relaxed)
+                    .saturating_add(1);
+                self.emitter.emit_partial_json(serde_json::json!({
+                    "jobId": self.job_id,
+                    "task": self.task,
+                    "seq": seq_no,
+                    "kind": "memory",
+                    "thresholdPct": pct,
+                    "memBytes": sample.mem_bytes,
+                    "limitBytes": self.limits.mem_limit_bytes,
+                    "tableElements": sample.table_elements,
+                    "tMs": sample.t_ms,
+                    "tick": self.logical_tick.load(Ordering::Relaxed),
+                }));
+            }
+        }
+
         fn log_p2(&mut self, level: u32, context: String, message: String) {
             let level_str = match level {
                 0 => "trace",
@@ -365,6 +641,62 @@ mod with_runtime {
                 "ts": Utc::now().timestamp_millis(),
             }));
         }
+
+        /// Admit one `uicp:host/log` entry: charge the job log budget and stamp seq/tick.
+        /// Returns `None` once the budget is spent, so dropped entries never wait on the rate.
+        fn admit_guest_log(&mut self, mut entry: GuestLogEntry) -> Option<GuestLogEntry> {
+            let size = entry.size_bytes();
+            if self.emitted_log_bytes.load(Ordering::Relaxed) as usize >= self.max_log_bytes {
+                return None;
+            }
+            self.emitted_log_bytes
+                .fetch_add(size as u64, Ordering::Relaxed);
+            self.log_count.fetch_add(1, Ordering::Relaxed);
+            entry.seq = self
+                .partial_seq
+                .fetch_add(1, Ordering::Relaxed)
+                .saturating_add(1);
+            entry.tick = self
+                .logical_tick
+                .fetch_add(1, Ordering::Relaxed)
+                .saturating_add(1);
+            Some(entry)
+        }
+
+        /// Take `size` bytes from the logger byte rate. Returns `None` once taken, or how long
+        /// to back off before trying again; the caller sleeps without holding the store.
+        fn take_logger_rate(&self, size: usize) -> Option<Duration> {
+            let mut rl = self.logger_rate.lock();
+            let avail = rl.available();
+            if avail >= size {
+                rl.consume(size);
+                return None;
+            }
+            self.logger_throttle_waits.fetch_add(1, Ordering::Relaxed);
+            Some(rl.recommended_sleep(size.saturating_sub(avail)))
+        }
+
+        /// Mirror a stored `uicp:host/log` entry as a `log` partial.
+        fn emit_guest_log(&self, entry: &GuestLogEntry) {
+            let preview_len = entry.message.len().min(LOG_PREVIEW_MAX);
+            let mut preview_end = preview_len;
+            while !entry.message.is_char_boundary(preview_end) {
+                preview_end -= 1;
+            }
+            self.emitter.emit_partial_json(serde_json::json!({
+                "jobId": self.job_id,
+                "task": self.task,
+                "seq": entry.seq,
+                "kind": "log",
+                "stream": "uicp-log",
+                "level": entry.level,
+                "tick": entry.tick,
+                "bytesLen": entry.message.len(),
+                "previewB64": BASE64_ENGINE.encode(&entry.message.as_bytes()[..preview_end]),
+                "fields": entry.fields,
+                "truncated": entry.truncated,
+            }));
+        }
     }
 
     struct QueueingEmitter {
@@ -800,6 +1132,49 @@ mod with_runtime {
         }
     }
 
+    /// Job/session output for a completed script call; guest errors are data, not faults.
+    fn script_call_payload(mode: &ScriptMode, result: Result<String, String>) -> serde_json::Value {
+        match (mode, result) {
+            (ScriptMode::Render, Ok(html)) => serde_json::json!({
+                "status": "ready",
+                "mode": script_mode_label(mode),
+                "html": html,
+            }),
+            (_, Ok(data)) => serde_json::json!({
+                "status": "ready",
+                "mode": script_mode_label(mode),
+                "data": data,
+            }),
+            (_, Err(msg)) => serde_json::json!({
+                "status": "error",
+                "mode": script_mode_label(mode),
+                "error": { "message": msg },
+            }),
+        }
+    }
+
+    /// Dispatch a script mode on an `applet-script@0.2` instance whose bundle is loaded.
+    async fn call_loaded_script(
+        iface: &SessionScriptGuest,
+        store: &mut Store<Ctx>,
+        input: &ScriptInput,
+    ) -> wasmtime::Result<Result<String, String>> {
+        match input.mode {
+            ScriptMode::Init => iface.call_init(store).await,
+            ScriptMode::Render => iface.call_render(store, &input.state).await,
+            ScriptMode::OnEvent => {
+                iface
+                    .call_on_event(
+                        store,
+                        input.action.as_deref().unwrap_or_default(),
+                        input.payload.as_deref().unwrap_or_default(),
+                        &input.state,
+                    )
+                    .await
+            }
+        }
+    }
+
     fn integer_key_to_u64(value: &Value) -> Option<u64> {
         match value {
             Value::Integer(int) => u64::try_from(*int).ok(),
@@ -998,6 +1373,12 @@ mod with_runtime {
             partial_rate: Arc::new(Mutex::new(RateLimiterEvents::new(EVENTS_MAX, EVENTS_MAX))),
             partial_throttle_waits: Arc::new(AtomicU64::new(0)),
             limits: LimitsWithPeak::new((DEFAULT_MEMORY_LIMIT_MB as usize) * 1024 * 1024),
+            pool_hit: false,
+            net: None,
+            kv: None,
+            action_log: None,
+            pause_rx: None,
+            pause: Arc::new(PauseClock::default()),
         }
     }
 
@@ -1019,10 +1400,70 @@ mod with_runtime {
         linker
     });
 
+    /// Linker for jobs granted `capabilities.net`: everything in `LINKER` plus `uicp:host/http`.
+    /// WHY: Jobs without a grant keep the default linker so network imports fail to link.
+    static LINKER_NET: Lazy<Linker<Ctx>> = Lazy::new(|| {
+        let mut linker = Linker::<Ctx>::new(&ENGINE);
+        add_wasi_and_host(&mut linker).expect("add wasi+host");
+        add_uicp_http(&mut linker).expect("add uicp:host/http");
+        linker
+    });
+
+    const DEFAULT_MAX_PAUSE_MS: u64 = 600_000;
+
+    /// Longest a job may stay paused before it times out (`UICP_COMPUTE_MAX_PAUSE_MS`).
+    fn max_pause_ms() -> u64 {
+        std::env::var("UICP_COMPUTE_MAX_PAUSE_MS")
+            .ok()
+            .and_then(|v| v.trim().parse::<u64>().ok())
+            .unwrap_or(DEFAULT_MAX_PAUSE_MS)
+    }
+
+    /// Enforce the deadline on every epoch tick against active (unpaused) time, sample memory
+    /// usage, and park the guest while the job is paused.
+    /// WHY: Parking inside the epoch callback suspends the store at an instruction boundary, so
+    /// guests need no cooperation and the wall clock keeps running only for the pause window.
+    fn install_deadline_callback(store: &mut Store<Ctx>) {
+        store.set_epoch_deadline(1);
+        store.epoch_deadline_callback(|mut ctx| {
+            ctx.data_mut().sample_memory();
+            let data = ctx.data();
+            // NOTE: Cancellation is handled by the job's select loop so it keeps its own code.
+            if data.active_elapsed_ms() >= u64::from(data.deadline_ms) {
+                return Err(wasmtime::Trap::Interrupt.into());
+            }
+            let Some(rx) = data.pause_rx.as_ref().filter(|rx| *rx.borrow()) else {
+                return Ok(UpdateDeadline::Continue(1));
+            };
+            let allowance = max_pause_ms().saturating_sub(data.pause.total_ms());
+            if allowance == 0 {
+                // ERROR: Paused past the ceiling; surfaces as Compute.Timeout.
+                return Err(wasmtime::Trap::Interrupt.into());
+            }
+            let mut rx = rx.clone();
+            let pause = data.pause.clone();
+            Ok(UpdateDeadline::YieldCustom(
+                1,
+                Box::pin(async move {
+                    pause.park();
+                    let _ = tokio::time::timeout(
+                        Duration::from_millis(allowance),
+                        rx.wait_for(|paused| !*paused),
+                    )
+                    .await;
+                    pause.unpark();
+                }),
+            ))
+        });
+    }
+
     /// LRU-ish compiled Component cache keyed by path + mtime.
     static COMPONENT_CACHE: Lazy<DashMap<String, Arc<Component>>> = Lazy::new(DashMap::new);
 
-    fn load_component_cached(path: &std::path::Path) -> anyhow::Result<Arc<Component>> {
+    fn load_component_cached(
+        path: &std::path::Path,
+        digest_sha256: &str,
+    ) -> anyhow::Result<Arc<Component>> {
         use std::fs;
         use std::time::{SystemTime, UNIX_EPOCH};
         let meta = fs::metadata(path)?;
@@ -1035,129 +1476,1005 @@ mod with_runtime {
         if let Some(found) = COMPONENT_CACHE.get(&key) {
             return Ok(found.clone());
         }
-        let comp = Component::from_file(&ENGINE, path)?;
+        let comp = load_component_aot(path, digest_sha256)?;
         let arc = Arc::new(comp);
         COMPONENT_CACHE.insert(key, arc.clone());
         Ok(arc)
     }
 
-    /// Build a fresh Engine (used by some unit tests); runtime uses the global ENGINE.
-    #[cfg(any(test, feature = "compute_harness"))]
-    #[cfg_attr(feature = "compute_harness", allow(dead_code))]
-    fn build_engine() -> anyhow::Result<Engine> {
-        let mut cfg = Config::new();
-        cfg.wasm_component_model(true)
-            .async_support(true)
-            .consume_fuel(true)
-            .epoch_interruption(true)
-            .wasm_memory64(false);
-        Ok(Engine::new(&cfg)?)
-    }
+    /// Precompiled artifacts live in the app-private cache (`<dataDir>/cache/aot`), never in the
+    /// modules dir, as `<digest>-<engine fingerprint>.cwasm` with a `.cwasm.mac` sidecar so
+    /// restarts skip Cranelift for unchanged modules.
+    const AOT_ARTIFACT_SUFFIX: &str = ".cwasm";
+    const AOT_MAC_SUFFIX: &str = ".cwasm.mac";
+    /// Host secret authenticating artifacts; created (0600) on first use inside the cache dir.
+    const AOT_KEY_FILE: &str = "aot.key";
+
+    /// Stable fingerprint of the engine's compilation settings and Wasmtime version.
+    /// Artifacts produced under a different fingerprint are never loaded and get pruned.
+    static ENGINE_FINGERPRINT: Lazy<String> = Lazy::new(|| {
+        use std::hash::Hash;
+        let mut hasher = Sha256Hasher(Sha256::new());
+        ENGINE.precompile_compatibility_hash().hash(&mut hasher);
+        hex::encode(&hasher.0.finalize()[..16])
+    });
 
-    /// Spawn a compute job using Wasmtime: build engine/store, link WASI + host, and instantiate the component world.
-    /// Execution of task exports will be wired in the next milestone; for now we finalize with a pending-wiring message.
-    pub(super) fn spawn_job<R: Runtime>(
-        app: tauri::AppHandle<R>,
-        spec: ComputeJobSpec,
-        permit: Option<OwnedSemaphorePermit>,
-        queue_wait_ms: u64,
-    ) -> JoinHandle<()> {
-        #[cfg(feature = "otel_spans")]
-        let span = tracing::info_span!(
-            "compute_spawn_job",
-            job_id = %spec.job_id,
-            task = %spec.task
-        );
+    /// Feeds `Hash` output into SHA-256; `DefaultHasher` makes no stability promise across
+    /// Rust releases, so it cannot name files that outlive the process.
+    struct Sha256Hasher(Sha256);
 
-        let fut = async move {
-            #[cfg(feature = "otel_spans")]
-            let _span = tracing::info_span!(
-                "compute_spawn_job_inner",
-                job_id = %spec.job_id,
-                task = %spec.task
-            );
-            let _permit = permit;
-            let started = Instant::now();
-            // WHY: Feature-flag-ish env var to dump diagnostics about WASI + component at job start.
-            let diag_enabled = {
-                let v = std::env::var("UICP_WASI_DIAG")
-                    .or_else(|_| std::env::var("uicp_wasi_diag"))
-                    .unwrap_or_default();
-                matches!(v.as_str(), "1" | "true" | "TRUE" | "yes" | "on")
-            };
+    impl std::hash::Hasher for Sha256Hasher {
+        fn write(&mut self, bytes: &[u8]) {
+            self.0.update(bytes);
+        }
 
-            // Register cancel channel for this job
-            let (tx_cancel, rx_cancel) = tokio::sync::watch::channel(false);
-            {
-                let state: tauri::State<'_, crate::AppState> = app.state();
-                state
-                    .compute_cancel
-                    .write()
-                    .await
-                    .insert(spec.job_id.clone(), tx_cancel);
+        fn finish(&self) -> u64 {
+            // Unused: the fingerprint is read from the SHA-256 state, not a u64.
+            0
+        }
+    }
+
+    /// AOT cache is on by default; `UICP_AOT_CACHE=0` forces in-process compilation.
+    fn aot_cache_enabled() -> bool {
+        std::env::var("UICP_AOT_CACHE")
+            .map(|v| !matches!(v.as_str(), "0" | "false" | "FALSE" | "off" | "no"))
+            .unwrap_or(true)
+    }
+
+    static AOT_CACHE: Lazy<Option<AotCache>> = Lazy::new(|| {
+        let dir = crate::DATA_DIR.join("cache").join("aot");
+        match AotCache::open(dir.clone()) {
+            Ok(cache) => Some(cache),
+            Err(err) => {
+                // Best-effort: an unwritable data dir must not block execution.
+                #[cfg(feature = "otel_spans")]
+                tracing::warn!(target = "uicp", path = %dir.display(), error = %err, "aot cache unavailable");
+                #[cfg(not(feature = "otel_spans"))]
+                log_warn(format!(
+                    "aot cache unavailable (path={}, error={err:#})",
+                    dir.display()
+                ));
+                None
             }
+        }
+    });
 
-            // Reuse global engine
-            let engine: &Engine = &ENGINE;
+    /// App-private artifact directory plus the host secret that authenticates its contents.
+    /// INVARIANT: An artifact is deserialized only when its MAC verifies under `key` for the
+    /// module digest and engine fingerprint it was compiled from.
+    struct AotCache {
+        dir: std::path::PathBuf,
+        key: [u8; 32],
+    }
 
-            // Resolve module by task@version
-            let module = match registry::find_module(&app, &spec.task) {
-                Ok(Some(m)) => m,
-                Ok(None) => {
-                    #[cfg(feature = "otel_spans")]
-                    tracing::info!(target = "uicp", job_id = %spec.job_id, task = %spec.task, "module not found");
-                    finalize_error(
-                        &app,
-                        &spec,
-                        error_codes::TASK_NOT_FOUND,
-                        "Module not found for task",
-                        started,
-                        queue_wait_ms,
-                        None,
-                    )
-                    .await;
-                    let state: tauri::State<'_, crate::AppState> = app.state();
-                    state.compute_cancel.write().await.remove(&spec.job_id);
-                    crate::remove_compute_job(&app, &spec.job_id).await;
-                    return;
-                }
+    impl AotCache {
+        fn open(dir: std::path::PathBuf) -> anyhow::Result<Self> {
+            std::fs::create_dir_all(&dir)
+                .with_context(|| format!("E-UICP-0238: create aot cache dir {}", dir.display()))?;
+            let key = load_or_create_aot_key(&dir.join(AOT_KEY_FILE))?;
+            Ok(Self { dir, key })
+        }
+
+        fn artifact_path(&self, digest_sha256: &str) -> std::path::PathBuf {
+            self.dir.join(format!(
+                "{}-{}{AOT_ARTIFACT_SUFFIX}",
+                digest_sha256.to_ascii_lowercase(),
+                *ENGINE_FINGERPRINT
+            ))
+        }
+
+        fn artifact_mac(&self, digest_sha256: &str, bytes: &[u8]) -> Hmac<Sha256> {
+            let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(&self.key)
+                .expect("hmac accepts any key length");
+            mac.update(b"UICP-AOT\x00digest=");
+            mac.update(digest_sha256.to_ascii_lowercase().as_bytes());
+            mac.update(b"\x00engine=");
+            mac.update(ENGINE_FINGERPRINT.as_bytes());
+            mac.update(b"\x00artifact=");
+            mac.update(bytes);
+            mac
+        }
+
+        fn load_or_compile(&self, path: &Path, digest_sha256: &str) -> anyhow::Result<Component> {
+            if let Some(component) = self.try_load(digest_sha256) {
+                return Ok(component);
+            }
+            let component = Component::from_file(&ENGINE, path)?;
+            if let Err(err) = self.store(&component, digest_sha256) {
+                // Best-effort: a full or read-only cache dir must not block execution.
+                #[cfg(feature = "otel_spans")]
+                tracing::warn!(target = "uicp", digest = %digest_sha256, error = %err, "aot cache store failed");
+                #[cfg(not(feature = "otel_spans"))]
+                log_warn(format!(
+                    "aot cache store failed (digest={digest_sha256}, error={err:#})"
+                ));
+            }
+            Ok(component)
+        }
+
+        fn try_load(&self, digest_sha256: &str) -> Option<Component> {
+            let artifact = self.artifact_path(digest_sha256);
+            let tag = std::fs::read_to_string(aot_mac_path(&artifact)).ok()?;
+            let bytes = std::fs::read(&artifact).ok()?;
+            if !self.verify_artifact(digest_sha256, &bytes, &tag) {
+                discard_aot_artifact(&artifact, "mac mismatch");
+                return None;
+            }
+            // SAFETY: `deserialize` trusts its input to be a Wasmtime artifact. We only pass bytes
+            // whose MAC (keyed by the host secret, bound to this module digest and engine
+            // fingerprint) verified above; they were read into memory so the file cannot change
+            // underneath us, unlike `deserialize_file`'s mmap. Wasmtime additionally rejects
+            // version/config mismatches embedded in the artifact.
+            match unsafe { Component::deserialize(&ENGINE, &bytes) } {
+                Ok(component) => Some(component),
                 Err(err) => {
-                    // WHY: Surface precise context so UI can see root-cause for module resolution faults.
-                    // ERROR: E-UICP-0221 registry lookup failed for task; message carries inner error string.
-                    let any = err.context(format!(
-                        "E-UICP-0221: registry lookup failed for task '{}': module resolve error",
-                        spec.task
-                    ));
-                    let (code, msg) = map_trap_error(&any);
-                    let message = if msg.is_empty() { any.to_string() } else { msg };
-                    #[cfg(not(feature = "otel_spans"))]
-                    log_error(format!(
-                        "module resolve error (job_id={}, task={}, error={message})",
-                        spec.job_id, spec.task
-                    ));
-                    #[cfg(feature = "otel_spans")]
-                    tracing::error!(target = "uicp", job_id = %spec.job_id, task = %spec.task, error = %message, "module resolve error");
-                    finalize_error(&app, &spec, code, &message, started, queue_wait_ms, None).await;
-                    let state: tauri::State<'_, crate::AppState> = app.state();
-                    state.compute_cancel.write().await.remove(&spec.job_id);
-                    crate::remove_compute_job(&app, &spec.job_id).await;
-                    return;
+                    discard_aot_artifact(&artifact, &format!("{err:#}"));
+                    None
                 }
+            }
+        }
+
+        fn verify_artifact(&self, digest_sha256: &str, bytes: &[u8], tag_hex: &str) -> bool {
+            hex::decode(tag_hex.trim()).is_ok_and(|tag| {
+                self.artifact_mac(digest_sha256, bytes)
+                    .verify_slice(&tag)
+                    .is_ok()
+            })
+        }
+
+        fn store(&self, component: &Component, digest_sha256: &str) -> anyhow::Result<()> {
+            std::fs::create_dir_all(&self.dir).with_context(|| {
+                format!("E-UICP-0238: create aot cache dir {}", self.dir.display())
+            })?;
+            prune_stale_aot_artifacts(&self.dir);
+            let bytes = component
+                .serialize()
+                .context("E-UICP-0238: serialize component for aot cache failed")?;
+            let tag = hex::encode(
+                self.artifact_mac(digest_sha256, &bytes)
+                    .finalize()
+                    .into_bytes(),
+            );
+            let artifact = self.artifact_path(digest_sha256);
+            // Write artifact first, MAC last: a crash in between leaves no loadable pair.
+            write_file_atomic(&artifact, &bytes)?;
+            write_file_atomic(&aot_mac_path(&artifact), tag.as_bytes())?;
+            Ok(())
+        }
+
+        /// Artifacts compiled under this engine whose sidecar is missing or does not verify.
+        /// Nothing is removed here; the integrity sweep decides what to do with them.
+        fn unverified(&self) -> Vec<UnverifiedAotArtifact> {
+            let Ok(read) = std::fs::read_dir(&self.dir) else {
+                return Vec::new();
             };
+            let suffix = format!("-{}{AOT_ARTIFACT_SUFFIX}", *ENGINE_FINGERPRINT);
+            let mut out = Vec::new();
+            for entry in read.flatten() {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                let Some(digest) = name.strip_suffix(&suffix) else {
+                    continue;
+                };
+                let artifact = entry.path();
+                let reason = match std::fs::read_to_string(aot_mac_path(&artifact)) {
+                    Err(_) => "missing sidecar",
+                    Ok(tag) => match std::fs::read(&artifact) {
+                        Ok(bytes) if self.verify_artifact(digest, &bytes, &tag) => continue,
+                        Ok(_) => "mac mismatch",
+                        Err(_) => "unreadable artifact",
+                    },
+                };
+                out.push(UnverifiedAotArtifact {
+                    digest_sha256: digest.to_string(),
+                    path: artifact,
+                    reason,
+                });
+            }
+            out
+        }
+    }
 
-            // Load compiled component from cache
-            let component = match load_component_cached(&module.path) {
-                Ok(c) => c,
-                Err(err) => {
-                    // WHY: Carry the on-disk path and root cause to aid diagnosing cache/compile errors.
-                    // ERROR: E-UICP-0222 component load failed; likely invalid encoding or wrong target (not a component).
-                    let any = err.context(format!(
-                        "E-UICP-0222: load component failed for path {}",
-                        module.path.display()
-                    ));
-                    let (code, msg) = map_trap_error(&any);
-                    let chain = format!("{:#}", &any); // include error chain
-                    let message = if msg.is_empty() { chain } else { msg };
+    fn load_or_create_aot_key(path: &Path) -> anyhow::Result<[u8; 32]> {
+        use rand::RngCore;
+        if let Ok(bytes) = std::fs::read(path) {
+            if let Ok(key) = <[u8; 32]>::try_from(bytes.as_slice()) {
+                return Ok(key);
+            }
+        }
+        // A missing or malformed key invalidates every existing artifact; they are rebuilt.
+        let mut key = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut key);
+        write_file_atomic(path, &key)?;
+        Ok(key)
+    }
+
+    fn aot_mac_path(artifact: &Path) -> std::path::PathBuf {
+        artifact.with_extension("cwasm.mac")
+    }
+
+    pub(super) fn unverified_aot_artifacts() -> Vec<UnverifiedAotArtifact> {
+        AOT_CACHE
+            .as_ref()
+            .map(AotCache::unverified)
+            .unwrap_or_default()
+    }
+
+    /// Delete the artifact and sidecar compiled from `digest_sha256` under this engine.
+    pub(super) fn remove_aot_artifact(digest_sha256: &str) {
+        if let Some(cache) = AOT_CACHE.as_ref() {
+            let artifact = cache.artifact_path(digest_sha256);
+            // MAC first: a crash in between leaves no loadable pair.
+            let _ = std::fs::remove_file(aot_mac_path(&artifact));
+            let _ = std::fs::remove_file(&artifact);
+        }
+    }
+
+    fn load_component_aot(path: &Path, digest_sha256: &str) -> anyhow::Result<Component> {
+        match AOT_CACHE.as_ref().filter(|_| aot_cache_enabled()) {
+            Some(cache) => cache.load_or_compile(path, digest_sha256),
+            None => Component::from_file(&ENGINE, path),
+        }
+    }
+
+    fn write_file_atomic(path: &Path, bytes: &[u8]) -> anyhow::Result<()> {
+        use std::io::Write;
+        let dir = path
+            .parent()
+            .context("E-UICP-0238: aot write target has no parent")?;
+        // NOTE: `NamedTempFile` creates files 0600 on Unix, so the key stays app-private.
+        let mut tmp = tempfile::NamedTempFile::new_in(dir)
+            .with_context(|| format!("E-UICP-0238: create temp file in {}", dir.display()))?;
+        tmp.write_all(bytes)?;
+        tmp.as_file().sync_all()?;
+        tmp.persist(path)
+            .map_err(|e| anyhow::anyhow!("E-UICP-0238: persist {}: {}", path.display(), e))?;
+        Ok(())
+    }
+
+    /// Remove artifacts compiled under a different engine fingerprint (Wasmtime upgrade or
+    /// config change). Runs once per cache directory the first time an artifact is written.
+    /// Only finished artifacts and sidecars are touched, so another writer's in-flight temp
+    /// file and the host key survive.
+    fn prune_stale_aot_artifacts(dir: &Path) {
+        static PRUNED: Lazy<Mutex<std::collections::HashSet<std::path::PathBuf>>> =
+            Lazy::new(|| Mutex::new(std::collections::HashSet::new()));
+        if !PRUNED.lock().insert(dir.to_path_buf()) {
+            return;
+        }
+        let Ok(read) = std::fs::read_dir(dir) else {
+            return;
+        };
+        let current = format!("-{}.", *ENGINE_FINGERPRINT);
+        for entry in read.flatten() {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let artifact_like = name.ends_with(AOT_ARTIFACT_SUFFIX)
+                || name.ends_with(AOT_MAC_SUFFIX)
+                || name.ends_with(".cwasm.sha256");
+            if artifact_like && !name.contains(&current) {
+                let _ = std::fs::remove_file(entry.path());
+            }
+        }
+    }
+
+    fn discard_aot_artifact(artifact: &Path, reason: &str) {
+        #[cfg(feature = "otel_spans")]
+        tracing::warn!(target = "uicp", path = %artifact.display(), reason = %reason, "discarding aot artifact");
+        #[cfg(not(feature = "otel_spans"))]
+        log_warn(format!(
+            "discarding aot artifact (path={}, reason={reason})",
+            artifact.display()
+        ));
+        let _ = std::fs::remove_file(artifact);
+        let _ = std::fs::remove_file(aot_mac_path(artifact));
+    }
+
+    /// Removes a job-scoped host directory when dropped (normal exit, error, or cancel).
+    struct JobDirGuard(Option<std::path::PathBuf>);
+
+    impl Drop for JobDirGuard {
+        fn drop(&mut self) {
+            if let Some(dir) = self.0.take() {
+                let _ = std::fs::remove_dir_all(dir);
+            }
+        }
+    }
+
+    /// Create the host directory for per-job artifacts, beside (never inside) the workspace
+    /// files dir. An existing directory means another live job shares this id; refuse rather
+    /// than wiping its files.
+    fn create_job_scoped_dir(kind: &str, job_id: &str) -> anyhow::Result<std::path::PathBuf> {
+        // WHY: Job ids come from the UI; hash them so an id can never shape the host path.
+        let name = hex::encode(&Sha256::digest(job_id.as_bytes())[..16]);
+        let files = crate::files_dir_path();
+        let parent = files.parent().unwrap_or(files).join(kind);
+        std::fs::create_dir_all(&parent)
+            .with_context(|| format!("create {kind} root {}", parent.display()))?;
+        let dir = parent.join(name);
+        match std::fs::create_dir(&dir) {
+            Ok(()) => Ok(dir),
+            Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => Err(anyhow::anyhow!(
+                "{kind} dir for job '{job_id}' already exists (duplicate job id?)"
+            )),
+            Err(err) => Err(err).with_context(|| format!("create {kind} dir {}", dir.display())),
+        }
+    }
+
+    /// Register read-only preopens for planned `fsRead` mounts. Directory grants are mounted
+    /// as-is; single-file grants are hard-linked into a job view mounted at `/ws/files`, so
+    /// siblings of a granted file stay invisible to the guest. Inputs are never copied: when a
+    /// link is impossible (e.g. the view sits on another device) the job fails instead.
+    fn mount_fs_read(
+        builder: &mut WasiCtxBuilder,
+        job_id: &str,
+        mounts: &[FsReadMount],
+    ) -> anyhow::Result<JobDirGuard> {
+        let mut guard = JobDirGuard(None);
+        let root_granted = mounts
+            .iter()
+            .any(|m| matches!(m, FsReadMount::Dir { guest, .. } if guest == WS_FILES_GUEST_ROOT));
+        let files: Vec<(&std::path::PathBuf, &String)> = mounts
+            .iter()
+            .filter_map(|m| match m {
+                FsReadMount::File { host, rel } => Some((host, rel)),
+                FsReadMount::Dir { .. } => None,
+            })
+            .collect();
+        if !files.is_empty() && !root_granted {
+            let view = create_job_scoped_dir("compute-views", job_id)?;
+            guard.0 = Some(view.clone());
+            for (host, rel) in files {
+                // INVARIANT: Link the resolved target so the view never contains a symlink.
+                let source = host.canonicalize()?;
+                let target = view.join(rel);
+                if let Some(parent) = target.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::hard_link(&source, &target).with_context(|| {
+                    format!(
+                        "link {} into fsRead view failed; grant its directory with '/**' instead",
+                        source.display()
+                    )
+                })?;
+            }
+            builder.preopened_dir(&view, WS_FILES_GUEST_ROOT, DirPerms::READ, FilePerms::READ)?;
+        }
+        for mount in mounts {
+            if let FsReadMount::Dir { host, guest } = mount {
+                builder.preopened_dir(host, guest, DirPerms::READ, FilePerms::READ)?;
+            }
+        }
+        Ok(guard)
+    }
+
+    /// Read-only `fsRead` mounts plus, when `fsWrite` grants workspace paths, a writable
+    /// scratch preopen at `/ws/scratch`. Returns guards for the read view and the staging dir.
+    fn mount_workspace_preopens(
+        builder: &mut WasiCtxBuilder,
+        spec: &ComputeJobSpec,
+        mounts: &[FsReadMount],
+    ) -> anyhow::Result<(JobDirGuard, JobDirGuard)> {
+        let view = mount_fs_read(builder, &spec.job_id, mounts)?;
+        let mut staging = JobDirGuard(None);
+        if wants_fs_write_staging(spec) {
+            let dir = create_job_scoped_dir("compute-staging", &spec.job_id)?;
+            staging.0 = Some(dir.clone());
+            builder.preopened_dir(
+                &dir,
+                WS_SCRATCH_GUEST_ROOT,
+                DirPerms::all(),
+                FilePerms::all(),
+            )?;
+        }
+        Ok((view, staging))
+    }
+
+    /// Move staged files into the workspace and record each write in the action log.
+    async fn commit_fs_writes<R: Runtime>(
+        app: &AppHandle<R>,
+        spec: &ComputeJobSpec,
+        staging: Option<&Path>,
+    ) -> Result<Vec<CommittedWrite>, crate::compute::compute_input::TaskInputError> {
+        let Some(staging) = staging else {
+            return Ok(Vec::new());
+        };
+        let written = commit_staged_writes(spec, staging)?;
+        let state: tauri::State<'_, crate::AppState> = app.state();
+        for write in &written {
+            let entry = serde_json::json!({
+                "jobId": spec.job_id,
+                "task": spec.task,
+                "workspaceId": spec.workspace_id,
+                "path": write.path,
+                "sha256": write.sha256,
+                "bytes": write.bytes,
+            });
+            if let Err(err) = state
+                .action_log
+                .append_json("compute.fs.write", &entry)
+                .await
+            {
+                #[cfg(feature = "otel_spans")]
+                tracing::warn!(target = "uicp", job_id = %spec.job_id, error = %err, "action log append failed for fs write");
+                #[cfg(not(feature = "otel_spans"))]
+                log_warn(format!(
+                    "action log append failed for fs write (job_id={}, error={err:#})",
+                    spec.job_id
+                ));
+            }
+        }
+        Ok(written)
+    }
+
+    /// Default number of idle pre-instantiated stores kept per task (override via env).
+    const DEFAULT_INSTANCE_POOL_SIZE: usize = 1;
+    const MAX_INSTANCE_POOL_SIZE: usize = 8;
+
+    /// A store whose component has been instantiated but never had an export called.
+    struct PooledInstance {
+        store: Store<Ctx>,
+        instance: wasmtime::component::Instance,
+    }
+
+    /// Per-module pool: a pre-linked `InstancePre` plus idle pre-instantiated stores.
+    struct TaskPool {
+        pre: InstancePre<Ctx>,
+        idle: Mutex<Vec<PooledInstance>>,
+        refilling: AtomicBool,
+        last_used: AtomicU64,
+    }
+
+    /// Pools keyed by `task@version|digest` so a reinstalled module never serves stale instances.
+    static INSTANCE_POOLS: Lazy<DashMap<String, Arc<TaskPool>>> = Lazy::new(DashMap::new);
+    static POOL_HITS: AtomicU64 = AtomicU64::new(0);
+    static POOL_COLD_STARTS: AtomicU64 = AtomicU64::new(0);
+    /// Logical clock stamped on each pool lookup; the smallest stamp is evicted first.
+    static POOL_CLOCK: AtomicU64 = AtomicU64::new(0);
+    /// Upper bound on live pools; each holds a pre-linked component plus idle stores.
+    const MAX_INSTANCE_POOLS: usize = 16;
+
+    /// Idle stores kept per task. `UICP_INSTANCE_POOL_SIZE=0` disables pre-instantiation
+    /// (jobs still reuse the pre-linked `InstancePre`).
+    fn instance_pool_size() -> usize {
+        std::env::var("UICP_INSTANCE_POOL_SIZE")
+            .ok()
+            .and_then(|v| v.trim().parse::<usize>().ok())
+            .unwrap_or(DEFAULT_INSTANCE_POOL_SIZE)
+            .min(MAX_INSTANCE_POOL_SIZE)
+    }
+
+    fn instance_pool_key(entry: &registry::ModuleEntry) -> String {
+        format!("{}@{}|{}", entry.task, entry.version, entry.digest_sha256)
+    }
+
+    fn task_pool(key: &str, component: &Component) -> anyhow::Result<Arc<TaskPool>> {
+        let stamp = POOL_CLOCK.fetch_add(1, Ordering::Relaxed);
+        if let Some(found) = INSTANCE_POOLS.get(key) {
+            found.last_used.store(stamp, Ordering::Relaxed);
+            return Ok(found.clone());
+        }
+        let linker: &Linker<Ctx> = &LINKER;
+        let pre = linker
+            .instantiate_pre(component)
+            .context("E-UICP-0231: linker instantiate_pre for instance pool failed")?;
+        let pool = Arc::new(TaskPool {
+            pre,
+            idle: Mutex::new(Vec::new()),
+            refilling: AtomicBool::new(false),
+            last_used: AtomicU64::new(stamp),
+        });
+        // A new digest for the same task@version supersedes the old pool outright.
+        if let Some((task_ref, _)) = key.split_once('|') {
+            let prefix = format!("{task_ref}|");
+            INSTANCE_POOLS.retain(|k, _| k == key || !k.starts_with(&prefix));
+        }
+        let pool = INSTANCE_POOLS
+            .entry(key.to_string())
+            .or_insert(pool)
+            .clone();
+        evict_idle_pools(key);
+        Ok(pool)
+    }
+
+    /// Drop least recently used pools beyond `MAX_INSTANCE_POOLS`, never evicting `keep`.
+    /// Jobs already holding an `Arc<TaskPool>` finish unaffected.
+    fn evict_idle_pools(keep: &str) {
+        while INSTANCE_POOLS.len() > MAX_INSTANCE_POOLS {
+            let oldest = INSTANCE_POOLS
+                .iter()
+                .filter(|e| e.key() != keep)
+                .min_by_key(|e| e.value().last_used.load(Ordering::Relaxed))
+                .map(|e| e.key().clone());
+            match oldest {
+                Some(k) => {
+                    INSTANCE_POOLS.remove(&k);
+                }
+                None => break,
+            }
+        }
+    }
+
+    /// Drop the pool built from `entry`. Jobs already holding its `Arc<TaskPool>` finish.
+    pub(super) fn evict_instance_pool(entry: &registry::ModuleEntry) -> bool {
+        INSTANCE_POOLS.remove(&instance_pool_key(entry)).is_some()
+    }
+
+    /// Process-wide pool counters for `compute_queue_status`.
+    pub(super) fn instance_pool_stats() -> serde_json::Value {
+        serde_json::json!({
+            "pools": INSTANCE_POOLS.len(),
+            "hits": POOL_HITS.load(Ordering::Relaxed),
+            "coldStarts": POOL_COLD_STARTS.load(Ordering::Relaxed),
+        })
+    }
+
+    /// Take an idle instance whose instantiation-time memory fits the job's limit.
+    fn checkout_pooled(pool: &TaskPool, mem_limit_bytes: usize) -> Option<PooledInstance> {
+        let mut idle = pool.idle.lock();
+        while let Some(entry) = idle.pop() {
+            if entry.store.data().limits.mem_peak_bytes <= mem_limit_bytes {
+                return Some(entry);
+            }
+            // Too large for this job's limit; drop it and let the caller cold start.
+        }
+        None
+    }
+
+    /// Swap the job's context into a pooled store.
+    /// INVARIANT: Pooled instances never ran an export, so replacing `Ctx` (fresh RNG seed,
+    /// fresh limits, fresh WASI/table) is observably identical to a cold instantiation.
+    /// Memory materialized at instantiation is carried into the new limits so `memPeakMb`,
+    /// `memSeries` and threshold partials match.
+    fn reset_pooled_store(store: &mut Store<Ctx>, mut ctx: Ctx) {
+        ctx.limits.inherit_usage(&store.data().limits);
+        ctx.pool_hit = true;
+        *store.data_mut() = ctx;
+    }
+
+    /// Instantiate idle stores until the pool holds `target` entries. Single-flight per pool.
+    async fn refill_pool(pool: Arc<TaskPool>, task: String, target: usize) {
+        if pool.refilling.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        while pool.idle.lock().len() < target {
+            let emitter: Arc<dyn TelemetryEmitter> = Arc::new(NullTelemetry);
+            let wasi = WasiCtxBuilder::new().build();
+            let mut store: Store<Ctx> = Store::new(
+                &ENGINE,
+                make_ctx(&task, "instance-pool", wasi, emitter, 1_000),
+            );
+            store.limiter(|ctx| &mut ctx.limits);
+            let _ = store.set_fuel(DEFAULT_RUNTIME_FUEL);
+            store.set_epoch_deadline(u64::MAX);
+            match pool.pre.instantiate_async(&mut store).await {
+                Ok(instance) => pool.idle.lock().push(PooledInstance { store, instance }),
+                Err(err) => {
+                    #[cfg(feature = "otel_spans")]
+                    tracing::warn!(target = "uicp", task = %task, error = %err, "instance pool refill failed");
+                    #[cfg(not(feature = "otel_spans"))]
+                    log_warn(format!(
+                        "instance pool refill failed (task={task}, error={err:#})"
+                    ));
+                    break;
+                }
+            }
+        }
+        pool.refilling.store(false, Ordering::Release);
+    }
+
+    // -------------------------------------------------------------------------
+    // Persistent applet sessions (`applet-script@0.2`)
+    // -------------------------------------------------------------------------
+
+    /// Live sessions kept at once; opening past the cap evicts the least recently used one.
+    const MAX_APPLET_SESSIONS: usize = 16;
+    /// Sessions untouched for this long are dropped (override via `UICP_APPLET_SESSION_IDLE_MS`).
+    const DEFAULT_APPLET_SESSION_IDLE_MS: u64 = 10 * 60 * 1000;
+    const APPLET_SESSION_SWEEP_MS: u64 = 30 * 1000;
+    /// Wall-clock budget for a single `load`/`init`/`render`/`on-event` call.
+    const APPLET_SESSION_CALL_MS: u64 = 5_000;
+    const DEFAULT_APPLET_SESSION_MEMORY_MB: u64 = 64;
+
+    /// A per-window applet instance whose bundle stays loaded between calls.
+    struct AppletSession {
+        task: String,
+        mem_limit_mb: u64,
+        /// Backs `uicp:host/kv` for this window; shared with the store's `Ctx`.
+        kv: Arc<KvScope>,
+        last_used: Mutex<Instant>,
+        instance: tokio::sync::Mutex<SessionInstance>,
+    }
+
+    struct SessionInstance {
+        store: Store<Ctx>,
+        bindings: SessionScriptTask,
+    }
+
+    /// Sessions keyed by window id.
+    static APPLET_SESSIONS: Lazy<DashMap<String, Arc<AppletSession>>> = Lazy::new(DashMap::new);
+    static APPLET_SWEEPER_STARTED: AtomicBool = AtomicBool::new(false);
+
+    fn applet_session_idle() -> Duration {
+        let ms = std::env::var("UICP_APPLET_SESSION_IDLE_MS")
+            .ok()
+            .and_then(|v| v.trim().parse::<u64>().ok())
+            .filter(|ms| *ms > 0)
+            .unwrap_or(DEFAULT_APPLET_SESSION_IDLE_MS);
+        Duration::from_millis(ms)
+    }
+
+    impl AppletSession {
+        fn touch(&self) {
+            *self.last_used.lock() = Instant::now();
+        }
+
+        fn idle_for(&self) -> Duration {
+            self.last_used.lock().elapsed()
+        }
+    }
+
+    /// Give the next guest call a fresh fuel budget and deadline, and pump epochs for it.
+    /// The caller aborts the returned pump once the call returns.
+    fn arm_session_call(store: &mut Store<Ctx>) -> JoinHandle<()> {
+        store.data_mut().started = Instant::now();
+        let _ = store.set_fuel(DEFAULT_RUNTIME_FUEL);
+        store.set_epoch_deadline(1);
+        let ticks = APPLET_SESSION_CALL_MS.div_ceil(EPOCH_TICK_INTERVAL_MS) + 1;
+        tauri_spawn(async move {
+            for _ in 0..ticks {
+                tokio::time::sleep(Duration::from_millis(EPOCH_TICK_INTERVAL_MS)).await;
+                ENGINE.increment_epoch();
+            }
+        })
+    }
+
+    /// Drop sessions idle past `idle`. Sessions mid-call are skipped and picked up next sweep.
+    fn evict_idle_sessions(idle: Duration) -> usize {
+        let stale: Vec<String> = APPLET_SESSIONS
+            .iter()
+            .filter(|entry| entry.idle_for() >= idle)
+            .map(|entry| entry.key().clone())
+            .collect();
+        stale
+            .iter()
+            .filter(|id| {
+                APPLET_SESSIONS
+                    .remove_if(*id, |_, session| session.instance.try_lock().is_ok())
+                    .is_some()
+            })
+            .count()
+    }
+
+    /// Make room for one more session by evicting the least recently used idle one.
+    fn ensure_session_capacity() -> anyhow::Result<()> {
+        if APPLET_SESSIONS.len() < MAX_APPLET_SESSIONS {
+            return Ok(());
+        }
+        let mut by_age: Vec<(String, Duration)> = APPLET_SESSIONS
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.idle_for()))
+            .collect();
+        by_age.sort_by(|a, b| b.1.cmp(&a.1));
+        for (id, _) in by_age {
+            if APPLET_SESSIONS
+                .remove_if(&id, |_, session| session.instance.try_lock().is_ok())
+                .is_some()
+            {
+                return Ok(());
+            }
+        }
+        Err(anyhow::anyhow!(
+            "E-UICP-0256: applet session limit reached ({MAX_APPLET_SESSIONS} busy sessions)"
+        ))
+    }
+
+    fn start_session_sweeper() {
+        if APPLET_SWEEPER_STARTED.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        tauri_spawn(async {
+            loop {
+                tokio::time::sleep(Duration::from_millis(APPLET_SESSION_SWEEP_MS)).await;
+                evict_idle_sessions(applet_session_idle());
+            }
+        });
+    }
+
+    async fn new_session_instance(
+        module: &registry::ModuleRef,
+        component: &Component,
+        task: &str,
+        window_id: &str,
+        mem_limit_mb: u64,
+        kv: Arc<KvScope>,
+    ) -> anyhow::Result<SessionInstance> {
+        let emitter: Arc<dyn TelemetryEmitter> = Arc::new(NullTelemetry);
+        let wasi = WasiCtxBuilder::new().build();
+        let mut ctx = make_ctx(
+            task,
+            &format!("applet-session:{window_id}"),
+            wasi,
+            emitter,
+            APPLET_SESSION_CALL_MS as u32,
+        );
+        ctx.limits = LimitsWithPeak::new((mem_limit_mb as usize) * 1024 * 1024);
+        ctx.kv = Some(kv);
+        let mut store: Store<Ctx> = Store::new(&ENGINE, ctx);
+        store.limiter(|ctx| &mut ctx.limits);
+        install_deadline_callback(&mut store);
+        let _ = store.set_fuel(DEFAULT_RUNTIME_FUEL);
+
+        // NOTE: Sessions never get `uicp:host/http`; reuse the task's pre-linked `InstancePre`.
+        let pool = task_pool(&instance_pool_key(&module.entry), component)?;
+        let pre = &pool.pre;
+        let pump = arm_session_call(&mut store);
+        let instance = pre.instantiate_async(&mut store).await;
+        pump.abort();
+        let instance =
+            instance.context("E-UICP-0254: instantiate applet session component failed")?;
+        let bindings = SessionScriptTask::new(&mut store, &instance)
+            .context("E-UICP-0254: applet session binding init failed")?;
+        Ok(SessionInstance { store, bindings })
+    }
+
+    async fn load_session_source(
+        session: &mut SessionInstance,
+        source: &str,
+    ) -> anyhow::Result<()> {
+        let SessionInstance { store, bindings } = session;
+        let iface = bindings.uicp_applet_script_script();
+        let pump = arm_session_call(store);
+        let loaded = iface.call_load(&mut *store, source).await;
+        pump.abort();
+        loaded
+            .context("E-UICP-0253: call script#load failed")?
+            .map_err(|msg| anyhow::anyhow!(msg))
+    }
+
+    /// Open the window's applet session, evaluating `source` once.
+    /// WHY: Reopening with the same task, workspace and memory limit reloads the bundle in the
+    /// existing instance (`dispose` then `load`) so hot reloads skip instantiation; anything else
+    /// starts from a fresh store.
+    pub(super) async fn applet_session_open<R: Runtime>(
+        app: &AppHandle<R>,
+        workspace_id: &str,
+        window_id: &str,
+        task: &str,
+        source: &str,
+        mem_limit_mb: Option<u64>,
+    ) -> anyhow::Result<()> {
+        if !script_loads_source(task) {
+            anyhow::bail!(
+                "E-UICP-0254: applet sessions require applet.quickjs@0.2 or later, got '{task}'"
+            );
+        }
+        let mem_limit_mb = mem_limit_mb
+            .filter(|mb| *mb > 0)
+            .unwrap_or(DEFAULT_APPLET_SESSION_MEMORY_MB)
+            .min(DEFAULT_MEMORY_LIMIT_MB);
+        start_session_sweeper();
+
+        let existing = APPLET_SESSIONS.get(window_id).map(|entry| entry.clone());
+        if let Some(session) = existing.filter(|s| {
+            s.task == task && s.mem_limit_mb == mem_limit_mb && s.kv.workspace_id == workspace_id
+        }) {
+            let mut instance = session.instance.lock().await;
+            let SessionInstance { store, bindings } = &mut *instance;
+            let pump = arm_session_call(store);
+            let disposed = bindings
+                .uicp_applet_script_script()
+                .call_dispose(&mut *store)
+                .await;
+            pump.abort();
+            if disposed.is_ok() && load_session_source(&mut instance, source).await.is_ok() {
+                session.touch();
+                return Ok(());
+            }
+            // Fall through: a failed reload leaves the old instance suspect, so replace it.
+        }
+        APPLET_SESSIONS.remove(window_id);
+
+        let module = registry::find_module(app, task)?.ok_or_else(|| {
+            anyhow::anyhow!("E-UICP-0254: module not found for applet session task '{task}'")
+        })?;
+        let component = load_component_cached(&module.path, &module.entry.digest_sha256)?;
+        let db = app.state::<crate::AppState>().db_rw.clone();
+        let kv = Arc::new(KvScope::new(db, workspace_id, window_id));
+        let mut instance = new_session_instance(
+            &module,
+            &component,
+            task,
+            window_id,
+            mem_limit_mb,
+            kv.clone(),
+        )
+        .await?;
+        load_session_source(&mut instance, source).await?;
+
+        evict_idle_sessions(applet_session_idle());
+        ensure_session_capacity()?;
+        APPLET_SESSIONS.insert(
+            window_id.to_string(),
+            Arc::new(AppletSession {
+                task: task.to_string(),
+                mem_limit_mb,
+                kv,
+                last_used: Mutex::new(Instant::now()),
+                instance: tokio::sync::Mutex::new(instance),
+            }),
+        );
+        Ok(())
+    }
+
+    /// Run one `init`/`render`/`on-event` call against the window's loaded applet.
+    /// Returns the same `{ status, mode, html | data | error }` shape as `applet.quickjs` jobs.
+    pub(super) async fn applet_session_call(
+        window_id: &str,
+        task: &str,
+        input: &ScriptInput,
+    ) -> anyhow::Result<serde_json::Value> {
+        let session = APPLET_SESSIONS
+            .get(window_id)
+            .map(|entry| entry.clone())
+            .ok_or_else(|| {
+                anyhow::anyhow!("E-UICP-0255: no applet session for window '{window_id}'")
+            })?;
+        // INVARIANT: Calls were admitted for `task`; never run them against another module.
+        if session.task != task {
+            anyhow::bail!(
+                "E-UICP-0255: applet session for window '{window_id}' runs '{}', not '{task}'",
+                session.task
+            );
+        }
+        session.touch();
+        let mut instance = session.instance.lock().await;
+        let SessionInstance { store, bindings } = &mut *instance;
+        let pump = arm_session_call(store);
+        let called = call_loaded_script(bindings.uicp_applet_script_script(), store, input).await;
+        pump.abort();
+        session.touch();
+        match called {
+            Ok(result) => Ok(script_call_payload(&input.mode, result)),
+            Err(err) => {
+                // INVARIANT: A trapped instance cannot be re-entered; drop it so the next call
+                // reports a missing session instead of a poisoned one.
+                drop(instance);
+                APPLET_SESSIONS.remove_if(window_id, |_, s| Arc::ptr_eq(s, &session));
+                let (code, message) = map_trap_error(&err);
+                Err(anyhow::anyhow!(
+                    "E-UICP-0257: applet session call trapped ({code}); session closed: {message}"
+                ))
+            }
+        }
+    }
+
+    /// Drop the window's applet session. Returns whether one existed.
+    pub(super) fn applet_session_dispose(window_id: &str) -> bool {
+        APPLET_SESSIONS.remove(window_id).is_some()
+    }
+
+    /// Build a fresh Engine (used by some unit tests); runtime uses the global ENGINE.
+    #[cfg(any(test, feature = "compute_harness"))]
+    #[cfg_attr(feature = "compute_harness", allow(dead_code))]
+    fn build_engine() -> anyhow::Result<Engine> {
+        let mut cfg = Config::new();
+        cfg.wasm_component_model(true)
+            .async_support(true)
+            .consume_fuel(true)
+            .epoch_interruption(true)
+            .wasm_memory64(false);
+        Ok(Engine::new(&cfg)?)
+    }
+
+    /// Spawn a compute job using Wasmtime: build engine/store, link WASI + host, and instantiate the component world.
+    /// Execution of task exports will be wired in the next milestone; for now we finalize with a pending-wiring message.
+    pub(super) fn spawn_job<R: Runtime>(
+        app: tauri::AppHandle<R>,
+        spec: ComputeJobSpec,
+        permit: Option<OwnedSemaphorePermit>,
+        queue_wait_ms: u64,
+    ) -> JoinHandle<()> {
+        #[cfg(feature = "otel_spans")]
+        let span = tracing::info_span!(
+            "compute_spawn_job",
+            job_id = %spec.job_id,
+            task = %spec.task
+        );
+
+        let fut = async move {
+            #[cfg(feature = "otel_spans")]
+            let _span = tracing::info_span!(
+                "compute_spawn_job_inner",
+                job_id = %spec.job_id,
+                task = %spec.task
+            );
+            let _permit = permit;
+            let started = Instant::now();
+            // WHY: Feature-flag-ish env var to dump diagnostics about WASI + component at job start.
+            let diag_enabled = {
+                let v = std::env::var("UICP_WASI_DIAG")
+                    .or_else(|_| std::env::var("uicp_wasi_diag"))
+                    .unwrap_or_default();
+                matches!(v.as_str(), "1" | "true" | "TRUE" | "yes" | "on")
+            };
+
+            // Register cancel and pause channels for this job
+            let (tx_cancel, rx_cancel) = tokio::sync::watch::channel(false);
+            let (tx_pause, rx_pause) = tokio::sync::watch::channel(false);
+            {
+                let state: tauri::State<'_, crate::AppState> = app.state();
+                state
+                    .compute_cancel
+                    .write()
+                    .await
+                    .insert(spec.job_id.clone(), tx_cancel);
+                state
+                    .compute_pause
+                    .write()
+                    .await
+                    .insert(spec.job_id.clone(), tx_pause);
+            }
+
+            // Reuse global engine
+            let engine: &Engine = &ENGINE;
+
+            // Resolve module by task@version
+            let module = match registry::find_module(&app, &spec.task) {
+                Ok(Some(m)) => m,
+                Ok(None) => {
+                    #[cfg(feature = "otel_spans")]
+                    tracing::info!(target = "uicp", job_id = %spec.job_id, task = %spec.task, "module not found");
+                    finalize_error(
+                        &app,
+                        &spec,
+                        error_codes::TASK_NOT_FOUND,
+                        "Module not found for task",
+                        started,
+                        queue_wait_ms,
+                        None,
+                    )
+                    .await;
+                    let state: tauri::State<'_, crate::AppState> = app.state();
+                    state.compute_cancel.write().await.remove(&spec.job_id);
+                    crate::remove_compute_job(&app, &spec.job_id).await;
+                    return;
+                }
+                Err(err) => {
+                    // WHY: Surface precise context so UI can see root-cause for module resolution faults.
+                    // ERROR: E-UICP-0221 registry lookup failed for task; message carries inner error string.
+                    let any = err.context(format!(
+                        "E-UICP-0221: registry lookup failed for task '{}': module resolve error",
+                        spec.task
+                    ));
+                    let (code, msg) = map_trap_error(&any);
+                    let message = if msg.is_empty() { any.to_string() } else { msg };
+                    #[cfg(not(feature = "otel_spans"))]
+                    log_error(format!(
+                        "module resolve error (job_id={}, task={}, error={message})",
+                        spec.job_id, spec.task
+                    ));
+                    #[cfg(feature = "otel_spans")]
+                    tracing::error!(target = "uicp", job_id = %spec.job_id, task = %spec.task, error = %message, "module resolve error");
+                    finalize_error(&app, &spec, code, &message, started, queue_wait_ms, None).await;
+                    let state: tauri::State<'_, crate::AppState> = app.state();
+                    state.compute_cancel.write().await.remove(&spec.job_id);
+                    crate::remove_compute_job(&app, &spec.job_id).await;
+                    return;
+                }
+            };
+
+            // Load compiled component from cache
+            let component = match load_component_cached(&module.path, &module.entry.digest_sha256) {
+                Ok(c) => c,
+                Err(err) => {
+                    // WHY: Carry the on-disk path and root cause to aid diagnosing cache/compile errors.
+                    // ERROR: E-UICP-0222 component load failed; likely invalid encoding or wrong target (not a component).
+                    let any = err.context(format!(
+                        "E-UICP-0222: load component failed for path {}",
+                        module.path.display()
+                    ));
+                    let (code, msg) = map_trap_error(&any);
+                    let chain = format!("{:#}", &any); // include error chain
+                    let message = if msg.is_empty() { chain } else { msg };
                     #[cfg(not(feature = "otel_spans"))]
                     log_error(format!(
                         "component load error (job_id={}, task={}, error={message})",
@@ -1187,7 +2504,85 @@ mod with_runtime {
                 );
             }
 
-            // Build store context (no preopens by default; FS/NET off). Seed deterministic fields.
+            // Job-scoped preopens: read-only mounts for `fsRead`, a scratch dir for `fsWrite`
+            // (none by default). Staged writes are discarded unless the job succeeds.
+            let mut wasi_builder = WasiCtxBuilder::new();
+            let fs_setup = match plan_fs_read_mounts(&spec) {
+                Ok(mounts) => mount_workspace_preopens(&mut wasi_builder, &spec, &mounts)
+                    .map(|(view, staging)| (mounts, view, staging))
+                    .map_err(|err| {
+                        (
+                            error_codes::IO_DENIED,
+                            format!("E-UICP-0239: mount workspace preopens failed: {err:#}"),
+                        )
+                    }),
+                Err(err) => Err((err.code, err.message)),
+            };
+            let (fs_read_mounts, _fs_view_guard, fs_staging_guard) = match fs_setup {
+                Ok(setup) => setup,
+                Err((code, message)) => {
+                    finalize_error(&app, &spec, code, &message, started, queue_wait_ms, None).await;
+                    let state: tauri::State<'_, crate::AppState> = app.state();
+                    state.compute_cancel.write().await.remove(&spec.job_id);
+                    crate::remove_compute_job(&app, &spec.job_id).await;
+                    return;
+                }
+            };
+
+            // Network grant: `uicp:host/http` is linked only when the spec lists allowed hosts.
+            let net_rules: Vec<NetRule> = spec
+                .capabilities
+                .net
+                .iter()
+                .filter_map(|entry| NetRule::parse(entry))
+                .collect();
+            let net_setup = if net_rules.is_empty() {
+                if component_imports_http(&component) {
+                    // ERROR: E-UICP-0242 component needs network but the spec grants no hosts.
+                    Err((
+                        error_codes::CAPABILITY_DENIED,
+                        format!(
+                            "E-UICP-0242: component imports {HTTP_IMPORT} but capabilities.net grants no hosts"
+                        ),
+                    ))
+                } else {
+                    Ok(None)
+                }
+            } else {
+                // ERROR: E-UICP-0241 app data dir unavailable; egress policy cannot be loaded.
+                app.path()
+                    .app_data_dir()
+                    .map(|appdata_root| {
+                        let state: tauri::State<'_, crate::AppState> = app.state();
+                        Some(Arc::new(NetGrant {
+                            rules: net_rules,
+                            installed_id: format!("compute:{}", spec.task),
+                            host: HostCtx::from_app(&state, appdata_root),
+                            requests: AtomicU64::new(0),
+                        }))
+                    })
+                    .map_err(|err| {
+                        (
+                            error_codes::IO_DENIED,
+                            format!(
+                                "E-UICP-0241: resolve app data dir for egress policy failed: {err}"
+                            ),
+                        )
+                    })
+            };
+            let net_grant = match net_setup {
+                Ok(grant) => grant,
+                Err((code, message)) => {
+                    finalize_error(&app, &spec, code, &message, started, queue_wait_ms, None).await;
+                    let state: tauri::State<'_, crate::AppState> = app.state();
+                    state.compute_cancel.write().await.remove(&spec.job_id);
+                    crate::remove_compute_job(&app, &spec.job_id).await;
+                    return;
+                }
+            };
+            let net_enabled = net_grant.is_some();
+
+            // Build store context (FS limited to the mounts above; NET via grant only). Seed deterministic fields.
             let mem_limit_mb = spec
                 .mem_limit_mb
                 .filter(|mb| *mb > 0)
@@ -1349,29 +2744,17 @@ mod with_runtime {
                     None
                 };
 
-            let mut wasi_builder = WasiCtxBuilder::new();
+            // NOTE: 0.2+ applets receive the bundle through `script.load` instead.
             if let Some(Ok(ref script_input)) = script_input_result {
-                if let Some(source) = script_input.source.as_ref() {
+                if let Some(source) = script_input
+                    .source
+                    .as_ref()
+                    .filter(|_| !script_loads_source(&spec.task))
+                {
                     let encoded = BASE64_ENGINE.encode(source.as_bytes());
                     wasi_builder.env(SCRIPT_SOURCE_ENV, &encoded);
                 }
             }
-            // Single readonly preopen for workspace files, mounted at /ws/files in the guest.
-            // Only enable when the job requested workspace file access via capabilities.
-            let want_ws_preopen = spec
-                .capabilities
-                .fs_read
-                .iter()
-                .chain(spec.capabilities.fs_write.iter())
-                .any(|p| p.starts_with("ws:/files/"));
-            if want_ws_preopen {
-                let _ = wasi_builder.preopened_dir(
-                    crate::files_dir_path(),
-                    "/ws/files",
-                    DirPerms::READ,
-                    FilePerms::READ,
-                );
-            }
 
             let action_log = {
                 let state: tauri::State<'_, crate::AppState> = app.state();
@@ -1391,7 +2774,7 @@ mod with_runtime {
                 buf: Mutex::new(Vec::new()),
                 log_rate: log_rate.clone(),
                 log_throttle_waits: log_throttle_waits.clone(),
-                action_log,
+                action_log: action_log.clone(),
             });
 
             let stdout_stream = GuestLogStream {
@@ -1407,8 +2790,11 @@ mod with_runtime {
             wasi_builder.stderr(stderr_stream);
             let wasi = wasi_builder.build();
 
-            // Derive deterministic seed from job + env for replay stability
-            let seed = derive_job_seed(&spec.job_id, &spec.provenance.env_hash);
+            // Derive deterministic seed from job + env for replay stability; a replay inherits the
+            // seed of the job it re-executes.
+            let seed_job_id = crate::compute::replay::seed_job_id(&spec.job_id)
+                .unwrap_or_else(|| spec.job_id.clone());
+            let seed = derive_job_seed(&seed_job_id, &spec.provenance.env_hash);
             let seed_hex = hex::encode(seed);
 
             let ctx = Ctx {
@@ -1437,9 +2823,58 @@ mod with_runtime {
                 partial_rate,
                 partial_throttle_waits,
                 limits,
+                pool_hit: false,
+                net: net_grant,
+                // NOTE: kv is session-only; a job spec has no window id to scope state to.
+                kv: None,
+                action_log: Some(action_log),
+                pause_rx: Some(rx_pause),
+                pause: Arc::new(PauseClock::default()),
             };
-            let mut store: Store<Ctx> = Store::new(engine, ctx);
-            store.limiter(|ctx| &mut ctx.limits);
+
+            // Prefer a pre-instantiated store from the task pool; fall back to a cold store that
+            // still skips linking via the pool's `InstancePre`.
+            // WHY: Pools are pre-linked against `LINKER`, so networked jobs always start cold.
+            let pool_key = instance_pool_key(&module.entry);
+            let pool = match (!net_enabled).then(|| task_pool(&pool_key, &component)) {
+                None => None,
+                Some(Ok(pool)) => Some(pool),
+                Some(Err(err)) => {
+                    #[cfg(feature = "otel_spans")]
+                    tracing::warn!(target = "uicp", job_id = %spec.job_id, task = %spec.task, error = %err, "instance pool unavailable");
+                    #[cfg(not(feature = "otel_spans"))]
+                    log_warn(format!(
+                        "instance pool unavailable (job_id={}, task={}, error={err:#})",
+                        spec.job_id, spec.task
+                    ));
+                    None
+                }
+            };
+            let pool_size = instance_pool_size();
+            let pooled = pool
+                .as_ref()
+                .filter(|_| pool_size > 0)
+                .and_then(|p| checkout_pooled(p, mem_limit_bytes));
+            let (mut store, pooled_instance) = match pooled {
+                Some(PooledInstance {
+                    mut store,
+                    instance,
+                }) => {
+                    POOL_HITS.fetch_add(1, Ordering::Relaxed);
+                    reset_pooled_store(&mut store, ctx);
+                    (store, Some(instance))
+                }
+                None => {
+                    POOL_COLD_STARTS.fetch_add(1, Ordering::Relaxed);
+                    let mut store: Store<Ctx> = Store::new(engine, ctx);
+                    store.limiter(|ctx| &mut ctx.limits);
+                    (store, None)
+                }
+            };
+            // Top the pool back up in the background so the next job can skip instantiation.
+            if let Some(p) = pool.as_ref().filter(|_| pool_size > 0) {
+                tauri_spawn(refill_pool(p.clone(), spec.task.clone(), pool_size));
+            }
 
             // Optional diagnostics for mounts/imports at job start (support both cases)
             if diag_enabled {
@@ -1447,8 +2882,12 @@ mod with_runtime {
                     "event": "wasi_diag",
                     "jobId": spec.job_id,
                     "task": spec.task,
-                    "mounts": [{ "guest": "/ws/files", "host": crate::files_dir_path().display().to_string(), "perms": "ro" }],
+                    "mounts": fs_read_mounts.iter().map(|m| match m {
+                        FsReadMount::Dir { host, guest } => serde_json::json!({ "guest": guest, "host": host.display().to_string(), "perms": "ro" }),
+                        FsReadMount::File { host, rel } => serde_json::json!({ "guest": format!("{WS_FILES_GUEST_ROOT}/{rel}"), "host": host.display().to_string(), "perms": "ro" }),
+                    }).collect::<Vec<_>>(),
                     "imports": ["wasi:io/streams", "wasi:clocks", "wasi:random", "wasi:logging", "uicp:host/*"],
+                    "net": store.data().net.as_ref().map(|g| g.hosts.clone()).unwrap_or_default(),
                 }));
             }
             // Emit seed for reproducibility in debug logs
@@ -1461,24 +2900,25 @@ mod with_runtime {
 
             // Configure fuel (optional) and epoch deadline enforcement.
             // Spawn epoch tick pump to enforce wall-clock deadline.
-            if let Some(f) = spec.fuel.filter(|f| *f > 0) {
+            let fuel_budget = if let Some(f) = spec.fuel.filter(|f| *f > 0) {
                 // Record initial fuel in context and seed store fuel.
                 store.data_mut().initial_fuel = f;
-                let _ = store.set_fuel(f);
+                f
             } else {
                 // SAFETY: Provide a default fuel budget for execution but do not record
-                // `initial_fuel` so metrics omit `fuelUsed` unless the caller explicitly set fuel.
-                let _ = store.set_fuel(DEFAULT_RUNTIME_FUEL);
-            }
-            let mut deadline_ticks =
-                deadline_ms.saturating_add(EPOCH_TICK_INTERVAL_MS - 1) / EPOCH_TICK_INTERVAL_MS;
-            if deadline_ticks == 0 {
-                deadline_ticks = 1;
-            }
-            store.set_epoch_deadline(deadline_ticks);
+                // `initial_fuel`; metrics report it as `fuelBudget`.
+                DEFAULT_RUNTIME_FUEL
+            };
+            let _ = store.set_fuel(fuel_budget);
+            install_deadline_callback(&mut store);
+            // INVARIANT: The pump must outlive the deadline plus the longest allowed pause, and
+            // stay bounded so a hard-aborted job cannot leak it.
+            let ticks = deadline_ms
+                .saturating_add(max_pause_ms())
+                .saturating_add(EPOCH_TICK_INTERVAL_MS - 1)
+                / EPOCH_TICK_INTERVAL_MS;
             let eng: &Engine = engine;
             let tick_interval = Duration::from_millis(EPOCH_TICK_INTERVAL_MS);
-            let ticks = deadline_ticks;
             let epoch_pump: JoinHandle<()> = tauri_spawn(async move {
                 for _ in 0..ticks {
                     tokio::time::sleep(tick_interval).await;
@@ -1497,16 +2937,24 @@ mod with_runtime {
             }
 
             // Instantiate with shared linker (WASI + host already registered)
-            let linker: &Linker<Ctx> = &LINKER;
+            let linker: &Linker<Ctx> = if net_enabled { &LINKER_NET } else { &LINKER };
 
             // Instantiate the world and call exports using typed API (no bindgen for now)
             {
                 // WHY: Add instantiation context so missing-import/linkage issues are visible in final error.
                 // ERROR: E-UICP-0223 instantiation failure; often indicates a missing or version-mismatched import.
                 let inst_res: Result<wasmtime::component::Instance, _> =
-                    linker.instantiate_async(&mut store, &component).await;
+                    match (pooled_instance, pool.as_ref()) {
+                        (Some(instance), _) => Ok(instance),
+                        (None, Some(p)) => p.pre.instantiate_async(&mut store).await,
+                        (None, None) => linker.instantiate_async(&mut store, &component).await,
+                    };
                 match inst_res {
                     Ok(instance) => {
+                        // INVARIANT: The budget covers export execution only. Pooled instances were
+                        // instantiated under another store's fuel, so reset here to keep pool hits
+                        // and cold starts metering identically.
+                        let _ = store.set_fuel(fuel_budget);
                         let task_name = task_prefix.as_str();
                         let artificial_delay_ms = std::env::var("UICP_TEST_COMPUTE_DELAY_MS")
                             .ok()
@@ -1517,6 +2965,46 @@ mod with_runtime {
                         let mut cancel_watch = rx_cancel.clone();
                         let call_future = async {
                             match task_name {
+                                "csv.parse" if csv_parse_streaming(&spec.task) => {
+                                    match extract_csv_parse_input(&spec.input) {
+                                        Ok(input) => match resolve_csv_source(&spec, &input.source)
+                                        {
+                                            Ok(resolved) => {
+                                                let bindings =
+                                                    StreamingCsvTask::new(&mut store, &instance)
+                                                        .context(
+                                                        "E-UICP-0224: csv task binding init failed",
+                                                    )?;
+                                                let options = csv_options_to_wit(&input);
+                                                let csv_iface = bindings.uicp_task_csv_parse_csv();
+                                                match csv_iface
+                                                    .call_run(
+                                                        &mut store,
+                                                        &spec.job_id,
+                                                        resolved.as_str(),
+                                                        &options,
+                                                    )
+                                                    .await
+                                                {
+                                                    Ok(Ok(rows)) => Ok(csv_rows_to_json(rows)),
+                                                    Ok(Err(msg)) => Err(anyhow::Error::msg(msg)),
+                                                    Err(e) => Err(anyhow::anyhow!(
+                                                        "E-UICP-0225: call csv#run failed: {}",
+                                                        e
+                                                    )),
+                                                }
+                                            }
+                                            Err(err) => Err(anyhow::anyhow!(format!(
+                                                "{}: {}",
+                                                err.code, err.message
+                                            ))),
+                                        },
+                                        Err(err) => Err(anyhow::anyhow!(format!(
+                                            "{}: {}",
+                                            err.code, err.message
+                                        ))),
+                                    }
+                                }
                                 "csv.parse" => match extract_csv_input(&spec.input) {
                                     Ok((source, has_header)) => {
                                         match resolve_csv_source(&spec, &source) {
@@ -1549,11 +3037,48 @@ mod with_runtime {
                                             ))),
                                         }
                                     }
-                                    Err(err) => Err(anyhow::anyhow!(format!(
-                                        "{}: {}",
-                                        err.code, err.message
-                                    ))),
-                                },
+                                    Err(err) => Err(anyhow::anyhow!(format!(
+                                        "{}: {}",
+                                        err.code, err.message
+                                    ))),
+                                },
+                                "table.query" if table_query_typed_cells(&spec.task) => {
+                                    match extract_table_query_input_typed(&spec.input) {
+                                        Ok(query) => {
+                                            let bindings =
+                                                TypedTableTask::new(&mut store, &instance)
+                                                    .context(
+                                                    "E-UICP-0226: table task binding init failed",
+                                                )?;
+                                            let input = table_query_to_wit(query);
+                                            let table_iface =
+                                                bindings.uicp_task_table_query_table();
+                                            match table_iface
+                                                .call_run(&mut store, &spec.job_id, &input)
+                                                .await
+                                            {
+                                                Ok(Ok(out)) => Ok(typed_rows_to_json(out)),
+                                                Ok(Err(TypedTableRunError::Cancelled)) => {
+                                                    Err(anyhow::Error::msg("cancelled"))
+                                                }
+                                                Ok(Err(TypedTableRunError::InvalidQuery(msg))) => {
+                                                    Err(anyhow::anyhow!(
+                                                        "E-UICP-0245: table.query rejected query: {}",
+                                                        msg
+                                                    ))
+                                                }
+                                                Err(e) => Err(anyhow::anyhow!(
+                                                    "E-UICP-0227: call table#run failed: {}",
+                                                    e
+                                                )),
+                                            }
+                                        }
+                                        Err(err) => Err(anyhow::anyhow!(format!(
+                                            "{}: {}",
+                                            err.code, err.message
+                                        ))),
+                                    }
+                                }
                                 "table.query" => match extract_table_query_input(&spec.input) {
                                     Ok((rows, select, where_opt)) => {
                                         let bindings = TableTask::new(&mut store, &instance)
@@ -1588,6 +3113,91 @@ mod with_runtime {
                                         err.code, err.message
                                     ))),
                                 },
+                                "json.query" => match extract_json_query_input(&spec.input) {
+                                    Ok(query) => {
+                                        let bindings = JsonQueryTask::new(&mut store, &instance)
+                                            .context(
+                                                "E-UICP-0246: json.query task binding init failed",
+                                            )?;
+                                        let input = json_query_to_wit(&query);
+                                        let query_iface = bindings.uicp_task_json_query_query();
+                                        match query_iface
+                                            .call_run(&mut store, &spec.job_id, &input)
+                                            .await
+                                        {
+                                            Ok(Ok(text)) => serde_json::from_str(&text).map_err(
+                                                |err| {
+                                                    anyhow::anyhow!(
+                                                        "E-UICP-0249: json.query evaluation failed: guest returned invalid JSON: {}",
+                                                        err
+                                                    )
+                                                },
+                                            ),
+                                            Ok(Err(JsonQueryRunError::Cancelled)) => {
+                                                Err(anyhow::Error::msg("cancelled"))
+                                            }
+                                            Ok(Err(JsonQueryRunError::InvalidExpression(msg))) => {
+                                                Err(anyhow::anyhow!(
+                                                    "E-UICP-0248: json.query rejected expression: {}",
+                                                    msg
+                                                ))
+                                            }
+                                            Ok(Err(JsonQueryRunError::Eval(msg))) => {
+                                                Err(anyhow::anyhow!(
+                                                    "E-UICP-0249: json.query evaluation failed: {}",
+                                                    msg
+                                                ))
+                                            }
+                                            Err(e) => Err(anyhow::anyhow!(
+                                                "E-UICP-0247: call query#run failed: {}",
+                                                e
+                                            )),
+                                        }
+                                    }
+                                    Err(err) => Err(anyhow::anyhow!(format!(
+                                        "{}: {}",
+                                        err.code, err.message
+                                    ))),
+                                },
+                                "markdown.render" => {
+                                    match extract_markdown_render_input(&spec.input) {
+                                        Ok(doc) => {
+                                            let bindings = MarkdownTask::new(&mut store, &instance)
+                                                .context(
+                                                    "E-UICP-0250: markdown.render task binding init failed",
+                                                )?;
+                                            let render_iface =
+                                                bindings.uicp_task_markdown_render_render();
+                                            match render_iface
+                                                .call_run(
+                                                    &mut store,
+                                                    &spec.job_id,
+                                                    &doc.markdown,
+                                                    markdown_options_to_wit(&doc),
+                                                )
+                                                .await
+                                            {
+                                                // WHY: Sanitize before the result is cached so every
+                                                // replay and cache hit serves the policy-clean HTML.
+                                                Ok(Ok(html)) => Ok(serde_json::json!({
+                                                    "html": crate::security::html::sanitize_html(&html),
+                                                })),
+                                                Ok(Err(msg)) => Err(anyhow::anyhow!(
+                                                    "E-UICP-0252: markdown.render failed: {}",
+                                                    msg
+                                                )),
+                                                Err(e) => Err(anyhow::anyhow!(
+                                                    "E-UICP-0251: call render#run failed: {}",
+                                                    e
+                                                )),
+                                            }
+                                        }
+                                        Err(err) => Err(anyhow::anyhow!(format!(
+                                            "{}: {}",
+                                            err.code, err.message
+                                        ))),
+                                    }
+                                }
                                 "applet.quickjs" | "script.hello" => {
                                     let script_input = match &script_input_result {
                                         Some(Ok(input)) => input.clone(),
@@ -1616,67 +3226,74 @@ mod with_runtime {
                                         ));
                                     }
 
-                                    let bindings = ScriptTask::new(&mut store, &instance)
+                                    let js_call = if script_loads_source(&spec.task) {
+                                        let bindings = SessionScriptTask::new(
+                                            &mut store, &instance,
+                                        )
                                         .context("E-UICP-0232: script task binding init failed")?;
-                                    let script_iface = bindings.uicp_applet_script_script();
-
-                                    let js_call = match script_input.mode {
-                                        ScriptMode::Init => {
-                                            script_iface.call_init(&mut store).await
-                                        }
-                                        ScriptMode::Render => {
-                                            script_iface
-                                                .call_render(&mut store, &script_input.state)
-                                                .await
-                                        }
-                                        ScriptMode::OnEvent => {
-                                            let action = script_input
-                                                .action
-                                                .as_deref()
-                                                .unwrap_or_default()
-                                                .to_string();
-                                            let payload = script_input
-                                                .payload
-                                                .as_deref()
-                                                .unwrap_or_default()
-                                                .to_string();
-                                            script_iface
-                                                .call_on_event(
+                                        let script_iface = bindings.uicp_applet_script_script();
+                                        let source =
+                                            script_input.source.as_deref().unwrap_or_default();
+                                        match script_iface.call_load(&mut store, source).await {
+                                            Ok(Ok(())) => {
+                                                call_loaded_script(
+                                                    script_iface,
                                                     &mut store,
-                                                    &action,
-                                                    &payload,
-                                                    &script_input.state,
+                                                    &script_input,
                                                 )
                                                 .await
+                                            }
+                                            Ok(Err(msg)) => Ok(Err(msg)),
+                                            Err(e) => {
+                                                return Err(anyhow::anyhow!(
+                                                    "E-UICP-0253: call script#load failed: {}",
+                                                    e
+                                                ));
+                                            }
+                                        }
+                                    } else {
+                                        let bindings = ScriptTask::new(&mut store, &instance)
+                                            .context(
+                                                "E-UICP-0232: script task binding init failed",
+                                            )?;
+                                        let script_iface = bindings.uicp_applet_script_script();
+
+                                        match script_input.mode {
+                                            ScriptMode::Init => {
+                                                script_iface.call_init(&mut store).await
+                                            }
+                                            ScriptMode::Render => {
+                                                script_iface
+                                                    .call_render(&mut store, &script_input.state)
+                                                    .await
+                                            }
+                                            ScriptMode::OnEvent => {
+                                                let action = script_input
+                                                    .action
+                                                    .as_deref()
+                                                    .unwrap_or_default()
+                                                    .to_string();
+                                                let payload = script_input
+                                                    .payload
+                                                    .as_deref()
+                                                    .unwrap_or_default()
+                                                    .to_string();
+                                                script_iface
+                                                    .call_on_event(
+                                                        &mut store,
+                                                        &action,
+                                                        &payload,
+                                                        &script_input.state,
+                                                    )
+                                                    .await
+                                            }
                                         }
                                     };
 
                                     match js_call {
-                                        Ok(Ok(value)) => {
-                                            let payload = match script_input.mode {
-                                                ScriptMode::Init => serde_json::json!({
-                                                    "status": "ready",
-                                                    "mode": script_mode_label(&script_input.mode),
-                                                    "data": value,
-                                                }),
-                                                ScriptMode::Render => serde_json::json!({
-                                                    "status": "ready",
-                                                    "mode": script_mode_label(&script_input.mode),
-                                                    "html": value,
-                                                }),
-                                                ScriptMode::OnEvent => serde_json::json!({
-                                                    "status": "ready",
-                                                    "mode": script_mode_label(&script_input.mode),
-                                                    "data": value,
-                                                }),
-                                            };
-                                            Ok(payload)
+                                        Ok(result) => {
+                                            Ok(script_call_payload(&script_input.mode, result))
                                         }
-                                        Ok(Err(msg)) => Ok(serde_json::json!({
-                                            "status": "error",
-                                            "mode": script_mode_label(&script_input.mode),
-                                            "error": { "message": msg },
-                                        })),
                                         Err(e) => {
                                             let ctx_msg = match script_input.mode {
                                                 ScriptMode::Init => {
@@ -1701,6 +3318,9 @@ mod with_runtime {
                                 // Force the epoch deadline so Wasmtime traps promptly.
                                 store.set_epoch_deadline(1);
                                 engine.increment_epoch();
+                                store.data().pause.unpark();
+                                // Final sample: thresholds crossed since the last epoch tick still emit before the final event.
+                                store.data_mut().sample_memory();
                                 let metrics = collect_metrics(&store);
                                 finalize_error(
                                     &app,
@@ -1734,19 +3354,42 @@ mod with_runtime {
                                                 let _ = store.set_fuel(new_rem);
                                             }
                                         }
-                                        let metrics = collect_metrics(&store);
-                                        finalize_ok_with_metrics(
-                                            &app,
-                                            &spec,
-                                            output_json,
-                                            metrics,
-                                            queue_wait_ms,
-                                        )
-                                        .await;
+                                        store.data_mut().sample_memory();
+                                        let mut metrics = collect_metrics(&store);
+                                        match commit_fs_writes(&app, &spec, fs_staging_guard.0.as_deref()).await {
+                                            Ok(written) => {
+                                                if !written.is_empty() {
+                                                    if let Some(obj) = metrics.as_object_mut() {
+                                                        obj.insert("fsWrites".into(), serde_json::json!(written));
+                                                    }
+                                                }
+                                                finalize_ok_with_metrics(
+                                                    &app,
+                                                    &spec,
+                                                    output_json,
+                                                    metrics,
+                                                    queue_wait_ms,
+                                                )
+                                                .await;
+                                            }
+                                            Err(err) => {
+                                                finalize_error(
+                                                    &app,
+                                                    &spec,
+                                                    err.code,
+                                                    &err.message,
+                                                    started,
+                                                    queue_wait_ms,
+                                                    Some(metrics),
+                                                )
+                                                .await;
+                                            }
+                                        }
                                     }
                                     Err(err) => {
                                         let (code, msg) = map_trap_error(&err);
                                         let message = if msg.is_empty() { err.to_string() } else { msg };
+                                        store.data_mut().sample_memory();
                                         let m = collect_metrics(&store);
                                         finalize_error(
                                             &app,
@@ -1774,6 +3417,7 @@ mod with_runtime {
                         ));
                         let (code, msg) = map_trap_error(&any);
                         let message = if msg.is_empty() { any.to_string() } else { msg };
+                        store.data_mut().sample_memory();
                         let m = collect_metrics(&store);
                         finalize_error(
                             &app,
@@ -1811,6 +3455,182 @@ mod with_runtime {
         tauri_spawn(fut)
     }
 
+    fn cell_to_wit(cell: TableCell) -> WitCell {
+        match cell {
+            TableCell::Null => WitCell::Null,
+            TableCell::Bool(b) => WitCell::Boolean(b),
+            TableCell::Int(i) => WitCell::Int(i),
+            TableCell::Float(f) => WitCell::Float(f),
+            TableCell::Text(s) => WitCell::Text(s),
+        }
+    }
+
+    fn csv_options_to_wit(input: &CsvParseInput) -> CsvOptions {
+        CsvOptions {
+            has_header: input.has_header,
+            delimiter: input.delimiter,
+            quote: input.quote,
+            encodings: input
+                .encodings
+                .iter()
+                .map(|e| match e {
+                    CsvEncoding::Utf8 => WitCsvEncoding::Utf8,
+                    CsvEncoding::Utf16Le => WitCsvEncoding::Utf16le,
+                    CsvEncoding::Utf16Be => WitCsvEncoding::Utf16be,
+                    CsvEncoding::Latin1 => WitCsvEncoding::Latin1,
+                    CsvEncoding::Windows1252 => WitCsvEncoding::Windows1252,
+                })
+                .collect(),
+            infer_types: input.infer_types,
+            max_rows: input.max_rows,
+            max_bytes: input.max_bytes,
+            batch_rows: input.batch_rows,
+        }
+    }
+
+    fn csv_rows_to_json(rows: Vec<Vec<CsvCell>>) -> serde_json::Value {
+        serde_json::Value::Array(
+            rows.into_iter()
+                .map(|row| {
+                    serde_json::Value::Array(
+                        row.into_iter()
+                            .map(|cell| {
+                                let cell = match cell {
+                                    CsvCell::Null => TableCell::Null,
+                                    CsvCell::Boolean(b) => TableCell::Bool(b),
+                                    CsvCell::Int(i) => TableCell::Int(i),
+                                    CsvCell::Float(f) => TableCell::Float(f),
+                                    CsvCell::Text(s) => TableCell::Text(s),
+                                };
+                                cell.to_json()
+                            })
+                            .collect(),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    fn markdown_options_to_wit(doc: &MarkdownRenderInput) -> WitMarkdownOptions {
+        WitMarkdownOptions {
+            gfm: doc.gfm,
+            smart_punctuation: doc.smart_punctuation,
+            raw_html: doc.raw_html,
+        }
+    }
+
+    // WHY: WIT has no recursive types; the document crosses as JSON text and is re-parsed in the
+    // guest, so canonical (sorted-key) serialization here keeps cache keys and outputs stable.
+    fn json_query_to_wit(query: &JsonQueryInput) -> WitJsonQueryInput {
+        WitJsonQueryInput {
+            document: query.document.to_string(),
+            language: match query.language {
+                JsonQueryLanguage::JsonPath => WitJsonQueryLanguage::Jsonpath,
+                JsonQueryLanguage::Jq => WitJsonQueryLanguage::Jq,
+            },
+            expression: query.expression.clone(),
+        }
+    }
+
+    fn table_query_to_wit(query: TableQuery) -> TypedTableInput {
+        let mut predicates = Vec::new();
+        if let Some(clause) = &query.where_clause {
+            flatten_where(clause, &mut predicates);
+        }
+        TypedTableInput {
+            rows: query
+                .rows
+                .into_iter()
+                .map(|row| row.into_iter().map(cell_to_wit).collect())
+                .collect(),
+            has_header: query.has_header,
+            select: query.select,
+            where_contains: query
+                .where_contains
+                .map(|(col, needle)| TypedTableFilter { col, needle }),
+            predicates,
+            order_by: query
+                .order_by
+                .into_iter()
+                .map(|k| WitOrderKey {
+                    col: k.col,
+                    descending: k.descending,
+                })
+                .collect(),
+            group_by: query.group_by,
+            aggregates: query
+                .aggregates
+                .into_iter()
+                .map(|a| WitAggregate {
+                    op: match a.op {
+                        TableAggregateOp::Count => WitAggregateOp::Count,
+                        TableAggregateOp::Sum => WitAggregateOp::Sum,
+                        TableAggregateOp::Min => WitAggregateOp::Min,
+                        TableAggregateOp::Max => WitAggregateOp::Max,
+                        TableAggregateOp::Avg => WitAggregateOp::Avg,
+                    },
+                    col: a.col,
+                    label: a.label,
+                })
+                .collect(),
+            limit: query.limit,
+            offset: query.offset,
+        }
+    }
+
+    /// Post-order flattening for the WIT `where-node` list; returns the index of `node`.
+    /// INVARIANT: children always precede their parent, so the root is the last entry.
+    fn flatten_where(node: &TableWhere, out: &mut Vec<WitWhereNode>) -> u32 {
+        let flat = match node {
+            TableWhere::Leaf(p) => WitWhereNode::Leaf(WitPredicate {
+                col: p.col,
+                op: match p.op {
+                    TableCompareOp::Eq => WitCompareOp::Eq,
+                    TableCompareOp::Ne => WitCompareOp::Ne,
+                    TableCompareOp::Lt => WitCompareOp::Lt,
+                    TableCompareOp::Lte => WitCompareOp::Lte,
+                    TableCompareOp::Gt => WitCompareOp::Gt,
+                    TableCompareOp::Gte => WitCompareOp::Gte,
+                    TableCompareOp::Contains => WitCompareOp::Contains,
+                    TableCompareOp::Regex => WitCompareOp::Regex,
+                },
+                value: cell_to_wit(p.value.clone()),
+                case_insensitive: p.case_insensitive,
+            }),
+            TableWhere::All(children) => {
+                WitWhereNode::All(children.iter().map(|c| flatten_where(c, out)).collect())
+            }
+            TableWhere::Any(children) => {
+                WitWhereNode::Any(children.iter().map(|c| flatten_where(c, out)).collect())
+            }
+        };
+        out.push(flat);
+        (out.len() - 1) as u32
+    }
+
+    fn typed_rows_to_json(rows: Vec<Vec<WitCell>>) -> serde_json::Value {
+        serde_json::Value::Array(
+            rows.into_iter()
+                .map(|row| {
+                    serde_json::Value::Array(
+                        row.into_iter()
+                            .map(|cell| {
+                                let cell = match cell {
+                                    WitCell::Null => TableCell::Null,
+                                    WitCell::Boolean(b) => TableCell::Bool(b),
+                                    WitCell::Int(i) => TableCell::Int(i),
+                                    WitCell::Float(f) => TableCell::Float(f),
+                                    WitCell::Text(s) => TableCell::Text(s),
+                                };
+                                cell.to_json()
+                            })
+                            .collect(),
+                    )
+                })
+                .collect(),
+        )
+    }
+
     pub fn component_import_names(path: &Path) -> anyhow::Result<BTreeSet<String>> {
         let component = Component::from_file(&ENGINE, path).with_context(|| {
             format!(
@@ -1838,31 +3658,115 @@ mod with_runtime {
         raw.to_string()
     }
 
-    fn allowed_imports_for(task: &str) -> anyhow::Result<BTreeSet<String>> {
+    /// Import namespaces the host linker can ever satisfy. Manifest-declared allowlists are
+    /// clamped to this ceiling so a module entry can narrow, but never widen, the host surface.
+    const HOST_IMPORT_CEILING: &[&str] = &[
+        "wasi:cli/",
+        "wasi:clocks/",
+        "wasi:filesystem/",
+        "wasi:io/",
+        "wasi:random/",
+        "wasi:logging/",
+        "uicp:host/",
+        // Guest-owned type packages (e.g. uicp:task-table-query/types) carry no host functions.
+        "uicp:task-",
+    ];
+
+    /// Namespaces that are never linked regardless of what a manifest declares.
+    const HOST_IMPORT_DENY: &[&str] = &["wasi:sockets/", "wasi:http/"];
+
+    fn check_import_ceiling(task: &str, imports: &BTreeSet<String>) -> anyhow::Result<()> {
+        // ERROR: E-UICP-0237 import outside the host ceiling (sockets/http or unknown namespace)
+        for name in imports {
+            if HOST_IMPORT_DENY.iter().any(|p| name.starts_with(p)) {
+                anyhow::bail!(
+                    "E-UICP-0237: component '{task}' import '{name}' is denied by host ceiling (no sockets/http)"
+                );
+            }
+            if !HOST_IMPORT_CEILING.iter().any(|p| name.starts_with(p)) {
+                anyhow::bail!(
+                    "E-UICP-0237: component '{task}' import '{name}' is outside the host ceiling"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolve the import allowlist for a task. A manifest-declared list wins; bundled tasks
+    /// fall back to the built-in defaults so manifests predating `imports` keep verifying.
+    fn resolve_import_policy(
+        task: &str,
+        declared: Option<&[String]>,
+    ) -> anyhow::Result<BTreeSet<String>> {
+        let allowed: BTreeSet<String> = match declared {
+            Some(list) => list.iter().map(|s| normalize_import_name(s)).collect(),
+            None => default_imports_for(task)?,
+        };
+        check_import_ceiling(task, &allowed)?;
+        Ok(allowed)
+    }
+
+    fn default_imports_for(task: &str) -> anyhow::Result<BTreeSet<String>> {
         let prefix = task.split('@').next().unwrap_or(task);
         match prefix {
             // NOTE: Allow any 0.2.x patch for core WASI packages by comparing on X.Y only.
-            "csv.parse" => Ok([
+            "csv.parse" => {
+                let mut allowed: BTreeSet<String> = [
+                    "wasi:cli/environment@0.2",
+                    "wasi:cli/exit@0.2",
+                    "wasi:cli/stderr@0.2",
+                    "wasi:cli/stdin@0.2",
+                    "wasi:cli/stdout@0.2",
+                    "wasi:clocks/wall-clock@0.2",
+                    "wasi:filesystem/preopens@0.2",
+                    "wasi:filesystem/types@0.2",
+                    "wasi:io/error@0.2",
+                    "wasi:io/streams@0.2",
+                ]
+                .into_iter()
+                .map(normalize_import_name)
+                .collect();
+                // WHY: 1.3 streams row batches through the partial sink and checks cancellation.
+                if csv_parse_streaming(task) {
+                    allowed.extend(
+                        ["uicp:host/control@1.0.0", "wasi:clocks/monotonic-clock@0.2"]
+                            .into_iter()
+                            .map(normalize_import_name),
+                    );
+                }
+                Ok(allowed)
+            }
+            "table.query" => Ok([
+                // Host control (accept versioned and unversioned names)
+                "uicp:host/control@1.0.0",
+                "uicp:host/control",
+                // Guest package types (one per world) and core WASI packages
+                if table_query_typed_cells(task) {
+                    "uicp:task-table-query/types@0.2.0"
+                } else {
+                    "uicp:task-table-query/types@0.1.0"
+                },
                 "wasi:cli/environment@0.2",
                 "wasi:cli/exit@0.2",
                 "wasi:cli/stderr@0.2",
                 "wasi:cli/stdin@0.2",
                 "wasi:cli/stdout@0.2",
+                "wasi:clocks/monotonic-clock@0.2",
                 "wasi:clocks/wall-clock@0.2",
                 "wasi:filesystem/preopens@0.2",
                 "wasi:filesystem/types@0.2",
                 "wasi:io/error@0.2",
                 "wasi:io/streams@0.2",
+                // Some builds import wasi:logging; allow both versioned and unversioned forms
+                "wasi:logging/logging@0.2",
+                "wasi:logging/logging",
             ]
             .into_iter()
             .map(normalize_import_name)
             .collect()),
-            "table.query" => Ok([
-                // Host control (accept versioned and unversioned names)
+            // WHY: json.query emits progress partials and polls cancellation like csv.parse@1.3.
+            "json.query" => Ok([
                 "uicp:host/control@1.0.0",
-                "uicp:host/control",
-                // Guest package types and core WASI packages
-                "uicp:task-table-query/types@0.1.0",
                 "wasi:cli/environment@0.2",
                 "wasi:cli/exit@0.2",
                 "wasi:cli/stderr@0.2",
@@ -1874,41 +3778,95 @@ mod with_runtime {
                 "wasi:filesystem/types@0.2",
                 "wasi:io/error@0.2",
                 "wasi:io/streams@0.2",
-                // Some builds import wasi:logging; allow both versioned and unversioned forms
-                "wasi:logging/logging@0.2",
-                "wasi:logging/logging",
             ]
             .into_iter()
             .map(normalize_import_name)
             .collect()),
-            // Script demo component is permitted to have zero WASI imports.
-            "script.hello" => Ok(BTreeSet::new()),
-            "applet.quickjs" => Ok([
+            // WHY: Pure renderer; only the WASI surface the std adapter always pulls in.
+            "markdown.render" => Ok([
                 "wasi:cli/environment@0.2",
                 "wasi:cli/exit@0.2",
                 "wasi:cli/stderr@0.2",
                 "wasi:cli/stdin@0.2",
                 "wasi:cli/stdout@0.2",
-                "wasi:clocks/monotonic-clock@0.2",
                 "wasi:clocks/wall-clock@0.2",
                 "wasi:filesystem/preopens@0.2",
                 "wasi:filesystem/types@0.2",
                 "wasi:io/error@0.2",
                 "wasi:io/streams@0.2",
-                "wasi:random/random@0.2",
             ]
             .into_iter()
             .map(normalize_import_name)
             .collect()),
+            // Script demo component is permitted to have zero WASI imports.
+            "script.hello" => Ok(BTreeSet::new()),
+            "applet.quickjs" => {
+                let mut allowed: BTreeSet<String> = [
+                    "wasi:cli/environment@0.2",
+                    "wasi:cli/exit@0.2",
+                    "wasi:cli/stderr@0.2",
+                    "wasi:cli/stdin@0.2",
+                    "wasi:cli/stdout@0.2",
+                    "wasi:clocks/monotonic-clock@0.2",
+                    "wasi:clocks/wall-clock@0.2",
+                    "wasi:filesystem/preopens@0.2",
+                    "wasi:filesystem/types@0.2",
+                    "wasi:io/error@0.2",
+                    "wasi:io/streams@0.2",
+                    "wasi:random/random@0.2",
+                ]
+                .into_iter()
+                .map(normalize_import_name)
+                .collect();
+                // WHY: 0.3 bundles reach per-window state through `uicp:host/kv`.
+                if applet_uses_kv(task) {
+                    allowed.insert(normalize_import_name("uicp:host/kv@1.2.0"));
+                }
+                Ok(allowed)
+            }
             other => anyhow::bail!(
                 "E-UICP-0229: no component import policy registered for task '{other}'"
             ),
         }
     }
 
-    pub fn preflight_component_imports(path: &Path, task: &str) -> anyhow::Result<()> {
+    /// Preflight against an explicit allowlist (typically `ModuleEntry::imports`).
+    /// `None` uses the built-in defaults for bundled tasks.
+    pub fn preflight_component_imports_with(
+        path: &Path,
+        task: &str,
+        declared: Option<&[String]>,
+    ) -> anyhow::Result<()> {
         let actual_raw = component_import_names(path)?;
-        let allowed_raw = allowed_imports_for(task)?;
+        check_component_imports(task, actual_raw, declared)
+    }
+
+    /// Default-policy entries a component may leave out: alternate spellings some builds emit.
+    /// Declared manifest lists are exact.
+    fn optional_default_imports(task: &str) -> &'static [&'static str] {
+        match task.split('@').next().unwrap_or(task) {
+            "table.query" => &[
+                "uicp:host/control",
+                "wasi:logging/logging@0.2",
+                "wasi:logging/logging",
+            ],
+            _ => &[],
+        }
+    }
+
+    /// Compare a component's imports with the task's policy: nothing outside it, and nothing
+    /// required by it left out.
+    fn check_component_imports(
+        task: &str,
+        actual_raw: BTreeSet<String>,
+        declared: Option<&[String]>,
+    ) -> anyhow::Result<()> {
+        let allowed_raw = resolve_import_policy(task, declared)?;
+        let optional = if declared.is_none() {
+            optional_default_imports(task)
+        } else {
+            &[]
+        };
 
         // Normalize both sides to collapse patch versions (X.Y.Z -> X.Y)
         let actual: BTreeSet<String> = actual_raw
@@ -1919,9 +3877,15 @@ mod with_runtime {
             .into_iter()
             .map(|s| normalize_import_name(&s))
             .collect();
+        // INVARIANT: The ceiling applies to what the binary actually imports, not just the policy.
+        check_import_ceiling(task, &actual)?;
 
         let unexpected: Vec<String> = actual.difference(&allowed).cloned().collect();
-        let missing: Vec<String> = allowed.difference(&actual).cloned().collect();
+        let missing: Vec<String> = allowed
+            .difference(&actual)
+            .filter(|name| !optional.contains(&name.as_str()))
+            .cloned()
+            .collect();
 
         if !unexpected.is_empty() || !missing.is_empty() {
             anyhow::bail!(
@@ -1960,7 +3924,9 @@ mod with_runtime {
         );
         store.limiter(|ctx| &mut ctx.limits);
 
-        let linker: &Linker<Ctx> = &LINKER;
+        // NOTE: Verify against the superset linker; whether a job may use `uicp:host/http` is
+        // decided per job from `capabilities.net`.
+        let linker: &Linker<Ctx> = &LINKER_NET;
         let instance_pre = linker
             .instantiate_pre(&component)
             .context("E-UICP-0231: linker instantiate_pre for contract failed")?;
@@ -1969,16 +3935,41 @@ mod with_runtime {
             .context("E-UICP-0233: instantiate component for contract verification failed")?;
 
         match task.split('@').next().unwrap_or("") {
+            "csv.parse" if csv_parse_streaming(task) => {
+                let bindings = StreamingCsvTask::new(&mut store, &instance)
+                    .context("E-UICP-0234: csv contract binding init failed")?;
+                let _ = bindings.uicp_task_csv_parse_csv();
+            }
             "csv.parse" => {
                 let bindings = CsvTask::new(&mut store, &instance)
                     .context("E-UICP-0234: csv contract binding init failed")?;
                 let _ = bindings.uicp_task_csv_parse_csv();
             }
+            "table.query" if table_query_typed_cells(task) => {
+                let bindings = TypedTableTask::new(&mut store, &instance)
+                    .context("E-UICP-0235: table contract binding init failed")?;
+                let _ = bindings.uicp_task_table_query_table();
+            }
             "table.query" => {
                 let bindings = TableTask::new(&mut store, &instance)
                     .context("E-UICP-0235: table contract binding init failed")?;
                 let _ = bindings.uicp_task_table_query_table();
             }
+            "json.query" => {
+                let bindings = JsonQueryTask::new(&mut store, &instance)
+                    .context("E-UICP-0246: json.query contract binding init failed")?;
+                let _ = bindings.uicp_task_json_query_query();
+            }
+            "markdown.render" => {
+                let bindings = MarkdownTask::new(&mut store, &instance)
+                    .context("E-UICP-0250: markdown.render contract binding init failed")?;
+                let _ = bindings.uicp_task_markdown_render_render();
+            }
+            "applet.quickjs" if script_loads_source(task) => {
+                let bindings = SessionScriptTask::new(&mut store, &instance)
+                    .context("E-UICP-0236: script contract binding init failed")?;
+                let _ = bindings.uicp_applet_script_script();
+            }
             "applet.quickjs" | "script.hello" => {
                 let bindings = ScriptTask::new(&mut store, &instance)
                     .context("E-UICP-0236: script contract binding init failed")?;
@@ -1990,27 +3981,29 @@ mod with_runtime {
         Ok(())
     }
 
+    /// Warm the newest installed `applet.quickjs`: compile it, run one init/render/on-event
+    /// pass, and seed its instance pool so the first interactive applet skips instantiation.
     pub fn prewarm_quickjs<R: Runtime>(app: &AppHandle<R>) -> anyhow::Result<()> {
-        let Some(module) = registry::find_module(app, "applet.quickjs@0.1.0")? else {
+        let Some(module) = registry::find_module(app, "applet.quickjs")? else {
             return Ok(());
         };
-        let component = load_component_cached(&module.path)?;
-        let encoded = BASE64_ENGINE.encode(PREWARM_SCRIPT.as_bytes());
+        let task = format!("{}@{}", module.entry.task, module.entry.version);
+        let component = load_component_cached(&module.path, &module.entry.digest_sha256)?;
 
         let mut wasi_builder = WasiCtxBuilder::new();
-        wasi_builder.env(SCRIPT_SOURCE_ENV, &encoded);
+        // WHY: 0.2+ bundles arrive through `script.load`; earlier versions read the env var.
+        if !script_loads_source(&task) {
+            wasi_builder.env(
+                SCRIPT_SOURCE_ENV,
+                BASE64_ENGINE.encode(PREWARM_SCRIPT.as_bytes()),
+            );
+        }
         let wasi = wasi_builder.build();
         let emitter: Arc<dyn TelemetryEmitter> = Arc::new(NullTelemetry);
 
         let mut store: Store<Ctx> = Store::new(
             &ENGINE,
-            make_ctx(
-                "applet.quickjs@0.1.0",
-                "quickjs-prewarm",
-                wasi,
-                emitter,
-                1_000,
-            ),
+            make_ctx(&task, "quickjs-prewarm", wasi, emitter, 1_000),
         );
         store.limiter(|ctx| &mut ctx.limits);
         let _ = store.set_fuel(DEFAULT_RUNTIME_FUEL);
@@ -2021,31 +4014,80 @@ mod with_runtime {
             block_on(async { linker.instantiate_async(&mut store, &component).await })
                 .context("E-UICP-0703: instantiate applet.quickjs component for prewarm failed")?;
 
-        let bindings = ScriptTask::new(&mut store, &instance)
-            .context("E-UICP-0704: script bindings init during prewarm failed")?;
-        let script_iface = bindings.uicp_applet_script_script();
+        if script_loads_source(&task) {
+            prewarm_loaded_script(&mut store, &instance)?;
+        } else {
+            let bindings = ScriptTask::new(&mut store, &instance)
+                .context("E-UICP-0704: script bindings init during prewarm failed")?;
+            let script_iface = bindings.uicp_applet_script_script();
+
+            let init_output = block_on(async { script_iface.call_init(&mut store).await })
+                .context("E-UICP-0705: prewarm init call trapped")?
+                .map_err(|msg| {
+                    anyhow::anyhow!("E-UICP-0706: prewarm init returned error: {msg}")
+                })?;
+            let state = if init_output.trim().is_empty() {
+                "{}".to_string()
+            } else {
+                init_output
+            };
+
+            block_on(async { script_iface.call_render(&mut store, &state).await })
+                .context("E-UICP-0707: prewarm render call trapped")?
+                .map_err(|msg| {
+                    anyhow::anyhow!("E-UICP-0708: prewarm render returned error: {msg}")
+                })?;
+
+            block_on(async {
+                script_iface
+                    .call_on_event(&mut store, "noop", "{}", &state)
+                    .await
+            })
+            .context("E-UICP-0709: prewarm onEvent call trapped")?
+            .map_err(|msg| anyhow::anyhow!("E-UICP-0710: prewarm onEvent returned error: {msg}"))?;
+        }
+
+        // Seed the instance pool so the first interactive applet skips instantiation.
+        let pool_size = instance_pool_size();
+        if pool_size > 0 {
+            let pool = task_pool(&instance_pool_key(&module.entry), &component)?;
+            block_on(refill_pool(pool, task, pool_size));
+        }
+
+        Ok(())
+    }
 
-        let init_output = block_on(async { script_iface.call_init(&mut store).await })
+    /// Prewarm pass for `applet.quickjs@0.2+`: load the bundle, then init, render and on-event.
+    fn prewarm_loaded_script(store: &mut Store<Ctx>, instance: &Instance) -> anyhow::Result<()> {
+        let bindings = SessionScriptTask::new(&mut *store, instance)
+            .context("E-UICP-0704: script bindings init during prewarm failed")?;
+        let iface = bindings.uicp_applet_script_script();
+        block_on(async { iface.call_load(&mut *store, PREWARM_SCRIPT).await })
+            .context("E-UICP-0705: prewarm load call trapped")?
+            .map_err(|msg| anyhow::anyhow!("E-UICP-0706: prewarm load returned error: {msg}"))?;
+        let mut input = ScriptInput {
+            mode: ScriptMode::Init,
+            state: "{}".into(),
+            action: None,
+            payload: None,
+            source: None,
+        };
+        let state = block_on(call_loaded_script(iface, store, &input))
             .context("E-UICP-0705: prewarm init call trapped")?
             .map_err(|msg| anyhow::anyhow!("E-UICP-0706: prewarm init returned error: {msg}"))?;
-        let state = if init_output.trim().is_empty() {
-            "{}".to_string()
-        } else {
-            init_output
-        };
-
-        block_on(async { script_iface.call_render(&mut store, &state).await })
+        if !state.trim().is_empty() {
+            input.state = state;
+        }
+        input.mode = ScriptMode::Render;
+        block_on(call_loaded_script(iface, store, &input))
             .context("E-UICP-0707: prewarm render call trapped")?
             .map_err(|msg| anyhow::anyhow!("E-UICP-0708: prewarm render returned error: {msg}"))?;
-
-        block_on(async {
-            script_iface
-                .call_on_event(&mut store, "noop", "{}", &state)
-                .await
-        })
-        .context("E-UICP-0709: prewarm onEvent call trapped")?
-        .map_err(|msg| anyhow::anyhow!("E-UICP-0710: prewarm onEvent returned error: {msg}"))?;
-
+        input.mode = ScriptMode::OnEvent;
+        input.action = Some("noop".into());
+        input.payload = Some("{}".into());
+        block_on(call_loaded_script(iface, store, &input))
+            .context("E-UICP-0709: prewarm onEvent call trapped")?
+            .map_err(|msg| anyhow::anyhow!("E-UICP-0710: prewarm onEvent returned error: {msg}"))?;
         Ok(())
     }
 
@@ -2093,13 +4135,308 @@ mod with_runtime {
         Ok(())
     }
 
-    fn add_uicp_host(linker: &mut Linker<Ctx>) -> anyhow::Result<()> {
-        register_control_interface(linker, "uicp:host/control")?;
-        register_control_interface(linker, "uicp:host/control@1.0.0")?;
-        register_rng_interface(linker, "uicp:host/rng")?;
-        register_rng_interface(linker, "uicp:host/rng@1.0.0")?;
-        Ok(())
+    fn add_uicp_host(linker: &mut Linker<Ctx>) -> anyhow::Result<()> {
+        register_control_interface(linker, "uicp:host/control")?;
+        register_control_interface(linker, "uicp:host/control@1.0.0")?;
+        register_control_interface(linker, "uicp:host/control@1.1.0")?;
+        register_rng_interface(linker, "uicp:host/rng")?;
+        register_rng_interface(linker, "uicp:host/rng@1.0.0")?;
+        register_rng_interface(linker, "uicp:host/rng@1.1.0")?;
+        register_control_interface(linker, "uicp:host/control@1.2.0")?;
+        register_rng_interface(linker, "uicp:host/rng@1.2.0")?;
+        register_control_interface(linker, "uicp:host/control@1.3.0")?;
+        register_rng_interface(linker, "uicp:host/rng@1.3.0")?;
+        // WHY: kv is linked for every store so pooled instances stay shareable; stores without a
+        // session scope answer every call with `err`.
+        for name in ["uicp:host/kv", "uicp:host/kv@1.2.0", "uicp:host/kv@1.3.0"] {
+            let mut instance = linker.instance(name)?;
+            instance.func_wrap_async("get", host_kv_get)?;
+            instance.func_wrap_async("set", host_kv_set)?;
+            instance.func_wrap_async("delete", host_kv_delete)?;
+            instance.func_wrap_async("keys", host_kv_keys)?;
+        }
+        for name in ["uicp:host/log", "uicp:host/log@1.3.0"] {
+            linker
+                .instance(name)?
+                .func_wrap_async("write", host_log_write)?;
+        }
+        Ok(())
+    }
+
+    fn add_uicp_http(linker: &mut Linker<Ctx>) -> anyhow::Result<()> {
+        for name in [
+            "uicp:host/http",
+            "uicp:host/http@1.1.0",
+            "uicp:host/http@1.2.0",
+            "uicp:host/http@1.3.0",
+        ] {
+            let mut instance = linker.instance(name)?;
+            instance.func_wrap_async("fetch", host_http_fetch)?;
+        }
+        Ok(())
+    }
+
+    /// True when the component imports `uicp:host/http` (any version).
+    fn component_imports_http(component: &Component) -> bool {
+        component
+            .component_type()
+            .imports(&ENGINE)
+            .any(|(name, _)| name.split('@').next() == Some(HTTP_IMPORT))
+    }
+
+    const HTTP_IMPORT: &str = "uicp:host/http";
+
+    #[derive(ComponentType, Lift, Lower, Clone, Debug)]
+    #[component(record)]
+    struct HttpHeader {
+        name: String,
+        value: String,
+    }
+
+    #[derive(ComponentType, Lift, Lower, Clone, Debug)]
+    #[component(record)]
+    struct HttpResponse {
+        status: u16,
+        headers: Vec<HttpHeader>,
+        body: Vec<u8>,
+    }
+
+    type HttpFetchParams = (String, String, String, Vec<HttpHeader>, Option<Vec<u8>>);
+
+    /// `uicp:host/http.fetch`: route a guest request through the shared egress core so per-app
+    /// rate/concurrency limits, private-IP blocking, policy labels and receipts all apply.
+    /// Policy failures surface to the guest as `err(string)`; only host faults trap.
+    fn host_http_fetch<'a>(
+        store: StoreContextMut<'a, Ctx>,
+        (job, method, url, headers, body): HttpFetchParams,
+    ) -> Box<
+        dyn std::future::Future<Output = anyhow::Result<(Result<HttpResponse, String>,)>>
+            + Send
+            + 'a,
+    > {
+        Box::new(async move {
+            // NOTE: Scoped so no borrow of the store's data is held across the fetch await.
+            let (grant, remaining) = {
+                let ctx = store.data();
+                if job != ctx.job_id {
+                    log_job_mismatch(ctx, "http.fetch", &job);
+                    return Ok((Err("job id mismatch".to_string()),));
+                }
+                if ctx.cancelled.load(Ordering::Relaxed) {
+                    return Ok((Err("Cancelled".to_string()),));
+                }
+                // INVARIANT: `LINKER_NET` is only used when the job carries a grant.
+                let Some(grant) = ctx.net.clone() else {
+                    return Ok((Err("PolicyDenied: capabilities.net not granted".to_string()),));
+                };
+                (
+                    grant,
+                    (ctx.deadline_ms as u64).saturating_sub(ctx.active_elapsed_ms()),
+                )
+            };
+
+            let parsed = match url::Url::parse(&url) {
+                Ok(u) => u,
+                Err(err) => return Ok((Err(format!("InvalidUrl: {err}")),)),
+            };
+            if parsed.scheme() != "https" {
+                return Ok((Err(format!(
+                    "PolicyDenied: {} is not allowed; uicp:host/http requires https",
+                    parsed.scheme()
+                )),));
+            }
+            if !net_url_granted(&grant.rules, &parsed) {
+                let host = parsed.host_str().unwrap_or_default();
+                return Ok((Err(format!(
+                    "PolicyDenied: api:NET:{host}{} not in capabilities.net",
+                    parsed.path()
+                )),));
+            }
+            if remaining == 0 {
+                return Ok((Err("Timeout".to_string()),));
+            }
+
+            let mut header_map = std::collections::HashMap::new();
+            for h in headers {
+                header_map.insert(h.name, h.value);
+            }
+            let req = EgressRequest {
+                method,
+                url,
+                headers: Some(header_map),
+                body,
+            };
+            grant.requests.fetch_add(1, Ordering::Relaxed);
+            // WHY: Host awaits are invisible to epoch interruption; cap the request at the
+            // job's remaining budget so a slow server cannot hold the job past its deadline.
+            let fetched = tokio::time::timeout(
+                Duration::from_millis(remaining),
+                egress_fetch_core(&grant.host, &grant.installed_id, &req),
+            )
+            .await;
+            let resp = match fetched {
+                Ok(Ok(resp)) => resp,
+                Ok(Err(msg)) => return Ok((Err(msg),)),
+                Err(_) => return Ok((Err("Timeout".to_string()),)),
+            };
+            let mut headers: Vec<HttpHeader> = resp
+                .headers
+                .into_iter()
+                .map(|(name, value)| HttpHeader { name, value })
+                .collect();
+            headers.sort_by(|a, b| a.name.cmp(&b.name));
+            Ok((Ok(HttpResponse {
+                status: resp.status,
+                headers,
+                body: resp.body,
+            }),))
+        })
+    }
+
+    const KV_UNAVAILABLE: &str =
+        "Unavailable: uicp:host/kv is only bound in applet sessions, not one-shot jobs";
+
+    /// Host futures for `uicp:host/kv`; scoped to the store's workspace and window.
+    type KvFuture<'a, T> =
+        Box<dyn std::future::Future<Output = anyhow::Result<(Result<T, String>,)>> + Send + 'a>;
+
+    /// `uicp:host/kv.get`: the value stored under `key`, or `none`.
+    fn host_kv_get<'a>(
+        store: StoreContextMut<'a, Ctx>,
+        (key,): (String,),
+    ) -> KvFuture<'a, Option<String>> {
+        let scope = store.data().kv.clone();
+        Box::new(async move {
+            let Some(scope) = scope else {
+                return Ok((Err(KV_UNAVAILABLE.to_string()),));
+            };
+            Ok((scope.get(key).await.map_err(|err| err.to_string()),))
+        })
+    }
+
+    /// `uicp:host/kv.set`: insert or replace `key`, subject to the per-window quotas.
+    fn host_kv_set<'a>(
+        store: StoreContextMut<'a, Ctx>,
+        (key, value): (String, String),
+    ) -> KvFuture<'a, ()> {
+        let scope = store.data().kv.clone();
+        Box::new(async move {
+            let Some(scope) = scope else {
+                return Ok((Err(KV_UNAVAILABLE.to_string()),));
+            };
+            Ok((scope.set(key, value).await.map_err(|err| err.to_string()),))
+        })
+    }
+
+    /// `uicp:host/kv.delete`: remove `key`; `true` when it existed.
+    fn host_kv_delete<'a>(
+        store: StoreContextMut<'a, Ctx>,
+        (key,): (String,),
+    ) -> KvFuture<'a, bool> {
+        let scope = store.data().kv.clone();
+        Box::new(async move {
+            let Some(scope) = scope else {
+                return Ok((Err(KV_UNAVAILABLE.to_string()),));
+            };
+            Ok((scope.delete(key).await.map_err(|err| err.to_string()),))
+        })
+    }
+
+    /// `uicp:host/kv.keys`: sorted keys starting with `prefix`.
+    fn host_kv_keys<'a>(
+        store: StoreContextMut<'a, Ctx>,
+        (prefix,): (String,),
+    ) -> KvFuture<'a, Vec<String>> {
+        let scope = store.data().kv.clone();
+        Box::new(async move {
+            let Some(scope) = scope else {
+                return Ok((Err(KV_UNAVAILABLE.to_string()),));
+            };
+            Ok((scope.keys(prefix).await.map_err(|err| err.to_string()),))
+        })
+    }
+
+    #[derive(ComponentType, Lift, Lower, Clone, Copy, Debug, PartialEq, Eq)]
+    #[component(enum)]
+    #[repr(u8)]
+    enum GuestLogLevel {
+        #[component(name = "trace")]
+        Trace,
+        #[component(name = "debug")]
+        Debug,
+        #[component(name = "info")]
+        Info,
+        #[component(name = "warn")]
+        Warn,
+        #[component(name = "error")]
+        Error,
+    }
+
+    impl GuestLogLevel {
+        fn as_str(self) -> &'static str {
+            job_logs::LEVELS[self as usize]
+        }
+    }
+
+    #[derive(ComponentType, Lift, Lower, Clone, Debug)]
+    #[component(record)]
+    struct GuestLogField {
+        key: String,
+        value: String,
+    }
+
+    type LogWriteParams = (String, GuestLogLevel, String, Vec<GuestLogField>);
+
+    /// `uicp:host/log.write`: append a structured entry to the action log, then mirror it as a
+    /// `log` partial. Shares the wasi:logging byte rate and the job log budget; entries past the
+    /// budget are dropped without telling the guest.
+    fn host_log_write<'a>(
+        mut store: StoreContextMut<'a, Ctx>,
+        (job, level, message, fields): LogWriteParams,
+    ) -> Box<dyn std::future::Future<Output = anyhow::Result<()>> + Send + 'a> {
+        Box::new(async move {
+            let (entry, action_log) = {
+                let ctx = store.data_mut();
+                if job != ctx.job_id {
+                    log_job_mismatch(ctx, "log.write", &job);
+                    return Ok(());
+                }
+                let entry = GuestLogEntry::new(
+                    &ctx.job_id,
+                    &ctx.task,
+                    level.as_str(),
+                    message,
+                    fields.into_iter().map(|f| (f.key, f.value)),
+                );
+                let Some(entry) = ctx.admit_guest_log(entry) else {
+                    return Ok(());
+                };
+                (entry, ctx.action_log.clone())
+            };
+            // WHY: Host functions run on tokio workers; back off asynchronously, never block one.
+            let size = entry.size_bytes();
+            while let Some(wait) = store.data().take_logger_rate(size) {
+                if wait.is_zero() {
+                    tokio::task::yield_now().await;
+                } else {
+                    tokio::time::sleep(wait).await;
+                }
+            }
+            // WHY: Append-first like guest stdio, so every entry the UI saw is retrievable later.
+            if let Some(action_log) = action_log {
+                let payload = serde_json::to_value(&entry)?;
+                action_log
+                    .append_json(job_logs::LOG_KIND, &payload)
+                    .await
+                    .map_err(|err| {
+                        anyhow::anyhow!("E-UICP-0601: action log append failed: {err}")
+                    })?;
+            }
+            store.data().emit_guest_log(&entry);
+            Ok(())
+        })
     }
+
     fn host_should_cancel(
         store: StoreContextMut<'_, Ctx>,
         (job,): (String,),
@@ -2131,9 +4468,8 @@ mod with_runtime {
         if job != ctx.job_id {
             log_job_mismatch(ctx, "remaining-ms", &job);
         }
-        let elapsed = u64::try_from(ctx.started.elapsed().as_millis()).unwrap_or(u64::MAX);
         let deadline = ctx.deadline_ms as u64;
-        let remaining = deadline.saturating_sub(elapsed);
+        let remaining = deadline.saturating_sub(ctx.active_elapsed_ms());
         Ok((remaining.min(u32::MAX as u64) as u32,))
     }
 
@@ -2225,6 +4561,23 @@ mod with_runtime {
             acc.push_str(&source.to_string().to_ascii_lowercase());
         }
 
+        // Typed traps first; the string heuristics below cover errors re-wrapped as text.
+        for cause in err.chain() {
+            match cause.downcast_ref::<wasmtime::Trap>() {
+                Some(wasmtime::Trap::OutOfFuel) => {
+                    return (error_codes::FUEL_EXHAUSTED, String::new());
+                }
+                Some(wasmtime::Trap::Interrupt) => return (error_codes::TIMEOUT, String::new()),
+                _ => {}
+            }
+        }
+        // CPU fuel exhaustion. Checked before timeouts so a deterministic instruction budget
+        // never masquerades as a wall-clock timeout.
+        if acc.contains("fuel")
+            && (acc.contains("exhaust") || acc.contains("consum") || acc.contains("out of"))
+        {
+            return (error_codes::FUEL_EXHAUSTED, String::new());
+        }
         // Timeout signals: epoch/interrupt/deadline
         if acc.contains("epoch")
             || acc.contains("deadline")
@@ -2233,12 +4586,6 @@ mod with_runtime {
         {
             return (error_codes::TIMEOUT, String::new());
         }
-        // CPU fuel exhaustion (if enabled)
-        if acc.contains("fuel")
-            && (acc.contains("exhaust") || acc.contains("consum") || acc.contains("out of"))
-        {
-            return (error_codes::RESOURCE_LIMIT, String::new());
-        }
         // Memory / resource limits
         if acc.contains("out of memory")
             || (acc.contains("memory")
@@ -2276,15 +4623,17 @@ mod with_runtime {
         metrics_opt: Option<serde_json::Value>,
     ) {
         let ms = started.elapsed().as_millis() as i64;
-        let metrics = if let Some(mut m) = metrics_opt {
+        let mut metrics = if let Some(mut m) = metrics_opt {
             if let Some(map) = m.as_object_mut() {
                 map.entry("queueMs".to_string())
                     .or_insert_with(|| serde_json::json!(queue_wait_ms));
             }
-            Some(m)
+            m
         } else {
-            Some(serde_json::json!({ "durationMs": ms, "queueMs": queue_wait_ms }))
+            serde_json::json!({ "durationMs": ms, "queueMs": queue_wait_ms })
         };
+        insert_module_metrics(app, spec, &mut metrics);
+        let metrics = Some(metrics);
         let payload = ComputeFinalErr {
             ok: false,
             job_id: spec.job_id.clone(),
@@ -2314,47 +4663,15 @@ mod with_runtime {
                 }),
             );
         }
+        append_final_record(app, spec, false, Some(code), None, metrics.as_ref()).await;
         crate::emit_or_log(
             app,
             crate::infrastructure::events::EVENT_COMPUTE_RESULT_FINAL,
             payload.clone(),
         );
-        if spec.replayable && spec.cache == "readwrite" {
-            let use_v2 = std::env::var("UICP_CACHE_V2")
-                .ok()
-                .map(|v| matches!(v.as_str(), "1" | "true" | "TRUE" | "on" | "yes"))
-                .unwrap_or(false);
-            let module_meta = crate::compute::registry::find_module(app, &spec.task)
-                .ok()
-                .flatten();
-            let invariants = {
-                let mut parts: Vec<String> = Vec::new();
-                if let Some(m) = &module_meta {
-                    parts.push(format!("modsha={}", m.entry.digest_sha256));
-                    parts.push(format!("modver={}", m.entry.version));
-                    if let Some(world) = m.provenance.as_ref().and_then(|p| p.wit_world.clone()) {
-                        if !world.is_empty() {
-                            parts.push(format!("world={}", world));
-                        }
-                    }
-                    parts.push("abi=wasi-p2".to_string());
-                }
-                if let Ok(pver) = std::env::var("UICP_POLICY_VERSION") {
-                    if !pver.is_empty() {
-                        parts.push(format!("policy={}", pver));
-                    }
-                }
-                parts.join("|")
-            };
-            let key = if use_v2 {
-                crate::compute::compute_cache::compute_key_v2_plus(spec, &spec.input, &invariants)
-            } else {
-                crate::compute::compute_cache::compute_key(
-                    &spec.task,
-                    &spec.input,
-                    &spec.provenance.env_hash,
-                )
-            };
+        // WHY: A cache hit would skip the job's workspace writes, so fsWrite jobs never populate it.
+        if spec.replayable && spec.cache == "readwrite" && spec.cacheable() {
+            let key = crate::compute::compute_cache::job_cache_key(app, spec, &spec.input);
             let obj = serde_json::to_value(&payload).unwrap_or(serde_json::json!({}));
             let _ = crate::compute::compute_cache::store(
                 app,
@@ -2368,6 +4685,78 @@ mod with_runtime {
         }
     }
 
+    /// Name the exact installed module behind `spec.task` in the final metrics, so a result stays
+    /// attributable after an upgrade or a semver-requirement resolution.
+    fn insert_module_metrics<R: Runtime>(
+        app: &AppHandle<R>,
+        spec: &ComputeJobSpec,
+        metrics: &mut serde_json::Value,
+    ) {
+        let Some(module) = crate::compute::registry::find_module(app, &spec.task)
+            .ok()
+            .flatten()
+        else {
+            return;
+        };
+        if let Some(map) = metrics.as_object_mut() {
+            map.insert(
+                "moduleVersion".into(),
+                serde_json::json!(module.entry.version),
+            );
+            map.insert(
+                "moduleDigest".into(),
+                serde_json::json!(module.entry.digest_sha256),
+            );
+        }
+    }
+
+    /// Append the `compute.job.final` record `compute_replay` pairs with the job's submit record.
+    /// WHY: Written before the final event so a caller reacting to the event can already replay it.
+    async fn append_final_record<R: Runtime>(
+        app: &AppHandle<R>,
+        spec: &ComputeJobSpec,
+        ok: bool,
+        code: Option<&str>,
+        output_hash: Option<&str>,
+        metrics: Option<&serde_json::Value>,
+    ) {
+        if spec.provenance.env_hash == "warmstart" {
+            return;
+        }
+        let Some(state) = app.try_state::<crate::AppState>() else {
+            return;
+        };
+        let module = crate::compute::registry::find_module(app, &spec.task)
+            .ok()
+            .flatten();
+        let metric = |key: &str| metrics.and_then(|m| m.get(key)).cloned();
+        let record = serde_json::json!({
+            "jobId": spec.job_id,
+            "task": spec.task,
+            "ok": ok,
+            "code": code,
+            "outputHash": output_hash,
+            "moduleDigest": module.as_ref().map(|m| m.entry.digest_sha256.clone()),
+            "moduleVersion": module.as_ref().map(|m| m.entry.version.clone()),
+            "rngSeedHex": metric("rngSeedHex"),
+            "rngCounter": metric("rngCounter"),
+            "ts": chrono::Utc::now().timestamp_millis(),
+        });
+        if let Err(err) = state
+            .action_log
+            .append_json(crate::compute::replay::FINAL_KIND, &record)
+            .await
+        {
+            #[cfg(feature = "otel_spans")]
+            tracing::warn!(target = "uicp", job_id = %spec.job_id, error = %err, "action log append failed for job final");
+            #[cfg(not(feature = "otel_spans"))]
+            log_warn(format!(
+                "action log append failed for job final (job_id={}, error={err:#})",
+                spec.job_id
+            ));
+        }
+    }
+
     async fn finalize_ok_with_metrics<R: Runtime>(
         app: &AppHandle<R>,
         spec: &ComputeJobSpec,
@@ -2481,6 +4870,7 @@ mod with_runtime {
             }
             metrics = m;
         }
+        insert_module_metrics(app, spec, &mut metrics);
         let payload = ComputeFinalOk {
             ok: true,
             job_id: spec.job_id.clone(),
@@ -2490,47 +4880,15 @@ mod with_runtime {
         };
         #[cfg(feature = "otel_spans")]
         tracing::info!(target = "uicp", job_id = %spec.job_id, task = %spec.task, "compute job completed with metrics");
+        append_final_record(app, spec, true, None, Some(&out_hash), Some(&metrics)).await;
         crate::emit_or_log(
             app,
             crate::infrastructure::events::EVENT_COMPUTE_RESULT_FINAL,
             payload,
         );
-        if spec.replayable && spec.cache == "readwrite" {
-            let use_v2 = std::env::var("UICP_CACHE_V2")
-                .ok()
-                .map(|v| matches!(v.as_str(), "1" | "true" | "TRUE" | "on" | "yes"))
-                .unwrap_or(false);
-            let module_meta = crate::compute::registry::find_module(app, &spec.task)
-                .ok()
-                .flatten();
-            let invariants = {
-                let mut parts: Vec<String> = Vec::new();
-                if let Some(m) = &module_meta {
-                    parts.push(format!("modsha={}", m.entry.digest_sha256));
-                    parts.push(format!("modver={}", m.entry.version));
-                    if let Some(world) = m.provenance.as_ref().and_then(|p| p.wit_world.clone()) {
-                        if !world.is_empty() {
-                            parts.push(format!("world={}", world));
-                        }
-                    }
-                    parts.push("abi=wasi-p2".to_string());
-                }
-                if let Ok(pver) = std::env::var("UICP_POLICY_VERSION") {
-                    if !pver.is_empty() {
-                        parts.push(format!("policy={}", pver));
-                    }
-                }
-                parts.join("|")
-            };
-            let key = if use_v2 {
-                crate::compute::compute_cache::compute_key_v2_plus(spec, &spec.input, &invariants)
-            } else {
-                crate::compute::compute_cache::compute_key(
-                    &spec.task,
-                    &spec.input,
-                    &spec.provenance.env_hash,
-                )
-            };
+        // WHY: A cache hit would skip the job's workspace writes, so fsWrite jobs never populate it.
+        if spec.replayable && spec.cache == "readwrite" && spec.cacheable() {
+            let key = crate::compute::compute_cache::job_cache_key(app, spec, &spec.input);
             let mut obj = serde_json::json!({ "ok": true, "jobId": spec.job_id, "task": spec.task, "output": output });
             if let Some(map) = obj.as_object_mut() {
                 map.insert("metrics".into(), metrics);
@@ -2549,10 +4907,13 @@ mod with_runtime {
 
     fn collect_metrics(store: &wasmtime::Store<Ctx>) -> serde_json::Value {
         let duration_ms = store.data().started.elapsed().as_millis() as i64;
-        let remaining = (store.data().deadline_ms as i64 - duration_ms).max(0);
+        let active_ms = store.data().active_elapsed_ms() as i64;
+        let remaining = (store.data().deadline_ms as i64 - active_ms).max(0);
         let mut metrics = serde_json::json!({
             "durationMs": duration_ms,
             "deadlineMs": store.data().deadline_ms,
+            "pausedMs": store.data().pause.total_ms(),
+            "pauseCount": store.data().pause.pauses.load(Ordering::Relaxed),
             "logCount": store.data().log_count.load(Ordering::Relaxed),
             "partialFrames": store.data().partial_frames.load(Ordering::Relaxed),
             "invalidPartialsDropped": store.data().invalid_partial_frames.load(Ordering::Relaxed),
@@ -2613,12 +4974,36 @@ mod with_runtime {
                 obj.insert("memPeakMb".into(), serde_json::json!(mem_peak));
             }
         }
-        // Optional fuel metrics if enabled
-        if store.data().initial_fuel > 0 {
+        if let Some(obj) = metrics.as_object_mut() {
+            obj.insert(
+                "memSeries".into(),
+                store.data().limits.series_json(active_ms as u64),
+            );
+        }
+        if let Some(grant) = store.data().net.as_ref() {
+            if let Some(obj) = metrics.as_object_mut() {
+                obj.insert(
+                    "netRequests".into(),
+                    serde_json::json!(grant.requests.load(Ordering::Relaxed)),
+                );
+            }
+        }
+        if let Some(obj) = metrics.as_object_mut() {
+            obj.insert("poolHit".into(), serde_json::json!(store.data().pool_hit));
+        }
+        // Fuel used against the job budget (explicit `spec.fuel` or the runtime default)
+        {
+            let budget = match store.data().initial_fuel {
+                0 => DEFAULT_RUNTIME_FUEL,
+                f => f,
+            };
             if let Ok(remaining) = store.get_fuel() {
-                let used = store.data().initial_fuel.saturating_sub(remaining);
                 if let Some(obj) = metrics.as_object_mut() {
-                    obj.insert("fuelUsed".into(), serde_json::json!(used));
+                    obj.insert("fuelBudget".into(), serde_json::json!(budget));
+                    obj.insert(
+                        "fuelUsed".into(),
+                        serde_json::json!(budget.saturating_sub(remaining)),
+                    );
                 }
             }
         }
@@ -2629,7 +5014,12 @@ mod with_runtime {
     mod tests {
         use super::*;
         use crate::compute::compute_input::{fs_read_allowed, sanitize_ws_files_path};
-        use crate::security::policy::{ComputeCapabilitiesSpec, ComputeProvenanceSpec};
+        use crate::security::policy::{
+            ComputeCapabilitiesSpec, ComputePriority, ComputeProvenanceSpec,
+        };
+
+        // Smallest valid component binary: no imports, no exports.
+        const EMPTY_COMPONENT: &[u8] = &[0x00, 0x61, 0x73, 0x6d, 0x0d, 0x00, 0x01, 0x00];
 
         #[test]
         fn sanitize_ws_files_path_blocks_traversal_and_maps_under_files_dir() {
@@ -2698,6 +5088,7 @@ mod with_runtime {
                 golden_key: None,
                 artifact_id: None,
                 expect_golden: false,
+                priority: ComputePriority::Normal,
             };
             assert!(fs_read_allowed(&spec, "ws:/files/sub/file.txt"));
             spec.capabilities.fs_read = vec!["ws:/files/sub/file.txt".into()];
@@ -2718,6 +5109,21 @@ mod with_runtime {
             assert_eq!(code, error_codes::CAPABILITY_DENIED);
         }
 
+        #[test]
+        fn trap_mapping_distinguishes_fuel_exhaustion_from_timeout() {
+            let typed = anyhow::Error::new(wasmtime::Trap::OutOfFuel).context("call csv#run");
+            assert_eq!(map_trap_error(&typed).0, error_codes::FUEL_EXHAUSTED);
+
+            let typed = anyhow::Error::new(wasmtime::Trap::Interrupt);
+            assert_eq!(map_trap_error(&typed).0, error_codes::TIMEOUT);
+
+            // Text-only fallback (trap re-wrapped via format!), even alongside deadline wording.
+            let text = anyhow::anyhow!(
+                "E-UICP-0225: call csv#run failed: all fuel consumed by WebAssembly before deadline"
+            );
+            assert_eq!(map_trap_error(&text).0, error_codes::FUEL_EXHAUSTED);
+        }
+
         #[test]
         fn trap_mapping_classifies_missing_imports_as_task_not_found() {
             // WHY: Prove we classify import/linkage failures distinctly so callers can react.
@@ -2727,6 +5133,96 @@ mod with_runtime {
             assert_eq!(code, error_codes::TASK_NOT_FOUND);
         }
 
+        #[test]
+        fn import_policy_prefers_manifest_declared_list() {
+            let declared = vec![
+                "wasi:io/streams@0.2.6".to_string(),
+                "uicp:host/control@1.0.0".to_string(),
+            ];
+            let allowed =
+                resolve_import_policy("custom.task@0.1.0", Some(&declared)).expect("declared");
+            assert!(allowed.contains("wasi:io/streams@0.2"));
+            assert!(allowed.contains("uicp:host/control@1.0"));
+            assert_eq!(allowed.len(), 2);
+
+            // Bundled tasks keep their defaults when the manifest omits `imports`.
+            let csv12 = resolve_import_policy("csv.parse@1.2.0", None).expect("csv 1.2");
+            assert!(!csv12.contains("uicp:host/control@1.0"));
+            let csv13 = resolve_import_policy("csv.parse@1.3.0", None).expect("csv 1.3");
+            assert!(csv13.contains("uicp:host/control@1.0"));
+            assert!(csv13.contains("wasi:clocks/monotonic-clock@0.2"));
+            let json_query = resolve_import_policy("json.query@0.1.0", None).expect("json.query");
+            assert!(json_query.contains("uicp:host/control@1.0"));
+            assert!(!json_query.contains("wasi:random/random@0.2"));
+            let markdown = resolve_import_policy("markdown.render@0.1.0", None).expect("markdown");
+            assert!(!markdown.contains("uicp:host/control@1.0"));
+            assert!(markdown.contains("wasi:io/streams@0.2"));
+            let table01 = resolve_import_policy("table.query@0.1.0", None).expect("table 0.1");
+            assert!(table01.contains("uicp:task-table-query/types@0.1"));
+            assert!(!table01.contains("uicp:task-table-query/types@0.2"));
+            let table02 = resolve_import_policy("table.query@0.2.0", None).expect("table 0.2");
+            assert!(table02.contains("uicp:task-table-query/types@0.2"));
+            assert!(!table02.contains("uicp:task-table-query/types@0.1"));
+            for version in ["0.1.0", "0.2.0"] {
+                let task = format!("applet.quickjs@{version}");
+                let applet = resolve_import_policy(&task, None).expect("applet");
+                assert!(!applet.contains("uicp:host/kv@1.2"), "{task}");
+            }
+            let applet = resolve_import_policy("applet.quickjs@0.3.0", None).expect("applet");
+            assert!(applet.contains("uicp:host/kv@1.2"));
+            assert!(!applet.contains("uicp:host/http@1.2"));
+            let err = resolve_import_policy("custom.task@0.1.0", None).unwrap_err();
+            assert!(err.to_string().contains("E-UICP-0229"));
+        }
+
+        #[test]
+        fn table_query_worlds_pass_preflight_with_default_policy() {
+            let world = |types: &str| -> BTreeSet<String> {
+                [
+                    "uicp:host/control@1.0.0",
+                    types,
+                    "wasi:cli/environment@0.2.3",
+                    "wasi:cli/exit@0.2.3",
+                    "wasi:cli/stderr@0.2.3",
+                    "wasi:cli/stdin@0.2.3",
+                    "wasi:cli/stdout@0.2.3",
+                    "wasi:clocks/monotonic-clock@0.2.3",
+                    "wasi:clocks/wall-clock@0.2.3",
+                    "wasi:filesystem/preopens@0.2.3",
+                    "wasi:filesystem/types@0.2.3",
+                    "wasi:io/error@0.2.3",
+                    "wasi:io/streams@0.2.8",
+                ]
+                .into_iter()
+                .map(str::to_string)
+                .collect()
+            };
+            let v01 = world("uicp:task-table-query/types@0.1.0");
+            let v02 = world("uicp:task-table-query/types@0.2.0");
+            check_component_imports("table.query@0.1.0", v01.clone(), None).expect("0.1 world");
+            check_component_imports("table.query@0.2.0", v02.clone(), None).expect("0.2 world");
+
+            // Each version requires its own types package and refuses the other world's.
+            let err = check_component_imports("table.query@0.2.0", v01, None).unwrap_err();
+            assert!(err.to_string().contains("E-UICP-0230"), "{err}");
+            let err = check_component_imports("table.query@0.1.0", v02, None).unwrap_err();
+            assert!(err.to_string().contains("E-UICP-0230"), "{err}");
+        }
+
+        #[test]
+        fn import_policy_ceiling_rejects_sockets_http_and_unknown_namespaces() {
+            for bad in [
+                "wasi:sockets/tcp@0.2.0",
+                "wasi:http/outgoing-handler@0.2.0",
+                "acme:backdoor/shell",
+            ] {
+                let declared = vec!["wasi:io/streams@0.2".to_string(), bad.to_string()];
+                let err = resolve_import_policy("custom.task@0.1.0", Some(&declared))
+                    .expect_err("ceiling must reject");
+                assert!(err.to_string().contains("E-UICP-0237"), "{bad}: {err}");
+            }
+        }
+
         #[test]
         fn resolve_csv_source_passes_through_non_workspace_values() {
             let spec = ComputeJobSpec {
@@ -2749,6 +5245,7 @@ mod with_runtime {
                 golden_key: None,
                 artifact_id: None,
                 expect_golden: false,
+                priority: ComputePriority::Normal,
             };
             let original = "data:text/csv,foo,bar";
             let resolved = resolve_csv_source(&spec, original).expect("passthrough");
@@ -2789,6 +5286,7 @@ mod with_runtime {
                 golden_key: None,
                 artifact_id: None,
                 expect_golden: false,
+                priority: ComputePriority::Normal,
             };
             let ws_path = "ws:/files/tests/resolve_csv_source.csv";
             let resolved = resolve_csv_source(&spec_ok, ws_path).expect("resolves");
@@ -3002,6 +5500,12 @@ mod with_runtime {
                     partial_rate: Arc::new(Mutex::new(RateLimiterEvents::new(10, 10))),
                     partial_throttle_waits: Arc::new(AtomicU64::new(0)),
                     limits,
+                    pool_hit: false,
+                    net: None,
+                    kv: None,
+                    action_log: None,
+                    pause_rx: None,
+                    pause: Arc::new(PauseClock::default()),
                 },
             );
 
@@ -3022,6 +5526,72 @@ mod with_runtime {
             assert_eq!(part.get("level").and_then(|v| v.as_str()), Some("info"));
         }
 
+        #[test]
+        fn uicp_log_write_stores_entry_before_partial() {
+            use wasmtime::AsContextMut;
+
+            struct Partials(Arc<Mutex<Vec<serde_json::Value>>>);
+            impl TelemetryEmitter for Partials {
+                fn emit_debug(&self, _payload: serde_json::Value) {}
+                fn emit_partial(&self, _event: ComputePartialEvent) {}
+                fn emit_partial_json(&self, payload: serde_json::Value) {
+                    self.0.lock().push(payload);
+                }
+                fn emit_shutdown(&self) {}
+            }
+
+            let dir = tempfile::tempdir().expect("tempdir");
+            let db_path = dir.path().join("action.db");
+            let partials = Arc::new(Mutex::new(Vec::new()));
+            let mut ctx = make_ctx(
+                "csv.parse@1.3.0",
+                "job-structured",
+                WasiCtxBuilder::new().build(),
+                Arc::new(Partials(partials.clone())),
+                1_000,
+            );
+            ctx.action_log = Some(
+                crate::infrastructure::action_log::ActionLogService::start_with_seed(
+                    &db_path, None,
+                )
+                .expect("action log"),
+            );
+            let mut store: Store<Ctx> = Store::new(&ENGINE, ctx);
+            let write = |store: &mut Store<Ctx>, job: &str, level| {
+                let fields = vec![GuestLogField {
+                    key: "row".into(),
+                    value: "17".into(),
+                }];
+                let fut = host_log_write(
+                    store.as_context_mut(),
+                    (job.into(), level, "bad cell".into(), fields),
+                );
+                block_on(Box::into_pin(fut)).expect("log.write");
+            };
+            write(&mut store, "job-structured", GuestLogLevel::Warn);
+            // A mismatched job id is dropped rather than attributed to this job.
+            write(&mut store, "job-other", GuestLogLevel::Error);
+
+            let part = partials.lock().pop().expect("one partial emitted");
+            assert!(partials.lock().is_empty());
+            assert_eq!(part["stream"], "uicp-log");
+            assert_eq!(part["level"], "warn");
+            assert_eq!(part["fields"][0]["key"], "row");
+
+            let conn = rusqlite::Connection::open(&db_path).expect("open sqlite");
+            let payload: String = conn
+                .query_row(
+                    "SELECT payload_json FROM action_log WHERE kind = ?1",
+                    [job_logs::LOG_KIND],
+                    |r| r.get(0),
+                )
+                .expect("one structured record");
+            let entry: GuestLogEntry = serde_json::from_str(&payload).expect("entry");
+            assert_eq!(entry.job_id, "job-structured");
+            assert_eq!(entry.seq, part["seq"].as_u64().unwrap());
+            assert_eq!(entry.fields[0].value, "17");
+        }
+
         #[cfg(feature = "uicp_wasi_enable")]
         #[test]
         fn wasi_logging_guest_component_emits_partial_event() {
@@ -3106,6 +5676,12 @@ mod with_runtime {
                         partial_rate: Arc::new(Mutex::new(RateLimiterEvents::new(10, 10))),
                         partial_throttle_waits: Arc::new(AtomicU64::new(0)),
                         limits: LimitsWithPeak::new(64 * 1024 * 1024),
+                        pool_hit: false,
+                        net: None,
+                        kv: None,
+                        action_log: None,
+                        pause_rx: None,
+                        pause: Arc::new(PauseClock::default()),
                     },
                 );
 
@@ -3267,6 +5843,12 @@ mod with_runtime {
                     partial_rate: Arc::new(Mutex::new(RateLimiterEvents::new(10, 10))),
                     partial_throttle_waits: Arc::new(AtomicU64::new(0)),
                     limits: LimitsWithPeak::new(64 * 1024 * 1024),
+                    pool_hit: false,
+                    net: None,
+                    kv: None,
+                    action_log: None,
+                    pause_rx: None,
+                    pause: Arc::new(PauseClock::default()),
                 },
             );
 
@@ -3327,6 +5909,12 @@ mod with_runtime {
                     partial_rate: Arc::new(Mutex::new(RateLimiterEvents::new(10, 10))),
                     partial_throttle_waits: Arc::new(AtomicU64::new(0)),
                     limits: LimitsWithPeak::new(64 * 1024 * 1024),
+                    pool_hit: false,
+                    net: None,
+                    kv: None,
+                    action_log: None,
+                    pause_rx: None,
+                    pause: Arc::new(PauseClock::default()),
                 },
             );
             let (r1,) = host_remaining_ms(store.as_context_mut(), ("clock-job".into(),)).unwrap();
@@ -3385,6 +5973,12 @@ mod with_runtime {
                     partial_rate: Arc::new(Mutex::new(RateLimiterEvents::new(10, 10))),
                     partial_throttle_waits: Arc::new(AtomicU64::new(0)),
                     limits,
+                    pool_hit: false,
+                    net: None,
+                    kv: None,
+                    action_log: None,
+                    pause_rx: None,
+                    pause: Arc::new(PauseClock::default()),
                 },
             );
 
@@ -3409,6 +6003,300 @@ mod with_runtime {
             // memPeakMb should be at least 5
             let mem_peak = m.get("memPeakMb").and_then(|v| v.as_i64()).unwrap_or(0);
             assert!(mem_peak >= 5);
+            let series = m
+                .get("memSeries")
+                .and_then(|v| v.as_array())
+                .expect("memSeries");
+            assert_eq!(
+                series
+                    .last()
+                    .and_then(|p| p.get("memBytes"))
+                    .and_then(|v| v.as_u64()),
+                Some(5 * 1024 * 1024)
+            );
+            assert_eq!(m.get("poolHit").and_then(|v| v.as_bool()), Some(false));
+        }
+
+        #[test]
+        fn memory_thresholds_fire_once_and_series_stays_bounded() {
+            use wasmtime::ResourceLimiter;
+
+            const MIB: usize = 1024 * 1024;
+            let mut limits = LimitsWithPeak::new(10 * MIB);
+            limits.memory_growing(0, 4 * MIB, None).unwrap();
+            assert!(limits.take_crossed_thresholds().is_empty());
+            limits.memory_growing(4 * MIB, 8 * MIB, None).unwrap();
+            assert_eq!(limits.take_crossed_thresholds(), vec![50, 75]);
+            assert!(limits.take_crossed_thresholds().is_empty());
+            // Denied growth is not usage.
+            assert!(!limits.memory_growing(8 * MIB, 12 * MIB, None).unwrap());
+            assert!(limits.take_crossed_thresholds().is_empty());
+            limits.memory_growing(8 * MIB, 10 * MIB, None).unwrap();
+            assert_eq!(limits.take_crossed_thresholds(), vec![90]);
+
+            for t in 0..(MEM_SERIES_CAPACITY as u64 * 4) {
+                let mut sample = limits.sample(t * 10);
+                if t == 1_000 {
+                    sample.mem_bytes = 11 * MIB;
+                }
+                limits.series.record(sample);
+            }
+            let samples = &limits.series.samples;
+            assert!(samples.len() <= MEM_SERIES_CAPACITY);
+            assert_eq!(samples.first().map(|s| s.t_ms), Some(0));
+            assert!(samples.last().map(|s| s.t_ms).unwrap() > 19_000);
+            let reported = limits.series.downsample(MEM_SERIES_POINTS);
+            assert!(reported.len() <= MEM_SERIES_POINTS);
+            assert!(
+                reported.iter().any(|s| s.mem_bytes == 11 * MIB),
+                "peak survives downsampling"
+            );
+        }
+
+        #[test]
+        fn instance_pool_refills_and_resets_ctx_on_checkout() {
+            let component = Component::new(&ENGINE, EMPTY_COMPONENT).expect("empty component");
+            let pool = task_pool("pool.test@0.0.1|00", &component).expect("pool");
+            block_on(refill_pool(pool.clone(), "pool.test@0.0.1".into(), 2));
+            assert_eq!(pool.idle.lock().len(), 2);
+
+            let mut pooled = checkout_pooled(&pool, 64 * 1024 * 1024).expect("pooled entry");
+            let emitter: Arc<dyn TelemetryEmitter> = Arc::new(NullTelemetry);
+            let mut ctx = make_ctx(
+                "pool.test@0.0.1",
+                "job-a",
+                WasiCtxBuilder::new().build(),
+                emitter,
+                5_000,
+            );
+            ctx.rng_seed = [7; 32];
+            reset_pooled_store(&mut pooled.store, ctx);
+            let data = pooled.store.data();
+            assert!(data.pool_hit);
+            assert_eq!(data.job_id, "job-a");
+            assert_eq!(data.rng_seed, [7; 32]);
+            assert_eq!(data.rng_counter, 0);
+            assert_eq!(data.deadline_ms, 5_000);
+
+            // Entries whose instantiation-time memory exceeds the job limit are never handed out.
+            pool.idle.lock()[0].store.data_mut().limits.mem_peak_bytes = 128 * 1024 * 1024;
+            assert!(checkout_pooled(&pool, 64 * 1024 * 1024).is_none());
+            assert!(pool.idle.lock().is_empty());
+        }
+
+        #[test]
+        fn paused_time_is_excluded_from_deadline_and_reported() {
+            use wasmtime::AsContextMut;
+
+            let emitter: Arc<dyn TelemetryEmitter> = Arc::new(NullTelemetry);
+            let mut store: Store<Ctx> = Store::new(
+                &ENGINE,
+                make_ctx(
+                    "pause.test@0.1.0",
+                    "pause-job",
+                    WasiCtxBuilder::new().build(),
+                    emitter,
+                    1_000,
+                ),
+            );
+            store.data().pause.park();
+            store.data().pause.park(); // re-parking an open interval is a no-op
+            let active_at_park = store.data().active_elapsed_ms();
+            std::thread::sleep(std::time::Duration::from_millis(60));
+            assert!(store.data().active_elapsed_ms() <= active_at_park + 2);
+            store.data().pause.unpark();
+            store.data().pause.unpark();
+
+            let paused = store.data().pause.total_ms();
+            assert!(paused >= 60, "paused {paused}ms");
+            let wall = u64::try_from(store.data().started.elapsed().as_millis()).unwrap();
+            let (remaining,) =
+                host_remaining_ms(store.as_context_mut(), ("pause-job".into(),)).unwrap();
+            assert!(u64::from(remaining) + 2 >= (1_000 + paused).saturating_sub(wall));
+
+            let metrics = collect_metrics(&store);
+            assert_eq!(
+                metrics.get("pausedMs").and_then(|v| v.as_u64()),
+                Some(paused)
+            );
+            assert_eq!(metrics.get("pauseCount").and_then(|v| v.as_u64()), Some(1));
+        }
+
+        #[test]
+        fn http_fetch_denies_hosts_outside_the_net_grant() {
+            use crate::compute::hostctx::{InMemorySink, Limits, PolicyMap, PolicyStore};
+            use wasmtime::AsContextMut;
+
+            struct EmptyPolicy;
+            impl PolicyStore for EmptyPolicy {
+                fn load(&self) -> PolicyMap {
+                    PolicyMap::new()
+                }
+            }
+            fn fetch(store: &mut Store<Ctx>, job: &str, url: &str) -> Result<HttpResponse, String> {
+                let call = host_http_fetch(
+                    store.as_context_mut(),
+                    (job.into(), "GET".into(), url.into(), Vec::new(), None),
+                );
+                block_on(Box::into_pin(call)).expect("host call").0
+            }
+
+            let emitter: Arc<dyn TelemetryEmitter> = Arc::new(NullTelemetry);
+            let mut store: Store<Ctx> = Store::new(
+                &ENGINE,
+                make_ctx(
+                    "fetch.test@0.1.0",
+                    "net-job",
+                    WasiCtxBuilder::new().build(),
+                    emitter,
+                    5_000,
+                ),
+            );
+            let err = fetch(&mut store, "net-job", "https://api.example.com/").unwrap_err();
+            assert!(err.contains("not granted"), "{err}");
+
+            let receipts = Arc::new(parking_lot::RwLock::new(Vec::new()));
+            let grant = Arc::new(NetGrant {
+                rules: NetRule::parse("https://api.example.com/v1")
+                    .into_iter()
+                    .collect(),
+                installed_id: "compute:fetch.test@0.1.0".into(),
+                host: HostCtx::test(
+                    reqwest::Client::new(),
+                    Arc::new(EmptyPolicy),
+                    Arc::new(InMemorySink(receipts.clone())),
+                    Limits::default(),
+                ),
+                requests: AtomicU64::new(0),
+            });
+            store.data_mut().net = Some(grant.clone());
+
+            let err = fetch(&mut store, "net-job", "https://evil.example.net/data").unwrap_err();
+            assert!(
+                err.starts_with("PolicyDenied: api:NET:evil.example.net"),
+                "{err}"
+            );
+            let err = fetch(&mut store, "other-job", "https://api.example.com/").unwrap_err();
+            assert_eq!(err, "job id mismatch");
+            let err = fetch(&mut store, "net-job", "https://api.example.com/admin").unwrap_err();
+            assert!(
+                err.starts_with("PolicyDenied: api:NET:api.example.com/admin"),
+                "{err}"
+            );
+            let err = fetch(&mut store, "net-job", "http://api.example.com/v1").unwrap_err();
+            assert!(err.contains("requires https"), "{err}");
+            let err = fetch(&mut store, "net-job", "not a url").unwrap_err();
+            assert!(err.starts_with("InvalidUrl"), "{err}");
+
+            // Denied calls never reach the egress core: no requests counted, no receipts written.
+            assert_eq!(grant.requests.load(Ordering::Relaxed), 0);
+            assert!(receipts.read().is_empty());
+            let metrics = collect_metrics(&store);
+            assert_eq!(metrics.get("netRequests").and_then(|v| v.as_u64()), Some(0));
+        }
+
+        #[test]
+        fn aot_cache_roundtrips_and_discards_tampered_artifacts() {
+            let modules = tempfile::tempdir().expect("tempdir");
+            let cache_dir = tempfile::tempdir().expect("tempdir");
+            let wasm = modules.path().join("empty@0.0.1.wasm");
+            std::fs::write(&wasm, EMPTY_COMPONENT).expect("write wasm");
+            let digest = hex::encode(Sha256::digest(EMPTY_COMPONENT));
+            let cache = AotCache::open(cache_dir.path().to_path_buf()).expect("open cache");
+
+            cache
+                .load_or_compile(&wasm, &digest)
+                .expect("compile + store");
+            let artifact = cache.artifact_path(&digest);
+            assert!(artifact.exists(), "artifact persisted");
+            assert!(aot_mac_path(&artifact).exists(), "mac persisted");
+            assert!(artifact
+                .to_string_lossy()
+                .contains(ENGINE_FINGERPRINT.as_str()));
+            assert_eq!(
+                std::fs::read_dir(modules.path()).unwrap().count(),
+                1,
+                "modules dir holds only the module"
+            );
+            assert!(cache.try_load(&digest).is_some());
+
+            // The MAC binds the module digest: the same pair renamed to another digest is refused.
+            let other = "00".repeat(32);
+            let renamed = cache.artifact_path(&other);
+            std::fs::copy(&artifact, &renamed).expect("copy artifact");
+            std::fs::copy(aot_mac_path(&artifact), aot_mac_path(&renamed)).expect("copy mac");
+            assert!(cache.try_load(&other).is_none());
+            assert!(!renamed.exists());
+
+            // A replaced artifact with a recomputed unkeyed checksum must never be deserialized.
+            let forged = b"not an artifact";
+            std::fs::write(&artifact, forged).expect("tamper");
+            std::fs::write(aot_mac_path(&artifact), hex::encode(Sha256::digest(forged)))
+                .expect("forge sidecar");
+            assert!(cache.try_load(&digest).is_none());
+            assert!(!artifact.exists());
+            cache.load_or_compile(&wasm, &digest).expect("recompile");
+            assert!(cache.try_load(&digest).is_some());
+
+            // Another key (e.g. a different host) cannot vouch for these artifacts.
+            let foreign = AotCache {
+                dir: cache_dir.path().to_path_buf(),
+                key: [7u8; 32],
+            };
+            assert!(foreign.try_load(&digest).is_none());
+        }
+
+        #[test]
+        fn aot_unverified_reports_missing_and_forged_sidecars() {
+            let modules = tempfile::tempdir().expect("tempdir");
+            let cache_dir = tempfile::tempdir().expect("tempdir");
+            let wasm = modules.path().join("empty@0.0.1.wasm");
+            std::fs::write(&wasm, EMPTY_COMPONENT).expect("write wasm");
+            let digest = hex::encode(Sha256::digest(EMPTY_COMPONENT));
+            let cache = AotCache::open(cache_dir.path().to_path_buf()).expect("open cache");
+            cache
+                .load_or_compile(&wasm, &digest)
+                .expect("compile + store");
+            assert!(cache.unverified().is_empty());
+
+            let orphan = cache.artifact_path(&"00".repeat(32));
+            std::fs::copy(cache.artifact_path(&digest), &orphan).expect("copy artifact");
+            let artifact = cache.artifact_path(&digest);
+            std::fs::write(aot_mac_path(&artifact), "00".repeat(32)).expect("forge sidecar");
+
+            let mut found = cache.unverified();
+            found.sort_by(|a, b| a.digest_sha256.cmp(&b.digest_sha256));
+            let reasons: Vec<(&str, &str)> = found
+                .iter()
+                .map(|a| (a.digest_sha256.as_str(), a.reason))
+                .collect();
+            assert_eq!(
+                reasons,
+                vec![
+                    ("00".repeat(32).as_str(), "missing sidecar"),
+                    (digest.as_str(), "mac mismatch"),
+                ]
+            );
+            assert!(artifact.exists(), "reporting leaves artifacts in place");
+        }
+
+        #[test]
+        fn aot_prune_keeps_temp_files_and_current_fingerprint() {
+            let dir = tempfile::tempdir().expect("tempdir");
+            let stale = dir.path().join(format!("{}-0000.cwasm", "ab".repeat(32)));
+            let current =
+                dir.path()
+                    .join(format!("{}-{}.cwasm", "ab".repeat(32), *ENGINE_FINGERPRINT));
+            let in_flight = dir.path().join(".tmpA1b2C3");
+            let key = dir.path().join(AOT_KEY_FILE);
+            for path in [&stale, &current, &in_flight, &key] {
+                std::fs::write(path, b"x").unwrap();
+            }
+            prune_stale_aot_artifacts(dir.path());
+            assert!(!stale.exists());
+            assert!(current.exists());
+            assert!(in_flight.exists());
+            assert!(key.exists());
         }
     }
 }
@@ -3416,7 +6304,8 @@ mod with_runtime {
 #[cfg(feature = "wasm_compute")]
 #[allow(unused_imports)]
 pub use with_runtime::{
-    component_import_names, preflight_component_imports, prewarm_quickjs, verify_component_contract,
+    component_import_names, preflight_component_imports_with, prewarm_quickjs,
+    verify_component_contract,
 };
 
 #[cfg(not(feature = "wasm_compute"))]
@@ -3464,30 +6353,8 @@ mod no_runtime {
                         "task": spec.task,
                     }));
                     crate::emit_or_log(&app, crate::infrastructure::events::EVENT_COMPUTE_RESULT_FINAL, payload.clone());
-                    if spec.replayable && spec.cache == "readwrite" {
-                        let use_v2 = std::env::var("UICP_CACHE_V2")
-                            .ok()
-                            .map(|v| matches!(v.as_str(), "1" | "true" | "TRUE" | "on" | "yes"))
-                            .unwrap_or(false);
-                        let module_meta = crate::compute::registry::find_module(&app, &spec.task).ok().flatten();
-                        let invariants = {
-                            let mut parts: Vec<String> = Vec::new();
-                            if let Some(m) = &module_meta {
-                                parts.push(format!("modsha={}", m.entry.digest_sha256));
-                                parts.push(format!("modver={}", m.entry.version));
-                                if let Some(world) = m.provenance.as_ref().and_then(|p| p.wit_world.clone()) {
-                                    if !world.is_empty() { parts.push(format!("world={}", world)); }
-                                }
-                                parts.push("abi=wasi-p2".to_string());
-                            }
-                            if let Ok(pver) = std::env::var("UICP_POLICY_VERSION") { if !pver.is_empty() { parts.push(format!("policy={}", pver)); } }
-                            parts.join("|")
-                        };
-                        let key = if use_v2 {
-                            crate::compute::compute_cache::compute_key_v2_plus(&spec, &spec.input, &invariants)
-                        } else {
-                            crate::compute::compute_cache::compute_key(&spec.task, &spec.input, &spec.provenance.env_hash)
-                        };
+                    if spec.replayable && spec.cache == "readwrite" && spec.cacheable() {
+                        let key = crate::compute::compute_cache::job_cache_key(&app, &spec, &spec.input);
                         let mut obj = serde_json::to_value(&payload).unwrap_or(serde_json::json!({}));
                         if let Some(map) = obj.as_object_mut() {
                             map.insert(
@@ -3525,3 +6392,109 @@ pub fn spawn_job<R: Runtime>(
         no_runtime::spawn_job(app, spec, permit, queue_wait_ms)
     }
 }
+
+/// Process-wide instance pool counters (live pools, pool hits, cold starts).
+pub fn instance_pool_stats() -> serde_json::Value {
+    #[cfg(feature = "wasm_compute")]
+    {
+        with_runtime::instance_pool_stats()
+    }
+    #[cfg(not(feature = "wasm_compute"))]
+    {
+        serde_json::json!({ "pools": 0, "hits": 0, "coldStarts": 0 })
+    }
+}
+
+/// An AOT artifact whose `.cwasm.mac` sidecar is missing or does not verify.
+#[derive(Debug, Clone)]
+pub struct UnverifiedAotArtifact {
+    pub digest_sha256: String,
+    pub path: std::path::PathBuf,
+    pub reason: &'static str,
+}
+
+/// Check every AOT artifact compiled under the current engine against its sidecar MAC.
+pub fn unverified_aot_artifacts() -> Vec<UnverifiedAotArtifact> {
+    #[cfg(feature = "wasm_compute")]
+    {
+        with_runtime::unverified_aot_artifacts()
+    }
+    #[cfg(not(feature = "wasm_compute"))]
+    {
+        Vec::new()
+    }
+}
+
+/// Forget what was built from the manifest `entry`: its instance pool and, unless another entry
+/// still names the same digest, its AOT artifact. Called on uninstall and quarantine.
+pub fn evict_module(entry: &crate::compute::registry::ModuleEntry, digest_in_use: bool) {
+    #[cfg(feature = "wasm_compute")]
+    {
+        with_runtime::evict_instance_pool(entry);
+        if !digest_in_use {
+            with_runtime::remove_aot_artifact(&entry.digest_sha256);
+        }
+    }
+    #[cfg(not(feature = "wasm_compute"))]
+    {
+        let _ = (entry, digest_in_use);
+    }
+}
+
+/// Open (or reload) the persistent `applet.quickjs` session for a window. `uicp:host/kv` calls
+/// from the session read and write `(workspace_id, window_id)` state.
+pub async fn applet_session_open<R: Runtime>(
+    app: &tauri::AppHandle<R>,
+    workspace_id: &str,
+    window_id: &str,
+    task: &str,
+    source: &str,
+    mem_limit_mb: Option<u64>,
+) -> anyhow::Result<()> {
+    #[cfg(feature = "wasm_compute")]
+    {
+        with_runtime::applet_session_open(app, workspace_id, window_id, task, source, mem_limit_mb)
+            .await
+    }
+    #[cfg(not(feature = "wasm_compute"))]
+    {
+        let _ = (app, workspace_id, window_id, task, source, mem_limit_mb);
+        Err(anyhow::anyhow!(
+            "E-UICP-0254: Wasm compute runtime disabled in this build; recompile with feature wasm_compute"
+        ))
+    }
+}
+
+/// Call `init`/`render`/`on-event` on a window's applet session. `input` has the
+/// `applet.quickjs` job shape minus `source`; `task` must match the session's task.
+pub async fn applet_session_call(
+    window_id: &str,
+    task: &str,
+    input: &serde_json::Value,
+) -> anyhow::Result<serde_json::Value> {
+    #[cfg(feature = "wasm_compute")]
+    {
+        let input = extract_script_input(input)?;
+        with_runtime::applet_session_call(window_id, task, &input).await
+    }
+    #[cfg(not(feature = "wasm_compute"))]
+    {
+        let _ = (task, input);
+        Err(anyhow::anyhow!(
+            "E-UICP-0255: no applet session for window '{window_id}'"
+        ))
+    }
+}
+
+/// Dispose a window's applet session. Returns whether one was open.
+pub fn applet_session_dispose(window_id: &str) -> bool {
+    #[cfg(feature = "wasm_compute")]
+    {
+        with_runtime::applet_session_dispose(window_id)
+    }
+    #[cfg(not(feature = "wasm_compute"))]
+    {
+        let _ = window_id;
+        false
+    }
+}
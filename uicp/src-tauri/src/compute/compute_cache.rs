@@ -184,6 +184,47 @@ pub fn compute_key_v2_plus(spec: &ComputeJobSpec, input: &Value, invariants: &st
     hex::encode(digest)
 }
 
+/// Cache key for a job's final event. The resolved module's digest and version are always part of
+/// the key, so a reinstall or a different version selected by a semver requirement never reuses
+/// another module's results; `UICP_CACHE_V2` additionally binds `ws:/files` input contents.
+pub fn job_cache_key<R: Runtime>(
+    app: &tauri::AppHandle<R>,
+    spec: &ComputeJobSpec,
+    input: &Value,
+) -> String {
+    let use_v2 = std::env::var("UICP_CACHE_V2")
+        .ok()
+        .map(|v| matches!(v.as_str(), "1" | "true" | "TRUE" | "on" | "yes"))
+        .unwrap_or(false);
+    let module_meta = crate::compute::registry::find_module(app, &spec.task)
+        .ok()
+        .flatten();
+    if !use_v2 {
+        let task = match &module_meta {
+            Some(m) => format!("{}#{}", spec.task, m.entry.digest_sha256),
+            None => spec.task.clone(),
+        };
+        return compute_key(&task, input, &spec.provenance.env_hash);
+    }
+    let mut parts: Vec<String> = Vec::new();
+    if let Some(m) = &module_meta {
+        parts.push(format!("modsha={}", m.entry.digest_sha256));
+        parts.push(format!("modver={}", m.entry.version));
+        if let Some(world) = m.provenance.as_ref().and_then(|p| p.wit_world.clone()) {
+            if !world.is_empty() {
+                parts.push(format!("world={}", world));
+            }
+        }
+        parts.push("abi=wasi-p2".to_string());
+    }
+    if let Ok(pver) = std::env::var("UICP_POLICY_VERSION") {
+        if !pver.is_empty() {
+            parts.push(format!("policy={}", pver));
+        }
+    }
+    compute_key_v2_plus(spec, input, &parts.join("|"))
+}
+
 /// Golden artifact lookup result.
 #[derive(Debug, Clone, PartialEq)]
 pub struct GoldenRecord {
@@ -194,7 +235,9 @@ pub struct GoldenRecord {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::security::policy::{ComputeCapabilitiesSpec, ComputeJobSpec, ComputeProvenanceSpec};
+    use crate::security::policy::{
+        ComputeCapabilitiesSpec, ComputeJobSpec, ComputePriority, ComputeProvenanceSpec,
+    };
     use serde_json::Number;
 
     #[test]
@@ -382,6 +425,7 @@ mod tests {
             golden_key: None,
             artifact_id: None,
             expect_golden: false,
+            priority: ComputePriority::Normal,
         };
 
         let input = serde_json::json!({"x": 1});
@@ -2,7 +2,7 @@
 // WHY: compute_input helpers back the wasm runtime; keep them compiling (and testable) even when
 // WHY: the runtime feature is disabled without surfacing dead_code warnings during desktop builds.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use base64::engine::general_purpose::STANDARD as BASE64_ENGINE;
 use base64::Engine as _;
@@ -66,118 +66,1082 @@ pub fn extract_csv_input(input: &serde_json::Value) -> Result<(String, bool), Ta
     Ok((source, has_header))
 }
 
+/// Rows per `csv.parse@1.3` partial frame when the caller does not set `batchRows`.
+pub const DEFAULT_CSV_BATCH_ROWS: u32 = 100;
+
+/// Text encoding `csv.parse@1.3` can decode; mirrors the WIT `encoding` enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsvEncoding {
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+    Latin1,
+    Windows1252,
+}
+
+impl CsvEncoding {
+    fn parse(label: &str) -> Option<Self> {
+        Some(match label.to_ascii_lowercase().as_str() {
+            "utf-8" | "utf8" => Self::Utf8,
+            "utf-16le" => Self::Utf16Le,
+            "utf-16be" => Self::Utf16Be,
+            "iso-8859-1" | "latin1" | "latin-1" => Self::Latin1,
+            "windows-1252" | "cp1252" => Self::Windows1252,
+            _ => return None,
+        })
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Utf8 => "utf-8",
+            Self::Utf16Le => "utf-16le",
+            Self::Utf16Be => "utf-16be",
+            Self::Latin1 => "iso-8859-1",
+            Self::Windows1252 => "windows-1252",
+        }
+    }
+}
+
+/// Parsed `csv.parse@1.3` input. `None` delimiter/quote asks the module to sniff the dialect.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvParseInput {
+    pub source: String,
+    pub has_header: bool,
+    pub delimiter: Option<u8>,
+    pub quote: Option<u8>,
+    pub encodings: Vec<CsvEncoding>,
+    pub infer_types: bool,
+    pub max_rows: Option<u32>,
+    pub max_bytes: Option<u64>,
+    pub batch_rows: u32,
+}
+
+impl CsvParseInput {
+    /// Canonical JSON form; parsing it again yields the same input.
+    pub fn to_json(&self) -> serde_json::Value {
+        let byte_str = |b: Option<u8>| b.map(|b| char::from(b).to_string());
+        serde_json::json!({
+            "source": self.source,
+            "hasHeader": self.has_header,
+            "delimiter": byte_str(self.delimiter),
+            "quote": byte_str(self.quote),
+            "encoding": self.encodings.iter().map(|e| e.as_str()).collect::<Vec<_>>(),
+            "inferTypes": self.infer_types,
+            "maxRows": self.max_rows,
+            "maxBytes": self.max_bytes,
+            "batchRows": self.batch_rows,
+        })
+    }
+}
+
+/// `csv.parse@1.3` and later take parse options and stream row batches; 1.2 keeps the
+/// `(input, has-header)` world.
+pub fn csv_parse_streaming(task: &str) -> bool {
+    task.split_once('@')
+        .and_then(|(_, ver)| semver::Version::parse(ver).ok())
+        .map(|v| (v.major, v.minor) >= (1, 3))
+        .unwrap_or(false)
+}
+
+/// WHY: Reject bad dialect/limit options before instantiation so callers see
+/// `Compute.Input.Invalid` instead of a module fault.
+pub fn extract_csv_parse_input(input: &serde_json::Value) -> Result<CsvParseInput, TaskInputError> {
+    let (source, has_header) = extract_csv_input(input)?;
+    let obj = input
+        .as_object()
+        .ok_or_else(|| TaskInputError::invalid("csv.parse input must be an object"))?;
+
+    let delimiter = csv_dialect_byte(obj, "delimiter")?;
+    let quote = csv_dialect_byte(obj, "quote")?;
+    if let (Some(d), Some(q)) = (delimiter, quote) {
+        if d == q {
+            return Err(TaskInputError::invalid(
+                "csv.parse delimiter and quote must differ",
+            ));
+        }
+    }
+
+    let encodings = match obj.get("encoding") {
+        None | Some(serde_json::Value::Null) => Vec::new(),
+        Some(serde_json::Value::String(label)) => vec![csv_encoding(label)?],
+        Some(serde_json::Value::Array(labels)) => labels
+            .iter()
+            .map(|v| {
+                v.as_str().map(csv_encoding).unwrap_or_else(|| {
+                    Err(TaskInputError::invalid(
+                        "csv.parse encoding entries must be strings",
+                    ))
+                })
+            })
+            .collect::<Result<_, _>>()?,
+        Some(_) => {
+            return Err(TaskInputError::invalid(
+                "csv.parse encoding must be a string or an array of strings",
+            ))
+        }
+    };
+
+    let infer_types = match obj.get("inferTypes") {
+        None | Some(serde_json::Value::Null) => false,
+        Some(v) => v
+            .as_bool()
+            .ok_or_else(|| TaskInputError::invalid("csv.parse inferTypes must be a boolean"))?,
+    };
+
+    let max_rows = csv_optional_u64(obj, "maxRows")?
+        .map(|v| {
+            u32::try_from(v).map_err(|_| TaskInputError::invalid("csv.parse maxRows must be a u32"))
+        })
+        .transpose()?;
+    let max_bytes = csv_optional_u64(obj, "maxBytes")?;
+    let batch_rows = csv_optional_u64(obj, "batchRows")?
+        .map(|v| {
+            u32::try_from(v)
+                .map_err(|_| TaskInputError::invalid("csv.parse batchRows must be a u32"))
+        })
+        .transpose()?
+        .unwrap_or(DEFAULT_CSV_BATCH_ROWS);
+
+    Ok(CsvParseInput {
+        source,
+        has_header,
+        delimiter,
+        quote,
+        encodings,
+        infer_types,
+        max_rows,
+        max_bytes,
+        batch_rows,
+    })
+}
+
+// INVARIANT: Dialect bytes are single printable ASCII characters or tab; "auto" means sniff.
+fn csv_dialect_byte(
+    obj: &serde_json::Map<String, serde_json::Value>,
+    key: &str,
+) -> Result<Option<u8>, TaskInputError> {
+    let raw = match obj.get(key) {
+        None | Some(serde_json::Value::Null) => return Ok(None),
+        Some(v) => v
+            .as_str()
+            .ok_or_else(|| TaskInputError::invalid(format!("csv.parse {key} must be a string")))?,
+    };
+    if raw == "auto" {
+        return Ok(None);
+    }
+    match raw.as_bytes() {
+        [b] if *b == b'\t' || b.is_ascii_graphic() || *b == b' ' => Ok(Some(*b)),
+        _ => Err(TaskInputError::invalid(format!(
+            "csv.parse {key} must be \"auto\" or a single ASCII character"
+        ))),
+    }
+}
+
+fn csv_encoding(label: &str) -> Result<CsvEncoding, TaskInputError> {
+    CsvEncoding::parse(label).ok_or_else(|| {
+        TaskInputError::invalid(format!("csv.parse encoding '{label}' is not supported"))
+    })
+}
+
+fn csv_optional_u64(
+    obj: &serde_json::Map<String, serde_json::Value>,
+    key: &str,
+) -> Result<Option<u64>, TaskInputError> {
+    match obj.get(key) {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(v) => v.as_u64().map(Some).ok_or_else(|| {
+            TaskInputError::invalid(format!("csv.parse {key} must be a non-negative integer"))
+        }),
+    }
+}
+
 /// WHY: table.query inputs arrive from orchestrators; enforce schema + keep canonical casing.
 /// INVARIANT: Returned rows retain ordering; select values are u32; where clause mirrors optional input.
 type TableInputParsed = (Vec<Vec<String>>, Vec<u32>, Option<(u32, String)>);
 
+/// Upper bound on predicate-tree nodes (leaves plus `all`/`any` groups) in one query.
+pub const MAX_TABLE_WHERE_NODES: usize = 256;
+const MAX_TABLE_WHERE_DEPTH: usize = 32;
+
+/// Cell value for `table.query@0.2`. Mirrors the WIT `cell` variant without depending on the
+/// runtime bindings so validation and cache canonicalization work with the runtime disabled.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TableCell {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Text(String),
+}
+
+impl TableCell {
+    /// Scalars only; integers that fit `i64` stay integral, other numbers become `Float`.
+    pub fn from_json(value: &serde_json::Value) -> Option<Self> {
+        match value {
+            serde_json::Value::Null => Some(Self::Null),
+            serde_json::Value::Bool(b) => Some(Self::Bool(*b)),
+            serde_json::Value::Number(n) => n
+                .as_i64()
+                .map(Self::Int)
+                .or_else(|| n.as_f64().map(Self::Float)),
+            serde_json::Value::String(s) => Some(Self::Text(s.clone())),
+            serde_json::Value::Array(_) | serde_json::Value::Object(_) => None,
+        }
+    }
+
+    /// Non-finite floats have no JSON form and are reported as `null`.
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::Null => serde_json::Value::Null,
+            Self::Bool(b) => serde_json::Value::Bool(*b),
+            Self::Int(i) => serde_json::Value::from(*i),
+            Self::Float(f) => serde_json::Number::from_f64(*f)
+                .map(serde_json::Value::Number)
+                .unwrap_or(serde_json::Value::Null),
+            Self::Text(s) => serde_json::Value::String(s.clone()),
+        }
+    }
+
+    /// Header rendering: text as-is, scalars in their JSON form, null as `""`.
+    fn header_name(&self) -> String {
+        match self {
+            Self::Null => String::new(),
+            Self::Text(s) => s.clone(),
+            other => other.to_json().to_string(),
+        }
+    }
+}
+
+/// Comparison applied by a `table.query@0.2` `where` leaf.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableCompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+    Contains,
+    Regex,
+}
+
+impl TableCompareOp {
+    fn parse(s: &str) -> Option<Self> {
+        Some(match s {
+            "eq" => Self::Eq,
+            "ne" => Self::Ne,
+            "lt" => Self::Lt,
+            "lte" => Self::Lte,
+            "gt" => Self::Gt,
+            "gte" => Self::Gte,
+            "contains" => Self::Contains,
+            "regex" => Self::Regex,
+            _ => return None,
+        })
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Eq => "eq",
+            Self::Ne => "ne",
+            Self::Lt => "lt",
+            Self::Lte => "lte",
+            Self::Gt => "gt",
+            Self::Gte => "gte",
+            Self::Contains => "contains",
+            Self::Regex => "regex",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TablePredicate {
+    pub col: u32,
+    pub op: TableCompareOp,
+    pub value: TableCell,
+    pub case_insensitive: bool,
+}
+
+/// AND/OR predicate tree with header names already resolved to column indices.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TableWhere {
+    Leaf(TablePredicate),
+    All(Vec<TableWhere>),
+    Any(Vec<TableWhere>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableAggregateOp {
+    Count,
+    Sum,
+    Min,
+    Max,
+    Avg,
+}
+
+impl TableAggregateOp {
+    fn parse(s: &str) -> Option<Self> {
+        Some(match s {
+            "count" => Self::Count,
+            "sum" => Self::Sum,
+            "min" => Self::Min,
+            "max" => Self::Max,
+            "avg" => Self::Avg,
+            _ => return None,
+        })
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Count => "count",
+            Self::Sum => "sum",
+            Self::Min => "min",
+            Self::Max => "max",
+            Self::Avg => "avg",
+        }
+    }
+}
+
+/// `label` names the aggregate column in header output and in later name references.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableAggregate {
+    pub op: TableAggregateOp,
+    pub col: Option<u32>,
+    pub label: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableOrderKey {
+    pub col: u32,
+    pub descending: bool,
+}
+
+/// Parsed `table.query@0.2` input. Column references are indices: `where`, `groupBy` and
+/// aggregates index the source rows; `orderBy` and `select` index the grouped row when grouping
+/// (group-by cells, then aggregate values) and the source row otherwise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableQuery {
+    pub rows: Vec<Vec<TableCell>>,
+    pub has_header: bool,
+    pub select: Vec<u32>,
+    pub where_contains: Option<(u32, String)>,
+    pub where_clause: Option<TableWhere>,
+    pub order_by: Vec<TableOrderKey>,
+    pub group_by: Vec<u32>,
+    pub aggregates: Vec<TableAggregate>,
+    pub limit: Option<u32>,
+    pub offset: u32,
+}
+
+impl TableQuery {
+    pub fn grouped(&self) -> bool {
+        !self.group_by.is_empty() || !self.aggregates.is_empty()
+    }
+
+    /// Canonical JSON form; parsing it again yields the same query.
+    pub fn to_json(&self) -> serde_json::Value {
+        let rows: Vec<Vec<serde_json::Value>> = self
+            .rows
+            .iter()
+            .map(|row| row.iter().map(TableCell::to_json).collect())
+            .collect();
+        let mut obj = serde_json::json!({
+            "rows": rows,
+            "hasHeader": self.has_header,
+            "select": self.select,
+            "orderBy": self
+                .order_by
+                .iter()
+                .map(|k| serde_json::json!({ "col": k.col, "desc": k.descending }))
+                .collect::<Vec<_>>(),
+            "groupBy": self.group_by,
+            "aggregates": self
+                .aggregates
+                .iter()
+                .map(|a| serde_json::json!({ "op": a.op.as_str(), "col": a.col, "as": a.label }))
+                .collect::<Vec<_>>(),
+            "offset": self.offset,
+        });
+        // SAFETY: json! macro with object literal always creates Value::Object
+        let map = obj
+            .as_object_mut()
+            .expect("json! object literal must be Value::Object");
+        if let Some((col, needle)) = &self.where_contains {
+            map.insert(
+                "where_contains".into(),
+                serde_json::json!({ "col": col, "needle": needle }),
+            );
+        }
+        if let Some(clause) = &self.where_clause {
+            map.insert("where".into(), where_to_json(clause));
+        }
+        if let Some(limit) = self.limit {
+            map.insert("limit".into(), serde_json::json!(limit));
+        }
+        obj
+    }
+}
+
+fn where_to_json(node: &TableWhere) -> serde_json::Value {
+    match node {
+        TableWhere::Leaf(p) => serde_json::json!({
+            "col": p.col,
+            "op": p.op.as_str(),
+            "value": p.value.to_json(),
+            "caseInsensitive": p.case_insensitive,
+        }),
+        TableWhere::All(children) => {
+            serde_json::json!({ "all": children.iter().map(where_to_json).collect::<Vec<_>>() })
+        }
+        TableWhere::Any(children) => {
+            serde_json::json!({ "any": children.iter().map(where_to_json).collect::<Vec<_>>() })
+        }
+    }
+}
+
+/// `table.query@0.2` and later carry typed cells; 0.1 keeps the all-string world.
+pub fn table_query_typed_cells(task: &str) -> bool {
+    task.split_once('@')
+        .and_then(|(_, ver)| semver::Version::parse(ver).ok())
+        .map(|v| (v.major, v.minor) >= (0, 2))
+        .unwrap_or(false)
+}
+
+/// `applet.quickjs@0.2+` receives its bundle through `script.load` and can be held across calls;
+/// earlier versions read it from `UICP_SCRIPT_SOURCE_B64` on every call.
+pub fn script_loads_source(task: &str) -> bool {
+    task.split_once('@')
+        .filter(|(name, _)| *name == "applet.quickjs")
+        .and_then(|(_, ver)| semver::Version::parse(ver).ok())
+        .map(|v| (v.major, v.minor) >= (0, 2))
+        .unwrap_or(false)
+}
+
+/// `applet.quickjs@0.3+` bundles keep per-window state through `uicp:host/kv`.
+pub fn applet_uses_kv(task: &str) -> bool {
+    task.split_once('@')
+        .filter(|(name, _)| *name == "applet.quickjs")
+        .and_then(|(_, ver)| semver::Version::parse(ver).ok())
+        .map(|v| (v.major, v.minor) >= (0, 3))
+        .unwrap_or(false)
+}
+
+fn table_input_error(message: impl Into<String>) -> TaskInputError {
+    TaskInputError::new(
+        error_codes::INPUT_INVALID,
+        config_errors::DETAIL_TABLE_INPUT,
+        message,
+    )
+}
+
+/// 0.1 string-cell input. Non-string cells are coerced to `""` to match what existing callers
+/// have always observed; use `table.query@0.2` to keep numbers, booleans and nulls.
 pub fn extract_table_query_input(
     input: &serde_json::Value,
 ) -> Result<TableInputParsed, TaskInputError> {
-    let obj = input.as_object().ok_or_else(|| {
-        TaskInputError::new(
-            error_codes::INPUT_INVALID,
-            config_errors::DETAIL_TABLE_INPUT,
-            "table.query input must be an object",
-        )
+    let obj = table_input_object(input)?;
+    let rows = parse_table_rows(obj, |_, _, cell| {
+        Ok(cell.as_str().unwrap_or("").to_string())
     })?;
-    let rows_val = obj.get("rows").ok_or_else(|| {
-        TaskInputError::new(
-            error_codes::INPUT_INVALID,
-            config_errors::DETAIL_TABLE_INPUT,
-            "table.query input.rows required",
-        )
+    let select = parse_table_select(obj, |v| {
+        v.as_u64().map(|u| u as u32).ok_or_else(|| {
+            table_input_error("table.query select entries must be non-negative integers")
+        })
     })?;
-    let rows = rows_val
-        .as_array()
-        .ok_or_else(|| {
-            TaskInputError::new(
-                error_codes::INPUT_INVALID,
-                config_errors::DETAIL_TABLE_INPUT,
-                "table.query input.rows must be an array",
-            )
-        })?
+    let where_opt = parse_where_contains(obj)?;
+    Ok((rows, select, where_opt))
+}
+
+/// 0.2 typed-cell input with the query stages. Nested arrays/objects in cells are rejected
+/// instead of coerced, header names are resolved to indices, and regexes are compiled up front so
+/// malformed queries fail as `Compute.Input.Invalid` before a module is instantiated.
+pub fn extract_table_query_input_typed(
+    input: &serde_json::Value,
+) -> Result<TableQuery, TaskInputError> {
+    let obj = table_input_object(input)?;
+    let rows = parse_table_rows(obj, |r, c, cell| {
+        TableCell::from_json(cell).ok_or_else(|| {
+            table_input_error(format!(
+                "table.query cell [{r}][{c}] must be null, boolean, number or string"
+            ))
+        })
+    })?;
+    let has_header = obj
+        .get("hasHeader")
+        .or_else(|| obj.get("has_header"))
+        .map(|v| {
+            v.as_bool()
+                .ok_or_else(|| table_input_error("table.query hasHeader must be a boolean"))
+        })
+        .transpose()?
+        .unwrap_or(false);
+    let source_names: Vec<String> = if has_header {
+        rows.first()
+            .map(|header| header.iter().map(TableCell::header_name).collect())
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    let source = ColumnNames {
+        names: &source_names,
+        has_header,
+    };
+
+    let where_contains = parse_where_contains(obj)?;
+    let where_clause = match obj.get("where") {
+        None | Some(serde_json::Value::Null) => None,
+        Some(w) => {
+            let mut budget = MAX_TABLE_WHERE_NODES;
+            Some(parse_where(w, &source, 0, &mut budget)?)
+        }
+    };
+
+    let group_by = optional_array(obj, "groupBy")?
         .iter()
-        .map(|row| {
-            let arr = row.as_array().ok_or_else(|| {
-                TaskInputError::new(
-                    error_codes::INPUT_INVALID,
-                    config_errors::DETAIL_TABLE_INPUT,
-                    "table.query row must be an array",
-                )
-            })?;
-            Ok(arr
+        .map(|v| source.resolve(v, "groupBy"))
+        .collect::<Result<Vec<u32>, _>>()?;
+    let aggregates = optional_array(obj, "aggregates")?
+        .iter()
+        .map(|v| parse_aggregate(v, &source))
+        .collect::<Result<Vec<TableAggregate>, _>>()?;
+
+    // WHY: after grouping, names refer to the grouped row: group-by columns keep their header
+    // names and aggregates are addressed by label.
+    let grouped = !group_by.is_empty() || !aggregates.is_empty();
+    let grouped_names: Vec<String>;
+    let stage = if grouped {
+        grouped_names = group_by
+            .iter()
+            .map(|&col| source_names.get(col as usize).cloned().unwrap_or_default())
+            .chain(aggregates.iter().map(|a| a.label.clone()))
+            .collect();
+        ColumnNames {
+            names: &grouped_names,
+            has_header: true,
+        }
+    } else {
+        source
+    };
+
+    let order_by = optional_array(obj, "orderBy")?
+        .iter()
+        .map(|v| parse_order_key(v, &stage))
+        .collect::<Result<Vec<TableOrderKey>, _>>()?;
+    let select = parse_table_select(obj, |v| stage.resolve(v, "select"))?;
+    let limit = optional_u32(obj, "limit")?;
+    let offset = optional_u32(obj, "offset")?.unwrap_or(0);
+
+    Ok(TableQuery {
+        rows,
+        has_header,
+        select,
+        where_contains,
+        where_clause,
+        order_by,
+        group_by,
+        aggregates,
+        limit,
+        offset,
+    })
+}
+
+/// Header names available for resolving string column references at one query stage.
+#[derive(Clone, Copy)]
+struct ColumnNames<'a> {
+    names: &'a [String],
+    has_header: bool,
+}
+
+impl ColumnNames<'_> {
+    /// Integers are used as-is; strings resolve to the first column with that header name.
+    fn resolve(&self, value: &serde_json::Value, field: &str) -> Result<u32, TaskInputError> {
+        match value {
+            serde_json::Value::Number(n) => n
+                .as_u64()
+                .and_then(|u| u32::try_from(u).ok())
+                .ok_or_else(|| {
+                    table_input_error(format!(
+                        "table.query {field} column index must be a u32, got {n}"
+                    ))
+                }),
+            serde_json::Value::String(name) => {
+                if !self.has_header {
+                    return Err(table_input_error(format!(
+                        "table.query {field} names column '{name}' but hasHeader is false"
+                    )));
+                }
+                self.names
+                    .iter()
+                    .position(|n| n == name)
+                    .map(|idx| idx as u32)
+                    .ok_or_else(|| {
+                        table_input_error(format!("table.query {field}: unknown column '{name}'"))
+                    })
+            }
+            _ => Err(table_input_error(format!(
+                "table.query {field} column must be an index or header name"
+            ))),
+        }
+    }
+
+    fn label(&self, col: u32) -> String {
+        self.names
+            .get(col as usize)
+            .filter(|n| !n.is_empty())
+            .cloned()
+            .unwrap_or_else(|| col.to_string())
+    }
+}
+
+fn parse_where(
+    value: &serde_json::Value,
+    names: &ColumnNames<'_>,
+    depth: usize,
+    budget: &mut usize,
+) -> Result<TableWhere, TaskInputError> {
+    if depth >= MAX_TABLE_WHERE_DEPTH {
+        return Err(table_input_error(format!(
+            "table.query where nests deeper than {MAX_TABLE_WHERE_DEPTH} levels"
+        )));
+    }
+    if *budget == 0 {
+        return Err(table_input_error(format!(
+            "table.query where exceeds {MAX_TABLE_WHERE_NODES} nodes"
+        )));
+    }
+    *budget -= 1;
+    let obj = value
+        .as_object()
+        .ok_or_else(|| table_input_error("table.query where node must be an object"))?;
+    for (key, group) in [("all", true), ("any", false)] {
+        if let Some(children) = obj.get(key) {
+            let children = children
+                .as_array()
+                .ok_or_else(|| {
+                    table_input_error(format!("table.query where.{key} must be an array"))
+                })?
                 .iter()
-                .map(|cell| cell.as_str().unwrap_or("").to_string())
-                .collect::<Vec<String>>())
+                .map(|child| parse_where(child, names, depth + 1, budget))
+                .collect::<Result<Vec<_>, _>>()?;
+            return Ok(if group {
+                TableWhere::All(children)
+            } else {
+                TableWhere::Any(children)
+            });
+        }
+    }
+
+    let col = names.resolve(
+        obj.get("col")
+            .ok_or_else(|| table_input_error("table.query where leaf requires col"))?,
+        "where",
+    )?;
+    let case_insensitive = obj
+        .get("caseInsensitive")
+        .map(|v| {
+            v.as_bool().ok_or_else(|| {
+                table_input_error("table.query where.caseInsensitive must be a boolean")
+            })
         })
-        .collect::<Result<Vec<Vec<String>>, TaskInputError>>()?;
+        .transpose()?
+        .unwrap_or(false);
+    let op_name = match obj.get("op") {
+        None => "eq",
+        Some(v) => v
+            .as_str()
+            .ok_or_else(|| table_input_error("table.query where.op must be a string"))?,
+    };
+    let raw = obj.get("value").unwrap_or(&serde_json::Value::Null);
+    let leaf = |op: TableCompareOp, raw: &serde_json::Value| {
+        predicate(col, op, raw, case_insensitive).map(TableWhere::Leaf)
+    };
+    // WHY: `between` is sugar for an inclusive range; expanding it keeps the guest's op set small.
+    if op_name == "between" {
+        let bounds = raw
+            .as_array()
+            .filter(|b| b.len() == 2)
+            .ok_or_else(|| table_input_error("table.query between value must be [low, high]"))?;
+        return Ok(TableWhere::All(vec![
+            leaf(TableCompareOp::Gte, &bounds[0])?,
+            leaf(TableCompareOp::Lte, &bounds[1])?,
+        ]));
+    }
+    let op = TableCompareOp::parse(op_name).ok_or_else(|| {
+        table_input_error(format!("table.query where.op '{op_name}' is not supported"))
+    })?;
+    leaf(op, raw)
+}
 
-    let select_val = obj.get("select").ok_or_else(|| {
-        TaskInputError::new(
-            error_codes::INPUT_INVALID,
-            config_errors::DETAIL_TABLE_INPUT,
-            "table.query input.select required",
-        )
+fn predicate(
+    col: u32,
+    op: TableCompareOp,
+    raw: &serde_json::Value,
+    case_insensitive: bool,
+) -> Result<TablePredicate, TaskInputError> {
+    let value = TableCell::from_json(raw)
+        .ok_or_else(|| table_input_error("table.query where.value must be a scalar"))?;
+    match (op, &value) {
+        (TableCompareOp::Regex, TableCell::Text(pattern)) => {
+            regex::RegexBuilder::new(pattern)
+                .case_insensitive(case_insensitive)
+                .build()
+                .map_err(|e| {
+                    table_input_error(format!("table.query where regex is invalid: {e}"))
+                })?;
+        }
+        (TableCompareOp::Contains, TableCell::Text(_)) => {}
+        (TableCompareOp::Contains | TableCompareOp::Regex, _) => {
+            return Err(table_input_error(format!(
+                "table.query where.op '{}' requires a string value",
+                op.as_str()
+            )));
+        }
+        (
+            TableCompareOp::Lt | TableCompareOp::Lte | TableCompareOp::Gt | TableCompareOp::Gte,
+            TableCell::Null,
+        ) => {
+            return Err(table_input_error(format!(
+                "table.query where.op '{}' cannot compare against null",
+                op.as_str()
+            )));
+        }
+        _ => {}
+    }
+    Ok(TablePredicate {
+        col,
+        op,
+        value,
+        case_insensitive,
+    })
+}
+
+fn parse_aggregate(
+    value: &serde_json::Value,
+    names: &ColumnNames<'_>,
+) -> Result<TableAggregate, TaskInputError> {
+    let obj = value
+        .as_object()
+        .ok_or_else(|| table_input_error("table.query aggregates entries must be objects"))?;
+    let op_name = obj
+        .get("op")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| table_input_error("table.query aggregate.op must be a string"))?;
+    let op = TableAggregateOp::parse(op_name).ok_or_else(|| {
+        table_input_error(format!(
+            "table.query aggregate.op '{op_name}' is not supported"
+        ))
     })?;
-    let select = select_val
+    let col = match obj.get("col") {
+        None | Some(serde_json::Value::Null) => None,
+        Some(v) => Some(names.resolve(v, "aggregates")?),
+    };
+    if col.is_none() && op != TableAggregateOp::Count {
+        return Err(table_input_error(format!(
+            "table.query aggregate '{op_name}' requires col"
+        )));
+    }
+    let label = match obj.get("as") {
+        Some(v) => v
+            .as_str()
+            .ok_or_else(|| table_input_error("table.query aggregate.as must be a string"))?
+            .to_string(),
+        None => match col {
+            Some(c) => format!("{op_name}({})", names.label(c)),
+            None => op_name.to_string(),
+        },
+    };
+    Ok(TableAggregate { op, col, label })
+}
+
+/// Accepts a bare column reference or `{ "col": ..., "desc": bool }`.
+fn parse_order_key(
+    value: &serde_json::Value,
+    names: &ColumnNames<'_>,
+) -> Result<TableOrderKey, TaskInputError> {
+    match value.as_object() {
+        Some(obj) => {
+            let col = names.resolve(
+                obj.get("col")
+                    .ok_or_else(|| table_input_error("table.query orderBy entry requires col"))?,
+                "orderBy",
+            )?;
+            let descending = obj
+                .get("desc")
+                .map(|v| {
+                    v.as_bool().ok_or_else(|| {
+                        table_input_error("table.query orderBy.desc must be a boolean")
+                    })
+                })
+                .transpose()?
+                .unwrap_or(false);
+            Ok(TableOrderKey { col, descending })
+        }
+        None => Ok(TableOrderKey {
+            col: names.resolve(value, "orderBy")?,
+            descending: false,
+        }),
+    }
+}
+
+fn optional_array<'a>(
+    obj: &'a serde_json::Map<String, serde_json::Value>,
+    key: &str,
+) -> Result<&'a [serde_json::Value], TaskInputError> {
+    match obj.get(key) {
+        None | Some(serde_json::Value::Null) => Ok(&[]),
+        Some(v) => v
+            .as_array()
+            .map(Vec::as_slice)
+            .ok_or_else(|| table_input_error(format!("table.query {key} must be an array"))),
+    }
+}
+
+fn optional_u32(
+    obj: &serde_json::Map<String, serde_json::Value>,
+    key: &str,
+) -> Result<Option<u32>, TaskInputError> {
+    match obj.get(key) {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(v) => v
+            .as_u64()
+            .and_then(|u| u32::try_from(u).ok())
+            .map(Some)
+            .ok_or_else(|| table_input_error(format!("table.query {key} must be a u32"))),
+    }
+}
+
+fn table_input_object(
+    input: &serde_json::Value,
+) -> Result<&serde_json::Map<String, serde_json::Value>, TaskInputError> {
+    input
+        .as_object()
+        .ok_or_else(|| table_input_error("table.query input must be an object"))
+}
+
+fn parse_table_rows<C>(
+    obj: &serde_json::Map<String, serde_json::Value>,
+    parse_cell: impl Fn(usize, usize, &serde_json::Value) -> Result<C, TaskInputError>,
+) -> Result<Vec<Vec<C>>, TaskInputError> {
+    let rows_val = obj
+        .get("rows")
+        .ok_or_else(|| table_input_error("table.query input.rows required"))?;
+    rows_val
         .as_array()
-        .ok_or_else(|| {
-            TaskInputError::new(
-                error_codes::INPUT_INVALID,
-                config_errors::DETAIL_TABLE_INPUT,
-                "table.query input.select must be an array",
-            )
-        })?
+        .ok_or_else(|| table_input_error("table.query input.rows must be an array"))?
         .iter()
-        .map(|v| {
-            v.as_u64()
-                .ok_or_else(|| {
-                    TaskInputError::new(
-                        error_codes::INPUT_INVALID,
-                        config_errors::DETAIL_TABLE_INPUT,
-                        "table.query select entries must be non-negative integers",
-                    )
-                })
-                .map(|u| u as u32)
+        .enumerate()
+        .map(|(r, row)| {
+            let arr = row
+                .as_array()
+                .ok_or_else(|| table_input_error("table.query row must be an array"))?;
+            arr.iter()
+                .enumerate()
+                .map(|(c, cell)| parse_cell(r, c, cell))
+                .collect::<Result<Vec<C>, TaskInputError>>()
         })
-        .collect::<Result<Vec<u32>, TaskInputError>>()?;
+        .collect()
+}
 
-    let where_opt = if let Some(w) = obj.get("where_contains") {
-        if w.is_null() {
-            None
-        } else {
-            let wobj = w.as_object().ok_or_else(|| {
-                TaskInputError::new(
-                    error_codes::INPUT_INVALID,
-                    config_errors::DETAIL_TABLE_INPUT,
-                    "table.query where_contains must be an object",
-                )
-            })?;
-            let col = wobj.get("col").and_then(|v| v.as_u64()).ok_or_else(|| {
-                TaskInputError::new(
-                    error_codes::INPUT_INVALID,
-                    config_errors::DETAIL_TABLE_INPUT,
-                    "table.query where_contains.col must be u32",
-                )
-            })? as u32;
-            let needle = wobj
-                .get("needle")
-                .and_then(|v| v.as_str())
-                .ok_or_else(|| {
-                    TaskInputError::new(
-                        error_codes::INPUT_INVALID,
-                        config_errors::DETAIL_TABLE_INPUT,
-                        "table.query where_contains.needle must be string",
-                    )
-                })?
-                .to_string();
-            Some((col, needle))
+fn parse_table_select(
+    obj: &serde_json::Map<String, serde_json::Value>,
+    parse_col: impl Fn(&serde_json::Value) -> Result<u32, TaskInputError>,
+) -> Result<Vec<u32>, TaskInputError> {
+    let select_val = obj
+        .get("select")
+        .ok_or_else(|| table_input_error("table.query input.select required"))?;
+    select_val
+        .as_array()
+        .ok_or_else(|| table_input_error("table.query input.select must be an array"))?
+        .iter()
+        .map(parse_col)
+        .collect()
+}
+
+fn parse_where_contains(
+    obj: &serde_json::Map<String, serde_json::Value>,
+) -> Result<Option<(u32, String)>, TaskInputError> {
+    let Some(w) = obj.get("where_contains") else {
+        return Ok(None);
+    };
+    if w.is_null() {
+        return Ok(None);
+    }
+    let wobj = w
+        .as_object()
+        .ok_or_else(|| table_input_error("table.query where_contains must be an object"))?;
+    let col = wobj
+        .get("col")
+        .and_then(|v| v.as_u64())
+        .ok_or_else(|| table_input_error("table.query where_contains.col must be u32"))?
+        as u32;
+    let needle = wobj
+        .get("needle")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| table_input_error("table.query where_contains.needle must be string"))?
+        .to_string();
+    Ok(Some((col, needle)))
+}
+
+/// Longest expression accepted by `json.query`; the guest parser caps nesting separately.
+pub const MAX_JSON_QUERY_EXPRESSION_BYTES: usize = 4096;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonQueryLanguage {
+    JsonPath,
+    Jq,
+}
+
+impl JsonQueryLanguage {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            JsonQueryLanguage::JsonPath => "jsonpath",
+            JsonQueryLanguage::Jq => "jq",
         }
-    } else {
-        None
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonQueryInput {
+    pub document: serde_json::Value,
+    pub language: JsonQueryLanguage,
+    pub expression: String,
+}
+
+impl JsonQueryInput {
+    /// Canonical form: explicit language, trimmed expression, `query` alias folded away.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "document": self.document,
+            "language": self.language.as_str(),
+            "expression": self.expression,
+        })
+    }
+}
+
+fn json_query_input_error(message: impl Into<String>) -> TaskInputError {
+    TaskInputError::new(
+        error_codes::INPUT_INVALID,
+        config_errors::DETAIL_JSON_QUERY_INPUT,
+        message,
+    )
+}
+
+/// WHY: `language` is optional so callers can send a bare expression; a leading `$` can only be
+/// JSONPath (jq variables are unsupported), anything else is treated as jq.
+pub fn extract_json_query_input(
+    input: &serde_json::Value,
+) -> Result<JsonQueryInput, TaskInputError> {
+    let obj = input
+        .as_object()
+        .ok_or_else(|| json_query_input_error("json.query input must be an object"))?;
+    let document = obj
+        .get("document")
+        .cloned()
+        .ok_or_else(|| json_query_input_error("json.query requires a document"))?;
+    let expression = obj
+        .get("expression")
+        .or_else(|| obj.get("query"))
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| json_query_input_error("json.query expression must be a non-empty string"))?
+        .to_string();
+    if expression.len() > MAX_JSON_QUERY_EXPRESSION_BYTES {
+        return Err(json_query_input_error(format!(
+            "json.query expression exceeds {MAX_JSON_QUERY_EXPRESSION_BYTES} bytes"
+        )));
+    }
+    let language = match obj.get("language") {
+        None | Some(serde_json::Value::Null) if expression.starts_with('$') => {
+            JsonQueryLanguage::JsonPath
+        }
+        None | Some(serde_json::Value::Null) => JsonQueryLanguage::Jq,
+        Some(v) => match v.as_str() {
+            Some("jsonpath") => JsonQueryLanguage::JsonPath,
+            Some("jq") => JsonQueryLanguage::Jq,
+            _ => {
+                return Err(json_query_input_error(
+                    "json.query language must be \"jsonpath\" or \"jq\"",
+                ))
+            }
+        },
     };
+    Ok(JsonQueryInput {
+        document,
+        language,
+        expression,
+    })
+}
 
-    Ok((rows, select, where_opt))
+/// Largest markdown source accepted by `markdown.render`.
+pub const MAX_MARKDOWN_BYTES: usize = 1024 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownRenderInput {
+    pub markdown: String,
+    pub gfm: bool,
+    pub smart_punctuation: bool,
+    pub raw_html: bool,
+}
+
+impl MarkdownRenderInput {
+    /// Canonical form: every option explicit so defaults cannot split cache keys.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "markdown": self.markdown,
+            "gfm": self.gfm,
+            "smartPunctuation": self.smart_punctuation,
+            "rawHtml": self.raw_html,
+        })
+    }
+}
+
+fn markdown_input_error(message: impl Into<String>) -> TaskInputError {
+    TaskInputError::new(
+        error_codes::INPUT_INVALID,
+        config_errors::DETAIL_MARKDOWN_INPUT,
+        message,
+    )
+}
+
+fn markdown_flag(
+    obj: &serde_json::Map<String, serde_json::Value>,
+    key: &str,
+    default: bool,
+) -> Result<bool, TaskInputError> {
+    match obj.get(key) {
+        None | Some(serde_json::Value::Null) => Ok(default),
+        Some(serde_json::Value::Bool(b)) => Ok(*b),
+        Some(_) => Err(markdown_input_error(format!(
+            "markdown.render {key} must be a boolean"
+        ))),
+    }
+}
+
+/// WHY: GFM and raw HTML default on because the host sanitizes the rendered output anyway;
+/// smart punctuation rewrites the author's text so it stays opt-in.
+pub fn extract_markdown_render_input(
+    input: &serde_json::Value,
+) -> Result<MarkdownRenderInput, TaskInputError> {
+    let obj = input
+        .as_object()
+        .ok_or_else(|| markdown_input_error("markdown.render input must be an object"))?;
+    let markdown = obj
+        .get("markdown")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| markdown_input_error("markdown.render requires a markdown string"))?
+        .to_string();
+    if markdown.len() > MAX_MARKDOWN_BYTES {
+        return Err(markdown_input_error(format!(
+            "markdown.render source exceeds {MAX_MARKDOWN_BYTES} bytes"
+        )));
+    }
+    Ok(MarkdownRenderInput {
+        markdown,
+        gfm: markdown_flag(obj, "gfm", true)?,
+        smart_punctuation: markdown_flag(obj, "smartPunctuation", false)?,
+        raw_html: markdown_flag(obj, "rawHtml", true)?,
+    })
 }
 
 /// Input parser for the `script` applet world.
@@ -310,6 +1274,11 @@ pub fn extract_script_input(input: &serde_json::Value) -> Result<ScriptInput, Ta
 /// WHY: Workspace paths must remain inside `files_dir_path`; reject traversal or malformed segments.
 /// INVARIANT: Successful result always resides under FILES_DIR (existing or future file).
 pub fn sanitize_ws_files_path(ws_path: &str) -> Result<PathBuf, TaskInputError> {
+    sanitize_ws_files_path_in(crate::files_dir_path(), ws_path)
+}
+
+/// `sanitize_ws_files_path` against an explicit files root (tests use a tempdir).
+fn sanitize_ws_files_path_in(base: &Path, ws_path: &str) -> Result<PathBuf, TaskInputError> {
     let prefix = "ws:/files/";
     if !ws_path.starts_with(prefix) {
         return Err(TaskInputError::new(
@@ -326,7 +1295,6 @@ pub fn sanitize_ws_files_path(ws_path: &str) -> Result<PathBuf, TaskInputError>
             "path missing trailing file segment",
         ));
     }
-    let base = crate::files_dir_path();
     let base_canonical = base.canonicalize().map_err(|err| {
         TaskInputError::new(
             error_codes::IO_DENIED,
@@ -408,6 +1376,284 @@ pub fn fs_read_allowed(spec: &ComputeJobSpec, ws_path: &str) -> bool {
     false
 }
 
+/// Guest mount point for workspace files.
+pub const WS_FILES_GUEST_ROOT: &str = "/ws/files";
+
+/// Read-only mount derived from a single `fsRead` grant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FsReadMount {
+    /// `ws:/files/<dir>/**`: the whole subtree, preopened at `guest`.
+    Dir { host: PathBuf, guest: String },
+    /// `ws:/files/<path>`: one file, surfaced at `/ws/files/<rel>` through a job-scoped view.
+    File { host: PathBuf, rel: String },
+}
+
+/// WHY: Guests should stream granted inputs instead of receiving them inlined as JSON strings.
+/// INVARIANT: Every host path passed `sanitize_ws_files_path` (no traversal, no symlink escape);
+/// grants outside `ws:/files/` and paths that do not exist yet are skipped, never widened.
+pub fn plan_fs_read_mounts(spec: &ComputeJobSpec) -> Result<Vec<FsReadMount>, TaskInputError> {
+    plan_fs_read_mounts_in(spec, crate::files_dir_path())
+}
+
+fn plan_fs_read_mounts_in(
+    spec: &ComputeJobSpec,
+    files_root: &Path,
+) -> Result<Vec<FsReadMount>, TaskInputError> {
+    let mut mounts = Vec::new();
+    for pat in spec.capabilities.fs_read.iter() {
+        if pat == "ws:/files/**" {
+            mounts.push(FsReadMount::Dir {
+                host: files_root.to_path_buf(),
+                guest: WS_FILES_GUEST_ROOT.to_string(),
+            });
+            continue;
+        }
+        if !pat.starts_with("ws:/files/") {
+            continue;
+        }
+        let (ws_path, is_tree) = match pat.strip_suffix("/**") {
+            Some(base) => (base, true),
+            None => (pat.as_str(), false),
+        };
+        if ws_path.contains('*') {
+            return Err(TaskInputError::new(
+                error_codes::CAPABILITY_DENIED,
+                config_errors::DETAIL_FS_CAP,
+                format!("unsupported fsRead glob '{pat}' (only literal paths and '/**')"),
+            ));
+        }
+        let host = sanitize_ws_files_path_in(files_root, ws_path)?;
+        let rel = ws_path["ws:/files/".len()..]
+            .split('/')
+            .filter(|seg| !seg.is_empty() && *seg != ".")
+            .collect::<Vec<_>>()
+            .join("/");
+        if is_tree && host.is_dir() {
+            mounts.push(FsReadMount::Dir {
+                host,
+                guest: format!("{WS_FILES_GUEST_ROOT}/{rel}"),
+            });
+        } else if !is_tree && host.is_file() {
+            mounts.push(FsReadMount::File { host, rel });
+        }
+    }
+    Ok(mounts)
+}
+
+/// Guest mount for staged writes; `/ws/scratch/<rel>` commits to `ws:/files/<rel>`.
+pub const WS_SCRATCH_GUEST_ROOT: &str = "/ws/scratch";
+
+/// WHY: Writes land only where `fsWrite` grants them; `/**` grants cover whole path segments.
+/// INVARIANT: `ws:/files/out/**` allows `ws:/files/out/a.csv` but not `ws:/files/outside.csv`.
+pub fn fs_write_allowed(spec: &ComputeJobSpec, ws_path: &str) -> bool {
+    spec.capabilities
+        .fs_write
+        .iter()
+        .any(|pat| match pat.strip_suffix("/**") {
+            Some(base) => ws_path
+                .strip_prefix(base)
+                .is_some_and(|rest| rest.starts_with('/')),
+            None => pat == ws_path,
+        })
+}
+
+/// Whether the job gets a writable scratch preopen at all.
+pub fn wants_fs_write_staging(spec: &ComputeJobSpec) -> bool {
+    spec.capabilities
+        .fs_write
+        .iter()
+        .any(|p| p.starts_with("ws:/files/"))
+}
+
+/// A staged file moved into the workspace after a successful run.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommittedWrite {
+    pub path: String,
+    pub sha256: String,
+    pub bytes: u64,
+}
+
+/// WHY: Guest output is only trusted after the job succeeds; validate every staged file before
+/// moving any so a single denied path leaves the workspace untouched.
+/// INVARIANT: Symlinks in staging are rejected; each destination passes `fs_write_allowed` and
+/// `sanitize_ws_files_path`. The commit is all-or-nothing: every file is first placed beside its
+/// destination, then renamed into place, and a failure restores every file already replaced.
+pub fn commit_staged_writes(
+    spec: &ComputeJobSpec,
+    staging: &Path,
+) -> Result<Vec<CommittedWrite>, TaskInputError> {
+    commit_staged_writes_in(spec, staging, crate::files_dir_path())
+}
+
+fn commit_staged_writes_in(
+    spec: &ComputeJobSpec,
+    staging: &Path,
+    files_root: &Path,
+) -> Result<Vec<CommittedWrite>, TaskInputError> {
+    let io_err =
+        |msg: String| TaskInputError::new(error_codes::IO_DENIED, config_errors::DETAIL_IO, msg);
+    let mut staged: Vec<(PathBuf, String)> = Vec::new();
+    let mut pending = vec![(staging.to_path_buf(), String::new())];
+    while let Some((dir, prefix)) = pending.pop() {
+        let entries =
+            std::fs::read_dir(&dir).map_err(|err| io_err(format!("read staging: {err}")))?;
+        for entry in entries {
+            let entry = entry.map_err(|err| io_err(format!("read staging entry: {err}")))?;
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let rel = if prefix.is_empty() {
+                name
+            } else {
+                format!("{prefix}/{name}")
+            };
+            let meta = std::fs::symlink_metadata(entry.path())
+                .map_err(|err| io_err(format!("stat staged '{rel}': {err}")))?;
+            if meta.is_dir() {
+                pending.push((entry.path(), rel));
+            } else if meta.is_file() {
+                staged.push((entry.path(), rel));
+            } else {
+                return Err(io_err(format!(
+                    "staged entry '{rel}' is not a regular file"
+                )));
+            }
+        }
+    }
+    staged.sort_by(|a, b| a.1.cmp(&b.1));
+
+    let mut planned: Vec<(PathBuf, PathBuf, CommittedWrite)> = Vec::with_capacity(staged.len());
+    for (source, rel) in staged {
+        let ws_path = format!("ws:/files/{rel}");
+        if !fs_write_allowed(spec, &ws_path) {
+            return Err(TaskInputError::new(
+                error_codes::CAPABILITY_DENIED,
+                config_errors::DETAIL_FS_CAP,
+                format!("fsWrite does not grant {ws_path}"),
+            ));
+        }
+        let dest = sanitize_ws_files_path_in(files_root, &ws_path)?;
+        if dest.is_dir() {
+            return Err(io_err(format!("destination {ws_path} is a directory")));
+        }
+        let (sha256, bytes) =
+            sha256_file(&source).map_err(|err| io_err(format!("hash staged '{rel}': {err}")))?;
+        planned.push((
+            source,
+            dest,
+            CommittedWrite {
+                path: ws_path,
+                sha256,
+                bytes,
+            },
+        ));
+    }
+
+    // Sibling names carry a per-job tag so concurrent jobs never collide on them.
+    let tag = hex::encode(&Sha256::digest(spec.job_id.as_bytes())[..8]);
+    let sibling = |dest: &Path, kind: &str| {
+        dest.with_file_name(format!(
+            ".{}.{tag}.uicp-{kind}",
+            dest.file_name().unwrap_or_default().to_string_lossy()
+        ))
+    };
+
+    // Phase 1: place every file beside its destination (same directory, so the final rename
+    // cannot cross filesystems). Nothing under its real name has changed yet.
+    let mut created_dirs: Vec<PathBuf> = Vec::new();
+    let mut placed: Vec<PathBuf> = Vec::with_capacity(planned.len());
+    for (source, dest, record) in &planned {
+        let next = sibling(dest, "stage");
+        let result = create_missing_parents(dest, &mut created_dirs).and_then(|()| {
+            std::fs::rename(source, &next).or_else(|_| std::fs::copy(source, &next).map(|_| ()))
+        });
+        if let Err(err) = result {
+            let _ = std::fs::remove_file(&next);
+            discard_commit_siblings(&placed, &created_dirs);
+            return Err(io_err(format!("stage {}: {err}", record.path)));
+        }
+        placed.push(next);
+    }
+
+    // Phase 2: rename each file into place, keeping any previous file as a backup until the
+    // whole set has landed.
+    let mut swapped: Vec<(&Path, Option<PathBuf>)> = Vec::with_capacity(planned.len());
+    for ((_, dest, record), next) in planned.iter().zip(&placed) {
+        let backup = sibling(dest, "backup");
+        let had_previous = dest.exists();
+        let result = if had_previous {
+            std::fs::rename(dest, &backup)
+        } else {
+            Ok(())
+        }
+        .and_then(|()| {
+            std::fs::rename(next, dest).inspect_err(|_| {
+                if had_previous {
+                    let _ = std::fs::rename(&backup, dest);
+                }
+            })
+        });
+        if let Err(err) = result {
+            rollback_committed(&swapped);
+            discard_commit_siblings(&placed, &created_dirs);
+            return Err(io_err(format!("commit {}: {err}", record.path)));
+        }
+        swapped.push((dest.as_path(), had_previous.then_some(backup)));
+    }
+    for backup in swapped.into_iter().filter_map(|(_, backup)| backup) {
+        let _ = std::fs::remove_file(backup);
+    }
+    Ok(planned.into_iter().map(|(_, _, record)| record).collect())
+}
+
+/// Streamed SHA-256 and length of a file, so large outputs are never read into memory.
+fn sha256_file(path: &Path) -> std::io::Result<(String, u64)> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let bytes = std::io::copy(&mut file, &mut hasher)?;
+    Ok((hex::encode(hasher.finalize()), bytes))
+}
+
+/// Create the missing ancestors of `path`, recording each one so a failed commit can remove it.
+fn create_missing_parents(path: &Path, created: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    let mut missing = Vec::new();
+    let mut cur = path.parent();
+    while let Some(dir) = cur {
+        if dir.exists() {
+            break;
+        }
+        missing.push(dir.to_path_buf());
+        cur = dir.parent();
+    }
+    for dir in missing.into_iter().rev() {
+        match std::fs::create_dir(&dir) {
+            Ok(()) => created.push(dir),
+            Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => {}
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(())
+}
+
+/// Undo phase 2: drop the new files and restore whatever they replaced, newest first.
+fn rollback_committed(swapped: &[(&Path, Option<PathBuf>)]) {
+    for (dest, backup) in swapped.iter().rev() {
+        let _ = std::fs::remove_file(dest);
+        if let Some(backup) = backup {
+            let _ = std::fs::rename(backup, dest);
+        }
+    }
+}
+
+/// Remove leftover staged siblings and any directories the commit created (if still empty).
+fn discard_commit_siblings(placed: &[PathBuf], created_dirs: &[PathBuf]) {
+    for next in placed {
+        let _ = std::fs::remove_file(next);
+    }
+    for dir in created_dirs.iter().rev() {
+        let _ = std::fs::remove_dir(dir);
+    }
+}
+
 /// WHY: Convert workspace references to data URIs eagerly so downstream caching sees canonical input.
 /// INVARIANT: Returns unchanged source for non-`ws:/files/` strings.
 pub fn resolve_csv_source(spec: &ComputeJobSpec, source: &str) -> Result<String, TaskInputError> {
@@ -719,6 +1965,11 @@ pub fn canonicalize_task_input(spec: &ComputeJobSpec) -> Result<serde_json::Valu
     let task_name = spec.task.split('@').next().unwrap_or("");
     match task_name {
         "csv.parse" => {
+            if csv_parse_streaming(&spec.task) {
+                let mut input = extract_csv_parse_input(&spec.input)?;
+                input.source = resolve_csv_source(spec, &input.source)?;
+                return Ok(input.to_json());
+            }
             let (source, has_header) = extract_csv_input(&spec.input)?;
             let resolved = resolve_csv_source(spec, &source)?;
             Ok(serde_json::json!({
@@ -727,6 +1978,9 @@ pub fn canonicalize_task_input(spec: &ComputeJobSpec) -> Result<serde_json::Valu
             }))
         }
         "table.query" => {
+            if table_query_typed_cells(&spec.task) {
+                return Ok(extract_table_query_input_typed(&spec.input)?.to_json());
+            }
             let (rows, select, where_opt) = extract_table_query_input(&spec.input)?;
             let mut obj = serde_json::json!({
                 "rows": rows,
@@ -743,6 +1997,8 @@ pub fn canonicalize_task_input(spec: &ComputeJobSpec) -> Result<serde_json::Valu
             }
             Ok(obj)
         }
+        "json.query" => Ok(extract_json_query_input(&spec.input)?.to_json()),
+        "markdown.render" => Ok(extract_markdown_render_input(&spec.input)?.to_json()),
         "applet.quickjs" => {
             let script = extract_script_input(&spec.input)?;
             let missing_source = script
@@ -768,7 +2024,9 @@ pub fn canonicalize_task_input(spec: &ComputeJobSpec) -> Result<serde_json::Valu
 mod tests {
     use super::*;
     use crate::compute::compute_input::error_codes;
-    use crate::security::policy::{ComputeCapabilitiesSpec, ComputeJobSpec, ComputeProvenanceSpec};
+    use crate::security::policy::{
+        ComputeCapabilitiesSpec, ComputeJobSpec, ComputePriority, ComputeProvenanceSpec,
+    };
     use serde_json::json;
 
     fn base_spec() -> ComputeJobSpec {
@@ -800,9 +2058,127 @@ mod tests {
             golden_key: None,
             artifact_id: None,
             expect_golden: false,
+            priority: ComputePriority::Normal,
         }
     }
 
+    #[test]
+    fn plan_fs_read_mounts_restricts_to_granted_paths() {
+        let files = tempfile::tempdir().expect("files root");
+        let base = files.path().join("plan-mounts");
+        std::fs::create_dir_all(base.join("tree/nested")).expect("create dirs");
+        std::fs::write(base.join("single.csv"), "a,b\n").expect("write file");
+        std::fs::write(base.join("sibling.csv"), "c,d\n").expect("write sibling");
+
+        let mut spec = base_spec();
+        spec.capabilities.fs_read = vec![
+            "ws:/files/plan-mounts/tree/**".into(),
+            "ws:/files/plan-mounts/single.csv".into(),
+            "ws:/files/plan-mounts/missing.csv".into(),
+            "ws:/other/**".into(),
+        ];
+        let mounts = plan_fs_read_mounts_in(&spec, files.path()).expect("plan");
+        assert_eq!(
+            mounts.len(),
+            2,
+            "missing files and non-files roots are skipped"
+        );
+        assert!(mounts.iter().any(|m| matches!(
+            m,
+            FsReadMount::Dir { guest, .. } if guest == "/ws/files/plan-mounts/tree"
+        )));
+        assert!(mounts.iter().any(|m| matches!(
+            m,
+            FsReadMount::File { rel, .. } if rel == "plan-mounts/single.csv"
+        )));
+
+        spec.capabilities.fs_read = vec!["ws:/files/../escape/**".into()];
+        let err = plan_fs_read_mounts_in(&spec, files.path()).unwrap_err();
+        assert_eq!(err.code, error_codes::IO_DENIED);
+
+        spec.capabilities.fs_read = vec!["ws:/files/plan-mounts/*.csv".into()];
+        let err = plan_fs_read_mounts_in(&spec, files.path()).unwrap_err();
+        assert_eq!(err.code, error_codes::CAPABILITY_DENIED);
+    }
+
+    #[test]
+    fn fs_write_allowed_respects_segment_boundaries() {
+        let mut spec = base_spec();
+        spec.capabilities.fs_write = vec!["ws:/files/out/**".into(), "ws:/files/report.md".into()];
+        assert!(fs_write_allowed(&spec, "ws:/files/out/a.csv"));
+        assert!(fs_write_allowed(&spec, "ws:/files/out/nested/b.csv"));
+        assert!(fs_write_allowed(&spec, "ws:/files/report.md"));
+        assert!(!fs_write_allowed(&spec, "ws:/files/outside.csv"));
+        assert!(!fs_write_allowed(&spec, "ws:/files/other.md"));
+    }
+
+    #[test]
+    fn commit_staged_writes_moves_granted_files_and_rejects_others() {
+        let files = tempfile::tempdir().expect("files root");
+        let staging = tempfile::tempdir().expect("staging");
+        std::fs::create_dir_all(staging.path().join("commit-test/nested")).unwrap();
+        std::fs::write(staging.path().join("commit-test/nested/out.csv"), "a,b\n").unwrap();
+
+        let mut spec = base_spec();
+        spec.capabilities.fs_write = vec!["ws:/files/commit-test/**".into()];
+        let written = commit_staged_writes_in(&spec, staging.path(), files.path()).expect("commit");
+        assert_eq!(written.len(), 1);
+        assert_eq!(written[0].path, "ws:/files/commit-test/nested/out.csv");
+        assert_eq!(written[0].bytes, 4);
+        assert_eq!(written[0].sha256, hex::encode(Sha256::digest(b"a,b\n")));
+        let dest = files.path().join("commit-test/nested/out.csv");
+        assert_eq!(std::fs::read_to_string(&dest).unwrap(), "a,b\n");
+        assert!(!staging.path().join("commit-test/nested/out.csv").exists());
+
+        // A single ungranted file aborts the whole commit before anything moves.
+        std::fs::write(staging.path().join("commit-test/ok.csv"), "x").unwrap();
+        std::fs::write(staging.path().join("elsewhere.csv"), "y").unwrap();
+        let err = commit_staged_writes_in(&spec, staging.path(), files.path()).unwrap_err();
+        assert_eq!(err.code, error_codes::CAPABILITY_DENIED);
+        assert!(staging.path().join("commit-test/ok.csv").exists());
+        assert!(!files.path().join("commit-test/ok.csv").exists());
+    }
+
+    #[test]
+    fn commit_staged_writes_is_all_or_nothing() {
+        let files = tempfile::tempdir().expect("files root");
+        let staging = tempfile::tempdir().expect("staging");
+        let spec = {
+            let mut spec = base_spec();
+            spec.capabilities.fs_write = vec!["ws:/files/out/**".into()];
+            spec
+        };
+        std::fs::create_dir_all(files.path().join("out")).unwrap();
+        std::fs::write(files.path().join("out/a.csv"), "old").unwrap();
+        // Occupy the sibling name `z.csv` is placed under so the last file cannot be staged.
+        let tag = hex::encode(&Sha256::digest(spec.job_id.as_bytes())[..8]);
+        let blocker = files.path().join(format!("out/.z.csv.{tag}.uicp-stage"));
+        std::fs::create_dir_all(&blocker).unwrap();
+        std::fs::write(blocker.join("keep"), "x").unwrap();
+        std::fs::create_dir_all(staging.path().join("out/fresh")).unwrap();
+        std::fs::write(staging.path().join("out/a.csv"), "new").unwrap();
+        std::fs::write(staging.path().join("out/fresh/b.csv"), "b").unwrap();
+        std::fs::write(staging.path().join("out/z.csv"), "z").unwrap();
+
+        let err = commit_staged_writes_in(&spec, staging.path(), files.path()).unwrap_err();
+        assert_eq!(err.code, error_codes::IO_DENIED);
+
+        // Earlier files were not committed; staged siblings and created dirs are gone.
+        assert_eq!(
+            std::fs::read_to_string(files.path().join("out/a.csv")).unwrap(),
+            "old"
+        );
+        let mut names: Vec<String> = std::fs::read_dir(files.path().join("out"))
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        assert_eq!(
+            names,
+            vec![format!(".z.csv.{tag}.uicp-stage"), "a.csv".to_string()]
+        );
+    }
+
     #[test]
     fn extract_csv_input_supports_has_header_variants() {
         let v1 = serde_json::json!({"source":"x","hasHeader":true});
@@ -816,6 +2192,51 @@ mod tests {
         assert!(!h2);
     }
 
+    #[test]
+    fn extract_csv_parse_input_reads_dialect_and_limits() {
+        let v = serde_json::json!({
+            "source": "a;b",
+            "delimiter": ";",
+            "quote": "auto",
+            "encoding": ["windows-1252", "UTF-16LE"],
+            "inferTypes": true,
+            "maxRows": 10,
+            "maxBytes": 4096,
+        });
+        let input = extract_csv_parse_input(&v).expect("csv options");
+        assert_eq!(input.delimiter, Some(b';'));
+        assert_eq!(input.quote, None);
+        assert_eq!(
+            input.encodings,
+            vec![CsvEncoding::Windows1252, CsvEncoding::Utf16Le]
+        );
+        assert!(input.has_header && input.infer_types);
+        assert_eq!((input.max_rows, input.max_bytes), (Some(10), Some(4096)));
+        assert_eq!(input.batch_rows, DEFAULT_CSV_BATCH_ROWS);
+        assert_eq!(extract_csv_parse_input(&input.to_json()).unwrap(), input);
+
+        let defaults = extract_csv_parse_input(&serde_json::json!({"source": "x"})).unwrap();
+        assert_eq!((defaults.delimiter, defaults.quote), (None, None));
+        assert!(defaults.encodings.is_empty() && !defaults.infer_types);
+
+        for (key, bad) in [
+            ("delimiter", serde_json::json!(",,")),
+            ("delimiter", serde_json::json!("\n")),
+            ("quote", serde_json::json!(1)),
+            ("encoding", serde_json::json!("ebcdic")),
+            ("inferTypes", serde_json::json!("yes")),
+            ("maxRows", serde_json::json!(-1)),
+            ("batchRows", serde_json::json!(u64::MAX)),
+        ] {
+            let mut v = serde_json::json!({ "source": "x" });
+            v[key] = bad;
+            let err = extract_csv_parse_input(&v).expect_err("bad option");
+            assert_eq!(err.code, error_codes::INPUT_INVALID, "{v}");
+        }
+        let same = serde_json::json!({ "source": "x", "delimiter": "'", "quote": "'" });
+        assert!(extract_csv_parse_input(&same).is_err());
+    }
+
     #[test]
     fn extract_table_query_input_parses_rows_select_and_where() {
         let v = serde_json::json!({
@@ -830,6 +2251,278 @@ mod tests {
         assert_eq!(where_opt, Some((0u32, "a".into())));
     }
 
+    #[test]
+    fn extract_table_query_input_typed_keeps_scalar_types() {
+        let v = serde_json::json!({
+            "rows": [["a", 1, 2.5, true, null], ["b", -7, 0.0, false, "x"]],
+            "select": [0, 1],
+        });
+        let query = extract_table_query_input_typed(&v).unwrap();
+        let rows = &query.rows;
+        assert_eq!(
+            rows[0],
+            vec![
+                TableCell::Text("a".into()),
+                TableCell::Int(1),
+                TableCell::Float(2.5),
+                TableCell::Bool(true),
+                TableCell::Null,
+            ]
+        );
+        assert_eq!(rows[1][1], TableCell::Int(-7));
+        assert_eq!(query.select, vec![0, 1]);
+        assert_eq!(query.where_contains, None);
+        assert!(!query.grouped());
+
+        let nested = serde_json::json!({ "rows": [[["nested"]]], "select": [0] });
+        let err = extract_table_query_input_typed(&nested).expect_err("nested cell rejected");
+        assert_eq!(err.code, error_codes::INPUT_INVALID);
+        assert!(err.message.contains("[0][0]"));
+    }
+
+    #[test]
+    fn extract_table_query_input_typed_resolves_header_names() {
+        let v = serde_json::json!({
+            "rows": [["name", "city", "age"], ["ann", "Austin", "31"], ["bob", "Boston", 25]],
+            "hasHeader": true,
+            "select": ["name", 2],
+            "where": {
+                "any": [
+                    { "col": "city", "op": "regex", "value": "^aus", "caseInsensitive": true },
+                    { "col": "age", "op": "between", "value": [20, 30] }
+                ]
+            },
+            "orderBy": [{ "col": "age", "desc": true }, "name"],
+            "limit": 10,
+            "offset": 1
+        });
+        let query = extract_table_query_input_typed(&v).unwrap();
+        assert!(query.has_header);
+        assert_eq!(query.select, vec![0, 2]);
+        assert_eq!(
+            query.order_by,
+            vec![
+                TableOrderKey {
+                    col: 2,
+                    descending: true
+                },
+                TableOrderKey {
+                    col: 0,
+                    descending: false
+                },
+            ]
+        );
+        assert_eq!((query.limit, query.offset), (Some(10), 1));
+        let Some(TableWhere::Any(branches)) = &query.where_clause else {
+            panic!("expected any: {:?}", query.where_clause);
+        };
+        assert_eq!(
+            branches[0],
+            TableWhere::Leaf(TablePredicate {
+                col: 1,
+                op: TableCompareOp::Regex,
+                value: TableCell::Text("^aus".into()),
+                case_insensitive: true,
+            })
+        );
+        // `between` expands to an inclusive range.
+        let TableWhere::All(range) = &branches[1] else {
+            panic!("between should expand to all: {:?}", branches[1]);
+        };
+        assert_eq!(range.len(), 2);
+        let TableWhere::Leaf(low) = &range[0] else {
+            panic!("range bound should be a leaf: {:?}", range[0]);
+        };
+        assert_eq!(
+            (low.op, &low.value),
+            (TableCompareOp::Gte, &TableCell::Int(20))
+        );
+
+        // The canonical form parses back to the same query.
+        let again = extract_table_query_input_typed(&query.to_json()).unwrap();
+        assert_eq!(again, query);
+    }
+
+    #[test]
+    fn extract_table_query_input_typed_names_grouped_columns() {
+        let v = serde_json::json!({
+            "rows": [["dept", "salary"], ["eng", 10], ["ops", 5]],
+            "hasHeader": true,
+            "groupBy": ["dept"],
+            "aggregates": [
+                { "op": "count" },
+                { "op": "sum", "col": "salary" },
+                { "op": "avg", "col": "salary", "as": "mean" }
+            ],
+            "orderBy": [{ "col": "sum(salary)", "desc": true }],
+            "select": ["dept", "count", "mean"]
+        });
+        let query = extract_table_query_input_typed(&v).unwrap();
+        assert!(query.grouped());
+        assert_eq!(query.group_by, vec![0]);
+        let labels: Vec<_> = query.aggregates.iter().map(|a| a.label.as_str()).collect();
+        assert_eq!(labels, vec!["count", "sum(salary)", "mean"]);
+        assert_eq!(query.order_by[0].col, 2);
+        assert_eq!(query.select, vec![0, 1, 3]);
+
+        // Source column names are not visible after grouping.
+        let mut bad = v.clone();
+        bad["select"] = serde_json::json!(["salary"]);
+        let err = extract_table_query_input_typed(&bad).expect_err("ungrouped name");
+        assert!(
+            err.message.contains("unknown column 'salary'"),
+            "{}",
+            err.message
+        );
+    }
+
+    #[test]
+    fn extract_table_query_input_typed_rejects_malformed_queries() {
+        let cases = [
+            ("select", serde_json::json!(["name"])),
+            (
+                "where",
+                serde_json::json!({ "col": 0, "op": "regex", "value": "(" }),
+            ),
+            (
+                "where",
+                serde_json::json!({ "col": 0, "op": "gt", "value": null }),
+            ),
+            (
+                "where",
+                serde_json::json!({ "col": 0, "op": "like", "value": "x" }),
+            ),
+            ("aggregates", serde_json::json!([{ "op": "sum" }])),
+            ("limit", serde_json::json!(-1)),
+        ];
+        for (key, bad) in cases {
+            let mut v = serde_json::json!({ "rows": [], "select": [0] });
+            v[key] = bad;
+            let err = extract_table_query_input_typed(&v).expect_err("malformed query");
+            assert_eq!(err.code, error_codes::INPUT_INVALID, "{v}");
+        }
+
+        let mut deep = serde_json::json!({ "col": 0, "value": "x" });
+        for _ in 0..MAX_TABLE_WHERE_DEPTH {
+            deep = serde_json::json!({ "all": [deep] });
+        }
+        let v = serde_json::json!({ "rows": [], "select": [0], "where": deep });
+        assert!(extract_table_query_input_typed(&v).is_err());
+    }
+
+    #[test]
+    fn canonicalize_table_query_preserves_types_only_for_v0_2() {
+        let mut spec = base_spec();
+        spec.input = serde_json::json!({ "rows": [[1, "a", null]], "select": [0] });
+        spec.task = "table.query@0.1.0".into();
+        let v1 = canonicalize_task_input(&spec).expect("v0.1");
+        assert_eq!(v1["rows"], serde_json::json!([["", "a", ""]]));
+
+        spec.task = "table.query@0.2.0".into();
+        let v2 = canonicalize_task_input(&spec).expect("v0.2");
+        assert_eq!(v2["rows"], serde_json::json!([[1, "a", null]]));
+        assert!(table_query_typed_cells("table.query@0.3.1"));
+        assert!(!table_query_typed_cells("table.query"));
+    }
+
+    #[test]
+    fn extract_json_query_input_infers_language_and_validates() {
+        let parsed = extract_json_query_input(&serde_json::json!({
+            "document": { "a": [1, 2] },
+            "query": "  $.a[*] ",
+        }))
+        .expect("jsonpath");
+        assert_eq!(parsed.language, JsonQueryLanguage::JsonPath);
+        assert_eq!(parsed.expression, "$.a[*]");
+
+        let parsed = extract_json_query_input(&serde_json::json!({
+            "document": null,
+            "expression": ".a | length",
+        }))
+        .expect("jq");
+        assert_eq!(parsed.language, JsonQueryLanguage::Jq);
+
+        for bad in [
+            serde_json::json!({ "expression": "." }),
+            serde_json::json!({ "document": {}, "expression": "  " }),
+            serde_json::json!({ "document": {}, "expression": ".", "language": "xpath" }),
+            serde_json::json!({ "document": {}, "expression": ".".repeat(MAX_JSON_QUERY_EXPRESSION_BYTES + 1) }),
+        ] {
+            let err = extract_json_query_input(&bad).unwrap_err();
+            assert_eq!(err.code, error_codes::INPUT_INVALID);
+            assert!(
+                err.message
+                    .starts_with(config_errors::DETAIL_JSON_QUERY_INPUT),
+                "{err}"
+            );
+        }
+    }
+
+    #[test]
+    fn canonicalize_json_query_makes_language_explicit() {
+        let mut spec = base_spec();
+        spec.task = "json.query@0.1.0".into();
+        spec.input = serde_json::json!({ "document": [1], "query": "$[0]" });
+        let canonical = canonicalize_task_input(&spec).expect("canonical");
+        assert_eq!(
+            canonical,
+            serde_json::json!({ "document": [1], "language": "jsonpath", "expression": "$[0]" })
+        );
+        spec.input = canonical.clone();
+        assert_eq!(
+            canonicalize_task_input(&spec).expect("idempotent"),
+            canonical
+        );
+    }
+
+    #[test]
+    fn markdown_render_input_defaults_and_validates() {
+        let mut spec = base_spec();
+        spec.task = "markdown.render@0.1.0".into();
+        spec.input = serde_json::json!({ "markdown": "# Hi\n", "smartPunctuation": true });
+        let canonical = canonicalize_task_input(&spec).expect("canonical");
+        assert_eq!(
+            canonical,
+            serde_json::json!({
+                "markdown": "# Hi\n",
+                "gfm": true,
+                "smartPunctuation": true,
+                "rawHtml": true,
+            })
+        );
+
+        for bad in [
+            serde_json::json!({ "gfm": true }),
+            serde_json::json!({ "markdown": "x", "rawHtml": "no" }),
+            serde_json::json!({ "markdown": "x".repeat(MAX_MARKDOWN_BYTES + 1) }),
+        ] {
+            let err = extract_markdown_render_input(&bad).unwrap_err();
+            assert_eq!(err.code, error_codes::INPUT_INVALID);
+            assert!(
+                err.message
+                    .starts_with(config_errors::DETAIL_MARKDOWN_INPUT),
+                "{err}"
+            );
+        }
+    }
+
+    #[test]
+    fn script_loads_source_only_for_quickjs_v0_2() {
+        assert!(script_loads_source("applet.quickjs@0.2.0"));
+        assert!(script_loads_source("applet.quickjs@1.0.0"));
+        assert!(!script_loads_source("applet.quickjs@0.1.0"));
+        assert!(!script_loads_source("applet.quickjs"));
+        assert!(!script_loads_source("script.hello@0.2.0"));
+    }
+
+    #[test]
+    fn applet_uses_kv_only_for_quickjs_v0_3() {
+        assert!(applet_uses_kv("applet.quickjs@0.3.0"));
+        assert!(!applet_uses_kv("applet.quickjs@0.2.0"));
+        assert!(!applet_uses_kv("applet.quickjs@0.1.0"));
+        assert!(!applet_uses_kv("script.hello@0.3.0"));
+    }
+
     #[test]
     fn extract_script_input_captures_source_when_present() {
         let v = serde_json::json!({
@@ -933,6 +2626,24 @@ mod tests {
             .starts_with("data:text/csv;base64,"));
     }
 
+    #[test]
+    fn canonicalize_csv_options_only_for_v1_3() {
+        let mut spec = base_spec();
+        spec.input = json!({"source": "a|b", "delimiter": "|", "inferTypes": true});
+        spec.task = "csv.parse@1.2.0".into();
+        let v12 = canonicalize_task_input(&spec).unwrap();
+        assert_eq!(v12, json!({"source": "a|b", "hasHeader": true}));
+
+        spec.task = "csv.parse@1.3.0".into();
+        let v13 = canonicalize_task_input(&spec).unwrap();
+        assert_eq!(v13["delimiter"], json!("|"));
+        assert_eq!(v13["quote"], json!(null));
+        assert_eq!(v13["inferTypes"], json!(true));
+        assert_eq!(v13["batchRows"], json!(DEFAULT_CSV_BATCH_ROWS));
+        assert!(csv_parse_streaming("csv.parse@1.4.0"));
+        assert!(!csv_parse_streaming("csv.parse@1.2.9"));
+    }
+
     #[test]
     fn canonicalize_table_query_preserves_semantics() {
         let mut spec = base_spec();
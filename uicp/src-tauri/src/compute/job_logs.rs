@@ -0,0 +1,214 @@
+//! Structured guest logs (`uicp:host/log`) and their retrieval (`compute_job_logs`).
+//!
+//! Each accepted `log.write` call becomes one `compute.guest.log` action-log record keyed by job
+//! id. This module owns the per-entry size limits, the level order used for filtering and the
+//! per-job query; throttling and the job log budget stay with the runtime.
+
+use ::rusqlite::params;
+use serde::{Deserialize, Serialize};
+use tokio_rusqlite::Connection as AsyncConn;
+
+pub const LOG_KIND: &str = "compute.guest.log";
+
+pub const MESSAGE_MAX_BYTES: usize = 4 * 1024;
+pub const FIELD_KEY_MAX_BYTES: usize = 64;
+pub const FIELD_VALUE_MAX_BYTES: usize = 1024;
+pub const FIELDS_MAX: usize = 32;
+
+/// Entries returned by `compute_job_logs` when the caller passes no limit, and the hard cap.
+pub const DEFAULT_QUERY_LIMIT: u32 = 500;
+pub const MAX_QUERY_LIMIT: u32 = 5000;
+
+/// Levels in ascending severity; matches the `uicp:host/log` `level` enum.
+pub const LEVELS: [&str; 5] = ["trace", "debug", "info", "warn", "error"];
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct LogField {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GuestLogEntry {
+    pub job_id: String,
+    pub task: String,
+    pub seq: u64,
+    pub tick: u64,
+    pub ts: i64,
+    pub level: String,
+    pub message: String,
+    #[serde(default)]
+    pub fields: Vec<LogField>,
+    /// True when the message or any field was cut to the entry limits.
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+impl GuestLogEntry {
+    /// Build an entry from guest input, applying the message and field limits.
+    pub fn new(
+        job_id: &str,
+        task: &str,
+        level: &str,
+        mut message: String,
+        fields: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        let mut truncated = truncate_bytes(&mut message, MESSAGE_MAX_BYTES);
+        let mut kept = Vec::new();
+        for (mut key, mut value) in fields {
+            if kept.len() == FIELDS_MAX {
+                truncated = true;
+                break;
+            }
+            truncated |= truncate_bytes(&mut key, FIELD_KEY_MAX_BYTES);
+            truncated |= truncate_bytes(&mut value, FIELD_VALUE_MAX_BYTES);
+            kept.push(LogField { key, value });
+        }
+        Self {
+            job_id: job_id.to_string(),
+            task: task.to_string(),
+            seq: 0,
+            tick: 0,
+            ts: chrono::Utc::now().timestamp_millis(),
+            level: level.to_string(),
+            message,
+            fields: kept,
+            truncated,
+        }
+    }
+
+    /// Bytes charged against the job log budget and the logger rate.
+    pub fn size_bytes(&self) -> usize {
+        self.fields.iter().fold(self.message.len(), |acc, f| {
+            acc + f.key.len() + f.value.len()
+        })
+    }
+}
+
+/// Position of `level` in [`LEVELS`] (case-insensitive).
+pub fn level_rank(level: &str) -> Option<usize> {
+    LEVELS.iter().position(|l| l.eq_ignore_ascii_case(level))
+}
+
+/// Cut `s` to at most `max` bytes on a char boundary. Returns whether anything was removed.
+fn truncate_bytes(s: &mut String, max: usize) -> bool {
+    if s.len() <= max {
+        return false;
+    }
+    let mut end = max;
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    s.truncate(end);
+    true
+}
+
+/// The latest `limit` structured entries for `job_id` at or above `min_level`, oldest first.
+/// The `kind`/`jobId` filter is served by the `action_log_kind_job` expression index.
+pub async fn load_job_logs(
+    db: &AsyncConn,
+    job_id: &str,
+    min_level: Option<&str>,
+    limit: Option<u32>,
+) -> Result<Vec<GuestLogEntry>, String> {
+    let min_rank = match min_level {
+        None => 0,
+        Some(level) => {
+            level_rank(level).ok_or_else(|| format!("E-UICP-0730: unknown log level '{level}'"))?
+        }
+    };
+    let levels = serde_json::to_string(&LEVELS[min_rank..]).unwrap_or_else(|_| "[]".into());
+    let limit = limit
+        .unwrap_or(DEFAULT_QUERY_LIMIT)
+        .clamp(1, MAX_QUERY_LIMIT);
+    let id = job_id.to_string();
+    let rows = db
+        .call(move |conn| {
+            let mut stmt = conn.prepare(
+                "SELECT payload_json FROM action_log
+                 WHERE kind = ?1 AND json_extract(payload_json, '$.jobId') = ?2
+                   AND json_extract(payload_json, '$.level') IN (SELECT value FROM json_each(?3))
+                 ORDER BY id DESC LIMIT ?4",
+            )?;
+            let rows = stmt
+                .query_map(params![LOG_KIND, id, levels, limit], |row| {
+                    row.get::<_, String>(0)
+                })?
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(rows)
+        })
+        .await
+        .map_err(|e| format!("E-UICP-0731: read action log failed: {e}"))?;
+    rows.into_iter()
+        .rev()
+        .map(|raw| {
+            serde_json::from_str(&raw)
+                .map_err(|e| format!("E-UICP-0731: {LOG_KIND} record is not valid: {e}"))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::action_log::ActionLogService;
+
+    #[test]
+    fn entry_limits_truncate_on_char_boundaries() {
+        let fields = (0..FIELDS_MAX + 3).map(|i| (format!("k{i}"), "v".to_string()));
+        let entry =
+            GuestLogEntry::new("job", "task", "info", "é".repeat(MESSAGE_MAX_BYTES), fields);
+        assert!(entry.truncated);
+        assert_eq!(entry.message.len(), MESSAGE_MAX_BYTES);
+        assert_eq!(entry.fields.len(), FIELDS_MAX);
+
+        let entry = GuestLogEntry::new(
+            "job",
+            "task",
+            "warn",
+            "ok".into(),
+            [("rows".to_string(), "42".to_string())],
+        );
+        assert!(!entry.truncated);
+        assert_eq!(entry.size_bytes(), 2 + 4 + 2);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn load_job_logs_filters_by_job_and_level() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let db_path = dir.path().join("data.db");
+        let action_log = ActionLogService::start_with_seed(&db_path, None).expect("action log");
+        for (job, level, message) in [
+            ("job-a", "debug", "parsing"),
+            ("job-a", "warn", "slow row"),
+            ("job-b", "error", "other job"),
+            ("job-a", "error", "bad row"),
+        ] {
+            let entry = GuestLogEntry::new(job, "csv.parse@1.3.0", level, message.into(), []);
+            action_log
+                .append_json(LOG_KIND, &serde_json::to_value(&entry).unwrap())
+                .await
+                .expect("append");
+        }
+
+        let db = AsyncConn::open(&db_path).await.expect("open db");
+        let all = load_job_logs(&db, "job-a", None, None).await.expect("all");
+        let messages: Vec<_> = all.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(messages, ["parsing", "slow row", "bad row"]);
+
+        let warn = load_job_logs(&db, "job-a", Some("WARN"), None)
+            .await
+            .expect("warn+");
+        assert_eq!(warn.len(), 2);
+        let latest = load_job_logs(&db, "job-a", None, Some(1))
+            .await
+            .expect("latest");
+        assert_eq!(latest[0].message, "bad row");
+
+        let err = load_job_logs(&db, "job-a", Some("fatal"), None)
+            .await
+            .unwrap_err();
+        assert!(err.starts_with("E-UICP-0730"));
+    }
+}
@@ -1,7 +1,15 @@
-﻿pub mod component_bindings;
+﻿pub mod applet_kv;
+pub mod component_bindings;
 pub mod compute;
 pub mod compute_cache;
 pub mod compute_input;
 pub mod hostctx;
+pub mod job_logs;
+pub mod module_integrity;
+pub mod module_pins;
+pub mod pipeline;
 pub mod registry;
+pub mod replay;
+pub mod scheduler;
+pub mod trust_store;
 pub mod wasi_logging;
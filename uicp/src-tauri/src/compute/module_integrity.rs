@@ -0,0 +1,331 @@
+//! Background integrity sweep for the installed modules directory.
+//!
+//! `find_module` refuses a module whose file no longer matches its manifest digest, which fails
+//! the job but leaves the bad file in place. The sweep re-hashes every manifest entry on a timer,
+//! moves mismatching files into `<modulesDir>/quarantine/` next to a JSON report, and restores the
+//! bundled copy when one with the recorded digest ships with the app. AOT artifacts whose MAC
+//! sidecar is missing or fails are quarantined the same way, and every quarantined module loses
+//! its instance pool and AOT artifact. The latest result is kept on `AppState` for
+//! `get_modules_info` and broadcast as a `modules-integrity` event.
+
+use std::{fs, path::Path, time::Instant};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use tauri::Runtime;
+
+use crate::compute::compute::{self, UnverifiedAotArtifact};
+use crate::compute::registry::{self, ModuleEntry};
+
+pub const QUARANTINE_DIR: &str = "quarantine";
+pub const EVENT_NAME: &str = "modules-integrity";
+
+/// Seconds between sweeps when `UICP_MODULES_INTEGRITY_INTERVAL_SECS` is unset; `0` disables them.
+pub const DEFAULT_INTERVAL_SECS: u64 = 15 * 60;
+
+/// One manifest entry whose file did not match its recorded digest.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrityIssue {
+    pub task: String,
+    pub version: String,
+    pub filename: String,
+    pub expected_digest: String,
+    /// `None` when the file could not be hashed (for example a symlink).
+    pub actual_digest: Option<String>,
+    /// Path of the moved file, relative to the modules directory.
+    pub quarantined_to: Option<String>,
+    /// True when a bundled copy with the expected digest replaced the file.
+    pub restored: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// One AOT artifact whose sidecar was missing or whose MAC did not verify.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AotIntegrityIssue {
+    pub digest: String,
+    /// Artifact file name inside the AOT cache directory.
+    pub artifact: String,
+    pub reason: String,
+    /// Path of the moved artifact, relative to the modules directory.
+    pub quarantined_to: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrityReport {
+    pub started_at: i64,
+    pub duration_ms: u64,
+    /// Entries whose file was present and hashed.
+    pub checked: usize,
+    /// Entries whose file does not exist (common in dev before modules are built).
+    pub missing: usize,
+    /// False when the sweep only reported issues and left files in place.
+    pub quarantine: bool,
+    pub issues: Vec<IntegrityIssue>,
+    #[serde(default)]
+    pub aot_issues: Vec<AotIntegrityIssue>,
+}
+
+/// Sweep interval from `UICP_MODULES_INTEGRITY_INTERVAL_SECS`, or `None` when disabled.
+pub fn interval_secs() -> Option<u64> {
+    let secs = std::env::var("UICP_MODULES_INTEGRITY_INTERVAL_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_INTERVAL_SECS);
+    (secs > 0).then_some(secs)
+}
+
+/// Sweep the app's modules directory. With `UICP_MODULES_DIR` set the directory is developer
+/// managed, so issues are reported but files are not moved (same rule as the bundled installer).
+pub fn sweep_app<R: Runtime>(app: &tauri::AppHandle<R>) -> Result<IntegrityReport> {
+    let dir = registry::modules_dir(app);
+    let bundled = registry::bundled_modules_path(app);
+    let quarantine = std::env::var("UICP_MODULES_DIR").is_err();
+    sweep(&dir, bundled.as_deref(), quarantine)
+}
+
+/// Re-hash every manifest entry in `modules_dir`. Mismatching files are moved to `quarantine/`
+/// (when `quarantine` is set) and replaced from `bundled` if it holds a copy with the expected
+/// digest; either way the entry's instance pool and AOT artifact are dropped. AOT artifacts that
+/// fail their MAC are checked next. Runs under the install lock so it never races
+/// `modules_install`.
+pub fn sweep(
+    modules_dir: &Path,
+    bundled: Option<&Path>,
+    quarantine: bool,
+) -> Result<IntegrityReport> {
+    let started = Instant::now();
+    let mut report = IntegrityReport {
+        started_at: chrono::Utc::now().timestamp(),
+        quarantine,
+        ..IntegrityReport::default()
+    };
+    let _lock = registry::acquire_install_lock(modules_dir).ok_or_else(|| {
+        anyhow::anyhow!("E-UICP-0275: module install in progress; integrity sweep skipped")
+    })?;
+    let manifest = registry::read_manifest_file(modules_dir)
+        .context("E-UICP-0275: read manifest for integrity sweep")?;
+    for entry in &manifest.entries {
+        if !registry::is_clean_filename(&entry.filename) {
+            continue;
+        }
+        let path = modules_dir.join(&entry.filename);
+        if fs::symlink_metadata(&path).is_err() {
+            report.missing += 1;
+            continue;
+        }
+        report.checked += 1;
+        let actual = registry::file_sha256_hex(&path).ok();
+        if actual
+            .as_deref()
+            .is_some_and(|got| got.eq_ignore_ascii_case(&entry.digest_sha256))
+        {
+            continue;
+        }
+        let mut issue = IntegrityIssue {
+            task: entry.task.clone(),
+            version: entry.version.clone(),
+            filename: entry.filename.clone(),
+            expected_digest: entry.digest_sha256.clone(),
+            actual_digest: actual,
+            quarantined_to: None,
+            restored: false,
+            error: None,
+        };
+        if quarantine {
+            if let Err(err) = quarantine_and_restore(modules_dir, bundled, entry, &mut issue) {
+                issue.error = Some(format!("E-UICP-0276: {err:#}"));
+            }
+            if issue.quarantined_to.is_some() {
+                compute::evict_module(entry, false);
+            }
+        }
+        report.issues.push(issue);
+    }
+    for artifact in compute::unverified_aot_artifacts() {
+        report.aot_issues.push(check_aot_artifact(
+            modules_dir,
+            &manifest.entries,
+            &artifact,
+            quarantine,
+        ));
+    }
+    report.duration_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+    Ok(report)
+}
+
+/// Move the bad file into `quarantine/` with a `.report.json` beside it, then put the bundled
+/// copy back when its digest matches the manifest.
+fn quarantine_and_restore(
+    modules_dir: &Path,
+    bundled: Option<&Path>,
+    entry: &ModuleEntry,
+    issue: &mut IntegrityIssue,
+) -> Result<()> {
+    let qdir = modules_dir.join(QUARANTINE_DIR);
+    fs::create_dir_all(&qdir).with_context(|| format!("mkdir: {}", qdir.display()))?;
+    let name = format!(
+        "{}-{}",
+        chrono::Utc::now().timestamp_millis(),
+        entry.filename
+    );
+    let path = modules_dir.join(&entry.filename);
+    let moved = qdir.join(&name);
+    fs::rename(&path, &moved)
+        .with_context(|| format!("quarantine {} -> {}", path.display(), moved.display()))?;
+    issue.quarantined_to = Some(format!("{QUARANTINE_DIR}/{name}"));
+
+    if let Some(candidate) = bundled.map(|dir| dir.join(&entry.filename)) {
+        match restore_from_bundle(&candidate, &path, &entry.digest_sha256) {
+            Ok(restored) => issue.restored = restored,
+            Err(err) => issue.error = Some(format!("E-UICP-0276: restore failed: {err:#}")),
+        }
+    }
+
+    let report_path = qdir.join(format!("{name}.report.json"));
+    let mut record = serde_json::to_value(&*issue)?;
+    record["detectedAt"] = serde_json::json!(chrono::Utc::now().timestamp());
+    fs::write(&report_path, serde_json::to_string_pretty(&record)? + "\n")
+        .with_context(|| format!("write {}", report_path.display()))?;
+    Ok(())
+}
+
+/// Report an unverified AOT artifact and, when `quarantine` is set, move it to `quarantine/`
+/// and drop the pools of every entry compiled from its digest.
+fn check_aot_artifact(
+    modules_dir: &Path,
+    entries: &[ModuleEntry],
+    artifact: &UnverifiedAotArtifact,
+    quarantine: bool,
+) -> AotIntegrityIssue {
+    let file_name = artifact
+        .path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let mut issue = AotIntegrityIssue {
+        digest: artifact.digest_sha256.clone(),
+        artifact: file_name.clone(),
+        reason: artifact.reason.to_string(),
+        quarantined_to: None,
+        error: None,
+    };
+    if !quarantine {
+        return issue;
+    }
+    let qdir = modules_dir.join(QUARANTINE_DIR);
+    let name = format!("{}-{file_name}", chrono::Utc::now().timestamp_millis());
+    // NOTE: The AOT cache may sit on another filesystem; a failed move deletes the artifact so
+    // it can never be loaded.
+    match fs::create_dir_all(&qdir).and_then(|()| fs::rename(&artifact.path, qdir.join(&name))) {
+        Ok(()) => issue.quarantined_to = Some(format!("{QUARANTINE_DIR}/{name}")),
+        Err(err) => {
+            let _ = fs::remove_file(&artifact.path);
+            issue.error = Some(format!("E-UICP-0276: quarantine failed, deleted: {err}"));
+        }
+    }
+    let mut sidecar = artifact.path.clone().into_os_string();
+    sidecar.push(".mac");
+    let _ = fs::remove_file(sidecar);
+    for entry in entries.iter().filter(|e| {
+        e.digest_sha256
+            .eq_ignore_ascii_case(&artifact.digest_sha256)
+    }) {
+        compute::evict_module(entry, false);
+    }
+    issue
+}
+
+/// Copy `candidate` over `dest` via a temp file, only if it hashes to `digest`.
+fn restore_from_bundle(candidate: &Path, dest: &Path, digest: &str) -> Result<bool> {
+    if !fs::symlink_metadata(candidate).is_ok_and(|meta| meta.file_type().is_file()) {
+        return Ok(false);
+    }
+    if !registry::verify_digest(candidate, digest)? {
+        return Ok(false);
+    }
+    let tmp = dest.with_extension("tmp");
+    fs::copy(candidate, &tmp)
+        .with_context(|| format!("copy {} -> {}", candidate.display(), tmp.display()))?;
+    if !registry::verify_digest(&tmp, digest)? {
+        let _ = fs::remove_file(&tmp);
+        return Ok(false);
+    }
+    registry::replace_file(&tmp, dest).with_context(|| format!("replace {}", dest.display()))?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compute::registry::ModuleManifest;
+
+    fn entry(dir: &Path, task: &str, bytes: &[u8]) -> ModuleEntry {
+        let filename = format!("{task}@1.0.0.wasm");
+        fs::write(dir.join(&filename), bytes).unwrap();
+        ModuleEntry {
+            task: task.into(),
+            version: "1.0.0".into(),
+            digest_sha256: registry::file_sha256_hex(&dir.join(&filename)).unwrap(),
+            filename,
+            signature: None,
+            keyid: None,
+            signed_at: None,
+            imports: None,
+        }
+    }
+
+    #[test]
+    fn sweep_quarantines_tampered_files_and_restores_bundled_copies() {
+        let modules = tempfile::tempdir().unwrap();
+        let bundle = tempfile::tempdir().unwrap();
+        let bundled = entry(bundle.path(), "csv.parse", b"bundled module");
+        fs::copy(
+            bundle.path().join(&bundled.filename),
+            modules.path().join(&bundled.filename),
+        )
+        .unwrap();
+        let local = entry(modules.path(), "table.query", b"local module");
+        let manifest = ModuleManifest {
+            entries: vec![bundled.clone(), local.clone()],
+        };
+        registry::write_manifest_file(modules.path(), &manifest).unwrap();
+        fs::write(modules.path().join(&bundled.filename), b"tampered").unwrap();
+        fs::write(modules.path().join(&local.filename), b"tampered").unwrap();
+
+        let dry = sweep(modules.path(), Some(bundle.path()), false).unwrap();
+        assert_eq!(dry.issues.len(), 2);
+        assert!(dry.issues.iter().all(|i| i.quarantined_to.is_none()));
+        assert!(modules.path().join(&local.filename).exists());
+
+        let report = sweep(modules.path(), Some(bundle.path()), true).unwrap();
+        assert_eq!((report.checked, report.missing), (2, 0));
+        let restored = &report.issues[0];
+        assert_eq!(restored.task, "csv.parse");
+        assert!(restored.restored);
+        assert!(registry::verify_digest(
+            &modules.path().join(&bundled.filename),
+            &bundled.digest_sha256
+        )
+        .unwrap());
+        let moved = &report.issues[1];
+        assert!(!moved.restored);
+        assert!(!modules.path().join(&local.filename).exists());
+        let qpath = modules.path().join(moved.quarantined_to.as_ref().unwrap());
+        assert_eq!(fs::read(&qpath).unwrap(), b"tampered");
+        let mut report_path = qpath.into_os_string();
+        report_path.push(".report.json");
+        let record: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(report_path).unwrap()).unwrap();
+        assert_eq!(record["expectedDigest"], local.digest_sha256.as_str());
+
+        let clean = sweep(modules.path(), Some(bundle.path()), true).unwrap();
+        assert!(clean.issues.is_empty());
+        assert_eq!((clean.checked, clean.missing), (1, 1));
+    }
+}
@@ -0,0 +1,205 @@
+//! Per-workspace module version pins and admission-time task resolution.
+//!
+//! A pin maps a task name to a semver requirement for one workspace (`table.query` -> `^0.1`), so
+//! upgrading an installed module does not silently change what older workspaces run. Jobs that name
+//! a task without a version pick up the workspace pin; an explicit `task@requirement` in the spec
+//! always wins. Requirements are resolved to one exact installed `task@version` before the job is
+//! recorded, so the submit record, cache key and final metrics all name the module that ran.
+
+use anyhow::Context;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::Serialize;
+use tauri::Runtime;
+use tokio_rusqlite::Connection as AsyncConn;
+
+use crate::compute::registry;
+
+pub fn ensure_module_pins_schema(conn: &Connection) -> anyhow::Result<()> {
+    conn.execute_batch(
+        r"
+        CREATE TABLE IF NOT EXISTS module_pins (
+            workspace_id TEXT NOT NULL,
+            task TEXT NOT NULL,
+            requirement TEXT NOT NULL,
+            updated_at INTEGER NOT NULL,
+            PRIMARY KEY (workspace_id, task)
+        );
+        ",
+    )
+    .context("ensure module_pins schema")?;
+    Ok(())
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ModulePin {
+    pub task: String,
+    pub requirement: String,
+    pub updated_at: i64,
+}
+
+/// Reject pins that could never resolve: a versioned task name or an unparsable requirement.
+pub fn validate_pin(task: &str, requirement: &str) -> Result<(), String> {
+    if task.is_empty() || task.contains('@') {
+        return Err(format!(
+            "E-UICP-0267: pin task must be a bare task name, got '{task}'"
+        ));
+    }
+    if semver::Version::parse(requirement).is_err()
+        && semver::VersionReq::parse(requirement).is_err()
+    {
+        return Err(format!(
+            "E-UICP-0267: invalid version requirement '{requirement}' for {task}"
+        ));
+    }
+    Ok(())
+}
+
+/// Insert or replace the pin for `task` in `workspace_id`.
+pub fn set_pin(
+    conn: &Connection,
+    workspace_id: &str,
+    task: &str,
+    requirement: &str,
+) -> rusqlite::Result<()> {
+    conn.execute(
+        "INSERT INTO module_pins (workspace_id, task, requirement, updated_at)
+         VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT (workspace_id, task)
+         DO UPDATE SET requirement = excluded.requirement, updated_at = excluded.updated_at",
+        params![
+            workspace_id,
+            task,
+            requirement,
+            chrono::Utc::now().timestamp()
+        ],
+    )?;
+    Ok(())
+}
+
+/// Remove the pin for `task`. Returns whether one existed.
+pub fn clear_pin(conn: &Connection, workspace_id: &str, task: &str) -> rusqlite::Result<bool> {
+    let removed = conn.execute(
+        "DELETE FROM module_pins WHERE workspace_id = ?1 AND task = ?2",
+        params![workspace_id, task],
+    )?;
+    Ok(removed > 0)
+}
+
+pub fn pin_for(
+    conn: &Connection,
+    workspace_id: &str,
+    task: &str,
+) -> rusqlite::Result<Option<String>> {
+    conn.query_row(
+        "SELECT requirement FROM module_pins WHERE workspace_id = ?1 AND task = ?2",
+        params![workspace_id, task],
+        |row| row.get(0),
+    )
+    .optional()
+}
+
+/// Every pin in `workspace_id`, sorted by task.
+pub fn list_pins(conn: &Connection, workspace_id: &str) -> rusqlite::Result<Vec<ModulePin>> {
+    let mut stmt = conn.prepare(
+        "SELECT task, requirement, updated_at FROM module_pins
+         WHERE workspace_id = ?1 ORDER BY task",
+    )?;
+    let rows = stmt
+        .query_map(params![workspace_id], |row| {
+            Ok(ModulePin {
+                task: row.get(0)?,
+                requirement: row.get(1)?,
+                updated_at: row.get(2)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(rows)
+}
+
+/// The task string a job should run as. Bare tasks pick up the workspace pin; requirements
+/// (`^0.1`, `=0.1.3`) resolve to the highest installed match. Bare tasks without a pin and exact
+/// versions pass through unchanged so their existing not-found handling applies.
+pub async fn resolve_job_task<R: Runtime>(
+    app: &tauri::AppHandle<R>,
+    db: &AsyncConn,
+    workspace_id: &str,
+    task: &str,
+) -> Result<String, String> {
+    let (name, version) = task.split_once('@').unwrap_or((task, ""));
+    let (requirement, pinned) = if version.is_empty() {
+        let ws = workspace_id.to_string();
+        let key = name.to_string();
+        let pin = db
+            .call(move |conn| Ok(pin_for(conn, &ws, &key)?))
+            .await
+            .map_err(|e| format!("E-UICP-0268: read module pins failed: {e}"))?;
+        match pin {
+            Some(requirement) => (requirement, true),
+            None => return Ok(task.to_string()),
+        }
+    } else if semver::Version::parse(version).is_ok() {
+        return Ok(task.to_string());
+    } else {
+        (version.to_string(), false)
+    };
+    let source = if pinned { " (workspace pin)" } else { "" };
+    match registry::resolve_task_ref(app, &format!("{name}@{requirement}")) {
+        Ok(Some(resolved)) => Ok(resolved),
+        Ok(None) => Err(format!(
+            "E-UICP-0267: no installed version of {name} satisfies '{requirement}'{source}"
+        )),
+        Err(err) => Err(format!(
+            "E-UICP-0267: resolve {name}@{requirement}{source}: {err:#}"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn conn() -> Connection {
+        let conn = Connection::open_in_memory().expect("open in-memory sqlite");
+        ensure_module_pins_schema(&conn).expect("schema");
+        conn
+    }
+
+    #[test]
+    fn pins_are_scoped_per_workspace_and_replaceable() {
+        let conn = conn();
+        set_pin(&conn, "default", "table.query", "^0.1").unwrap();
+        set_pin(&conn, "other", "table.query", "=0.2.0").unwrap();
+        set_pin(&conn, "default", "table.query", "=0.1.3").unwrap();
+        set_pin(&conn, "default", "csv.parse", "^1").unwrap();
+
+        assert_eq!(
+            pin_for(&conn, "default", "table.query").unwrap().as_deref(),
+            Some("=0.1.3")
+        );
+        let tasks: Vec<_> = list_pins(&conn, "default")
+            .unwrap()
+            .into_iter()
+            .map(|p| p.task)
+            .collect();
+        assert_eq!(tasks, ["csv.parse", "table.query"]);
+
+        assert!(clear_pin(&conn, "default", "table.query").unwrap());
+        assert!(!clear_pin(&conn, "default", "table.query").unwrap());
+        assert_eq!(pin_for(&conn, "default", "table.query").unwrap(), None);
+        assert_eq!(
+            pin_for(&conn, "other", "table.query").unwrap().as_deref(),
+            Some("=0.2.0")
+        );
+    }
+
+    #[test]
+    fn validate_pin_rejects_versioned_tasks_and_bad_ranges() {
+        assert!(validate_pin("table.query", "^0.1").is_ok());
+        assert!(validate_pin("table.query", "0.1.3").is_ok());
+        assert!(validate_pin("table.query@0.1.3", "^0.1")
+            .unwrap_err()
+            .starts_with("E-UICP-0267"));
+        assert!(validate_pin("table.query", "latest").is_err());
+    }
+}
@@ -0,0 +1,532 @@
+//! Host-side execution of compute job DAGs (`compute_pipeline`).
+//!
+//! A pipeline is a list of nodes, each wrapping an ordinary `ComputeJobSpec`. Anywhere in a node's
+//! input, `{"$ref": "<nodeId>#<json-pointer>"}` is replaced with that slice of the referenced
+//! node's output before submission and implies a dependency edge. Nodes are admitted through the
+//! same path as `compute_call`, so policy, cache lookups and scheduling apply per node.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use tauri::{AppHandle, Emitter, Listener, Runtime};
+use tokio::sync::mpsc;
+
+use crate::compute::compute::error_codes;
+use crate::infrastructure::core::emit_or_log;
+use crate::infrastructure::events::{
+    EVENT_COMPUTE_PIPELINE_FINAL, EVENT_COMPUTE_PIPELINE_PARTIAL, EVENT_COMPUTE_RESULT_FINAL,
+    EVENT_COMPUTE_RESULT_PARTIAL,
+};
+use crate::security::policy::ComputeJobSpec;
+
+/// Upper bound on nodes per pipeline; larger graphs should be split by the caller.
+pub const MAX_PIPELINE_NODES: usize = 64;
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ComputePipelineSpec {
+    pub pipeline_id: String,
+    pub nodes: Vec<PipelineNodeSpec>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PipelineNodeSpec {
+    pub id: String,
+    /// Ordering edges in addition to those implied by `$ref` inputs.
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+    pub job: ComputeJobSpec,
+}
+
+/// Validated graph: dependency indices per node and a topological order.
+#[derive(Debug)]
+pub struct PipelinePlan {
+    pub deps: Vec<Vec<usize>>,
+    pub order: Vec<usize>,
+}
+
+fn ref_target(map: &Map<String, Value>) -> Option<&str> {
+    if map.len() != 1 {
+        return None;
+    }
+    map.get("$ref").and_then(Value::as_str)
+}
+
+/// Split `node#/pointer` into its parts. The pointer may be empty (whole output).
+fn parse_ref(raw: &str) -> Result<(&str, &str), String> {
+    let (node, pointer) = raw.split_once('#').unwrap_or((raw, ""));
+    if node.is_empty() || !(pointer.is_empty() || pointer.starts_with('/')) {
+        return Err(format!(
+            "invalid $ref '{raw}' (expected '<nodeId>#/json/pointer')"
+        ));
+    }
+    Ok((node, pointer))
+}
+
+fn collect_refs<'a>(value: &'a Value, out: &mut Vec<&'a str>) -> Result<(), String> {
+    match value {
+        Value::Object(map) => {
+            if let Some(raw) = ref_target(map) {
+                out.push(parse_ref(raw)?.0);
+            } else {
+                for v in map.values() {
+                    collect_refs(v, out)?;
+                }
+            }
+        }
+        Value::Array(items) => {
+            for v in items {
+                collect_refs(v, out)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Replace every `$ref` object in `value` with the referenced slice of a completed node's output.
+pub fn resolve_refs(value: &Value, outputs: &HashMap<String, Value>) -> Result<Value, String> {
+    match value {
+        Value::Object(map) => {
+            if let Some(raw) = ref_target(map) {
+                let (node, pointer) = parse_ref(raw)?;
+                let output = outputs.get(node).ok_or_else(|| {
+                    format!("$ref '{raw}' points at node '{node}' with no output")
+                })?;
+                return output
+                    .pointer(pointer)
+                    .cloned()
+                    .ok_or_else(|| format!("$ref '{raw}' does not match the output of '{node}'"));
+            }
+            map.iter()
+                .map(|(k, v)| Ok((k.clone(), resolve_refs(v, outputs)?)))
+                .collect::<Result<Map<_, _>, String>>()
+                .map(Value::Object)
+        }
+        Value::Array(items) => items
+            .iter()
+            .map(|v| resolve_refs(v, outputs))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array),
+        other => Ok(other.clone()),
+    }
+}
+
+/// Validate ids and references and order the graph.
+pub fn plan(spec: &ComputePipelineSpec) -> Result<PipelinePlan, String> {
+    // ERROR: E-UICP-0244 malformed pipeline (empty, oversized, duplicate ids, unknown refs, cycles).
+    let invalid = |msg: String| format!("E-UICP-0244: pipeline '{}': {msg}", spec.pipeline_id);
+    if spec.nodes.is_empty() {
+        return Err(invalid("no nodes".into()));
+    }
+    if spec.nodes.len() > MAX_PIPELINE_NODES {
+        return Err(invalid(format!(
+            "{} nodes exceeds the limit of {MAX_PIPELINE_NODES}",
+            spec.nodes.len()
+        )));
+    }
+    let mut index = HashMap::new();
+    let mut job_ids = HashSet::new();
+    for (i, node) in spec.nodes.iter().enumerate() {
+        if node.id.is_empty() || index.insert(node.id.as_str(), i).is_some() {
+            return Err(invalid(format!("duplicate or empty node id '{}'", node.id)));
+        }
+        if !job_ids.insert(node.job.job_id.as_str()) {
+            return Err(invalid(format!("duplicate jobId '{}'", node.job.job_id)));
+        }
+    }
+
+    let mut deps = Vec::with_capacity(spec.nodes.len());
+    for node in &spec.nodes {
+        let mut names: Vec<&str> = node.depends_on.iter().map(String::as_str).collect();
+        collect_refs(&node.job.input, &mut names).map_err(invalid)?;
+        let mut ids: Vec<usize> = Vec::new();
+        for name in names {
+            let Some(&dep) = index.get(name) else {
+                return Err(invalid(format!(
+                    "node '{}' depends on unknown node '{name}'",
+                    node.id
+                )));
+            };
+            if !ids.contains(&dep) {
+                ids.push(dep);
+            }
+        }
+        deps.push(ids);
+    }
+
+    // Kahn's algorithm; anything left over sits on a cycle.
+    let mut indegree: Vec<usize> = deps.iter().map(Vec::len).collect();
+    let dependents = dependents_of(&deps);
+    let mut ready: VecDeque<usize> = (0..deps.len()).filter(|&i| indegree[i] == 0).collect();
+    let mut order = Vec::with_capacity(deps.len());
+    while let Some(i) = ready.pop_front() {
+        order.push(i);
+        for &d in &dependents[i] {
+            indegree[d] -= 1;
+            if indegree[d] == 0 {
+                ready.push_back(d);
+            }
+        }
+    }
+    if order.len() != deps.len() {
+        let stuck: Vec<&str> = (0..deps.len())
+            .filter(|i| !order.contains(i))
+            .map(|i| spec.nodes[i].id.as_str())
+            .collect();
+        return Err(invalid(format!("cycle through {}", stuck.join(", "))));
+    }
+    Ok(PipelinePlan { deps, order })
+}
+
+fn dependents_of(deps: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let mut out = vec![Vec::new(); deps.len()];
+    for (i, ds) in deps.iter().enumerate() {
+        for &d in ds {
+            out[d].push(i);
+        }
+    }
+    out
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum NodeState {
+    Pending,
+    Running,
+    Ok,
+    Failed,
+    Skipped,
+}
+
+impl NodeState {
+    fn as_str(self) -> &'static str {
+        match self {
+            NodeState::Pending => "pending",
+            NodeState::Running => "running",
+            NodeState::Ok => "ok",
+            NodeState::Failed => "error",
+            NodeState::Skipped => "skipped",
+        }
+    }
+}
+
+fn payload_job_id(payload: &Value) -> Option<&str> {
+    payload
+        .get("jobId")
+        .or_else(|| payload.get("job_id"))
+        .and_then(Value::as_str)
+}
+
+/// Run a planned pipeline to completion and emit one `compute-pipeline-final` event.
+///
+/// `submit` admits a single node job (the `compute_call` path). Node results are collected from
+/// `compute-result-final`; partials are re-emitted on `compute-pipeline-partial` tagged with the
+/// node id. Dependents of a failed node are skipped; independent branches keep running.
+pub async fn run<R, S, Fut>(
+    app: AppHandle<R>,
+    spec: ComputePipelineSpec,
+    plan: PipelinePlan,
+    submit: S,
+) -> Value
+where
+    R: Runtime,
+    S: Fn(AppHandle<R>, ComputeJobSpec) -> Fut,
+    Fut: Future<Output = Result<(), String>>,
+{
+    let started = Instant::now();
+    let pipeline_id = spec.pipeline_id.clone();
+    let node_of_job: Arc<HashMap<String, String>> = Arc::new(
+        spec.nodes
+            .iter()
+            .map(|n| (n.job.job_id.clone(), n.id.clone()))
+            .collect(),
+    );
+    let index: HashMap<&str, usize> = spec
+        .nodes
+        .iter()
+        .enumerate()
+        .map(|(i, n)| (n.id.as_str(), i))
+        .collect();
+
+    // INVARIANT: listeners are registered before the first submit so cache hits (emitted inline) are seen.
+    let (tx_final, mut rx_final) = mpsc::unbounded_channel::<Value>();
+    let final_jobs = Arc::clone(&node_of_job);
+    let final_listener = app.listen(EVENT_COMPUTE_RESULT_FINAL, move |event| {
+        if let Ok(value) = serde_json::from_str::<Value>(event.payload()) {
+            if payload_job_id(&value).is_some_and(|id| final_jobs.contains_key(id)) {
+                let _ = tx_final.send(value);
+            }
+        }
+    });
+    let partial_jobs = Arc::clone(&node_of_job);
+    let partial_app = app.clone();
+    let partial_pipeline = pipeline_id.clone();
+    let partial_listener = app.listen(EVENT_COMPUTE_RESULT_PARTIAL, move |event| {
+        let Ok(value) = serde_json::from_str::<Value>(event.payload()) else {
+            return;
+        };
+        let Some(node_id) = payload_job_id(&value).and_then(|id| partial_jobs.get(id)) else {
+            return;
+        };
+        let _ = partial_app.emit(
+            EVENT_COMPUTE_PIPELINE_PARTIAL,
+            json!({ "pipelineId": partial_pipeline, "nodeId": node_id, "partial": value }),
+        );
+    });
+
+    let n = spec.nodes.len();
+    let dependents = dependents_of(&plan.deps);
+    let mut indegree: Vec<usize> = plan.deps.iter().map(Vec::len).collect();
+    let mut states = vec![NodeState::Pending; n];
+    let mut summaries: Vec<Value> = vec![Value::Null; n];
+    let mut outputs: HashMap<String, Value> = HashMap::new();
+    let mut ready: VecDeque<usize> = plan
+        .order
+        .iter()
+        .copied()
+        .filter(|&i| indegree[i] == 0)
+        .collect();
+    let mut running = 0usize;
+
+    loop {
+        while let Some(i) = ready.pop_front() {
+            let node = &spec.nodes[i];
+            let mut job = node.job.clone();
+            let admitted = match resolve_refs(&job.input, &outputs) {
+                Ok(input) => {
+                    job.input = input;
+                    submit(app.clone(), job)
+                        .await
+                        .map_err(|e| (error_codes::RUNTIME_FAULT, e))
+                }
+                Err(e) => Err((error_codes::INPUT_INVALID, e)),
+            };
+            match admitted {
+                Ok(()) => {
+                    states[i] = NodeState::Running;
+                    running += 1;
+                }
+                Err((code, message)) => {
+                    summaries[i] = json!({ "code": code, "message": message });
+                    settle(
+                        i,
+                        false,
+                        &mut states,
+                        &dependents,
+                        &mut indegree,
+                        &mut ready,
+                    );
+                }
+            }
+        }
+        if running == 0 {
+            break;
+        }
+        let Some(payload) = rx_final.recv().await else {
+            break;
+        };
+        let Some(&i) = payload_job_id(&payload)
+            .and_then(|id| node_of_job.get(id))
+            .and_then(|node| index.get(node.as_str()))
+        else {
+            continue;
+        };
+        if states[i] != NodeState::Running {
+            continue;
+        }
+        running -= 1;
+        let ok = payload.get("ok").and_then(Value::as_bool).unwrap_or(false);
+        if ok {
+            outputs.insert(
+                spec.nodes[i].id.clone(),
+                payload.get("output").cloned().unwrap_or(Value::Null),
+            );
+            summaries[i] = json!({ "metrics": payload.get("metrics") });
+        } else {
+            summaries[i] = json!({
+                "code": payload.get("code"),
+                "message": payload.get("message"),
+                "metrics": payload.get("metrics"),
+            });
+        }
+        settle(i, ok, &mut states, &dependents, &mut indegree, &mut ready);
+    }
+
+    app.unlisten(final_listener);
+    app.unlisten(partial_listener);
+
+    let mut nodes = Map::new();
+    let mut leaf_outputs = Map::new();
+    let mut cache_hits = 0u64;
+    for (i, node) in spec.nodes.iter().enumerate() {
+        let mut summary = json!({
+            "jobId": node.job.job_id,
+            "task": node.job.task,
+            "status": states[i].as_str(),
+        });
+        if let (Some(dst), Value::Object(src)) = (summary.as_object_mut(), summaries[i].take()) {
+            dst.extend(src.into_iter().filter(|(_, v)| !v.is_null()));
+        }
+        if summary
+            .pointer("/metrics/cacheHit")
+            .and_then(Value::as_bool)
+            .unwrap_or(false)
+        {
+            cache_hits += 1;
+        }
+        nodes.insert(node.id.clone(), summary);
+        if dependents[i].is_empty() {
+            if let Some(out) = outputs.remove(&node.id) {
+                leaf_outputs.insert(node.id.clone(), out);
+            }
+        }
+    }
+    let count = |s: NodeState| states.iter().filter(|&&x| x == s).count();
+    let payload = json!({
+        "pipelineId": pipeline_id,
+        "ok": count(NodeState::Ok) == n,
+        "nodes": nodes,
+        "outputs": leaf_outputs,
+        "metrics": {
+            "durationMs": u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX),
+            "nodeCount": n,
+            "succeeded": count(NodeState::Ok),
+            "failed": count(NodeState::Failed),
+            "skipped": count(NodeState::Skipped),
+            "cacheHits": cache_hits,
+        },
+    });
+    emit_or_log(&app, EVENT_COMPUTE_PIPELINE_FINAL, &payload);
+    payload
+}
+
+/// Record a node outcome: release dependents on success, skip everything downstream on failure.
+fn settle(
+    i: usize,
+    ok: bool,
+    states: &mut [NodeState],
+    dependents: &[Vec<usize>],
+    indegree: &mut [usize],
+    ready: &mut VecDeque<usize>,
+) {
+    if ok {
+        states[i] = NodeState::Ok;
+        for &d in &dependents[i] {
+            indegree[d] -= 1;
+            if indegree[d] == 0 && states[d] == NodeState::Pending {
+                ready.push_back(d);
+            }
+        }
+        return;
+    }
+    states[i] = NodeState::Failed;
+    let mut stack: Vec<usize> = dependents[i].clone();
+    while let Some(d) = stack.pop() {
+        if states[d] == NodeState::Pending {
+            states[d] = NodeState::Skipped;
+            stack.extend(dependents[d].iter().copied());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(id: &str, input: Value, depends_on: &[&str]) -> PipelineNodeSpec {
+        let job: ComputeJobSpec = serde_json::from_value(json!({
+            "jobId": format!("job-{id}"),
+            "task": "csv.parse@1.2.0",
+            "input": input,
+            "provenance": { "envHash": "test" },
+        }))
+        .expect("job spec");
+        PipelineNodeSpec {
+            id: id.into(),
+            depends_on: depends_on.iter().map(|s| s.to_string()).collect(),
+            job,
+        }
+    }
+
+    fn pipeline(nodes: Vec<PipelineNodeSpec>) -> ComputePipelineSpec {
+        ComputePipelineSpec {
+            pipeline_id: "p1".into(),
+            nodes,
+        }
+    }
+
+    #[test]
+    fn plan_orders_nodes_by_refs_and_explicit_edges() {
+        let spec = pipeline(vec![
+            node("query", json!({ "rows": { "$ref": "parse#" } }), &[]),
+            node("report", json!({}), &["query"]),
+            node("parse", json!({ "source": "data:text/csv,a" }), &[]),
+        ]);
+        let plan = plan(&spec).expect("plan");
+        assert_eq!(plan.order, vec![2, 0, 1]);
+        assert_eq!(plan.deps[0], vec![2]);
+    }
+
+    #[test]
+    fn plan_rejects_cycles_unknown_refs_and_duplicates() {
+        let cyclic = pipeline(vec![
+            node("a", json!({ "x": { "$ref": "b#/0" } }), &[]),
+            node("b", json!({}), &["a"]),
+        ]);
+        assert!(plan(&cyclic).unwrap_err().contains("cycle"));
+
+        let unknown = pipeline(vec![node("a", json!({ "x": { "$ref": "zzz#" } }), &[])]);
+        assert!(plan(&unknown).unwrap_err().contains("unknown node 'zzz'"));
+
+        let dup = pipeline(vec![node("a", json!({}), &[]), node("a", json!({}), &[])]);
+        assert!(plan(&dup).unwrap_err().starts_with("E-UICP-0244"));
+    }
+
+    #[test]
+    fn resolve_refs_substitutes_json_pointer_slices() {
+        let mut outputs = HashMap::new();
+        outputs.insert("parse".to_string(), json!([["a", "b"], ["1", "2"]]));
+        let input = json!({
+            "rows": { "$ref": "parse#" },
+            "header": { "$ref": "parse#/0" },
+            "nested": [{ "$ref": "parse#/1/1" }],
+            "literal": { "$ref": "parse#/0", "other": true },
+        });
+        let resolved = resolve_refs(&input, &outputs).expect("resolve");
+        assert_eq!(resolved["rows"], json!([["a", "b"], ["1", "2"]]));
+        assert_eq!(resolved["header"], json!(["a", "b"]));
+        assert_eq!(resolved["nested"], json!(["2"]));
+        assert_eq!(resolved["literal"]["other"], json!(true));
+
+        let missing = json!({ "x": { "$ref": "parse#/9" } });
+        assert!(resolve_refs(&missing, &outputs).is_err());
+    }
+
+    #[test]
+    fn failed_nodes_skip_their_downstream_only() {
+        // a -> b -> c, d independent
+        let deps = vec![vec![], vec![0], vec![1], vec![]];
+        let dependents = dependents_of(&deps);
+        let mut indegree: Vec<usize> = deps.iter().map(Vec::len).collect();
+        let mut states = vec![NodeState::Pending; 4];
+        let mut ready = VecDeque::new();
+        settle(
+            0,
+            false,
+            &mut states,
+            &dependents,
+            &mut indegree,
+            &mut ready,
+        );
+        settle(3, true, &mut states, &dependents, &mut indegree, &mut ready);
+        assert!(ready.is_empty());
+        assert_eq!(states[1].as_str(), "skipped");
+        assert_eq!(states[2].as_str(), "skipped");
+        assert_eq!(states[3].as_str(), "ok");
+    }
+}
@@ -25,7 +25,7 @@ use base64::engine::general_purpose::{
     STANDARD as BASE64_STANDARD, URL_SAFE_NO_PAD as BASE64_URL_SAFE_NO_PAD,
 };
 use base64::Engine as _;
-use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModuleEntry {
@@ -39,6 +39,11 @@ pub struct ModuleEntry {
     pub keyid: Option<String>, // identifier of signing key (e.g., fingerprint, key name)
     #[serde(default)]
     pub signed_at: Option<i64>, // unix timestamp of signature creation
+    /// Component import allowlist (e.g. "wasi:io/streams@0.2"). When present it replaces the
+    /// built-in policy for the task and is bound into the entry signature; the host ceiling
+    /// (no sockets/http) still applies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub imports: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -471,13 +476,17 @@ pub fn find_module<R: Runtime>(
                     }),
                 );
             }
+            // Revoked or expired signers are refused whether or not strict mode is on.
+            let trust_dir = crate::compute::trust_store::default_dir();
+            crate::compute::trust_store::check_signer(&trust_dir, entry)?;
             // Strict mode: require a valid signature using UICP_MODULES_PUBKEY
-            enforce_strict_signature(entry)?;
+            enforce_strict_signature(&trust_dir, entry)?;
             #[cfg(feature = "wasm_compute")]
             {
-                crate::compute::compute::preflight_component_imports(
+                crate::compute::compute::preflight_component_imports_with(
                     &path,
                     &format!("{}@{}", entry.task, entry.version),
+                    trusted_imports(&trust_dir, entry),
                 )
                 .with_context(|| {
                     format!(
@@ -528,6 +537,19 @@ pub fn find_module<R: Runtime>(
     }
 }
 
+/// Exact `task@version` of the installed entry `task_ref` selects (see `select_manifest_entry`),
+/// or `None` when nothing installed matches. Reads the manifest only; the module file is verified
+/// when the job loads it.
+pub fn resolve_task_ref<R: Runtime>(
+    app: &tauri::AppHandle<R>,
+    task_ref: &str,
+) -> Result<Option<String>> {
+    let (task, version) = task_ref.split_once('@').unwrap_or((task_ref, ""));
+    let manifest = load_manifest(app)?;
+    Ok(select_manifest_entry(&manifest.entries, task, version)?
+        .map(|entry| format!("{}@{}", entry.task, entry.version)))
+}
+
 fn strict_verify_enabled() -> bool {
     std::env::var("STRICT_MODULES_VERIFY")
         .ok()
@@ -554,7 +576,7 @@ fn require_pubkey_from_env() -> AnyResult<[u8; 32]> {
     Ok(arr)
 }
 
-fn decode_pubkey_any(s: &str) -> AnyResult<[u8; 32]> {
+pub fn decode_pubkey_any(s: &str) -> AnyResult<[u8; 32]> {
     let b64 = BASE64_STANDARD.decode(s.as_bytes()).ok();
     let bytes = if let Some(v) = b64 {
         v
@@ -567,7 +589,7 @@ fn decode_pubkey_any(s: &str) -> AnyResult<[u8; 32]> {
     Ok(arr)
 }
 
-fn read_trust_store() -> AnyResult<Option<std::collections::HashMap<String, [u8; 32]>>> {
+pub(crate) fn read_trust_store() -> AnyResult<Option<std::collections::HashMap<String, [u8; 32]>>> {
     if let Ok(json_inline) = std::env::var("UICP_TRUST_STORE_JSON") {
         let map: serde_json::Value =
             serde_json::from_str(&json_inline).context("parse UICP_TRUST_STORE_JSON")?;
@@ -605,8 +627,11 @@ fn read_trust_store() -> AnyResult<Option<std::collections::HashMap<String, [u8;
     Ok(None)
 }
 
-fn require_pubkey_for_entry(entry: &ModuleEntry) -> AnyResult<[u8; 32]> {
+fn require_pubkey_for_entry(trust_dir: &Path, entry: &ModuleEntry) -> AnyResult<[u8; 32]> {
     if let Some(keyid) = &entry.keyid {
+        if let Some(pk) = crate::compute::trust_store::managed_pubkey(trust_dir, keyid)? {
+            return Ok(pk);
+        }
         if let Some(store) = read_trust_store()? {
             if let Some(pk) = store.get(keyid) {
                 return Ok(*pk);
@@ -616,17 +641,40 @@ fn require_pubkey_for_entry(entry: &ModuleEntry) -> AnyResult<[u8; 32]> {
                 keyid
             );
         }
-        anyhow::bail!("STRICT_MODULES_VERIFY: UICP_TRUST_STORE or UICP_TRUST_STORE_JSON required for keyid {}", keyid);
+        anyhow::bail!("STRICT_MODULES_VERIFY: trust_store.json, UICP_TRUST_STORE or UICP_TRUST_STORE_JSON required for keyid {}", keyid);
     }
     // Fallback: single pubkey from UICP_MODULES_PUBKEY
     require_pubkey_from_env()
 }
 
-fn enforce_strict_signature(entry: &ModuleEntry) -> AnyResult<()> {
+/// The import allowlist preflight may honor for `entry`. A declared `imports` list widens what
+/// gets linked, so it only counts once the entry signature verifies against a trusted key;
+/// unsigned or unverifiable entries fall back to the built-in defaults.
+fn trusted_imports<'a>(trust_dir: &Path, entry: &'a ModuleEntry) -> Option<&'a [String]> {
+    let imports = entry.imports.as_deref()?;
+    let pubkey = require_pubkey_for_entry(trust_dir, entry).ok()?;
+    match verify_entry_signature(entry, &pubkey) {
+        Ok(SignatureStatus::Verified) => Some(imports),
+        _ => None,
+    }
+}
+
+/// True when `module`'s verified manifest entry declares `interface` (any version) in its
+/// `imports`. Unsigned or unverifiable entries never count, matching what preflight honors.
+pub fn declares_import(module: &ModuleRef, interface: &str) -> bool {
+    let trust_dir = crate::compute::trust_store::default_dir();
+    trusted_imports(&trust_dir, &module.entry).is_some_and(|imports| {
+        imports
+            .iter()
+            .any(|name| name.split('@').next() == Some(interface))
+    })
+}
+
+fn enforce_strict_signature(trust_dir: &Path, entry: &ModuleEntry) -> AnyResult<()> {
     if !strict_verify_enabled() {
         return Ok(());
     }
-    let pk = require_pubkey_for_entry(entry)?;
+    let pk = require_pubkey_for_entry(trust_dir, entry)?;
     let sig_status = verify_entry_signature(entry, &pk)?;
     match sig_status {
         SignatureStatus::Verified => Ok(()),
@@ -643,7 +691,9 @@ fn enforce_strict_signature(entry: &ModuleEntry) -> AnyResult<()> {
     }
 }
 
-pub fn verify_digest(path: &Path, expected_hex: &str) -> Result<bool> {
+/// Hex SHA-256 of a module file. Symlinks are refused so a digest always describes the file
+/// itself.
+pub fn file_sha256_hex(path: &Path) -> Result<String> {
     let meta = fs::symlink_metadata(path)
         .with_context(|| format!("stat module for digest: {}", path.display()))?;
     if meta.file_type().is_symlink() {
@@ -660,30 +710,28 @@ pub fn verify_digest(path: &Path, expected_hex: &str) -> Result<bool> {
         }
         hasher.update(&buf[..read]);
     }
-    let got = hex::encode(hasher.finalize());
+    Ok(hex::encode(hasher.finalize()))
+}
+
+pub fn verify_digest(path: &Path, expected_hex: &str) -> Result<bool> {
+    let got = file_sha256_hex(path)?;
     Ok(expected_hex.eq_ignore_ascii_case(&got))
 }
 
-/// Verify an entry's Ed25519 signature against the expected digest.
-/// - `pubkey_bytes` must be the 32-byte Ed25519 public key.
-/// - `entry.signature` may be base64 or hex encoded.
-///
-/// The message that is signed uses domain separation to bind metadata alongside the digest.
-pub fn verify_entry_signature(entry: &ModuleEntry, pubkey_bytes: &[u8]) -> Result<SignatureStatus> {
-    let Some(sig_str) = &entry.signature else {
-        return Ok(SignatureStatus::Missing);
-    }; // no signature provided
-    if pubkey_bytes.len() != 32 {
-        anyhow::bail!("pubkey must be 32 bytes (Ed25519)");
+/// The message an entry signature covers: domain tag, task, version, digest bytes and, when
+/// present, the canonical import allowlist and `signed_at`.
+pub fn signing_message(entry: &ModuleEntry) -> Result<Vec<u8>> {
+    let mut message = signing_message_without_time(entry)?;
+    if let Some(signed_at) = entry.signed_at {
+        // WHY: Key expiry is judged by `signed_at`; an uncovered timestamp could be backdated.
+        message.push(0);
+        message.extend_from_slice(format!("signed_at={signed_at}").as_bytes());
     }
+    Ok(message)
+}
 
-    // Decode signature (try base64, then hex)
-    let sig_bytes = BASE64_STANDARD
-        .decode(sig_str.as_bytes())
-        .or_else(|_| BASE64_URL_SAFE_NO_PAD.decode(sig_str.as_bytes()))
-        .or_else(|_| hex::decode(sig_str).context("decode signature hex"))?;
-    let sig = Signature::try_from(sig_bytes.as_slice()).context("ed25519 signature parse")?;
-
+/// The message signed before `signed_at` was covered.
+fn signing_message_without_time(entry: &ModuleEntry) -> Result<Vec<u8>> {
     ensure!(
         is_valid_digest_hex(&entry.digest_sha256),
         "digest must be 64 hex chars"
@@ -702,13 +750,88 @@ pub fn verify_entry_signature(entry: &ModuleEntry, pubkey_bytes: &[u8]) -> Resul
     message.push(0);
     message.extend_from_slice(b"sha256=");
     message.extend_from_slice(&digest_bytes);
+    if let Some(imports) = canonical_imports(entry) {
+        // WHY: A declared allowlist widens what preflight accepts, so it must be covered by the
+        // signature; entries without `imports` keep the original message for compatibility.
+        message.push(0);
+        message.extend_from_slice(b"imports=");
+        message.extend_from_slice(imports.as_bytes());
+    }
+    Ok(message)
+}
+
+/// Sign `entry` with the Ed25519 key derived from `seed`, recording `keyid` and `signed_at`.
+/// The signature is stored base64-encoded, matching the bundled manifest.
+pub fn sign_entry(
+    entry: &mut ModuleEntry,
+    seed: &[u8; 32],
+    keyid: Option<&str>,
+    signed_at: i64,
+) -> Result<()> {
+    entry.signed_at = Some(signed_at);
+    let message = signing_message(entry)?;
+    let signature = SigningKey::from_bytes(seed).sign(&message);
+    entry.signature = Some(BASE64_STANDARD.encode(signature.to_bytes()));
+    entry.keyid = keyid.map(str::to_string);
+    Ok(())
+}
+
+fn parse_signature(sig_str: &str) -> Result<Signature> {
+    // Decode signature (try base64, then hex)
+    let sig_bytes = BASE64_STANDARD
+        .decode(sig_str.as_bytes())
+        .or_else(|_| BASE64_URL_SAFE_NO_PAD.decode(sig_str.as_bytes()))
+        .or_else(|_| hex::decode(sig_str).context("decode signature hex"))?;
+    Signature::try_from(sig_bytes.as_slice()).context("ed25519 signature parse")
+}
+
+/// True when `entry`'s signature verifies under `pubkey` over a message that includes its
+/// `signed_at`. Signatures from before `signed_at` was covered return false.
+pub fn signature_covers_signed_at(entry: &ModuleEntry, pubkey: &[u8; 32]) -> bool {
+    let (Some(sig_str), Some(_)) = (&entry.signature, entry.signed_at) else {
+        return false;
+    };
+    let (Ok(sig), Ok(message), Ok(vk)) = (
+        parse_signature(sig_str),
+        signing_message(entry),
+        VerifyingKey::from_bytes(pubkey),
+    ) else {
+        return false;
+    };
+    vk.verify(&message, &sig).is_ok()
+}
+
+/// Verify an entry's Ed25519 signature against the expected digest.
+/// - `pubkey_bytes` must be the 32-byte Ed25519 public key.
+/// - `entry.signature` may be base64 or hex encoded.
+///
+/// The message that is signed uses domain separation to bind metadata alongside the digest.
+pub fn verify_entry_signature(entry: &ModuleEntry, pubkey_bytes: &[u8]) -> Result<SignatureStatus> {
+    let Some(sig_str) = &entry.signature else {
+        return Ok(SignatureStatus::Missing);
+    }; // no signature provided
+    if pubkey_bytes.len() != 32 {
+        anyhow::bail!("pubkey must be 32 bytes (Ed25519)");
+    }
+
+    let sig = parse_signature(sig_str)?;
+    let message = signing_message(entry)?;
 
     let vk = VerifyingKey::from_bytes(pubkey_bytes.try_into().expect("len checked"))
         .context("verifying key parse")?;
-    match vk.verify(&message, &sig) {
-        Ok(_) => Ok(SignatureStatus::Verified),
-        Err(_) => Ok(SignatureStatus::Invalid),
+    if vk.verify(&message, &sig).is_ok() {
+        return Ok(SignatureStatus::Verified);
+    }
+    // NOTE: Signatures made before `signed_at` was covered still verify here; `check_signer`
+    // refuses them for keys that expire.
+    if entry.signed_at.is_some()
+        && vk
+            .verify(&signing_message_without_time(entry)?, &sig)
+            .is_ok()
+    {
+        return Ok(SignatureStatus::Verified);
     }
+    Ok(SignatureStatus::Invalid)
 }
 
 #[cfg(test)]
@@ -735,6 +858,7 @@ mod tests {
             signature: sig_b64,
             keyid: None,
             signed_at: None,
+            imports: None,
         }
     }
 
@@ -744,7 +868,7 @@ mod tests {
         std::env::set_var("STRICT_MODULES_VERIFY", "1");
         std::env::remove_var("UICP_MODULES_PUBKEY");
         let entry = make_entry("task", "1.0.0", "00", None);
-        let err = enforce_strict_signature(&entry).unwrap_err();
+        let err = enforce_strict_signature(Path::new("."), &entry).unwrap_err();
         assert!(format!("{err}").contains("UICP_MODULES_PUBKEY"));
         std::env::remove_var("STRICT_MODULES_VERIFY");
     }
@@ -758,7 +882,7 @@ mod tests {
         let vk = sk.verifying_key();
         std::env::set_var("UICP_MODULES_PUBKEY", BASE64_STANDARD.encode(vk.to_bytes()));
         let entry = make_entry("task-x", "1.2.3", "aa", None);
-        let err = enforce_strict_signature(&entry).unwrap_err();
+        let err = enforce_strict_signature(Path::new("."), &entry).unwrap_err();
         assert!(format!("{err}").contains("signature missing"));
         std::env::remove_var("STRICT_MODULES_VERIFY");
         std::env::remove_var("UICP_MODULES_PUBKEY");
@@ -789,11 +913,173 @@ mod tests {
         let sig_b64 = BASE64_STANDARD.encode(sig.to_bytes());
         let entry = make_entry("task-y", "2.0.0", &digest_hex, Some(sig_b64));
 
-        enforce_strict_signature(&entry).expect("strict ok");
+        enforce_strict_signature(Path::new("."), &entry).expect("strict ok");
         std::env::remove_var("STRICT_MODULES_VERIFY");
         std::env::remove_var("UICP_MODULES_PUBKEY");
     }
 
+    // Smallest valid component: passes import preflight with an empty declared allowlist.
+    const EMPTY_COMPONENT: &[u8] = &[0x00, 0x61, 0x73, 0x6d, 0x0d, 0x00, 0x01, 0x00];
+
+    fn write_bundle(dir: &Path, digest_hex: &str, signature: Option<String>) -> ModuleEntry {
+        let mut entry = make_entry("demo.bundle", "1.0.0", digest_hex, signature);
+        entry.imports = Some(Vec::new());
+        fs::write(dir.join(&entry.filename), EMPTY_COMPONENT).unwrap();
+        fs::write(
+            dir.join(BUNDLE_ENTRY_FILE),
+            serde_json::to_vec(&entry).unwrap(),
+        )
+        .unwrap();
+        entry
+    }
+
+    fn sign_bundle_entry(sk: &SigningKey, digest_hex: &str) -> String {
+        let mut msg = Vec::new();
+        msg.extend_from_slice(b"UICP-MODULE\x00task=demo.bundle\x00version=1.0.0\x00sha256=");
+        msg.extend_from_slice(&hex::decode(digest_hex).unwrap());
+        msg.extend_from_slice(b"\x00imports=");
+        BASE64_STANDARD.encode(sk.sign(&msg).to_bytes())
+    }
+
+    #[test]
+    fn install_bundle_updates_manifest_and_uninstall_reverts_it() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::remove_var("STRICT_MODULES_VERIFY");
+        let bundle = tempfile::tempdir().unwrap();
+        let modules = tempfile::tempdir().unwrap();
+        let trust = tempfile::tempdir().unwrap();
+        let digest = hex::encode(Sha256::digest(EMPTY_COMPONENT));
+        let sk = SigningKey::from_bytes(&[5u8; 32]);
+        std::env::set_var(
+            "UICP_MODULES_PUBKEY",
+            BASE64_STANDARD.encode(sk.verifying_key().to_bytes()),
+        );
+        #[cfg(feature = "wasm_compute")]
+        {
+            // An unsigned bundle's declared `imports` are ignored, and unknown tasks have no
+            // built-in defaults to fall back to.
+            write_bundle(bundle.path(), &digest, None);
+            let err =
+                install_module_bundle(modules.path(), trust.path(), bundle.path()).unwrap_err();
+            assert!(format!("{err:#}").contains("E-UICP-0263"), "{err:#}");
+        }
+        write_bundle(
+            bundle.path(),
+            &digest,
+            Some(sign_bundle_entry(&sk, &digest)),
+        );
+        let prov = ModuleProvenance {
+            task: "demo.bundle".into(),
+            version: "1.0.0".into(),
+            origin_url: Some("file:///builds/demo".into()),
+            build_toolchain: None,
+            wit_world: None,
+            built_at: None,
+            source_revision: None,
+            builder: None,
+            metadata: None,
+        };
+        save_provenance(bundle.path(), &prov).unwrap();
+
+        let installed =
+            install_module_bundle(modules.path(), trust.path(), bundle.path()).expect("install");
+        assert!(installed.signed);
+        assert!(!installed.reinstalled);
+        assert!(installed.provenance.is_some());
+        let manifest = read_manifest_file(modules.path()).unwrap();
+        assert_eq!(manifest.entries.len(), 1);
+        assert!(verify_digest(&modules.path().join("demo.bundle@1.0.0.wasm"), &digest).unwrap());
+        assert!(load_provenance(modules.path(), "demo.bundle", "1.0.0")
+            .unwrap()
+            .is_some());
+        assert!(!modules.path().join(".install.lock").exists());
+
+        let again =
+            install_module_bundle(modules.path(), trust.path(), bundle.path()).expect("reinstall");
+        assert!(again.reinstalled);
+        assert_eq!(read_manifest_file(modules.path()).unwrap().entries.len(), 1);
+
+        // Versions are immutable: a recorded digest that differs from the bundle blocks install.
+        let original = read_manifest_file(modules.path()).unwrap();
+        let mut recorded = original.clone();
+        recorded.entries[0].digest_sha256 = "cd".repeat(32);
+        write_manifest_file(modules.path(), &recorded).unwrap();
+        let err = install_module_bundle(modules.path(), trust.path(), bundle.path()).unwrap_err();
+        assert!(format!("{err:#}").contains("E-UICP-0265"), "{err:#}");
+        write_manifest_file(modules.path(), &original).unwrap();
+
+        let removed = uninstall_module(modules.path(), "demo.bundle", "1.0.0").expect("uninstall");
+        assert_eq!(removed.digest_sha256, digest);
+        assert!(read_manifest_file(modules.path())
+            .unwrap()
+            .entries
+            .is_empty());
+        assert!(!modules.path().join("demo.bundle@1.0.0.wasm").exists());
+        let err = uninstall_module(modules.path(), "demo.bundle", "1.0.0").unwrap_err();
+        assert!(err.to_string().contains("E-UICP-0266"));
+        let err = uninstall_module(modules.path(), "../../x", "1.0.0").unwrap_err();
+        assert!(
+            err.to_string().contains("invalid module reference"),
+            "{err:#}"
+        );
+        std::env::remove_var("UICP_MODULES_PUBKEY");
+    }
+
+    #[test]
+    fn install_bundle_rejects_bad_digest_and_untrusted_signature() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::remove_var("STRICT_MODULES_VERIFY");
+        let bundle = tempfile::tempdir().unwrap();
+        let modules = tempfile::tempdir().unwrap();
+        let trust = tempfile::tempdir().unwrap();
+        let digest = hex::encode(Sha256::digest(EMPTY_COMPONENT));
+
+        write_bundle(bundle.path(), &"ab".repeat(32), None);
+        let err = install_module_bundle(modules.path(), trust.path(), bundle.path()).unwrap_err();
+        assert!(format!("{err:#}").contains("E-UICP-0261"), "{err:#}");
+
+        let trusted = SigningKey::from_bytes(&[3u8; 32]);
+        let mut entry = write_bundle(
+            bundle.path(),
+            &digest,
+            Some(sign_bundle_entry(&trusted, &digest)),
+        );
+        entry.keyid = Some("release".into());
+        fs::write(
+            bundle.path().join(BUNDLE_ENTRY_FILE),
+            serde_json::to_vec(&entry).unwrap(),
+        )
+        .unwrap();
+        let store = |sk: &SigningKey| {
+            serde_json::json!({ "release": BASE64_STANDARD.encode(sk.verifying_key().to_bytes()) })
+                .to_string()
+        };
+
+        std::env::set_var(
+            "UICP_TRUST_STORE_JSON",
+            store(&SigningKey::from_bytes(&[4u8; 32])),
+        );
+        let err = install_module_bundle(modules.path(), trust.path(), bundle.path()).unwrap_err();
+        assert!(format!("{err:#}").contains("E-UICP-0262"), "{err:#}");
+        assert!(!modules.path().join("manifest.json").exists());
+
+        std::env::set_var("UICP_TRUST_STORE_JSON", store(&trusted));
+        let installed =
+            install_module_bundle(modules.path(), trust.path(), bundle.path()).expect("install");
+        assert!(installed.signed);
+        assert_eq!(installed.keyid.as_deref(), Some("release"));
+        std::env::remove_var("UICP_TRUST_STORE_JSON");
+
+        // Managed keys verify without an env store; revoking the keyid refuses the bundle again.
+        let trusted_b64 = BASE64_STANDARD.encode(trusted.verifying_key().to_bytes());
+        crate::compute::trust_store::add_key(trust.path(), "release", &trusted_b64, None).unwrap();
+        install_module_bundle(modules.path(), trust.path(), bundle.path())
+            .expect("install with managed key");
+        crate::compute::trust_store::revoke_key(trust.path(), "release", None).unwrap();
+        let err = install_module_bundle(modules.path(), trust.path(), bundle.path()).unwrap_err();
+        assert!(format!("{err:#}").contains("E-UICP-0272"), "{err:#}");
+    }
+
     #[test]
     fn bundled_manifest_has_valid_signatures() {
         let _guard = ENV_LOCK.lock().unwrap();
@@ -840,7 +1126,7 @@ mod tests {
 
 /// Acquire a best-effort exclusive install lock in `dir` using a lock file.
 /// Returns a guard that removes the lock file when dropped.
-fn acquire_install_lock(dir: &Path) -> Option<FileLock> {
+pub(crate) fn acquire_install_lock(dir: &Path) -> Option<FileLock> {
     let _ = fs::create_dir_all(dir);
     let path = dir.join(".install.lock");
     match try_create_lock_file(&path) {
@@ -863,7 +1149,7 @@ fn acquire_install_lock(dir: &Path) -> Option<FileLock> {
     }
 }
 
-struct FileLock {
+pub(crate) struct FileLock {
     path: PathBuf,
 }
 
@@ -893,7 +1179,7 @@ fn try_create_lock_file(path: &Path) -> Option<FileLock> {
 }
 
 #[cfg(feature = "tauri2")]
-fn bundled_modules_path<R: Runtime>(app: &tauri::AppHandle<R>) -> Option<PathBuf> {
+pub(crate) fn bundled_modules_path<R: Runtime>(app: &tauri::AppHandle<R>) -> Option<PathBuf> {
     app.path()
         .resource_dir()
         .ok()
@@ -901,7 +1187,7 @@ fn bundled_modules_path<R: Runtime>(app: &tauri::AppHandle<R>) -> Option<PathBuf
 }
 
 #[cfg(not(feature = "tauri2"))]
-fn bundled_modules_path<R: Runtime>(app: &tauri::AppHandle<R>) -> Option<PathBuf> {
+pub(crate) fn bundled_modules_path<R: Runtime>(app: &tauri::AppHandle<R>) -> Option<PathBuf> {
     app.path()
         .resource_dir()
         .ok()
@@ -953,11 +1239,27 @@ fn verify_installed_modules(target: &Path) -> Result<()> {
     Ok(())
 }
 
-fn is_clean_filename(name: &str) -> bool {
+pub fn is_clean_filename(name: &str) -> bool {
     let mut components = Path::new(name).components();
     matches!(components.next(), Some(Component::Normal(_))) && components.next().is_none()
 }
 
+/// Task ids name files (`{task}@{version}.provenance.json`), so they stay within `[a-z0-9._-]`.
+pub fn is_valid_task_id(task: &str) -> bool {
+    !task.is_empty()
+        && task
+            .bytes()
+            .all(|b| matches!(b, b'a'..=b'z' | b'0'..=b'9' | b'.' | b'_' | b'-'))
+}
+
+/// Versions share the file names above; semver needs no more than `[A-Za-z0-9.+-]`.
+pub fn is_valid_version_label(version: &str) -> bool {
+    !version.is_empty()
+        && version
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'+' | b'-'))
+}
+
 fn is_valid_digest_hex(digest: &str) -> bool {
     digest.len() == 64 && digest.chars().all(|c| c.is_ascii_hexdigit())
 }
@@ -980,7 +1282,16 @@ fn parse_manifest(text: &str) -> Result<ModuleManifest> {
     Ok(manifest)
 }
 
-fn validate_manifest_entry(entry: &ModuleEntry) -> Result<()> {
+/// Sorted, de-duplicated, comma-joined import allowlist used in the signed message.
+fn canonical_imports(entry: &ModuleEntry) -> Option<String> {
+    let imports = entry.imports.as_ref()?;
+    let mut sorted: Vec<&str> = imports.iter().map(|s| s.trim()).collect();
+    sorted.sort_unstable();
+    sorted.dedup();
+    Some(sorted.join(","))
+}
+
+pub fn validate_manifest_entry(entry: &ModuleEntry) -> Result<()> {
     ensure!(
         !entry.task.trim().is_empty(),
         "manifest entry missing task identifier"
@@ -989,10 +1300,18 @@ fn validate_manifest_entry(entry: &ModuleEntry) -> Result<()> {
         !entry.task.contains('@'),
         "manifest entry task must not contain '@'"
     );
+    ensure!(
+        is_valid_task_id(&entry.task),
+        "manifest entry task must match [a-z0-9._-]+"
+    );
     ensure!(
         !entry.version.trim().is_empty(),
         "manifest entry missing version"
     );
+    ensure!(
+        is_valid_version_label(&entry.version),
+        "manifest entry version must match [A-Za-z0-9.+-]+"
+    );
     ensure!(
         is_clean_filename(&entry.filename),
         "manifest entry filename must be a basename"
@@ -1001,9 +1320,20 @@ fn validate_manifest_entry(entry: &ModuleEntry) -> Result<()> {
         is_valid_digest_hex(&entry.digest_sha256),
         "manifest digest must be 64 hex chars"
     );
+    if let Some(imports) = &entry.imports {
+        ensure!(
+            imports
+                .iter()
+                .all(|name| !name.trim().is_empty() && !name.contains(',')),
+            "manifest entry imports must be non-empty interface names"
+        );
+    }
     Ok(())
 }
 
+/// Pick the entry for `task` that `version` names. An empty version selects the highest semver;
+/// an exact version (`1.2.0`) matches literally; anything else is a semver requirement
+/// (`^0.1`, `=0.1.3`, `>=1.0, <2`) resolved to the highest installed match.
 #[cfg_attr(not(feature = "wasm_compute"), allow(dead_code))]
 fn select_manifest_entry<'a>(
     entries: &'a [ModuleEntry],
@@ -1011,30 +1341,45 @@ fn select_manifest_entry<'a>(
     version: &str,
 ) -> Result<Option<&'a ModuleEntry>> {
     let filtered: Vec<&ModuleEntry> = entries.iter().filter(|e| e.task == task).collect();
-    if version.is_empty() {
-        let mut parsed: Vec<(&ModuleEntry, semver::Version)> = Vec::new();
-        for entry in &filtered {
-            match semver::Version::parse(&entry.version) {
-                Ok(v) => parsed.push((*entry, v)),
-                Err(err) => {
-                    log_warn(format!(
-                        "skipping module {} due to invalid semver {}: {err}",
-                        entry.task, entry.version
-                    ));
-                }
-            }
+    if !version.is_empty() {
+        if let Some(entry) = filtered.iter().find(|e| e.version == version) {
+            return Ok(Some(entry));
         }
-        parsed.sort_by(|a, b| b.1.cmp(&a.1));
-        if parsed.is_empty() && filtered.is_empty() {
+        if semver::Version::parse(version).is_ok() {
             return Ok(None);
         }
-        if parsed.is_empty() {
-            anyhow::bail!("no valid semver entries for task {}", task);
+    }
+    let requirement =
+        if version.is_empty() {
+            None
+        } else {
+            Some(semver::VersionReq::parse(version).with_context(|| {
+                format!("invalid version requirement '{version}' for task {task}")
+            })?)
+        };
+    let mut parsed: Vec<(&ModuleEntry, semver::Version)> = Vec::new();
+    for entry in &filtered {
+        match semver::Version::parse(&entry.version) {
+            Ok(v) => parsed.push((*entry, v)),
+            Err(err) => {
+                log_warn(format!(
+                    "skipping module {} due to invalid semver {}: {err}",
+                    entry.task, entry.version
+                ));
+            }
         }
-        Ok(parsed.first().map(|(entry, _)| *entry))
-    } else {
-        Ok(filtered.into_iter().find(|e| e.version == version))
     }
+    parsed.sort_by(|a, b| b.1.cmp(&a.1));
+    if parsed.is_empty() && filtered.is_empty() {
+        return Ok(None);
+    }
+    if parsed.is_empty() {
+        anyhow::bail!("no valid semver entries for task {}", task);
+    }
+    Ok(parsed
+        .into_iter()
+        .find(|(_, v)| requirement.as_ref().is_none_or(|req| req.matches(v)))
+        .map(|(entry, _)| entry))
 }
 
 fn is_regular_file(path: &Path) -> bool {
@@ -1056,7 +1401,7 @@ fn is_regular_file(path: &Path) -> bool {
 }
 
 #[cfg(windows)]
-fn replace_file(tmp: &Path, dest: &Path) -> std::io::Result<()> {
+pub(crate) fn replace_file(tmp: &Path, dest: &Path) -> std::io::Result<()> {
     if dest.exists() {
         match fs::remove_file(dest) {
             Ok(_) => {}
@@ -1068,7 +1413,7 @@ fn replace_file(tmp: &Path, dest: &Path) -> std::io::Result<()> {
 }
 
 #[cfg(not(windows))]
-fn replace_file(tmp: &Path, dest: &Path) -> std::io::Result<()> {
+pub(crate) fn replace_file(tmp: &Path, dest: &Path) -> std::io::Result<()> {
     fs::rename(tmp, dest)
 }
 
@@ -1108,8 +1453,7 @@ pub fn load_provenance(
 }
 
 /// Save provenance metadata for a module.
-/// This is typically called by module build tools, not at runtime.
-#[allow(dead_code)]
+/// Called by module build tools and by `install_module_bundle`.
 pub fn save_provenance(modules_dir: &Path, prov: &ModuleProvenance) -> Result<()> {
     let path = provenance_path(modules_dir, &prov.task, &prov.version);
     let json = serde_json::to_string_pretty(prov).context("serialize provenance")?;
@@ -1118,6 +1462,228 @@ pub fn save_provenance(modules_dir: &Path, prov: &ModuleProvenance) -> Result<()
     Ok(())
 }
 
+/// Manifest fragment inside a local module bundle. The bundle directory also holds the `.wasm`
+/// named by the fragment and, optionally, `{task}@{version}.provenance.json`.
+pub const BUNDLE_ENTRY_FILE: &str = "module.json";
+
+/// Result of `install_module_bundle`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstalledModule {
+    pub task: String,
+    pub version: String,
+    pub filename: String,
+    pub digest_sha256: String,
+    /// True when the entry signature verified against the trust store.
+    pub signed: bool,
+    pub keyid: Option<String>,
+    pub provenance: Option<ModuleProvenance>,
+    /// True when the same task@version and digest was already installed.
+    pub reinstalled: bool,
+}
+
+/// Read `manifest.json` from `dir`, or an empty manifest when none exists yet.
+pub fn read_manifest_file(dir: &Path) -> Result<ModuleManifest> {
+    let path = dir.join("manifest.json");
+    if !path.exists() {
+        return Ok(ModuleManifest::default());
+    }
+    let text =
+        fs::read_to_string(&path).with_context(|| format!("read manifest: {}", path.display()))?;
+    parse_manifest(&text)
+}
+
+/// Replace `manifest.json` in `dir` via write-then-rename so readers never see a partial file.
+pub fn write_manifest_file(dir: &Path, manifest: &ModuleManifest) -> Result<()> {
+    let path = dir.join("manifest.json");
+    let tmp = dir.join("manifest.json.tmp");
+    let text = serde_json::to_string_pretty(manifest)? + "\n";
+    fs::write(&tmp, text.as_bytes()).with_context(|| format!("write {}", tmp.display()))?;
+    replace_file(&tmp, &path).with_context(|| format!("replace {}", path.display()))
+}
+
+/// Check a bundle entry's signature. Signed entries must verify against the trust store
+/// (`keyid`) or `UICP_MODULES_PUBKEY`; unsigned entries are accepted only outside strict mode.
+fn verify_bundle_signature(trust_dir: &Path, entry: &ModuleEntry) -> Result<SignatureStatus> {
+    crate::compute::trust_store::check_signer(trust_dir, entry)
+        .context("E-UICP-0262: bundle signer is not trusted")?;
+    if entry.signature.is_none() {
+        ensure!(
+            !strict_verify_enabled(),
+            "E-UICP-0262: STRICT_MODULES_VERIFY requires a signed bundle for {}@{}",
+            entry.task,
+            entry.version
+        );
+        return Ok(SignatureStatus::Missing);
+    }
+    let pubkey = require_pubkey_for_entry(trust_dir, entry)
+        .context("E-UICP-0262: no trusted key for bundle signature")?;
+    match verify_entry_signature(entry, &pubkey)? {
+        SignatureStatus::Verified => Ok(SignatureStatus::Verified),
+        _ => bail!(
+            "E-UICP-0262: signature invalid for {}@{}",
+            entry.task,
+            entry.version
+        ),
+    }
+}
+
+/// Install a local module bundle (see [`BUNDLE_ENTRY_FILE`]) into `modules_dir`.
+/// The module is checked before anything is written: fragment shape, digest, signature (keys
+/// from the trust store in `trust_dir`), provenance and (with `wasm_compute`) import preflight. Under the install lock the `.wasm`
+/// is copied and re-verified, then `manifest.json` is replaced atomically.
+pub fn install_module_bundle(
+    modules_dir: &Path,
+    trust_dir: &Path,
+    bundle: &Path,
+) -> Result<InstalledModule> {
+    ensure!(
+        bundle.is_absolute() && bundle.is_dir(),
+        "E-UICP-0260: bundle must be an absolute directory path: {}",
+        bundle.display()
+    );
+    let entry_path = bundle.join(BUNDLE_ENTRY_FILE);
+    let text = fs::read_to_string(&entry_path)
+        .with_context(|| format!("E-UICP-0260: read bundle entry: {}", entry_path.display()))?;
+    let entry: ModuleEntry =
+        serde_json::from_str(&text).context("E-UICP-0260: parse bundle entry")?;
+    validate_manifest_entry(&entry).context("E-UICP-0260: invalid bundle entry")?;
+    semver::Version::parse(&entry.version)
+        .with_context(|| format!("E-UICP-0260: invalid semver {}", entry.version))?;
+    ensure!(
+        entry.filename.ends_with(".wasm"),
+        "E-UICP-0260: bundle filename must name a .wasm file"
+    );
+    let source = bundle.join(&entry.filename);
+    ensure!(
+        is_regular_file(&source),
+        "E-UICP-0260: bundle module missing: {}",
+        source.display()
+    );
+    ensure!(
+        verify_digest(&source, &entry.digest_sha256)?,
+        "E-UICP-0261: digest mismatch for {}",
+        entry.filename
+    );
+    let signature = verify_bundle_signature(trust_dir, &entry)?;
+    let provenance = load_provenance(bundle, &entry.task, &entry.version)
+        .context("E-UICP-0260: invalid bundle provenance")?;
+    #[cfg(feature = "wasm_compute")]
+    crate::compute::compute::preflight_component_imports_with(
+        &source,
+        &format!("{}@{}", entry.task, entry.version),
+        // Unsigned bundles cannot widen preflight beyond the built-in defaults.
+        match signature {
+            SignatureStatus::Verified => entry.imports.as_deref(),
+            _ => None,
+        },
+    )
+    .with_context(|| {
+        format!(
+            "E-UICP-0263: preflight imports failed for {}@{}",
+            entry.task, entry.version
+        )
+    })?;
+
+    let _lock = acquire_install_lock(modules_dir).ok_or_else(|| {
+        anyhow::anyhow!(
+            "E-UICP-0264: module installer busy: {}",
+            modules_dir.display()
+        )
+    })?;
+    let mut manifest = read_manifest_file(modules_dir)?;
+    let same = |e: &ModuleEntry| e.task == entry.task && e.version == entry.version;
+    let existing = manifest.entries.iter().find(|e| same(e));
+    if let Some(existing) = existing {
+        // WHY: Installed versions are immutable so cached outputs and replays keep their meaning.
+        ensure!(
+            existing
+                .digest_sha256
+                .eq_ignore_ascii_case(&entry.digest_sha256),
+            "E-UICP-0265: {}@{} is already installed with a different digest; uninstall it first",
+            entry.task,
+            entry.version
+        );
+    }
+    let reinstalled = existing.is_some();
+    ensure!(
+        !manifest
+            .entries
+            .iter()
+            .any(|e| e.filename == entry.filename && !same(e)),
+        "E-UICP-0265: filename {} belongs to another manifest entry",
+        entry.filename
+    );
+
+    let dest = modules_dir.join(&entry.filename);
+    let tmp = dest.with_extension("tmp");
+    fs::copy(&source, &tmp)
+        .with_context(|| format!("copy {} -> {}", source.display(), tmp.display()))?;
+    if !verify_digest(&tmp, &entry.digest_sha256)? {
+        let _ = fs::remove_file(&tmp);
+        bail!(
+            "E-UICP-0261: {} changed while it was being copied",
+            entry.filename
+        );
+    }
+    if let Err(err) = replace_file(&tmp, &dest) {
+        let _ = fs::remove_file(&tmp);
+        return Err(err).with_context(|| format!("replace {}", dest.display()));
+    }
+    if let Some(prov) = &provenance {
+        save_provenance(modules_dir, prov)?;
+    }
+    manifest.entries.retain(|e| !same(e));
+    manifest.entries.push(entry.clone());
+    // NOTE: The .wasm lands before the manifest names it; a crash in between leaves an
+    // unreferenced file rather than an entry without its module.
+    write_manifest_file(modules_dir, &manifest)?;
+
+    Ok(InstalledModule {
+        task: entry.task,
+        version: entry.version,
+        filename: entry.filename,
+        digest_sha256: entry.digest_sha256,
+        signed: signature == SignatureStatus::Verified,
+        keyid: entry.keyid,
+        provenance,
+        reinstalled,
+    })
+}
+
+/// Remove `task@version` from the manifest, then delete its `.wasm` and provenance files and
+/// drop its instance pool and AOT artifact. Returns the removed entry.
+pub fn uninstall_module(modules_dir: &Path, task: &str, version: &str) -> Result<ModuleEntry> {
+    // WHY: Both name files under `modules_dir`; reject anything that could leave it.
+    ensure!(
+        is_valid_task_id(task) && is_valid_version_label(version),
+        "E-UICP-0266: invalid module reference {task}@{version}"
+    );
+    let _lock = acquire_install_lock(modules_dir).ok_or_else(|| {
+        anyhow::anyhow!(
+            "E-UICP-0264: module installer busy: {}",
+            modules_dir.display()
+        )
+    })?;
+    let mut manifest = read_manifest_file(modules_dir)?;
+    let index = manifest
+        .entries
+        .iter()
+        .position(|e| e.task == task && e.version == version)
+        .ok_or_else(|| anyhow::anyhow!("E-UICP-0266: {task}@{version} is not installed"))?;
+    let entry = manifest.entries.remove(index);
+    write_manifest_file(modules_dir, &manifest)?;
+    // Best-effort: the manifest no longer references these files.
+    let _ = fs::remove_file(modules_dir.join(&entry.filename));
+    let _ = fs::remove_file(provenance_path(modules_dir, task, version));
+    let digest_in_use = manifest
+        .entries
+        .iter()
+        .any(|e| e.digest_sha256.eq_ignore_ascii_case(&entry.digest_sha256));
+    crate::compute::compute::evict_module(&entry, digest_in_use);
+    Ok(entry)
+}
+
 /// Tri-state signature verification outcome.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SignatureStatus {
@@ -1177,6 +1743,7 @@ mod tests_signature {
             signature: Some(sig_b64),
             keyid: None,
             signed_at: None,
+            imports: None,
         };
 
         let ok = verify_entry_signature(&entry, vk.as_bytes()).unwrap();
@@ -1193,6 +1760,85 @@ mod tests_signature {
         );
     }
 
+    #[test]
+    fn sign_entry_produces_verifiable_signature() {
+        let seed = [11u8; 32];
+        let vk = SigningKey::from_bytes(&seed).verifying_key();
+        let mut entry = ModuleEntry {
+            task: "demo".into(),
+            version: "1.0.0".into(),
+            filename: "demo@1.0.0.wasm".into(),
+            digest_sha256: "ab".repeat(32),
+            signature: None,
+            keyid: None,
+            signed_at: None,
+            imports: Some(vec!["wasi:io/streams@0.2.0".into()]),
+        };
+
+        sign_entry(&mut entry, &seed, Some("ci-2026"), 1_760_000_000).unwrap();
+        assert_eq!(entry.keyid.as_deref(), Some("ci-2026"));
+        assert_eq!(entry.signed_at, Some(1_760_000_000));
+        assert_eq!(
+            verify_entry_signature(&entry, vk.as_bytes()).unwrap(),
+            SignatureStatus::Verified
+        );
+        assert!(signature_covers_signed_at(&entry, vk.as_bytes()));
+
+        // `signed_at` is signed, so backdating it breaks the signature.
+        entry.signed_at = Some(1_700_000_000);
+        assert_eq!(
+            verify_entry_signature(&entry, vk.as_bytes()).unwrap(),
+            SignatureStatus::Invalid
+        );
+        assert!(!signature_covers_signed_at(&entry, vk.as_bytes()));
+    }
+
+    #[test]
+    fn signature_covers_declared_imports() {
+        let sk = SigningKey::from_bytes(&[9u8; 32]);
+        let vk = sk.verifying_key();
+        let digest_bytes = [0xabu8; 32];
+        let mut canonical_message = Vec::new();
+        canonical_message.extend_from_slice(b"UICP-MODULE\x00");
+        canonical_message.extend_from_slice(b"task=demo\x00");
+        canonical_message.extend_from_slice(b"version=1.0.0\x00");
+        canonical_message.extend_from_slice(b"sha256=");
+        canonical_message.extend_from_slice(&digest_bytes);
+        canonical_message.extend_from_slice(b"\x00imports=wasi:cli/stdout@0.2,wasi:io/streams@0.2");
+        let sig_b64 = BASE64_STANDARD.encode(sk.sign(&canonical_message).to_bytes());
+
+        let entry = ModuleEntry {
+            task: "demo".into(),
+            version: "1.0.0".into(),
+            filename: "demo@1.0.0.wasm".into(),
+            digest_sha256: hex::encode(digest_bytes),
+            signature: Some(sig_b64),
+            keyid: None,
+            signed_at: None,
+            // Order in the manifest does not matter; the signed form is sorted.
+            imports: Some(vec![
+                "wasi:io/streams@0.2".into(),
+                "wasi:cli/stdout@0.2".into(),
+            ]),
+        };
+        assert_eq!(
+            verify_entry_signature(&entry, vk.as_bytes()).unwrap(),
+            SignatureStatus::Verified
+        );
+
+        let mut widened = entry.clone();
+        widened
+            .imports
+            .as_mut()
+            .unwrap()
+            .push("wasi:random/random@0.2".into());
+        assert_eq!(
+            verify_entry_signature(&widened, vk.as_bytes()).unwrap(),
+            SignatureStatus::Invalid,
+            "adding an import must invalidate the signature"
+        );
+    }
+
     #[test]
     fn clean_filename_validation() {
         assert!(is_clean_filename("module.wasm"));
@@ -1217,6 +1863,7 @@ mod tests_signature {
             signature: None,
             keyid: None,
             signed_at: None,
+            imports: None,
         };
         assert!(validate_manifest_entry(&entry).is_ok());
 
@@ -1225,6 +1872,18 @@ mod tests_signature {
 
         entry.task = "bad@task".into();
         assert!(validate_manifest_entry(&entry).is_err());
+
+        entry.task = "../../x".into();
+        assert!(validate_manifest_entry(&entry).is_err());
+
+        entry.task = "Csv.Parse".into();
+        assert!(validate_manifest_entry(&entry).is_err());
+
+        entry.task = "csv.parse_v-2".into();
+        assert!(validate_manifest_entry(&entry).is_ok());
+
+        entry.version = "1.0.0/../x".into();
+        assert!(validate_manifest_entry(&entry).is_err());
     }
 
     #[test]
@@ -1238,6 +1897,7 @@ mod tests_signature {
                 signature: None,
                 keyid: None,
                 signed_at: None,
+                imports: None,
             },
             ModuleEntry {
                 task: "task".into(),
@@ -1247,6 +1907,7 @@ mod tests_signature {
                 signature: None,
                 keyid: None,
                 signed_at: None,
+                imports: None,
             },
             ModuleEntry {
                 task: "task".into(),
@@ -1256,6 +1917,7 @@ mod tests_signature {
                 signature: None,
                 keyid: None,
                 signed_at: None,
+                imports: None,
             },
         ];
 
@@ -1270,6 +1932,36 @@ mod tests_signature {
         assert_eq!(pinned.version, "1.0.0");
     }
 
+    #[test]
+    fn select_entry_resolves_semver_requirements() {
+        let entries: Vec<ModuleEntry> = ["0.1.2", "0.1.3", "0.2.0", "1.0.0"]
+            .into_iter()
+            .map(|version| ModuleEntry {
+                task: "table.query".into(),
+                version: version.into(),
+                filename: format!("table.query@{version}.wasm"),
+                digest_sha256: "aa".repeat(32),
+                signature: None,
+                keyid: None,
+                signed_at: None,
+                imports: None,
+            })
+            .collect();
+        let pick = |version: &str| {
+            select_manifest_entry(&entries, "table.query", version)
+                .unwrap()
+                .map(|e| e.version.as_str())
+        };
+
+        assert_eq!(pick("^0.1"), Some("0.1.3"));
+        assert_eq!(pick("=0.1.2"), Some("0.1.2"));
+        assert_eq!(pick(">=0.2, <2"), Some("1.0.0"));
+        assert_eq!(pick("0.1.2"), Some("0.1.2"));
+        assert_eq!(pick("0.1.4"), None);
+        assert_eq!(pick("^2"), None);
+        assert!(select_manifest_entry(&entries, "table.query", "not a range").is_err());
+    }
+
     #[test]
     fn select_entry_errors_when_only_invalid_semver() {
         let entries = vec![ModuleEntry {
@@ -1280,6 +1972,7 @@ mod tests_signature {
             signature: None,
             keyid: None,
             signed_at: None,
+            imports: None,
         }];
 
         let err = select_manifest_entry(&entries, "task", "").unwrap_err();
@@ -1302,6 +1995,7 @@ mod tests_signature {
                     signature: None,
                     keyid: None,
                     signed_at: None,
+                    imports: None,
                 },
                 ModuleEntry {
                     task: "task".into(),
@@ -1311,6 +2005,7 @@ mod tests_signature {
                     signature: None,
                     keyid: None,
                     signed_at: None,
+                    imports: None,
                 },
             ],
         };
@@ -0,0 +1,469 @@
+//! Deterministic replay of finished compute jobs (`compute_replay`).
+//!
+//! `compute.job.submit` records the admitted spec and `compute.job.final` the outcome (output hash,
+//! module digest, RNG seed). Replay rebuilds the spec from those action-log records, refuses to run
+//! when the installed module digest has drifted, and re-executes under a fresh job id that inherits
+//! the original job's RNG seed. The report states whether the output hash reproduced.
+
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use ::rusqlite::{params, OptionalExtension};
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use tauri::{AppHandle, Listener, Runtime};
+use tokio::sync::oneshot;
+use tokio_rusqlite::Connection as AsyncConn;
+
+use crate::infrastructure::events::EVENT_COMPUTE_RESULT_FINAL;
+use crate::security::policy::ComputeJobSpec;
+
+pub const SUBMIT_KIND: &str = "compute.job.submit";
+pub const FINAL_KIND: &str = "compute.job.final";
+pub const REPLAY_KIND: &str = "compute.job.replay";
+
+/// Largest serialized `input` a submit record keeps verbatim.
+pub const MAX_LOGGED_INPUT_BYTES: usize = 64 * 1024;
+/// Key of the stand-in object that replaces an input the submit record does not keep.
+const ELIDED_INPUT_KEY: &str = "$elidedInput";
+
+/// Slack on top of the original job timeout for queueing and instantiation.
+const REPLAY_GRACE: Duration = Duration::from_secs(30);
+const DEFAULT_JOB_TIMEOUT_MS: u64 = 30_000;
+
+/// Replay job id -> original job id, consulted when the runtime derives the RNG seed.
+static SEED_OVERRIDES: Lazy<DashMap<String, String>> = Lazy::new(DashMap::new);
+
+/// Job id whose seed `job_id` inherits while a replay of it is in flight.
+pub fn seed_job_id(job_id: &str) -> Option<String> {
+    SEED_OVERRIDES
+        .get(job_id)
+        .map(|entry| entry.value().clone())
+}
+
+/// Removes the seed override when the replay finishes, times out or is dropped.
+struct SeedPin(String);
+
+impl SeedPin {
+    fn new(replay_job_id: &str, original_job_id: &str) -> Self {
+        SEED_OVERRIDES.insert(replay_job_id.to_string(), original_job_id.to_string());
+        Self(replay_job_id.to_string())
+    }
+}
+
+impl Drop for SeedPin {
+    fn drop(&mut self) {
+        SEED_OVERRIDES.remove(&self.0);
+    }
+}
+
+/// Outcome fields of a `compute.job.final` record.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobOutcome {
+    pub ok: bool,
+    #[serde(default)]
+    pub code: Option<String>,
+    #[serde(default)]
+    pub output_hash: Option<String>,
+    #[serde(default)]
+    pub module_digest: Option<String>,
+    #[serde(default)]
+    pub module_version: Option<String>,
+    #[serde(default)]
+    pub rng_seed_hex: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordedJob {
+    pub spec: ComputeJobSpec,
+    pub outcome: JobOutcome,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayReport {
+    pub job_id: String,
+    pub replay_job_id: String,
+    pub task: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub module_digest: Option<String>,
+    pub expected_hash: String,
+    pub actual_hash: Option<String>,
+    /// Replay finished `ok` with the recorded output hash.
+    pub matched: bool,
+    /// Replay finished `ok` but produced a different output from the same spec, module and seed.
+    pub nondeterministic: bool,
+    pub seed_matched: bool,
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<Value>,
+}
+
+/// The spec as `compute.job.submit` records it: token stripped, and `input` swapped for
+/// `{ "$elidedInput": { "sha256", "bytes" } }` unless the job is replayable and its input fits in
+/// `MAX_LOGGED_INPUT_BYTES`. Inline documents and opted-out jobs stay out of the action log.
+pub fn logged_spec(spec: &ComputeJobSpec) -> ComputeJobSpec {
+    let mut logged = spec.clone();
+    logged.token = None;
+    let bytes = serde_json::to_vec(&spec.input).unwrap_or_default();
+    if !(spec.replayable && spec.cacheable()) || bytes.len() > MAX_LOGGED_INPUT_BYTES {
+        logged.input = serde_json::json!({
+            ELIDED_INPUT_KEY: {
+                "sha256": hex::encode(Sha256::digest(&bytes)),
+                "bytes": bytes.len(),
+            }
+        });
+    }
+    logged
+}
+
+/// Pair a job's submit and final records into something replayable.
+pub fn recorded_job(
+    job_id: &str,
+    submit: &Value,
+    final_record: &Value,
+) -> Result<RecordedJob, String> {
+    let spec = submit.get("spec").cloned().ok_or_else(|| {
+        format!("E-UICP-0721: job {job_id} predates spec logging and cannot be replayed")
+    })?;
+    let spec: ComputeJobSpec = serde_json::from_value(spec)
+        .map_err(|e| format!("E-UICP-0721: logged spec for job {job_id} is invalid: {e}"))?;
+    let outcome: JobOutcome = serde_json::from_value(final_record.clone())
+        .map_err(|e| format!("E-UICP-0721: final record for job {job_id} is invalid: {e}"))?;
+    if !outcome.ok {
+        return Err(format!(
+            "E-UICP-0723: job {job_id} finished with {}; only successful jobs have an output hash to replay",
+            outcome.code.as_deref().unwrap_or("an error")
+        ));
+    }
+    if outcome.output_hash.is_none() {
+        return Err(format!(
+            "E-UICP-0721: final record for job {job_id} has no outputHash"
+        ));
+    }
+    if spec.input.get(ELIDED_INPUT_KEY).is_some() {
+        return Err(format!(
+            "E-UICP-0723: job {job_id} input was not logged (not replayable or over {MAX_LOGGED_INPUT_BYTES} bytes)"
+        ));
+    }
+    // WHY: Replaying would rewrite workspace files the user may have changed since.
+    if !spec.capabilities.fs_write.is_empty() {
+        return Err(format!(
+            "E-UICP-0723: job {job_id} writes workspace files and cannot be replayed"
+        ));
+    }
+    // WHY: Output depended on live network responses; a rerun proves nothing about the module.
+    if !spec.capabilities.net.is_empty() {
+        return Err(format!(
+            "E-UICP-0723: job {job_id} was granted network access and cannot be replayed"
+        ));
+    }
+    Ok(RecordedJob { spec, outcome })
+}
+
+/// Read the latest submit and final records for `job_id` from the action log.
+pub async fn load_recorded_job(db: &AsyncConn, job_id: &str) -> Result<RecordedJob, String> {
+    let id = job_id.to_string();
+    let (submit, final_record) = db
+        .call(move |conn| {
+            let latest = |kind: &str| {
+                conn.query_row(
+                    "SELECT payload_json FROM action_log
+                     WHERE kind = ?1 AND json_extract(payload_json, '$.jobId') = ?2
+                     ORDER BY id DESC LIMIT 1",
+                    params![kind, id],
+                    |row| row.get::<_, String>(0),
+                )
+                .optional()
+            };
+            Ok((latest(SUBMIT_KIND)?, latest(FINAL_KIND)?))
+        })
+        .await
+        .map_err(|e| format!("E-UICP-0720: read action log failed: {e}"))?;
+    let parse = |raw: Option<String>, kind: &str| -> Result<Value, String> {
+        let raw = raw.ok_or_else(|| format!("E-UICP-0720: no {kind} record for job {job_id}"))?;
+        serde_json::from_str(&raw)
+            .map_err(|e| format!("E-UICP-0721: {kind} record is not JSON: {e}"))
+    };
+    recorded_job(
+        job_id,
+        &parse(submit, SUBMIT_KIND)?,
+        &parse(final_record, FINAL_KIND)?,
+    )
+}
+
+/// Fail when the installed module no longer matches the digest the job ran against.
+pub fn check_module_digest(outcome: &JobOutcome, installed: Option<&str>) -> Result<(), String> {
+    match (outcome.module_digest.as_deref(), installed) {
+        (None, _) => Ok(()),
+        (Some(expected), Some(actual)) if expected.eq_ignore_ascii_case(actual) => Ok(()),
+        (Some(expected), actual) => Err(format!(
+            "E-UICP-0722: module digest changed since the job ran (recorded {expected}, installed {})",
+            actual.unwrap_or("none")
+        )),
+    }
+}
+
+/// The spec to re-execute: same task, input, limits and env hash under a new job id.
+pub fn replay_spec(original: &ComputeJobSpec, replay_job_id: String) -> ComputeJobSpec {
+    let mut spec = original.clone();
+    spec.job_id = replay_job_id;
+    // WHY: A cache hit would echo the stored output instead of re-executing.
+    spec.cache = "bypass".into();
+    // Replays are diagnostics: they must not write into UI state or trip golden safe mode.
+    spec.bind = Vec::new();
+    spec.golden_key = None;
+    spec.expect_golden = false;
+    spec.token = None;
+    spec
+}
+
+/// Compare a replay's `compute-result-final` payload against the recorded outcome.
+pub fn report(recorded: &RecordedJob, replay_job_id: &str, final_payload: &Value) -> ReplayReport {
+    let ok = final_payload
+        .get("ok")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    let metrics = final_payload.get("metrics").cloned();
+    let metric = |key: &str| {
+        metrics
+            .as_ref()
+            .and_then(|m| m.get(key))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    };
+    let expected_hash = recorded.outcome.output_hash.clone().unwrap_or_default();
+    let actual_hash = metric("outputHash");
+    let matched = ok && actual_hash.as_deref() == Some(expected_hash.as_str());
+    let seed_matched = match (
+        recorded.outcome.rng_seed_hex.as_deref(),
+        metric("rngSeedHex"),
+    ) {
+        (Some(expected), Some(actual)) => expected == actual,
+        // Local tasks never seed an RNG.
+        (None, None) => true,
+        _ => false,
+    };
+    ReplayReport {
+        job_id: recorded.spec.job_id.clone(),
+        replay_job_id: replay_job_id.to_string(),
+        task: recorded.spec.task.clone(),
+        module_digest: recorded.outcome.module_digest.clone(),
+        expected_hash,
+        actual_hash,
+        matched,
+        nondeterministic: ok && !matched,
+        seed_matched,
+        ok,
+        code: final_payload
+            .get("code")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        message: final_payload
+            .get("message")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        metrics,
+    }
+}
+
+/// Re-execute a recorded job and report whether its output hash reproduced.
+///
+/// `installed_digest` is the registry digest for the recorded task right now; `submit` admits the
+/// replay spec through the `compute_call` path.
+pub async fn run<R, S, Fut>(
+    app: AppHandle<R>,
+    recorded: RecordedJob,
+    installed_digest: Option<String>,
+    submit: S,
+) -> Result<ReplayReport, String>
+where
+    R: Runtime,
+    S: FnOnce(AppHandle<R>, ComputeJobSpec) -> Fut,
+    Fut: Future<Output = Result<(), String>>,
+{
+    check_module_digest(&recorded.outcome, installed_digest.as_deref())?;
+    let replay_job_id = uuid::Uuid::new_v4().to_string();
+    let spec = replay_spec(&recorded.spec, replay_job_id.clone());
+    let wait =
+        Duration::from_millis(spec.timeout_ms.unwrap_or(DEFAULT_JOB_TIMEOUT_MS)) + REPLAY_GRACE;
+    let _pin = SeedPin::new(&replay_job_id, &recorded.spec.job_id);
+
+    // INVARIANT: the listener is registered before submit so inline policy denials are seen.
+    let (tx, rx) = oneshot::channel::<Value>();
+    let tx = Arc::new(Mutex::new(Some(tx)));
+    let listen_id = replay_job_id.clone();
+    let listener = app.listen(EVENT_COMPUTE_RESULT_FINAL, move |event| {
+        let Ok(value) = serde_json::from_str::<Value>(event.payload()) else {
+            return;
+        };
+        if value.get("jobId").and_then(Value::as_str) == Some(listen_id.as_str()) {
+            if let Some(sender) = tx.lock().ok().and_then(|mut guard| guard.take()) {
+                let _ = sender.send(value);
+            }
+        }
+    });
+
+    let outcome = match submit(app.clone(), spec).await {
+        Ok(()) => tokio::time::timeout(wait, rx)
+            .await
+            .map_err(|_| {
+                format!(
+                    "E-UICP-0724: replay {replay_job_id} did not finish within {}ms",
+                    wait.as_millis()
+                )
+            })
+            .and_then(|res| res.map_err(|_| "E-UICP-0724: replay listener closed".to_string())),
+        Err(err) => Err(err),
+    };
+    app.unlisten(listener);
+    Ok(report(&recorded, &replay_job_id, &outcome?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn submit_record(fs_write: &[&str]) -> Value {
+        submit_record_with(fs_write, &[])
+    }
+
+    fn submit_record_with(fs_write: &[&str], net: &[&str]) -> Value {
+        json!({
+            "jobId": "job-1",
+            "task": "table.query@0.1.0",
+            "spec": {
+                "jobId": "job-1",
+                "task": "table.query@0.1.0",
+                "input": { "rows": [["a", "1"]], "select": [0] },
+                "timeoutMs": 5000,
+                "fuel": null,
+                "memLimitMb": null,
+                "bind": [{ "toStatePath": "/rows" }],
+                "cache": "readwrite",
+                "capabilities": { "fsWrite": fs_write, "net": net },
+                "provenance": { "envHash": "env-a" },
+                "goldenKey": "g",
+                "expectGolden": true,
+            },
+        })
+    }
+
+    fn final_record() -> Value {
+        json!({
+            "jobId": "job-1",
+            "ok": true,
+            "outputHash": "abc",
+            "moduleDigest": "DEADBEEF",
+            "rngSeedHex": "00ff",
+        })
+    }
+
+    #[test]
+    fn recorded_job_requires_logged_spec_and_success() {
+        let job = recorded_job("job-1", &submit_record(&[]), &final_record()).expect("replayable");
+        assert_eq!(job.spec.provenance.env_hash, "env-a");
+        assert_eq!(job.outcome.output_hash.as_deref(), Some("abc"));
+
+        let legacy = json!({ "jobId": "job-1", "task": "table.query@0.1.0" });
+        let err = recorded_job("job-1", &legacy, &final_record()).unwrap_err();
+        assert!(err.starts_with("E-UICP-0721"), "{err}");
+
+        let failed = json!({ "jobId": "job-1", "ok": false, "code": "Compute.Timeout" });
+        let err = recorded_job("job-1", &submit_record(&[]), &failed).unwrap_err();
+        assert!(err.contains("Compute.Timeout"), "{err}");
+
+        let err =
+            recorded_job("job-1", &submit_record(&["ws:/files/**"]), &final_record()).unwrap_err();
+        assert!(err.starts_with("E-UICP-0723"), "{err}");
+
+        let net = submit_record_with(&[], &["https://api.example.com"]);
+        let err = recorded_job("job-1", &net, &final_record()).unwrap_err();
+        assert!(err.starts_with("E-UICP-0723"), "{err}");
+    }
+
+    #[test]
+    fn logged_spec_keeps_only_small_replayable_inputs() {
+        let job = recorded_job("job-1", &submit_record(&[]), &final_record()).unwrap();
+        let mut spec = job.spec.clone();
+        spec.token = Some("secret".into());
+        let logged = logged_spec(&spec);
+        assert!(logged.token.is_none());
+        assert_eq!(logged.input, spec.input);
+
+        spec.input = json!({ "blob": "x".repeat(MAX_LOGGED_INPUT_BYTES) });
+        let logged = logged_spec(&spec);
+        let elided = &logged.input[ELIDED_INPUT_KEY];
+        assert_eq!(elided["sha256"].as_str().map(str::len), Some(64));
+        assert!(elided["bytes"].as_u64().unwrap() > MAX_LOGGED_INPUT_BYTES as u64);
+
+        let mut opted_out = job.spec.clone();
+        opted_out.replayable = false;
+        assert!(logged_spec(&opted_out)
+            .input
+            .get(ELIDED_INPUT_KEY)
+            .is_some());
+
+        let mut submit = submit_record(&[]);
+        submit["spec"]["input"] = logged.input.clone();
+        let err = recorded_job("job-1", &submit, &final_record()).unwrap_err();
+        assert!(err.starts_with("E-UICP-0723"), "{err}");
+    }
+
+    #[test]
+    fn replay_spec_bypasses_cache_binds_and_goldens() {
+        let job = recorded_job("job-1", &submit_record(&[]), &final_record()).unwrap();
+        let spec = replay_spec(&job.spec, "replay-1".into());
+        assert_eq!(spec.job_id, "replay-1");
+        assert_eq!(spec.cache, "bypass");
+        assert!(spec.bind.is_empty());
+        assert!(spec.golden_key.is_none() && !spec.expect_golden);
+        assert_eq!(spec.input, job.spec.input);
+        assert_eq!(spec.provenance.env_hash, job.spec.provenance.env_hash);
+    }
+
+    #[test]
+    fn module_digest_must_match_installed_module() {
+        let job = recorded_job("job-1", &submit_record(&[]), &final_record()).unwrap();
+        assert!(check_module_digest(&job.outcome, Some("deadbeef")).is_ok());
+        let err = check_module_digest(&job.outcome, Some("cafe")).unwrap_err();
+        assert!(err.starts_with("E-UICP-0722"), "{err}");
+        assert!(check_module_digest(&job.outcome, None).is_err());
+    }
+
+    #[test]
+    fn report_flags_hash_mismatch_as_nondeterministic() {
+        let job = recorded_job("job-1", &submit_record(&[]), &final_record()).unwrap();
+        let same = json!({ "ok": true, "metrics": { "outputHash": "abc", "rngSeedHex": "00ff" } });
+        let r = report(&job, "replay-1", &same);
+        assert!(r.matched && !r.nondeterministic && r.seed_matched);
+
+        let drift = json!({ "ok": true, "metrics": { "outputHash": "xyz", "rngSeedHex": "00ff" } });
+        let r = report(&job, "replay-1", &drift);
+        assert!(!r.matched && r.nondeterministic);
+
+        let failed = json!({ "ok": false, "code": "Compute.Timeout", "message": "deadline" });
+        let r = report(&job, "replay-1", &failed);
+        assert!(!r.matched && !r.nondeterministic);
+        assert_eq!(r.code.as_deref(), Some("Compute.Timeout"));
+    }
+
+    #[test]
+    fn seed_pin_is_scoped_to_the_replay() {
+        {
+            let _pin = SeedPin::new("replay-x", "job-x");
+            assert_eq!(seed_job_id("replay-x").as_deref(), Some("job-x"));
+        }
+        assert!(seed_job_id("replay-x").is_none());
+    }
+}
@@ -0,0 +1,312 @@
+//! Priority-aware admission for the compute concurrency caps.
+//!
+//! Each `FairQueue` wraps one semaphore (`wasm_sem` or `compute_sem`). Instead of letting waiters
+//! race for permits in FIFO order, a single dispatcher acquires each freed permit and hands it to
+//! the best queued job: lowest effective class first (interactive < normal < batch, promoted one
+//! class per `UICP_COMPUTE_AGING_MS` of waiting), then the workspace served least recently, then
+//! arrival order.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+use serde_json::{json, Value};
+use tokio::sync::{oneshot, Notify, OwnedSemaphorePermit, Semaphore};
+
+use crate::security::policy::ComputePriority;
+
+/// Waiting this long promotes a queued job by one priority class.
+const DEFAULT_AGING_MS: u64 = 2_000;
+
+fn aging_from_env() -> Duration {
+    let ms = std::env::var("UICP_COMPUTE_AGING_MS")
+        .ok()
+        .and_then(|s| s.parse::<u64>().ok())
+        .filter(|&n| n > 0)
+        .unwrap_or(DEFAULT_AGING_MS);
+    Duration::from_millis(ms)
+}
+
+struct Waiter {
+    id: u64,
+    priority: ComputePriority,
+    workspace: String,
+    enqueued: Instant,
+    tx: oneshot::Sender<OwnedSemaphorePermit>,
+}
+
+#[derive(Default)]
+struct QueueState {
+    waiters: Vec<Waiter>,
+    next_id: u64,
+    /// Dispatch sequence of the most recent grant per workspace; lower means served longer ago.
+    last_served: HashMap<String, u64>,
+    served: u64,
+    dispatcher_running: bool,
+    /// True while the dispatcher holds a permit and is waiting for a job to hand it to.
+    holding_idle_permit: bool,
+}
+
+impl QueueState {
+    fn pick(&mut self, now: Instant, aging: Duration) -> Option<Waiter> {
+        let aging_ms = u64::try_from(aging.as_millis()).unwrap_or(u64::MAX).max(1);
+        let (idx, _) = self.waiters.iter().enumerate().min_by_key(|(_, w)| {
+            let waited = u64::try_from(now.saturating_duration_since(w.enqueued).as_millis())
+                .unwrap_or(u64::MAX);
+            let class = u64::from(w.priority.rank()).saturating_sub(waited / aging_ms);
+            let last = self.last_served.get(&w.workspace).copied().unwrap_or(0);
+            (class, last, w.id)
+        })?;
+        let waiter = self.waiters.remove(idx);
+        self.served += 1;
+        self.last_served
+            .insert(waiter.workspace.clone(), self.served);
+        Some(waiter)
+    }
+}
+
+pub struct FairQueue {
+    name: &'static str,
+    sem: Arc<Semaphore>,
+    aging: Duration,
+    state: Mutex<QueueState>,
+    wake: Notify,
+}
+
+/// Removes a waiter whose caller went away before it was granted a permit.
+struct WaiterGuard<'a> {
+    queue: &'a FairQueue,
+    id: u64,
+}
+
+impl Drop for WaiterGuard<'_> {
+    fn drop(&mut self) {
+        self.queue.state.lock().waiters.retain(|w| w.id != self.id);
+    }
+}
+
+impl FairQueue {
+    pub fn new(name: &'static str, sem: Arc<Semaphore>) -> Arc<Self> {
+        Self::with_aging(name, sem, aging_from_env())
+    }
+
+    fn with_aging(name: &'static str, sem: Arc<Semaphore>, aging: Duration) -> Arc<Self> {
+        Arc::new(Self {
+            name,
+            sem,
+            aging,
+            state: Mutex::new(QueueState::default()),
+            wake: Notify::new(),
+        })
+    }
+
+    /// Wait for a permit on the underlying semaphore, ordered by priority, age, and workspace.
+    pub async fn acquire(
+        self: &Arc<Self>,
+        priority: ComputePriority,
+        workspace: &str,
+    ) -> Result<OwnedSemaphorePermit, String> {
+        let (tx, rx) = oneshot::channel();
+        let id = {
+            let mut st = self.state.lock();
+            st.next_id += 1;
+            let id = st.next_id;
+            st.waiters.push(Waiter {
+                id,
+                priority,
+                workspace: workspace.to_string(),
+                enqueued: Instant::now(),
+                tx,
+            });
+            if !st.dispatcher_running {
+                st.dispatcher_running = true;
+                let queue = Arc::clone(self);
+                tauri::async_runtime::spawn(async move { queue.dispatch().await });
+            }
+            id
+        };
+        self.wake.notify_one();
+        let _guard = WaiterGuard { queue: self, id };
+        rx.await
+            .map_err(|_| format!("{} queue closed before a permit was granted", self.name))
+    }
+
+    async fn dispatch(self: Arc<Self>) {
+        loop {
+            // INVARIANT: the semaphore is never closed; a closed semaphore ends dispatching.
+            let Ok(mut permit) = Arc::clone(&self.sem).acquire_owned().await else {
+                self.state.lock().dispatcher_running = false;
+                return;
+            };
+            loop {
+                let next = {
+                    let mut st = self.state.lock();
+                    let next = st.pick(Instant::now(), self.aging);
+                    st.holding_idle_permit = next.is_none();
+                    next
+                };
+                match next {
+                    Some(waiter) => match waiter.tx.send(permit) {
+                        Ok(()) => break,
+                        // WHY: the caller was dropped between pick and send; offer the permit to the next job.
+                        Err(returned) => permit = returned,
+                    },
+                    None => self.wake.notified().await,
+                }
+            }
+        }
+    }
+
+    /// Queue depth per class and workspace plus the oldest wait, for `compute_queue_status`.
+    pub fn status(&self) -> Value {
+        let st = self.state.lock();
+        let now = Instant::now();
+        let mut by_class = serde_json::Map::new();
+        let mut oldest = serde_json::Map::new();
+        for class in ComputePriority::ALL {
+            let waits: Vec<u128> = st
+                .waiters
+                .iter()
+                .filter(|w| w.priority == class)
+                .map(|w| now.saturating_duration_since(w.enqueued).as_millis())
+                .collect();
+            by_class.insert(class.as_str().into(), json!(waits.len()));
+            oldest.insert(
+                class.as_str().into(),
+                json!(waits.iter().max().copied().unwrap_or(0)),
+            );
+        }
+        let mut by_workspace: BTreeMap<&str, usize> = BTreeMap::new();
+        for w in &st.waiters {
+            *by_workspace.entry(w.workspace.as_str()).or_default() += 1;
+        }
+        let available = self.sem.available_permits() + usize::from(st.holding_idle_permit);
+        json!({
+            "queued": st.waiters.len(),
+            "byClass": by_class,
+            "oldestWaitMs": oldest,
+            "byWorkspace": by_workspace,
+            "availablePermits": available,
+            "agingMs": u64::try_from(self.aging.as_millis()).unwrap_or(u64::MAX),
+        })
+    }
+}
+
+/// Schedulers for the two compute caps held in `AppState`.
+pub struct ComputeQueues {
+    pub wasm: Arc<FairQueue>,
+    pub compute: Arc<FairQueue>,
+}
+
+impl ComputeQueues {
+    pub fn new(wasm_sem: Arc<Semaphore>, compute_sem: Arc<Semaphore>) -> Self {
+        Self {
+            wasm: FairQueue::new("wasm", wasm_sem),
+            compute: FairQueue::new("compute", compute_sem),
+        }
+    }
+
+    pub fn status(&self) -> Value {
+        json!({
+            "wasm": self.wasm.status(),
+            "compute": self.compute.status(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn push(st: &mut QueueState, priority: ComputePriority, workspace: &str, enqueued: Instant) {
+        let (tx, _rx) = oneshot::channel();
+        st.next_id += 1;
+        st.waiters.push(Waiter {
+            id: st.next_id,
+            priority,
+            workspace: workspace.into(),
+            enqueued,
+            tx,
+        });
+    }
+
+    #[test]
+    fn interactive_jobs_are_picked_before_older_batch_jobs() {
+        let now = Instant::now();
+        let mut st = QueueState::default();
+        push(&mut st, ComputePriority::Batch, "ws", now);
+        push(&mut st, ComputePriority::Normal, "ws", now);
+        push(&mut st, ComputePriority::Interactive, "ws", now);
+        let aging = Duration::from_secs(60);
+        let order: Vec<_> = std::iter::from_fn(|| st.pick(now, aging))
+            .map(|w| w.priority)
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                ComputePriority::Interactive,
+                ComputePriority::Normal,
+                ComputePriority::Batch
+            ]
+        );
+    }
+
+    #[test]
+    fn aging_promotes_long_waiting_batch_jobs() {
+        let now = Instant::now();
+        let mut st = QueueState::default();
+        let long_ago = now.checked_sub(Duration::from_secs(5)).unwrap_or(now);
+        push(&mut st, ComputePriority::Batch, "ws", long_ago);
+        push(&mut st, ComputePriority::Normal, "ws", now);
+        let first = st.pick(now, Duration::from_secs(2)).expect("waiter");
+        assert_eq!(first.priority, ComputePriority::Batch);
+    }
+
+    #[test]
+    fn workspaces_are_served_round_robin_within_a_class() {
+        let now = Instant::now();
+        let mut st = QueueState::default();
+        push(&mut st, ComputePriority::Normal, "a", now);
+        push(&mut st, ComputePriority::Normal, "a", now);
+        push(&mut st, ComputePriority::Normal, "a", now);
+        push(&mut st, ComputePriority::Normal, "b", now);
+        let aging = Duration::from_secs(60);
+        let order: Vec<_> = std::iter::from_fn(|| st.pick(now, aging))
+            .map(|w| w.workspace)
+            .collect();
+        assert_eq!(order, vec!["a", "b", "a", "a"]);
+    }
+
+    #[tokio::test]
+    async fn status_reports_queue_depth_per_class() {
+        let sem = Arc::new(Semaphore::new(1));
+        let queue = FairQueue::with_aging("test", Arc::clone(&sem), Duration::from_secs(60));
+        let held = queue
+            .acquire(ComputePriority::Normal, "ws")
+            .await
+            .expect("first permit");
+
+        let q = Arc::clone(&queue);
+        let waiting = tokio::spawn(async move { q.acquire(ComputePriority::Batch, "ws").await });
+        for _ in 0..50 {
+            if queue.status()["queued"] == json!(1) {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        let status = queue.status();
+        assert_eq!(status["byClass"]["batch"], json!(1));
+        assert_eq!(status["byClass"]["interactive"], json!(0));
+        assert_eq!(status["byWorkspace"]["ws"], json!(1));
+
+        drop(held);
+        let permit = tokio::time::timeout(Duration::from_secs(2), waiting)
+            .await
+            .expect("permit handed to queued job")
+            .expect("join")
+            .expect("acquire");
+        drop(permit);
+        assert_eq!(queue.status()["queued"], json!(0));
+    }
+}
@@ -0,0 +1,417 @@
+//! Managed trust store for module signing keys (`<dataDir>/trust/trust_store.json`).
+//!
+//! Keys added here are trusted alongside the `UICP_TRUST_STORE` / `UICP_TRUST_STORE_JSON` maps.
+//! The file also carries the revocation list, which applies to keys from every source: modules
+//! signed by a revoked keyid are refused outright, and modules whose `signed_at` falls after a
+//! key's `expiresAt` are refused once the key has been rotated out or given an expiry.
+//! An expiring key only accepts signatures that cover `signed_at`.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, ensure, Context, Result};
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use base64::Engine as _;
+use serde::{Deserialize, Serialize};
+
+use crate::compute::registry::{self, ModuleEntry};
+
+pub const TRUST_STORE_FILE: &str = "trust_store.json";
+
+/// Directory holding `trust_store.json`: app data, outside the modules dir.
+/// WHY: The modules dir is rewritten by installs and bundled copies; keys kept beside the
+/// modules they vouch for could be swapped together with them.
+pub fn default_dir() -> PathBuf {
+    crate::DATA_DIR.join("trust")
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TrustStoreFile {
+    #[serde(default)]
+    pub keys: BTreeMap<String, TrustedKey>,
+    #[serde(default)]
+    pub revoked: BTreeMap<String, RevokedKey>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TrustedKey {
+    /// Base64 Ed25519 public key.
+    pub pubkey: String,
+    pub added_at: i64,
+    /// Signatures made after this unix time are refused.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<i64>,
+    /// Keyid that replaced this one in `rotate_key`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rotated_to: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RevokedKey {
+    pub revoked_at: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// One trusted key as reported by `modules_trust_list`.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TrustedKeyInfo {
+    pub keyid: String,
+    pub pubkey: String,
+    /// `managed` for `trust_store.json`, `env` for `UICP_TRUST_STORE(_JSON)`.
+    pub source: &'static str,
+    pub added_at: Option<i64>,
+    pub expires_at: Option<i64>,
+    pub rotated_to: Option<String>,
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TrustStoreListing {
+    pub keys: Vec<TrustedKeyInfo>,
+    pub revoked: BTreeMap<String, RevokedKey>,
+}
+
+/// Read `trust_store.json`; a missing file is an empty store.
+pub fn load(dir: &Path) -> Result<TrustStoreFile> {
+    let path = dir.join(TRUST_STORE_FILE);
+    if !path.exists() {
+        return Ok(TrustStoreFile::default());
+    }
+    let text = fs::read_to_string(&path)
+        .with_context(|| format!("E-UICP-0271: read trust store: {}", path.display()))?;
+    serde_json::from_str(&text)
+        .with_context(|| format!("E-UICP-0271: parse trust store: {}", path.display()))
+}
+
+fn save(dir: &Path, store: &TrustStoreFile) -> Result<()> {
+    fs::create_dir_all(dir).with_context(|| format!("E-UICP-0271: create {}", dir.display()))?;
+    let path = dir.join(TRUST_STORE_FILE);
+    let tmp = dir.join(format!("{TRUST_STORE_FILE}.tmp"));
+    let text = serde_json::to_string_pretty(store).context("E-UICP-0271: encode trust store")?;
+    fs::write(&tmp, text)
+        .with_context(|| format!("E-UICP-0271: write trust store: {}", tmp.display()))?;
+    registry::replace_file(&tmp, &path)
+        .with_context(|| format!("E-UICP-0271: replace trust store: {}", path.display()))
+}
+
+/// Apply `edit` to the store under the directory's install lock and persist the result.
+fn update<T>(dir: &Path, edit: impl FnOnce(&mut TrustStoreFile) -> Result<T>) -> Result<T> {
+    let _lock = registry::acquire_install_lock(dir)
+        .ok_or_else(|| anyhow::anyhow!("E-UICP-0271: another trust store change is in progress"))?;
+    let mut store = load(dir)?;
+    let out = edit(&mut store)?;
+    save(dir, &store)?;
+    Ok(out)
+}
+
+fn check_keyid(keyid: &str) -> Result<()> {
+    ensure!(
+        !keyid.is_empty()
+            && keyid.len() <= 128
+            && keyid
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':')),
+        "E-UICP-0270: keyid must be 1-128 chars of [A-Za-z0-9-_.:], got '{keyid}'"
+    );
+    Ok(())
+}
+
+fn normalize_pubkey(pubkey: &str) -> Result<String> {
+    let bytes = registry::decode_pubkey_any(pubkey.trim())
+        .context("E-UICP-0270: pubkey must be a 32-byte Ed25519 key (base64 or hex)")?;
+    Ok(BASE64_STANDARD.encode(bytes))
+}
+
+fn insert_key(
+    store: &mut TrustStoreFile,
+    keyid: &str,
+    pubkey: &str,
+    expires_at: Option<i64>,
+    now: i64,
+) -> Result<TrustedKey> {
+    check_keyid(keyid)?;
+    let pubkey = normalize_pubkey(pubkey)?;
+    // WHY: Revocation is permanent; reusing a revoked keyid would quietly re-trust old signatures.
+    ensure!(
+        !store.revoked.contains_key(keyid),
+        "E-UICP-0270: keyid {keyid} was revoked; add the key under a new keyid"
+    );
+    if let Some(existing) = store.keys.get_mut(keyid) {
+        ensure!(
+            existing.pubkey == pubkey,
+            "E-UICP-0270: keyid {keyid} is already trusted with a different pubkey; rotate to a new keyid"
+        );
+        existing.expires_at = expires_at;
+        return Ok(existing.clone());
+    }
+    let key = TrustedKey {
+        pubkey,
+        added_at: now,
+        expires_at,
+        rotated_to: None,
+    };
+    store.keys.insert(keyid.to_string(), key.clone());
+    Ok(key)
+}
+
+/// Trust `pubkey` under `keyid`. Re-adding the same key only updates its expiry.
+pub fn add_key(
+    dir: &Path,
+    keyid: &str,
+    pubkey: &str,
+    expires_at: Option<i64>,
+) -> Result<TrustedKey> {
+    let now = chrono::Utc::now().timestamp();
+    update(dir, |store| {
+        insert_key(store, keyid, pubkey, expires_at, now)
+    })
+}
+
+/// Trust `new_keyid` and expire `old_keyid` now, so modules it signed earlier keep loading while
+/// anything it signs from here on is refused.
+pub fn rotate_key(
+    dir: &Path,
+    old_keyid: &str,
+    new_keyid: &str,
+    new_pubkey: &str,
+) -> Result<TrustedKey> {
+    let now = chrono::Utc::now().timestamp();
+    update(dir, |store| {
+        ensure!(
+            old_keyid != new_keyid,
+            "E-UICP-0270: rotation needs a new keyid"
+        );
+        if !store.keys.contains_key(old_keyid) {
+            bail!("E-UICP-0274: keyid {old_keyid} is not in the managed trust store");
+        }
+        let key = insert_key(store, new_keyid, new_pubkey, None, now)?;
+        if let Some(old) = store.keys.get_mut(old_keyid) {
+            old.expires_at = Some(old.expires_at.map_or(now, |at| at.min(now)));
+            old.rotated_to = Some(new_keyid.to_string());
+        }
+        Ok(key)
+    })
+}
+
+/// Add `keyid` to the revocation list and drop it from the managed keys. Works for keys from any
+/// source, including `UICP_TRUST_STORE(_JSON)`. Revoking twice keeps the first record.
+pub fn revoke_key(dir: &Path, keyid: &str, reason: Option<String>) -> Result<RevokedKey> {
+    check_keyid(keyid)?;
+    let now = chrono::Utc::now().timestamp();
+    update(dir, |store| {
+        store.keys.remove(keyid);
+        Ok(store
+            .revoked
+            .entry(keyid.to_string())
+            .or_insert(RevokedKey {
+                revoked_at: now,
+                reason,
+            })
+            .clone())
+    })
+}
+
+/// Managed and env-provided keys (minus revoked ones), plus the revocation list.
+pub fn list(dir: &Path) -> Result<TrustStoreListing> {
+    let store = load(dir)?;
+    let mut keys: Vec<TrustedKeyInfo> = store
+        .keys
+        .iter()
+        .map(|(keyid, key)| TrustedKeyInfo {
+            keyid: keyid.clone(),
+            pubkey: key.pubkey.clone(),
+            source: "managed",
+            added_at: Some(key.added_at),
+            expires_at: key.expires_at,
+            rotated_to: key.rotated_to.clone(),
+        })
+        .collect();
+    for (keyid, pubkey) in registry::read_trust_store()?.unwrap_or_default() {
+        if store.keys.contains_key(&keyid) || store.revoked.contains_key(&keyid) {
+            continue;
+        }
+        keys.push(TrustedKeyInfo {
+            keyid,
+            pubkey: BASE64_STANDARD.encode(pubkey),
+            source: "env",
+            added_at: None,
+            expires_at: None,
+            rotated_to: None,
+        });
+    }
+    keys.sort_by(|a, b| a.keyid.cmp(&b.keyid));
+    Ok(TrustStoreListing {
+        keys,
+        revoked: store.revoked,
+    })
+}
+
+/// Managed pubkey for `keyid`, if any. Revoked keyids never resolve.
+pub fn managed_pubkey(dir: &Path, keyid: &str) -> Result<Option<[u8; 32]>> {
+    let store = load(dir)?;
+    if store.revoked.contains_key(keyid) {
+        return Ok(None);
+    }
+    store
+        .keys
+        .get(keyid)
+        .map(|key| registry::decode_pubkey_any(&key.pubkey))
+        .transpose()
+}
+
+/// Refuse entries signed by a revoked keyid, or signed after their key's expiry (or with a
+/// signature that leaves `signed_at` out). Entries without a keyid are not covered (they verify
+/// against `UICP_MODULES_PUBKEY`).
+pub fn check_signer(dir: &Path, entry: &ModuleEntry) -> Result<()> {
+    let Some(keyid) = entry.keyid.as_deref() else {
+        return Ok(());
+    };
+    let store = load(dir)?;
+    if let Some(revoked) = store.revoked.get(keyid) {
+        bail!(
+            "E-UICP-0272: {}@{} is signed by revoked key {keyid} (revoked at {})",
+            entry.task,
+            entry.version,
+            revoked.revoked_at
+        );
+    }
+    let Some(key) = store.keys.get(keyid) else {
+        return Ok(());
+    };
+    let Some(expires_at) = key.expires_at else {
+        return Ok(());
+    };
+    // WHY: Expiry trusts `signed_at`, so it must be signed; older signatures left it out.
+    let covered = registry::decode_pubkey_any(&key.pubkey)
+        .is_ok_and(|pubkey| registry::signature_covers_signed_at(entry, &pubkey));
+    match entry.signed_at {
+        Some(_) if !covered => bail!(
+            "E-UICP-0273: {}@{} signature does not cover signed_at and key {keyid} expires at {expires_at}; re-sign it",
+            entry.task,
+            entry.version
+        ),
+        Some(signed_at) if signed_at <= expires_at => Ok(()),
+        Some(signed_at) => bail!(
+            "E-UICP-0273: {}@{} was signed at {signed_at}, after key {keyid} expired at {expires_at}",
+            entry.task,
+            entry.version
+        ),
+        None => bail!(
+            "E-UICP-0273: {}@{} has no signed_at and key {keyid} expires at {expires_at}",
+            entry.task,
+            entry.version
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::Signer;
+
+    const SEED_A: [u8; 32] = [4u8; 32];
+
+    fn key_a() -> String {
+        let sk = ed25519_dalek::SigningKey::from_bytes(&SEED_A);
+        BASE64_STANDARD.encode(sk.verifying_key().to_bytes())
+    }
+
+    fn key_b() -> String {
+        hex::encode([5u8; 32])
+    }
+
+    /// An entry signed by `SEED_A` at `signed_at`; without one it carries a placeholder signature.
+    fn entry(keyid: &str, signed_at: Option<i64>) -> ModuleEntry {
+        let mut entry = ModuleEntry {
+            task: "csv.parse".into(),
+            version: "1.2.0".into(),
+            filename: "csv.parse@1.2.0.wasm".into(),
+            digest_sha256: "aa".repeat(32),
+            signature: Some("sig".into()),
+            keyid: Some(keyid.into()),
+            signed_at: None,
+            imports: None,
+        };
+        if let Some(at) = signed_at {
+            registry::sign_entry(&mut entry, &SEED_A, Some(keyid), at).unwrap();
+        }
+        entry
+    }
+
+    #[test]
+    fn rotation_expires_old_key_and_revocation_refuses_all_its_modules() {
+        let dir = tempfile::tempdir().unwrap();
+        add_key(dir.path(), "release-1", &key_a(), None).unwrap();
+        assert!(add_key(dir.path(), "release-1", &key_b(), None)
+            .unwrap_err()
+            .to_string()
+            .contains("E-UICP-0270"));
+        assert_eq!(
+            managed_pubkey(dir.path(), "release-1").unwrap(),
+            Some(registry::decode_pubkey_any(&key_a()).unwrap())
+        );
+
+        rotate_key(dir.path(), "release-1", "release-2", &key_b()).unwrap();
+        let store = load(dir.path()).unwrap();
+        let old = &store.keys["release-1"];
+        let expires_at = old.expires_at.expect("rotated key expires");
+        assert_eq!(old.rotated_to.as_deref(), Some("release-2"));
+        assert!(check_signer(dir.path(), &entry("release-1", Some(expires_at - 60))).is_ok());
+        let err = check_signer(dir.path(), &entry("release-1", Some(expires_at + 60))).unwrap_err();
+        assert!(err.to_string().starts_with("E-UICP-0273"), "{err}");
+        assert!(check_signer(dir.path(), &entry("release-1", None)).is_err());
+        // A signature made before `signed_at` was covered cannot vouch for it.
+        let mut uncovered = entry("release-1", Some(expires_at - 60));
+        let legacy = registry::signing_message(&ModuleEntry {
+            signed_at: None,
+            ..uncovered.clone()
+        })
+        .unwrap();
+        let sk = ed25519_dalek::SigningKey::from_bytes(&SEED_A);
+        uncovered.signature = Some(BASE64_STANDARD.encode(sk.sign(&legacy).to_bytes()));
+        let err = check_signer(dir.path(), &uncovered).unwrap_err();
+        assert!(
+            err.to_string().contains("does not cover signed_at"),
+            "{err}"
+        );
+        assert!(check_signer(dir.path(), &entry("release-2", None)).is_ok());
+
+        revoke_key(dir.path(), "release-1", Some("leaked".into())).unwrap();
+        let err = check_signer(dir.path(), &entry("release-1", Some(expires_at - 60))).unwrap_err();
+        assert!(err.to_string().starts_with("E-UICP-0272"), "{err}");
+        assert_eq!(managed_pubkey(dir.path(), "release-1").unwrap(), None);
+        assert!(add_key(dir.path(), "release-1", &key_a(), None).is_err());
+    }
+
+    #[test]
+    fn list_reports_managed_keys_and_revocations() {
+        let dir = tempfile::tempdir().unwrap();
+        let managed = |listing: TrustStoreListing| -> Vec<TrustedKeyInfo> {
+            listing
+                .keys
+                .into_iter()
+                .filter(|k| k.source == "managed")
+                .collect()
+        };
+        assert_eq!(managed(list(dir.path()).unwrap()), Vec::new());
+        add_key(dir.path(), "ci", &key_b(), Some(2_000_000_000)).unwrap();
+        revoke_key(dir.path(), "dev-seed", None).unwrap();
+        revoke_key(dir.path(), "dev-seed", Some("ignored".into())).unwrap();
+
+        let listing = list(dir.path()).unwrap();
+        assert_eq!(listing.revoked["dev-seed"].reason, None);
+        let keys = managed(listing);
+        assert_eq!(keys.len(), 1);
+        assert_eq!(keys[0].keyid, "ci");
+        assert_eq!(keys[0].pubkey, BASE64_STANDARD.encode([5u8; 32]));
+        assert_eq!(keys[0].expires_at, Some(2_000_000_000));
+        assert!(revoke_key(dir.path(), "bad keyid", None).is_err());
+    }
+}
@@ -17,6 +17,8 @@ pub const DETAIL_FS_CAP: &str = "E-UICP-0404";
 pub const DETAIL_IO: &str = "E-UICP-0405";
 pub const DETAIL_SCRIPT_INPUT: &str = "E-UICP-0406";
 pub const DETAIL_CODEGEN_INPUT: &str = "E-UICP-0407";
+pub const DETAIL_JSON_QUERY_INPUT: &str = "E-UICP-0409";
+pub const DETAIL_MARKDOWN_INPUT: &str = "E-UICP-0410";
 
 /// Security error codes (SEC-*)
 pub const RNG_FAILURE_CODE: &str = "E-UICP-SEC-RNG";
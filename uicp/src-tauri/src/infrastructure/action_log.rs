@@ -368,6 +368,9 @@ pub fn ensure_action_log_schema(conn: &Connection) -> anyhow::Result<()> {
             sig BLOB
         );
         CREATE INDEX IF NOT EXISTS action_log_hash ON action_log(hash);
+        -- WHY: Job log and replay lookups filter on this exact expression; keep them in sync.
+        CREATE INDEX IF NOT EXISTS action_log_kind_job
+            ON action_log(kind, json_extract(payload_json, '$.jobId'));
         ",
     )
     .context("ensure action_log schema")?;
@@ -171,7 +171,10 @@ pub struct AppState {
     pub compute_sem: Arc<Semaphore>,
     pub codegen_sem: Arc<Semaphore>,
     pub wasm_sem: Arc<Semaphore>,
+    /// Priority/fairness scheduling in front of `wasm_sem` and `compute_sem`.
+    pub compute_queues: crate::compute::scheduler::ComputeQueues,
     pub compute_cancel: RwLock<HashMap<String, tokio::sync::watch::Sender<bool>>>,
+    pub compute_pause: RwLock<HashMap<String, tokio::sync::watch::Sender<bool>>>,
     pub safe_mode: RwLock<bool>,
     pub safe_reason: RwLock<Option<String>>,
     pub circuit_breakers: Arc<RwLock<HashMap<String, CircuitState>>>,
@@ -181,6 +184,8 @@ pub struct AppState {
     pub resilience_metrics: crate::infrastructure::chaos::ResilienceMetrics,
     pub action_log: crate::infrastructure::action_log::ActionLogHandle,
     pub job_token_key: [u8; 32],
+    /// Result of the latest module integrity sweep (`None` until the first one finishes).
+    pub modules_integrity: RwLock<Option<crate::compute::module_integrity::IntegrityReport>>,
 }
 
 /// Compute a stable cache key for compute tasks so callers outside compute_cache can derive the same key.
@@ -278,6 +283,10 @@ pub fn init_database(db_path: &PathBuf) -> anyhow::Result<()> {
     .context("apply migrations")?;
     crate::infrastructure::action_log::ensure_action_log_schema(&conn)
         .context("ensure crate::infrastructure::action_log schema (init_database)")?;
+    crate::compute::applet_kv::ensure_applet_kv_schema(&conn)
+        .context("ensure applet_kv schema (init_database)")?;
+    crate::compute::module_pins::ensure_module_pins_schema(&conn)
+        .context("ensure module_pins schema (init_database)")?;
 
     match conn.execute("ALTER TABLE window ADD COLUMN width REAL DEFAULT 640", []) {
         Ok(_) => {}
@@ -490,6 +499,7 @@ where
 pub async fn remove_compute_job<R: Runtime>(app_handle: &tauri::AppHandle<R>, job_id: &str) {
     let state: State<'_, crate::AppState> = app_handle.state();
     state.compute_ongoing.write().await.remove(job_id);
+    state.compute_pause.write().await.remove(job_id);
 }
 
 // ----------------------------------------------------------------------------
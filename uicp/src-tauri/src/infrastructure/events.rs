@@ -1,8 +1,9 @@
 // WHY: Centralize compute event channel names to prevent drift between host components.
 pub const EVENT_COMPUTE_RESULT_FINAL: &str = "compute-result-final";
-#[cfg(any(test, feature = "wasm_compute", feature = "compute_harness"))]
-#[allow(dead_code)]
 pub const EVENT_COMPUTE_RESULT_PARTIAL: &str = "compute-result-partial";
+// WHY: Pipelines report node partials and one aggregate result on their own channels so per-job listeners stay unchanged.
+pub const EVENT_COMPUTE_PIPELINE_PARTIAL: &str = "compute-pipeline-partial";
+pub const EVENT_COMPUTE_PIPELINE_FINAL: &str = "compute-pipeline-final";
 
 // WHY: UI debug stream uses a dedicated event channel that the frontend listens to.
 #[allow(dead_code)]
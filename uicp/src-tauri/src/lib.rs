@@ -14,11 +14,15 @@ pub use infrastructure::action_log::{
 };
 pub use security::policy::{
     enforce_compute_policy, ComputeBindSpec, ComputeCapabilitiesSpec, ComputeFinalErr,
-    ComputeFinalOk, ComputeJobSpec, ComputePartialEvent, ComputeProvenanceSpec,
+    ComputeFinalOk, ComputeJobSpec, ComputePartialEvent, ComputePriority, ComputeProvenanceSpec,
 };
 
+pub use compute::pipeline::{ComputePipelineSpec, PipelineNodeSpec};
+pub use compute::job_logs::GuestLogEntry;
+pub use compute::replay::ReplayReport;
+
 // WHY: Keep compute event channel names consistent across host layers (commands, runtime, bridge).
-pub use infrastructure::events::EVENT_COMPUTE_RESULT_FINAL;
+pub use infrastructure::events::{EVENT_COMPUTE_PIPELINE_FINAL, EVENT_COMPUTE_RESULT_FINAL};
 #[cfg(any(test, feature = "wasm_compute", feature = "compute_harness"))]
 pub use infrastructure::events::EVENT_COMPUTE_RESULT_PARTIAL;
 
@@ -90,7 +94,8 @@ pub mod commands;
 pub mod commands_harness;
 #[cfg(any(test, feature = "compute_harness"))]
 pub use commands_harness::{
-    clear_compute_cache, compute_call, compute_cancel, copy_into_files, get_modules_info,
+    clear_compute_cache, compute_call, compute_cancel, compute_job_logs, compute_pipeline,
+    compute_queue_status, compute_replay, compute_set_paused, copy_into_files, get_modules_info,
     load_workspace, save_workspace,
 };
 
@@ -735,6 +735,69 @@ fn spawn_db_maintenance(app_handle: tauri::AppHandle) {
     });
 }
 
+/// Spawn the periodic module integrity sweep.
+///
+/// Runs at startup and then every 15 minutes (configurable via
+/// `UICP_MODULES_INTEGRITY_INTERVAL_SECS`, `0` disables). Each result is stored for
+/// `get_modules_info` and emitted as `modules-integrity`; sweeps that found issues also append a
+/// `modules.integrity` action-log record.
+fn spawn_modules_integrity(app_handle: tauri::AppHandle) {
+    use crate::compute::module_integrity;
+
+    let Some(secs) = module_integrity::interval_secs() else {
+        return;
+    };
+    spawn(async move {
+        let mut ticker = interval(Duration::from_secs(secs));
+        loop {
+            ticker.tick().await;
+            let state: State<'_, AppState> = app_handle.state();
+            if *state.safe_mode.read().await {
+                continue;
+            }
+            let sweep_handle = app_handle.clone();
+            let report = match tauri::async_runtime::spawn_blocking(move || {
+                module_integrity::sweep_app(&sweep_handle)
+            })
+            .await
+            {
+                Ok(Ok(report)) => report,
+                Ok(Err(err)) => {
+                    log_warn(format!("module integrity sweep failed: {err:#}"));
+                    continue;
+                }
+                Err(err) => {
+                    log_error(format!("module integrity sweep panicked: {err}"));
+                    continue;
+                }
+            };
+            for issue in &report.issues {
+                log_error(format!(
+                    "module {} failed integrity check (quarantined={:?}, restored={})",
+                    issue.filename, issue.quarantined_to, issue.restored
+                ));
+            }
+            for issue in &report.aot_issues {
+                log_error(format!(
+                    "aot artifact {} failed integrity check ({}, quarantined={:?})",
+                    issue.artifact, issue.reason, issue.quarantined_to
+                ));
+            }
+            if !report.issues.is_empty() || !report.aot_issues.is_empty() {
+                if let Err(err) = state
+                    .action_log
+                    .append_json("modules.integrity", &serde_json::json!(report))
+                    .await
+                {
+                    log_error(format!("modules.integrity action log append failed: {err}"));
+                }
+            }
+            emit_or_log(&app_handle, module_integrity::EVENT_NAME, &report);
+            *state.modules_integrity.write().await = Some(report);
+        }
+    });
+}
+
 #[allow(clippy::too_many_lines)]
 fn main() {
     #[cfg(feature = "otel_spans")]
@@ -870,6 +933,13 @@ fn main() {
             .field("summary", openai_shape_cfg.summary()),
     );
 
+    let compute_sem = Arc::new(Semaphore::new(2));
+    let wasm_sem = Arc::new(Semaphore::new(wasm_conc));
+    let compute_queues = crate::compute::scheduler::ComputeQueues::new(
+        Arc::clone(&wasm_sem),
+        Arc::clone(&compute_sem),
+    );
+
     let state = AppState {
         db_path: db_path.clone(),
         db_ro,
@@ -895,10 +965,12 @@ fn main() {
             .expect("Failed to build HTTP client"),
         ongoing: RwLock::new(HashMap::new()),
         compute_ongoing: RwLock::new(HashMap::new()),
-        compute_sem: Arc::new(Semaphore::new(2)),
+        compute_sem,
         codegen_sem: Arc::new(Semaphore::new(2)),
-        wasm_sem: Arc::new(Semaphore::new(wasm_conc)),
+        wasm_sem,
+        compute_queues,
         compute_cancel: RwLock::new(HashMap::new()),
+        compute_pause: RwLock::new(HashMap::new()),
         safe_mode: RwLock::new(false),
         safe_reason: RwLock::new(None),
         circuit_breakers: Arc::new(RwLock::new(HashMap::new())),
@@ -908,6 +980,7 @@ fn main() {
         resilience_metrics: crate::infrastructure::chaos::ResilienceMetrics::new(),
         action_log,
         job_token_key,
+        modules_integrity: RwLock::new(None),
     };
 
     // NOTE: Environment API key loading moved to embedded keystore flows.
@@ -949,6 +1022,8 @@ fn main() {
             spawn_autosave(handle.clone());
             // Periodic DB maintenance to keep WAL and stats tidy
             spawn_db_maintenance(handle.clone());
+            // Periodic re-hash of installed modules; quarantines tampered files
+            spawn_modules_integrity(handle.clone());
 
             #[cfg(feature = "wasm_compute")]
             {
@@ -1104,6 +1179,15 @@ fn main() {
             commands::modules::verify_modules,
             commands::modules::get_modules_info,
             commands::modules::get_modules_registry,
+            commands::modules::modules_install,
+            commands::modules::modules_uninstall,
+            commands::modules::modules_pin_set,
+            commands::modules::modules_pin_clear,
+            commands::modules::modules_pins,
+            commands::modules::modules_trust_list,
+            commands::modules::modules_trust_add,
+            commands::modules::modules_trust_rotate,
+            commands::modules::modules_trust_revoke,
 
             // Network
             commands::network::egress_fetch,
@@ -1153,7 +1237,16 @@ fn main() {
             // Compute
             commands::compute::compute_call,
             commands::compute::compute_cancel,
+            commands::compute::compute_pause,
+            commands::compute::compute_resume,
+            commands::compute::compute_queue_status,
+            commands::compute::compute_pipeline,
+            commands::compute::compute_replay,
+            commands::compute::compute_job_logs,
             commands::compute::clear_compute_cache,
+            commands::compute::applet_session_open,
+            commands::compute::applet_session_call,
+            commands::compute::applet_session_dispose,
 
             // Chat
             commands::chat::chat_completion,
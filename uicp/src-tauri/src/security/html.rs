@@ -0,0 +1,183 @@
+//! Host-side HTML sanitization for markup produced by compute tasks.
+//!
+//! WHY: Component output is untrusted. The policy mirrors the DOMPurify configuration in
+//! `src/lib/utils.ts` so HTML rendered on the host (e.g. `markdown.render`) is already in the
+//! shape the frontend would accept, and the cached result is the sanitized one.
+
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+
+use ammonia::Builder;
+
+#[rustfmt::skip]
+const ALLOWED_TAGS: &[&str] = &[
+    "a", "abbr", "article", "aside", "b", "bdi", "bdo", "blockquote", "br", "caption", "code",
+    "col", "colgroup", "dd", "del", "details", "div", "dl", "dt", "em", "figcaption", "figure",
+    "footer", "h1", "h2", "h3", "h4", "h5", "h6", "header", "hr", "i", "img", "ins", "kbd",
+    "label", "li", "button", "main", "mark", "nav", "ol", "p", "picture", "pre", "q", "s", "samp",
+    "section", "small", "span", "strong", "sub", "summary", "sup", "table", "tbody", "td",
+    "tfoot", "th", "thead", "time", "tr", "u", "ul",
+];
+
+// NOTE: `rel` is absent on purpose; ammonia owns it and sets `noopener noreferrer` on links.
+#[rustfmt::skip]
+const ALLOWED_ATTRIBUTES: &[&str] = &[
+    "abbr", "align", "alt", "aria-activedescendant", "aria-atomic", "aria-autocomplete",
+    "aria-busy", "aria-checked", "aria-colcount", "aria-colindex", "aria-colspan",
+    "aria-controls", "aria-current", "aria-describedby", "aria-description", "aria-details",
+    "aria-disabled", "aria-dropeffect", "aria-errormessage", "aria-expanded", "aria-flowto",
+    "aria-grabbed", "aria-haspopup", "aria-hidden", "aria-invalid", "aria-keyshortcuts",
+    "aria-label", "aria-labelledby", "aria-level", "aria-live", "aria-modal", "aria-multiline",
+    "aria-multiselectable", "aria-orientation", "aria-owns", "aria-placeholder", "aria-pressed",
+    "aria-readonly", "aria-required", "aria-roledescription", "aria-rowcount", "aria-rowindex",
+    "aria-rowspan", "aria-selected", "aria-setsize", "aria-sort", "aria-valuemax",
+    "aria-valuemin", "aria-valuenow", "aria-valuetext", "class", "colspan", "dir", "download",
+    "draggable", "headers", "href", "hreflang", "id", "lang", "loading", "role", "rowspan",
+    "scope", "src", "srcset", "sizes", "tabindex", "target", "title", "type", "value", "width",
+    "height",
+];
+
+/// Tags removed together with their content rather than unwrapped.
+#[rustfmt::skip]
+const DROPPED_TAGS: &[&str] = &[
+    "script", "form", "input", "textarea", "select", "option", "iframe", "embed", "object",
+    "svg", "math", "meta", "link", "style",
+];
+
+/// Sanitizes an HTML fragment with the host HTML policy.
+pub fn sanitize_html(html: &str) -> String {
+    static POLICY: OnceLock<Builder<'static>> = OnceLock::new();
+    POLICY.get_or_init(build_policy).clean(html).to_string()
+}
+
+fn build_policy() -> Builder<'static> {
+    let mut builder = Builder::empty();
+    builder
+        .tags(ALLOWED_TAGS.iter().copied().collect())
+        .tag_attributes(HashMap::new())
+        .generic_attributes(ALLOWED_ATTRIBUTES.iter().copied().collect())
+        .generic_attribute_prefixes(HashSet::from(["data-"]))
+        .clean_content_tags(DROPPED_TAGS.iter().copied().collect())
+        .url_schemes(HashSet::from(["http", "https"]))
+        .attribute_filter(filter_attribute);
+    builder
+}
+
+// INVARIANT: Runs after ammonia's own allow-list and scheme checks, so only the checks it does
+// not cover (protocol-relative URLs, srcset candidates, id shape) live here.
+fn filter_attribute<'u>(_element: &str, attribute: &str, value: &'u str) -> Option<Cow<'u, str>> {
+    match attribute {
+        "href" | "src" => is_safe_url(value).then_some(Cow::Borrowed(value)),
+        "srcset" => {
+            let kept: Vec<&str> = value
+                .split(',')
+                .map(str::trim)
+                .filter(|candidate| candidate.split_whitespace().next().is_some_and(is_safe_url))
+                .collect();
+            (!kept.is_empty()).then(|| Cow::Owned(kept.join(", ")))
+        }
+        "id" => {
+            let id = value.strip_prefix("user-content-").unwrap_or(value);
+            is_valid_id(id).then_some(Cow::Borrowed(id))
+        }
+        _ => Some(Cow::Borrowed(value)),
+    }
+}
+
+/// Same rules as `isSafeUrl` in the frontend: fragments and relative paths are allowed,
+/// protocol-relative URLs are not, and the only absolute schemes are http(s).
+fn is_safe_url(value: &str) -> bool {
+    let url = value.trim();
+    if url.is_empty() {
+        return false;
+    }
+    if url.starts_with('#') {
+        return true;
+    }
+    if url.starts_with("//") {
+        return false;
+    }
+    if url.starts_with('/') || url.starts_with("./") || url.starts_with("../") {
+        return true;
+    }
+    match scheme_of(url) {
+        Some(scheme) => scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https"),
+        None => !url.contains(':'),
+    }
+}
+
+fn scheme_of(url: &str) -> Option<&str> {
+    let (scheme, _) = url.split_once(':')?;
+    let mut chars = scheme.chars();
+    let first = chars.next()?;
+    (first.is_ascii_alphabetic()
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '.' | '-')))
+    .then_some(scheme)
+}
+
+fn is_valid_id(id: &str) -> bool {
+    let mut chars = id.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | ':' | '-'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_allowed_markup() {
+        let html = "<h2 id=\"user-content-intro\">Intro</h2><table><tr><td align=\"right\" \
+                    data-row=\"1\">1</td></tr></table><span class=\"x\" aria-label=\"y\">z</span>";
+        assert_eq!(
+            sanitize_html(html),
+            "<h2 id=\"intro\">Intro</h2><table><tbody><tr><td align=\"right\" data-row=\"1\">1\
+             </td></tr></tbody></table><span class=\"x\" aria-label=\"y\">z</span>"
+        );
+    }
+
+    #[test]
+    fn drops_scripts_handlers_and_forbidden_content() {
+        let html = "<p onclick=\"x()\" style=\"color:red\">hi<script>alert(1)</script></p>\
+                    <form><input value=\"a\">secret</form><svg><text>s</text></svg><blink>b</blink>";
+        assert_eq!(sanitize_html(html), "<p>hi</p>b");
+    }
+
+    #[test]
+    fn filters_urls_like_the_frontend() {
+        assert_eq!(
+            sanitize_html("<a href=\"javascript:alert(1)\">x</a>"),
+            "<a rel=\"noopener noreferrer\">x</a>"
+        );
+        assert_eq!(
+            sanitize_html("<a href=\"//evil.example\">x</a>"),
+            "<a rel=\"noopener noreferrer\">x</a>"
+        );
+        assert_eq!(
+            sanitize_html("<a href=\"https://example.com/a\" target=\"_blank\">x</a>"),
+            "<a href=\"https://example.com/a\" target=\"_blank\" rel=\"noopener noreferrer\">x</a>"
+        );
+        assert_eq!(
+            sanitize_html("<img src=\"data:image/png;base64,AA\" alt=\"a\">"),
+            "<img alt=\"a\">"
+        );
+        assert_eq!(
+            sanitize_html("<img srcset=\"/a.png 1x, javascript:x 2x, https://e.com/b.png 3x\">"),
+            "<img srcset=\"/a.png 1x, https://e.com/b.png 3x\">"
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_ids_and_urls() {
+        assert_eq!(sanitize_html("<p id=\"1abc\">x</p>"), "<p>x</p>");
+        assert!(is_safe_url("#top"));
+        assert!(is_safe_url("../docs/a.md"));
+        assert!(is_safe_url("page.html"));
+        assert!(!is_safe_url("  "));
+        assert!(!is_safe_url("mailto:a@b.c"));
+        assert!(!is_safe_url("weird:thing"));
+    }
+}
@@ -1,4 +1,5 @@
 ﻿pub mod authz;
 pub mod egress;
+pub mod html;
 pub mod keystore;
 pub mod policy;
@@ -35,6 +35,38 @@ pub struct ComputeBindSpec {
     pub to_state_path: String,
 }
 
+/// Scheduling class for a compute job. Interactive work (applet renders) is dispatched ahead of
+/// normal and batch jobs; queued jobs age upward so batch work is never starved.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum ComputePriority {
+    Interactive,
+    #[default]
+    Normal,
+    Batch,
+}
+
+impl ComputePriority {
+    pub const ALL: [ComputePriority; 3] = [Self::Interactive, Self::Normal, Self::Batch];
+
+    /// Dispatch rank before aging (lower runs first).
+    pub fn rank(self) -> u32 {
+        match self {
+            Self::Interactive => 0,
+            Self::Normal => 1,
+            Self::Batch => 2,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Interactive => "interactive",
+            Self::Normal => "normal",
+            Self::Batch => "batch",
+        }
+    }
+}
+
 /// Host-side specification for executing a compute job.
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -65,6 +97,8 @@ pub struct ComputeJobSpec {
     pub artifact_id: Option<String>,
     #[serde(default)]
     pub expect_golden: bool,
+    #[serde(default)]
+    pub priority: ComputePriority,
 }
 
 /// Terminal error envelope emitted back to the UI.
@@ -102,6 +136,16 @@ pub struct ComputePartialEvent {
     pub payload_b64: String,
 }
 
+impl ComputeJobSpec {
+    /// Whether the job's result may be served from or stored in the compute cache. fsWrite jobs
+    /// must run to produce their workspace files and net-granted jobs depend on live responses;
+    /// codegen keeps its network results in its own golden cache.
+    pub fn cacheable(&self) -> bool {
+        self.capabilities.fs_write.is_empty()
+            && (self.capabilities.net.is_empty() || is_codegen_task(&self.task))
+    }
+}
+
 fn default_cache_mode() -> String {
     "readwrite".into()
 }
@@ -148,8 +192,100 @@ fn codegen_host_allowed(url: &str) -> bool {
         .any(|allowed| url.starts_with(allowed))
 }
 
-/// Enforce policy gates before dispatching a compute job.
-pub fn enforce_compute_policy(spec: &ComputeJobSpec) -> Option<ComputeFinalErr> {
+/// One parsed `capabilities.net` grant: an https origin and the path prefix it covers.
+/// `host` is a lowercase pattern (`example.com` or `*.example.com`); a bare hostname grants
+/// port 443 and every path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetRule {
+    pub host: String,
+    pub port: u16,
+    pub path_prefix: String,
+}
+
+impl NetRule {
+    /// Parse a `capabilities.net` entry. Accepts bare hostnames or `https://` URLs; plain http,
+    /// userinfo, queries, fragments, IP literals, `localhost` and bare wildcards never qualify.
+    pub fn parse(entry: &str) -> Option<Self> {
+        let trimmed = entry.trim();
+        let (authority, path) = match trimmed.split_once("://") {
+            Some((scheme, rest)) if scheme.eq_ignore_ascii_case("https") => match rest.find('/') {
+                Some(idx) => rest.split_at(idx),
+                None => (rest, "/"),
+            },
+            Some(_) => return None,
+            None => (trimmed, "/"),
+        };
+        if authority.contains(['@', '[', ']']) || path.contains(['?', '#']) {
+            return None;
+        }
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((h, port)) if !port.is_empty() && port.chars().all(|c| c.is_ascii_digit()) => {
+                (h, port.parse::<u16>().ok().filter(|p| *p != 0)?)
+            }
+            Some(_) => return None,
+            None => (authority, 443),
+        };
+        let host = host.trim_end_matches('.').to_ascii_lowercase();
+        let bare = host.strip_prefix("*.").unwrap_or(&host);
+        let valid_label = |label: &str| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        };
+        if !bare.contains('.')
+            || !bare.split('.').all(valid_label)
+            || bare.parse::<std::net::IpAddr>().is_ok()
+            || bare.ends_with(".localhost")
+        {
+            return None;
+        }
+        Some(Self {
+            host,
+            port,
+            path_prefix: path.to_string(),
+        })
+    }
+
+    /// True when `url` is https on this rule's host and port, under its path prefix.
+    /// `*.example.com` covers subdomains only, not the apex; `/v1` covers `/v1` and `/v1/...`
+    /// but not `/v10`.
+    pub fn allows(&self, url: &url::Url) -> bool {
+        if url.scheme() != "https" || !url.username().is_empty() || url.password().is_some() {
+            return false;
+        }
+        let Some(url::Host::Domain(host)) = url.host() else {
+            return false;
+        };
+        let host = host.trim_end_matches('.').to_ascii_lowercase();
+        let host_ok = match self.host.strip_prefix("*.") {
+            Some(suffix) => host
+                .strip_suffix(suffix)
+                .is_some_and(|prefix| prefix.len() > 1 && prefix.ends_with('.')),
+            None => host == self.host,
+        };
+        let path = url.path();
+        let prefix = self.path_prefix.as_str();
+        let path_ok = prefix == "/"
+            || path == prefix
+            || path
+                .strip_prefix(prefix)
+                .is_some_and(|rest| prefix.ends_with('/') || rest.starts_with('/'));
+        host_ok && url.port_or_known_default() == Some(self.port) && path_ok
+    }
+}
+
+/// True when `url` is allowed by one of the granted `capabilities.net` rules.
+pub fn net_url_granted(rules: &[NetRule], url: &url::Url) -> bool {
+    rules.iter().any(|rule| rule.allows(url))
+}
+
+/// Enforce policy gates before dispatching a compute job. `declares_http` is whether the task's
+/// verified manifest entry declares `uicp:host/http`; only such modules may hold net grants.
+pub fn enforce_compute_policy(
+    spec: &ComputeJobSpec,
+    declares_http: bool,
+) -> Option<ComputeFinalErr> {
     let timeout = spec.timeout_ms.unwrap_or(30_000);
     if !(1_000..=120_000).contains(&timeout) {
         return Some(ComputeFinalErr {
@@ -229,13 +365,30 @@ pub fn enforce_compute_policy(spec: &ComputeJobSpec) -> Option<ComputeFinalErr>
                 metrics: None,
             });
         }
-    } else if !spec.capabilities.net.is_empty() {
+    } else if let Some(bad) = spec
+        .capabilities
+        .net
+        .iter()
+        .find(|entry| NetRule::parse(entry).is_none())
+    {
+        // WHY: Non-codegen tasks reach the network only through `uicp:host/http`, which checks
+        // each request against these grants; reject anything we cannot match as a public host.
+        return Some(ComputeFinalErr {
+            ok: false,
+            job_id: spec.job_id.clone(),
+            task: spec.task.clone(),
+            code: "Compute.CapabilityDenied".into(),
+            message: format!("capabilities.net entries must be public https hosts (got '{bad}')"),
+            metrics: None,
+        });
+    } else if !spec.capabilities.net.is_empty() && !declares_http {
         return Some(ComputeFinalErr {
             ok: false,
             job_id: spec.job_id.clone(),
             task: spec.task.clone(),
             code: "Compute.CapabilityDenied".into(),
-            message: "Network is disabled by policy (cap.net required)".into(),
+            message: "capabilities.net requires a module whose manifest declares uicp:host/http"
+                .into(),
             metrics: None,
         });
     }
@@ -303,6 +456,7 @@ mod tests {
             golden_key: None,
             artifact_id: None,
             expect_golden: false,
+            priority: ComputePriority::Normal,
         }
     }
 
@@ -310,7 +464,7 @@ mod tests {
     fn timeout_below_minimum_is_denied() {
         let mut spec = base_spec();
         spec.timeout_ms = Some(500);
-        let deny = enforce_compute_policy(&spec).expect("expected rejection");
+        let deny = enforce_compute_policy(&spec, false).expect("expected rejection");
         assert_eq!(deny.code, "Compute.CapabilityDenied");
     }
 
@@ -318,36 +472,99 @@ mod tests {
     fn timeout_above_30s_requires_long_run_capability() {
         let mut spec = base_spec();
         spec.timeout_ms = Some(31_000);
-        let deny = enforce_compute_policy(&spec).expect("expected rejection");
+        let deny = enforce_compute_policy(&spec, false).expect("expected rejection");
         assert_eq!(deny.code, "Compute.CapabilityDenied");
         spec.capabilities.long_run = true;
-        assert!(enforce_compute_policy(&spec).is_none());
+        assert!(enforce_compute_policy(&spec, false).is_none());
     }
 
     #[test]
     fn memory_above_256_requires_mem_high_capability() {
         let mut spec = base_spec();
         spec.mem_limit_mb = Some(512);
-        let deny = enforce_compute_policy(&spec).expect("expected rejection");
+        let deny = enforce_compute_policy(&spec, false).expect("expected rejection");
         assert_eq!(deny.code, "Compute.CapabilityDenied");
         spec.capabilities.mem_high = true;
-        assert!(enforce_compute_policy(&spec).is_none());
+        assert!(enforce_compute_policy(&spec, false).is_none());
     }
 
     #[test]
     fn filesystem_paths_must_be_workspace_scoped() {
         let mut spec = base_spec();
         spec.capabilities.fs_read = vec!["file:/root/**".into()];
-        let deny = enforce_compute_policy(&spec).expect("expected rejection");
+        let deny = enforce_compute_policy(&spec, false).expect("expected rejection");
         assert_eq!(deny.code, "Compute.CapabilityDenied");
     }
 
     #[test]
-    fn network_capabilities_are_denied_in_v1() {
+    fn network_capabilities_require_public_https_hosts() {
+        let mut spec = base_spec();
+        spec.capabilities.net = vec!["https://example.com/v1".into(), "*.example.org".into()];
+        assert!(enforce_compute_policy(&spec, true).is_none());
+
+        for bad in [
+            "http://example.com",
+            "https://10.0.0.1",
+            "localhost",
+            "*",
+            "https://user@example.com",
+            "ftp://example.com",
+            "https://example.com/v1?key=1",
+            "https://example.com:0",
+        ] {
+            spec.capabilities.net = vec![bad.into()];
+            let deny = enforce_compute_policy(&spec, true).expect("expected rejection");
+            assert_eq!(deny.code, "Compute.CapabilityDenied", "entry {bad}");
+        }
+    }
+
+    #[test]
+    fn network_capabilities_require_a_module_declaring_http() {
         let mut spec = base_spec();
         spec.capabilities.net = vec!["https://example.com".into()];
-        let deny = enforce_compute_policy(&spec).expect("expected rejection");
+        let deny = enforce_compute_policy(&spec, false).expect("expected rejection");
         assert_eq!(deny.code, "Compute.CapabilityDenied");
+        assert!(deny.message.contains("uicp:host/http"), "{}", deny.message);
+        assert!(enforce_compute_policy(&spec, true).is_none());
+    }
+
+    #[test]
+    fn net_rules_match_scheme_host_port_and_path() {
+        let rules: Vec<NetRule> = ["https://API.example.com:8443/v1", "*.data.example.org"]
+            .iter()
+            .filter_map(|entry| NetRule::parse(entry))
+            .collect();
+        assert_eq!(rules.len(), 2);
+        let granted = |url: &str| net_url_granted(&rules, &url::Url::parse(url).unwrap());
+        assert!(granted("https://api.example.com:8443/v1"));
+        assert!(granted("https://api.example.com:8443/v1/items?page=2"));
+        assert!(!granted("https://api.example.com:8443/v10"));
+        assert!(!granted("https://api.example.com:8443/admin"));
+        assert!(!granted("https://api.example.com/v1"));
+        assert!(!granted("http://api.example.com:8443/v1"));
+        assert!(!granted("https://example.com:8443/v1"));
+        assert!(granted("https://eu.data.example.org/anything"));
+        assert!(!granted("https://eu.data.example.org:8443/anything"));
+        assert!(!granted("https://data.example.org/"));
+        assert!(!granted("https://evildata.example.org/"));
+        assert!(!granted("https://user@eu.data.example.org/"));
+        assert!(!net_url_granted(
+            &[],
+            &url::Url::parse("https://api.example.com/").unwrap()
+        ));
+    }
+
+    #[test]
+    fn net_granted_jobs_are_not_cacheable() {
+        let mut spec = base_spec();
+        assert!(spec.cacheable());
+        spec.capabilities.net = vec!["https://example.com".into()];
+        assert!(!spec.cacheable());
+        spec.task = "codegen.run@0.1.0".into();
+        assert!(spec.cacheable());
+        spec.capabilities.net.clear();
+        spec.capabilities.fs_write = vec!["ws:/files/out/**".into()];
+        assert!(!spec.cacheable());
     }
 
     #[test]
@@ -357,24 +574,24 @@ mod tests {
         let mut spec = base_spec();
         spec.task = "codegen.run@0.1.0".into();
         spec.capabilities.net = vec!["https://api.openai.com".into()];
-        assert!(enforce_compute_policy(&spec).is_none());
+        assert!(enforce_compute_policy(&spec, false).is_none());
 
         let mut spec_claude = spec.clone();
         spec_claude.capabilities.net = vec!["https://api.anthropic.com".into()];
         assert!(
-            enforce_compute_policy(&spec_claude).is_none(),
+            enforce_compute_policy(&spec_claude, false).is_none(),
             "anthropic endpoint should be allowed"
         );
 
         let mut spec_bad = spec.clone();
         spec_bad.capabilities.net = vec!["https://evil.example.com".into()];
-        let deny = enforce_compute_policy(&spec_bad).expect("expected rejection");
+        let deny = enforce_compute_policy(&spec_bad, false).expect("expected rejection");
         assert_eq!(deny.code, "Compute.CapabilityDenied");
 
         let mut spec_missing = spec.clone();
         spec_missing.capabilities.net.clear();
-        let deny_missing =
-            enforce_compute_policy(&spec_missing).expect("expected rejection for missing net");
+        let deny_missing = enforce_compute_policy(&spec_missing, false)
+            .expect("expected rejection for missing net");
         assert_eq!(deny_missing.code, "Compute.CapabilityDenied");
 
         std::env::set_var("UICP_CODEGEN_OPENAI_ENDPOINT", "http://localhost:11434");
@@ -382,7 +599,7 @@ mod tests {
         spec_local.task = "codegen.run@0.1.0".into();
         spec_local.capabilities.net = vec!["http://localhost:11434".into()];
         assert!(
-            enforce_compute_policy(&spec_local).is_none(),
+            enforce_compute_policy(&spec_local, false).is_none(),
             "custom endpoint from env should be allowed"
         );
         std::env::set_var("UICP_CODEGEN_ANTHROPIC_ENDPOINT", "http://localhost:2020");
@@ -390,7 +607,7 @@ mod tests {
         spec_claude_local.task = "codegen.run@0.1.0".into();
         spec_claude_local.capabilities.net = vec!["http://localhost:2020".into()];
         assert!(
-            enforce_compute_policy(&spec_claude_local).is_none(),
+            enforce_compute_policy(&spec_claude_local, false).is_none(),
             "anthropic override should be allowed"
         );
         std::env::remove_var("UICP_CODEGEN_OPENAI_ENDPOINT");
@@ -403,7 +620,7 @@ mod tests {
         spec.task = "codegen.run@0.1.0".into();
         spec.capabilities.net = vec!["https://api.openai.com".into()];
         spec.capabilities.fs_read = vec!["ws:/tmp/**".into()];
-        let deny = enforce_compute_policy(&spec).expect("expected rejection");
+        let deny = enforce_compute_policy(&spec, false).expect("expected rejection");
         assert_eq!(deny.code, "Compute.CapabilityDenied");
     }
 
@@ -125,6 +125,13 @@ impl ComputeTestHarness {
         let action_log =
             ActionLogService::start(&db_path).context("start action log service (harness)")?;
 
+        let compute_sem = Arc::new(Semaphore::new(2));
+        let wasm_sem = Arc::new(Semaphore::new(2));
+        let compute_queues = crate::compute::scheduler::ComputeQueues::new(
+            Arc::clone(&wasm_sem),
+            Arc::clone(&compute_sem),
+        );
+
         let state = AppState {
             db_path: db_path.clone(),
             db_ro,
@@ -146,10 +153,12 @@ impl ComputeTestHarness {
                 .context("build reqwest client")?,
             ongoing: RwLock::new(std::collections::HashMap::new()),
             compute_ongoing: RwLock::new(std::collections::HashMap::new()),
-            compute_sem: Arc::new(Semaphore::new(2)),
+            compute_sem,
             codegen_sem: Arc::new(Semaphore::new(2)),
-            wasm_sem: Arc::new(Semaphore::new(2)),
+            wasm_sem,
+            compute_queues,
             compute_cancel: RwLock::new(std::collections::HashMap::new()),
+            compute_pause: RwLock::new(std::collections::HashMap::new()),
             safe_mode: RwLock::new(false),
             safe_reason: RwLock::new(None),
             circuit_breakers: Arc::new(RwLock::new(std::collections::HashMap::new())),
@@ -159,6 +168,7 @@ impl ComputeTestHarness {
             resilience_metrics: crate::infrastructure::chaos::ResilienceMetrics::new(),
             action_log,
             job_token_key: [0u8; 32],
+            modules_integrity: RwLock::new(None),
         };
 
         // database initialized above
@@ -263,6 +273,62 @@ impl ComputeTestHarness {
         .map_err(|err| anyhow::anyhow!(err))
     }
 
+    /// Pause or resume a running job.
+    pub async fn set_job_paused(&self, job_id: &str, paused: bool) -> Result<()> {
+        let state: tauri::State<'_, AppState> = self.app.state();
+        crate::commands_harness::compute_set_paused(
+            self.app.handle().clone(),
+            state,
+            job_id.to_string(),
+            paused,
+        )
+        .await
+        .map_err(|err| anyhow::anyhow!(err))
+    }
+
+    /// Run a compute pipeline to completion and return the `compute-pipeline-final` payload.
+    pub async fn run_pipeline(&self, spec: crate::ComputePipelineSpec) -> Result<Value> {
+        tokio::time::timeout(
+            Duration::from_secs(60),
+            crate::commands_harness::compute_pipeline(self.app.handle().clone(), spec),
+        )
+        .await
+        .map_err(|_| anyhow::anyhow!("timed out waiting for compute-pipeline-final"))?
+        .map_err(|err| anyhow::anyhow!(err))
+    }
+
+    /// Replay a finished job from its action-log records and return the replay report.
+    pub async fn replay_job(&self, job_id: &str) -> Result<crate::ReplayReport> {
+        let state: tauri::State<'_, AppState> = self.app.state();
+        crate::commands_harness::compute_replay(self.app.handle().clone(), state, job_id.into())
+            .await
+            .map_err(|err| anyhow::anyhow!(err))
+    }
+
+    /// Structured guest log entries for a job, optionally at or above `min_level`.
+    pub async fn job_logs(
+        &self,
+        job_id: &str,
+        min_level: Option<&str>,
+    ) -> Result<Vec<crate::GuestLogEntry>> {
+        let state: tauri::State<'_, AppState> = self.app.state();
+        crate::commands_harness::compute_job_logs(
+            state,
+            job_id.into(),
+            min_level.map(str::to_string),
+            None,
+        )
+        .await
+        .map_err(|err| anyhow::anyhow!(err))
+    }
+
+    pub async fn queue_status(&self) -> Result<Value> {
+        let state: tauri::State<'_, AppState> = self.app.state();
+        crate::commands_harness::compute_queue_status(state)
+            .await
+            .map_err(|err| anyhow::anyhow!(err))
+    }
+
     /// WHY: Surface admin-style commands through the harness so the shim implementations stay exercised and ready for future tests.
     pub async fn modules_info(&self) -> Result<Value> {
         crate::commands_harness::get_modules_info(self.app.handle().clone())
@@ -6,7 +6,7 @@
 
 use serde_json::json;
 use uicp::{
-    test_support::ComputeTestHarness, ComputeCapabilitiesSpec, ComputeJobSpec, ComputeProvenanceSpec,
+    test_support::ComputeTestHarness, ComputeCapabilitiesSpec, ComputeJobSpec, ComputePriority, ComputeProvenanceSpec,
 };
 use uuid::Uuid;
 
@@ -82,6 +82,7 @@ async fn codegen_mock_success_and_golden_replay() {
         golden_key: None,
         artifact_id: None,
         expect_golden: false,
+        priority: ComputePriority::Normal,
     };
 
     let final1 = harness.run_job(spec1).await.expect("final");
@@ -117,6 +118,7 @@ async fn codegen_mock_success_and_golden_replay() {
         golden_key: None,
         artifact_id: None,
         expect_golden: false,
+        priority: ComputePriority::Normal,
     };
 
     let final2 = harness.run_job(spec2).await.expect("final");
@@ -161,6 +163,7 @@ async fn codegen_validation_rejects_unsafe_code() {
         golden_key: None,
         artifact_id: None,
         expect_golden: false,
+        priority: ComputePriority::Normal,
     };
 
     let final_ev = harness.run_job(job).await.expect("final");
@@ -201,6 +204,7 @@ async fn compute_readonly_cache_miss_fails() {
         golden_key: None,
         artifact_id: None,
         expect_golden: false,
+        priority: ComputePriority::Normal,
     };
 
     let final_ev = harness.run_job(job).await.expect("final");
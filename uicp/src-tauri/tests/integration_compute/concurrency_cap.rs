@@ -12,7 +12,7 @@ mod wasm_tests {
     use std::sync::Once;
     use uicp::registry;
     use uicp::{
-        test_support::ComputeTestHarness, ComputeCapabilitiesSpec, ComputeJobSpec,
+        test_support::ComputeTestHarness, ComputeCapabilitiesSpec, ComputeJobSpec, ComputePriority,
         ComputeProvenanceSpec,
     };
     use uuid::Uuid;
@@ -52,6 +52,7 @@ mod wasm_tests {
             golden_key: None,
             artifact_id: None,
             expect_golden: false,
+            priority: ComputePriority::Normal,
         }
     }
 
@@ -9,7 +9,7 @@ use serde_json::{json, Value};
 use std::sync::Once;
 use uicp::registry;
 use uicp::{
-    test_support::ComputeTestHarness, ComputeCapabilitiesSpec, ComputeJobSpec,
+    test_support::ComputeTestHarness, ComputeCapabilitiesSpec, ComputeJobSpec, ComputePriority,
     ComputeProvenanceSpec,
 };
 
@@ -46,6 +46,7 @@ fn make_job(job_id: &str, env_hash: &str, fuel: Option<u64>) -> ComputeJobSpec {
         golden_key: None,
         artifact_id: None,
         expect_golden: false,
+        priority: ComputePriority::Normal,
     }
 }
 
@@ -206,3 +207,50 @@ async fn different_env_hash_changes_seed_dependent_output() {
         );
     }
 }
+
+#[tokio::test]
+async fn replay_reuses_seed_and_reproduces_output_hash() {
+    skip_contract_verify();
+    let app = tauri::test::mock_builder()
+        .build(tauri::test::mock_context(tauri::test::noop_assets()))
+        .unwrap();
+    if let Ok(Some(m)) = registry::find_module(&app.handle(), "table.query@0.1.0") {
+        let mut cfg = wasmtime::Config::new();
+        cfg.wasm_component_model(true);
+        let engine = wasmtime::Engine::new(&cfg).expect("engine");
+        if wasmtime::component::Component::from_file(&engine, &m.path).is_err() {
+            tracing::warn!("skipping replay (component not loadable)");
+            return;
+        }
+    } else {
+        tracing::warn!("skipping replay (table.query module not available)");
+        return;
+    }
+    let harness = ComputeTestHarness::new_async().await.expect("harness");
+
+    let job_id = "00000000-0000-4000-8000-0000000000cc";
+    let original = harness
+        .run_job(make_job(job_id, "replay-env", Some(200_000)))
+        .await
+        .expect("original run should succeed");
+    let original_metrics = ensure_success(&original);
+
+    let report = harness.replay_job(job_id).await.expect("replay");
+    assert_ne!(
+        report.replay_job_id, job_id,
+        "replay runs under a fresh job id"
+    );
+    assert!(report.matched, "replay should reproduce: {report:?}");
+    assert!(!report.nondeterministic);
+    assert!(report.seed_matched, "replay must inherit the original seed");
+    assert_eq!(
+        report.actual_hash.as_deref(),
+        original_metrics.get("outputHash").and_then(|v| v.as_str())
+    );
+
+    let missing = harness
+        .replay_job("00000000-0000-4000-8000-0000000000cd")
+        .await
+        .expect_err("unknown job ids cannot be replayed");
+    assert!(missing.to_string().contains("E-UICP-0720"), "{missing}");
+}
@@ -20,6 +20,14 @@ fn table_query_dir() -> PathBuf {
     PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..").join("components").join("table.query")
 }
 
+fn json_query_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..").join("components").join("json.query")
+}
+
+fn markdown_render_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..").join("components").join("markdown.render")
+}
+
 fn wit_of_component(dir: PathBuf, artifact: &str) -> String {
     use std::process::Command as Cmd;
     let manifest = dir.join("Cargo.toml");
@@ -47,9 +55,9 @@ fn wit_of_component(dir: PathBuf, artifact: &str) -> String {
 #[test]
 fn csv_parse_component_imports_expected() {
     let wit = wit_of_component(csv_parse_dir(), "csv_parse.wasm");
-    // Simplified component should not import host control/logging
+    // 1.3 streams partial frames through host control but still does not log
     assert!(wit.contains("export csv"));
-    assert!(!wit.contains("uicp:host/control"), "legacy host control import present: \n{}", wit);
+    assert!(wit.contains("uicp:host/control"), "host control import missing: \n{}", wit);
     assert!(!wit.contains("wasi:logging"), "unexpected wasi:logging import: \n{}", wit);
     // Should not include networking/http surfaces
     assert!(!wit.contains("wasi:http"), "unexpected wasi:http in imports: \n{}", wit);
@@ -75,6 +83,36 @@ fn table_query_component_imports_expected() {
     );
 }
 
+#[test]
+fn json_query_component_imports_expected() {
+    let wit = wit_of_component(json_query_dir(), "json_query.wasm");
+    assert!(wit.contains("export uicp:task-json-query/query"));
+    assert!(wit.contains("uicp:host/control"), "host control import missing: \n{}", wit);
+    // Should not include randomness, networking or http surfaces
+    assert!(!wit.contains("wasi:random"), "unexpected wasi:random import: \n{}", wit);
+    assert!(!wit.contains("wasi:http"), "unexpected wasi:http in imports: \n{}", wit);
+    assert!(
+        !wit.contains("wasi:sockets"),
+        "unexpected wasi:sockets in imports: \n{}",
+        wit
+    );
+}
+
+#[test]
+fn markdown_render_component_imports_expected() {
+    let wit = wit_of_component(markdown_render_dir(), "markdown_render.wasm");
+    assert!(wit.contains("export uicp:task-markdown-render/render"));
+    // Pure renderer: no host control, randomness, networking or http surfaces
+    assert!(!wit.contains("uicp:host/control"), "unexpected host control import: \n{}", wit);
+    assert!(!wit.contains("wasi:random"), "unexpected wasi:random import: \n{}", wit);
+    assert!(!wit.contains("wasi:http"), "unexpected wasi:http in imports: \n{}", wit);
+    assert!(
+        !wit.contains("wasi:sockets"),
+        "unexpected wasi:sockets in imports: \n{}",
+        wit
+    );
+}
+
 #[test]
 fn log_test_component_imports_logging_only() {
     // Build the component (release to ensure stable path)
@@ -11,7 +11,7 @@
 use serde_json::json;
 use std::sync::Once;
 use uicp::{
-    test_support::ComputeTestHarness, ComputeCapabilitiesSpec, ComputeJobSpec,
+    test_support::ComputeTestHarness, ComputeCapabilitiesSpec, ComputeJobSpec, ComputePriority,
     ComputeProvenanceSpec,
 };
 use uuid::Uuid;
@@ -45,6 +45,7 @@ fn build_job(job_id: &str, env_hash: &str) -> ComputeJobSpec {
         golden_key: None,
         artifact_id: None,
         expect_golden: false,
+        priority: ComputePriority::Normal,
     }
 }
 
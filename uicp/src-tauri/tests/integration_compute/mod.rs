@@ -8,6 +8,7 @@ mod determinism;
 mod kill_replay_shakedown;
 mod module_smoke;
 mod negative_execution;
+mod pipeline;
 mod policy_enforcement;
 mod quickjs_applet;
 mod script_world;
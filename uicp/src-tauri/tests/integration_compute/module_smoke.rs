@@ -13,7 +13,7 @@ use std::path::PathBuf;
 use std::sync::Once;
 use uicp::{
     compute::preflight_component_imports, registry, test_support::ComputeTestHarness,
-    ComputeCapabilitiesSpec, ComputeJobSpec, ComputeProvenanceSpec,
+    ComputeCapabilitiesSpec, ComputeJobSpec, ComputePriority, ComputeProvenanceSpec,
 };
 
 fn skip_contract_verify() {
@@ -118,6 +118,7 @@ async fn csv_parse_smoke_when_module_present() {
         golden_key: None,
         artifact_id: None,
         expect_golden: false,
+        priority: ComputePriority::Normal,
     };
     let final_ev = h.run_job(spec).await.expect("final event");
     assert_eq!(final_ev.get("ok").and_then(|v| v.as_bool()), Some(true));
@@ -178,6 +179,7 @@ async fn table_query_smoke_when_module_present() {
         golden_key: None,
         artifact_id: None,
         expect_golden: false,
+        priority: ComputePriority::Normal,
     };
     let final_ev = h.run_job(spec).await.expect("final event");
     assert_eq!(final_ev.get("ok").and_then(|v| v.as_bool()), Some(true));
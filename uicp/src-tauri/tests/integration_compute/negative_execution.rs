@@ -4,7 +4,8 @@
 
 use serde_json::json;
 use uicp::{
-    enforce_compute_policy, ComputeCapabilitiesSpec, ComputeJobSpec, ComputeProvenanceSpec,
+    enforce_compute_policy, ComputeCapabilitiesSpec, ComputeJobSpec, ComputePriority,
+    ComputeProvenanceSpec,
 };
 
 fn base_spec() -> ComputeJobSpec {
@@ -28,6 +29,7 @@ fn base_spec() -> ComputeJobSpec {
         golden_key: None,
         artifact_id: None,
         expect_golden: false,
+        priority: ComputePriority::Normal,
     }
 }
 
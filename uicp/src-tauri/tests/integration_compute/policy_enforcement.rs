@@ -8,7 +8,7 @@
 mod wasm_tests {
     use serde_json::json;
     use uicp::{
-        test_support::ComputeTestHarness, ComputeCapabilitiesSpec, ComputeJobSpec,
+        test_support::ComputeTestHarness, ComputeCapabilitiesSpec, ComputeJobSpec, ComputePriority,
         ComputeProvenanceSpec,
     };
     use uuid::Uuid;
@@ -47,6 +47,7 @@ mod wasm_tests {
             golden_key: None,
             artifact_id: None,
             expect_golden: false,
+            priority: ComputePriority::Normal,
         }
     }
 
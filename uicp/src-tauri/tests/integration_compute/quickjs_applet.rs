@@ -12,7 +12,7 @@ use std::path::PathBuf;
 use std::sync::Once;
 use uicp::{
     compute::preflight_component_imports, registry, test_support::ComputeTestHarness,
-    ComputeCapabilitiesSpec, ComputeJobSpec, ComputeProvenanceSpec,
+    ComputeCapabilitiesSpec, ComputeJobSpec, ComputePriority, ComputeProvenanceSpec,
 };
 
 fn skip_contract_verify() {
@@ -136,6 +136,7 @@ async fn quickjs_init_returns_initial_state() {
         golden_key: None,
         artifact_id: None,
         expect_golden: false,
+        priority: ComputePriority::Normal,
     };
 
     let final_ev = h.run_job(spec).await.expect("final event");
@@ -200,6 +201,7 @@ async fn quickjs_render_produces_html() {
         golden_key: None,
         artifact_id: None,
         expect_golden: false,
+        priority: ComputePriority::Normal,
     };
 
     let final_ev = h.run_job(spec).await.expect("final event");
@@ -266,6 +268,7 @@ async fn quickjs_on_event_updates_state() {
         golden_key: None,
         artifact_id: None,
         expect_golden: false,
+        priority: ComputePriority::Normal,
     };
 
     let final_ev = h.run_job(spec).await.expect("final event");
@@ -326,6 +329,7 @@ async fn quickjs_rejects_missing_source() {
         golden_key: None,
         artifact_id: None,
         expect_golden: false,
+        priority: ComputePriority::Normal,
     };
 
     let final_ev = h.run_job(spec).await.expect("final event");
@@ -9,6 +9,7 @@ export const computeErrorCode = z.enum([
   "Task.NotFound",
   "Runtime.Fault",
   "Compute.Resource.Limit",
+  "Compute.Fuel.Exhausted",
   "IO.Denied",
   "Nondeterministic",
 ]);
@@ -39,6 +40,8 @@ export const jobSpecSchema = z.object({
   cache: z.enum(["readwrite", "readOnly", "bypass"]).default("readwrite"),
   capabilities: capabilitiesSchema.default({}),
   replayable: z.boolean().default(true),
+  // Scheduling class; interactive jobs are admitted ahead of normal and batch work.
+  priority: z.enum(['interactive', 'normal', 'batch']).default('normal'),
   // Workspace scoping for cache and bookkeeping on the host.
   workspaceId: z.string().min(1).default('default'),
   provenance: z.object({
@@ -54,6 +57,61 @@ export const jobSpecSchema = z.object({
 
 export type JobSpec = z.infer<typeof jobSpecSchema>;
 
+// Pipelines: node inputs may embed { "$ref": "<nodeId>#/json/pointer" } to consume a prior node's output.
+export const pipelineSpecSchema = z.object({
+  pipelineId: z.string().min(1),
+  nodes: z
+    .array(
+      z.object({
+        id: z.string().min(1),
+        dependsOn: z.array(z.string()).default([]),
+        job: jobSpecSchema,
+      }),
+    )
+    .min(1)
+    .max(64),
+});
+
+export type PipelineSpec = z.infer<typeof pipelineSpecSchema>;
+
+// compute_replay result: `nondeterministic` means the replay succeeded with a different output hash.
+export const replayReportSchema = z.object({
+  jobId: z.string(),
+  replayJobId: z.string(),
+  task: z.string(),
+  moduleDigest: z.string().optional(),
+  expectedHash: z.string(),
+  actualHash: z.string().nullable(),
+  matched: z.boolean(),
+  nondeterministic: z.boolean(),
+  seedMatched: z.boolean(),
+  ok: z.boolean(),
+  code: z.string().optional(),
+  message: z.string().optional(),
+  metrics: z.record(z.unknown()).optional(),
+});
+
+export type ReplayReport = z.infer<typeof replayReportSchema>;
+
+export const guestLogLevelSchema = z.enum(['trace', 'debug', 'info', 'warn', 'error']);
+
+export type GuestLogLevel = z.infer<typeof guestLogLevelSchema>;
+
+// compute_job_logs entry: one `uicp:host/log` write, already cut to the host's size limits.
+export const guestLogEntrySchema = z.object({
+  jobId: z.string(),
+  task: z.string(),
+  seq: z.number().int().nonnegative(),
+  tick: z.number().int().nonnegative(),
+  ts: z.number().int(),
+  level: guestLogLevelSchema,
+  message: z.string(),
+  fields: z.array(z.object({ key: z.string(), value: z.string() })),
+  truncated: z.boolean(),
+});
+
+export type GuestLogEntry = z.infer<typeof guestLogEntrySchema>;
+
 // Partial event payload — content is task-specific, validated by the host before emission
 export const partialEventSchema = z.object({
   jobId: z.string().uuid(),
@@ -65,6 +123,15 @@ export const partialEventSchema = z.object({
 
 export type ComputePartialEvent = z.infer<typeof partialEventSchema>;
 
+// Downsampled memory usage over the run (peak sample per window; last point is usage at finish)
+const memSeriesSchema = z.array(
+  z.object({
+    tMs: z.number().int().nonnegative(),
+    memBytes: z.number().int().nonnegative(),
+    tableElements: z.number().int().nonnegative(),
+  }),
+);
+
 // Final result envelope
 export const finalOkSchema = z.object({
   ok: z.literal(true),
@@ -75,10 +142,12 @@ export const finalOkSchema = z.object({
     .object({
       durationMs: z.number().int().nonnegative().optional(),
       fuelUsed: z.number().int().nonnegative().optional(),
+      fuelBudget: z.number().int().nonnegative().optional(),
       memPeakMb: z.number().int().nonnegative().optional(),
       cacheHit: z.boolean().optional(),
       deadlineMs: z.number().int().nonnegative().optional(),
       remainingMsAtFinish: z.number().int().nonnegative().optional(),
+      pausedMs: z.number().int().nonnegative().optional(),
       queueMs: z.number().int().nonnegative().optional(),
       queueWaitMs: z.number().int().nonnegative().optional(),
       logCount: z.number().int().nonnegative().optional(),
@@ -88,6 +157,7 @@ export const finalOkSchema = z.object({
       logThrottleWaits: z.number().int().nonnegative().optional(),
       loggerThrottleWaits: z.number().int().nonnegative().optional(),
       partialThrottleWaits: z.number().int().nonnegative().optional(),
+      memSeries: memSeriesSchema.optional(),
       rngCounter: z.number().int().nonnegative().optional(),
       outputHash: z.string().optional(),
       goldenHash: z.string().optional(),
@@ -106,10 +176,12 @@ export const finalErrSchema = z.object({
     .object({
       durationMs: z.number().int().nonnegative().optional(),
       fuelUsed: z.number().int().nonnegative().optional(),
+      fuelBudget: z.number().int().nonnegative().optional(),
       memPeakMb: z.number().int().nonnegative().optional(),
       cacheHit: z.boolean().optional(),
       deadlineMs: z.number().int().nonnegative().optional(),
       remainingMsAtFinish: z.number().int().nonnegative().optional(),
+      pausedMs: z.number().int().nonnegative().optional(),
       queueMs: z.number().int().nonnegative().optional(),
       queueWaitMs: z.number().int().nonnegative().optional(),
       logCount: z.number().int().nonnegative().optional(),
@@ -119,6 +191,7 @@ export const finalErrSchema = z.object({
       logThrottleWaits: z.number().int().nonnegative().optional(),
       loggerThrottleWaits: z.number().int().nonnegative().optional(),
       partialThrottleWaits: z.number().int().nonnegative().optional(),
+      memSeries: memSeriesSchema.optional(),
       goldenHash: z.string().optional(),
       goldenMatched: z.boolean().optional(),
     })
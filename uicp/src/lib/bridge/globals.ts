@@ -4,3 +4,19 @@ export const getBridgeWindow = (): (Window & typeof globalThis) | undefined =>
 export const getComputeBridge = () => getBridgeWindow()?.uicpComputeCall;
 
 export const getComputeCancelBridge = () => getBridgeWindow()?.uicpComputeCancel;
+
+export const getComputePauseBridge = () => getBridgeWindow()?.uicpComputePause;
+
+export const getComputeResumeBridge = () => getBridgeWindow()?.uicpComputeResume;
+
+export const getComputePipelineBridge = () => getBridgeWindow()?.uicpComputePipeline;
+
+export const getComputeReplayBridge = () => getBridgeWindow()?.uicpComputeReplay;
+
+export const getComputeJobLogsBridge = () => getBridgeWindow()?.uicpComputeJobLogs;
+
+export const getAppletSessionOpenBridge = () => getBridgeWindow()?.uicpAppletSessionOpen;
+
+export const getAppletSessionCallBridge = () => getBridgeWindow()?.uicpAppletSessionCall;
+
+export const getAppletSessionDisposeBridge = () => getBridgeWindow()?.uicpAppletSessionDispose;
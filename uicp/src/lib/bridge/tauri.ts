@@ -4,7 +4,7 @@ import { invoke } from '@tauri-apps/api/core';
 import { createOllamaAggregator } from '../uicp/stream';
 import { readBooleanEnv } from '../env/values';
 import { enqueueBatch, addQueueAppliedListener } from '../uicp/adapters/queue';
-import { finalEventSchema, type JobSpec, type ComputeFinalEvent } from '../../compute/types';
+import { finalEventSchema, guestLogEntrySchema, pipelineSpecSchema, replayReportSchema, type GuestLogLevel, type JobSpec, type ComputeFinalEvent, type PipelineSpec } from '../../compute/types';
 import { useComputeStore } from '../../state/compute';
 import { useAppStore } from '../../state/app';
 import { useChatStore } from '../../state/chat';
@@ -322,6 +322,7 @@ export async function initializeTauriBridge() {
           cacheHit: m?.cacheHit,
           deadlineMs: m?.deadlineMs,
           remainingMsAtFinish: m?.remainingMsAtFinish,
+          pausedMs: m?.pausedMs,
           queueWaitMs: m?.queueWaitMs ?? m?.queueMs,
           logCount: m?.logCount,
           emittedLogBytes: m?.emittedLogBytes,
@@ -365,6 +366,7 @@ export async function initializeTauriBridge() {
       cacheHit: meta?.cacheHit,
       deadlineMs: meta?.deadlineMs,
       remainingMsAtFinish: meta?.remainingMsAtFinish,
+      pausedMs: meta?.pausedMs,
       queueWaitMs: meta?.queueWaitMs ?? meta?.queueMs,
       logCount: meta?.logCount,
       emittedLogBytes: meta?.emittedLogBytes,
@@ -593,6 +595,65 @@ export async function initializeTauriBridge() {
     }
   };
 
+  // Pause/resume suspend the guest at its next epoch tick; paused time does not count against timeoutMs.
+  bridgeWindow.uicpComputePause = async (jobId: string) => {
+    await invoke('compute_pause', { jobId });
+  };
+
+  bridgeWindow.uicpComputeResume = async (jobId: string) => {
+    await invoke('compute_resume', { jobId });
+  };
+
+  // Pipelines run host-side; node results arrive on compute-pipeline-partial/-final rather than per-job binds.
+  bridgeWindow.uicpComputePipeline = async (spec: PipelineSpec) => {
+    await invoke('compute_pipeline', { spec: pipelineSpecSchema.parse(spec) });
+  };
+
+  // Replays re-run a finished job under a fresh job id; its binds are dropped so UI state is untouched.
+  bridgeWindow.uicpComputeReplay = async (jobId: string) =>
+    replayReportSchema.parse(await invoke('compute_replay', { jobId }));
+
+  // Structured guest logs come from the action log, so they outlive the job's partial events.
+  bridgeWindow.uicpComputeJobLogs = async (jobId: string, minLevel?: GuestLogLevel) =>
+    guestLogEntrySchema.array().parse(await invoke('compute_job_logs', { jobId, minLevel }));
+
+  // Applet sessions keep one applet.quickjs instance per window. Open and call are admitted like
+  // compute_call (token, permissions, policy, queue), so each needs its own jobId and token.
+  const withJobToken = async (spec: JobSpec): Promise<JobSpec> => {
+    const finalSpec: JobSpec = { ...spec, workspaceId: spec.workspaceId ?? 'default' };
+    try {
+      const token: string = await invoke('mint_job_token', {
+        jobId: finalSpec.jobId,
+        task: finalSpec.task,
+        workspaceId: finalSpec.workspaceId,
+        envHash: finalSpec.provenance.envHash,
+      } as never);
+      return typeof token === 'string' && token.length > 0 ? { ...finalSpec, token } : finalSpec;
+    } catch (err) {
+      console.warn('[tauri] mint_job_token failed', err);
+      return finalSpec;
+    }
+  };
+
+  // `spec.input` is `{ source }`; reopening a window reloads the bundle in place.
+  bridgeWindow.uicpAppletSessionOpen = async (windowId: string, spec: JobSpec) => {
+    await invoke('applet_session_open', { windowId, spec: await withJobToken(spec) });
+  };
+
+  // `spec.input` has the applet.quickjs job shape without `source`; resolves to the job payload.
+  bridgeWindow.uicpAppletSessionCall = async (windowId: string, spec: JobSpec) =>
+    invoke<unknown>('applet_session_call', { windowId, spec: await withJobToken(spec) });
+
+  bridgeWindow.uicpAppletSessionDispose = async (
+    windowId: string,
+    options?: { workspaceId?: string; clearState?: boolean },
+  ) =>
+    invoke<boolean>('applet_session_dispose', {
+      windowId,
+      workspaceId: options?.workspaceId,
+      clearState: options?.clearState,
+    });
+
   // Dev-only: enable backend debug logs and mirror key events to DevTools
   if (import.meta.env.DEV) {
     try {
@@ -800,17 +861,19 @@ export async function initializeTauriBridge() {
             ? '[Denied]'
             : code === 'Compute.Resource.Limit'
               ? '[Resource]'
-              : code === 'Runtime.Fault'
-                ? '[Runtime]'
-                : code === 'Task.NotFound'
-                  ? '[NotFound]'
-                  : code === 'IO.Denied'
-                    ? '[IO]'
-                    : code === 'Compute.Input.Invalid'
-                      ? '[Input]'
-                      : code === 'Nondeterministic'
-                        ? '[Nondet]'
-                        : `[${code}]`;
+              : code === 'Compute.Fuel.Exhausted'
+                ? '[Fuel]'
+                : code === 'Runtime.Fault'
+                  ? '[Runtime]'
+                  : code === 'Task.NotFound'
+                    ? '[NotFound]'
+                    : code === 'IO.Denied'
+                      ? '[IO]'
+                      : code === 'Compute.Input.Invalid'
+                        ? '[Input]'
+                        : code === 'Nondeterministic'
+                          ? '[Nondet]'
+                          : `[${code}]`;
     const m = final.metrics;
     const parts: string[] = [];
     if (typeof m?.durationMs === 'number') parts.push(`dur=${Math.round(m.durationMs)}ms`);
@@ -1226,5 +1289,8 @@ export function teardownTauriBridge() {
   if (bridgeWindow) {
     bridgeWindow.uicpComputeCall = undefined;
     bridgeWindow.uicpComputeCancel = undefined;
+    bridgeWindow.uicpComputePause = undefined;
+    bridgeWindow.uicpComputeResume = undefined;
+    bridgeWindow.uicpComputePipeline = undefined;
   }
 }
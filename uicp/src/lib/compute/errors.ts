@@ -6,6 +6,7 @@ export const ComputeError = {
   Cancelled: 'Compute.Cancelled',
   CapabilityDenied: 'Compute.CapabilityDenied',
   ResourceLimit: 'Compute.Resource.Limit',
+  FuelExhausted: 'Compute.Fuel.Exhausted',
   RuntimeFault: 'Runtime.Fault',
   IODenied: 'IO.Denied',
   TaskNotFound: 'Task.NotFound',
@@ -1,4 +1,4 @@
-import type { JobSpec } from '../compute/types';
+import type { GuestLogEntry, GuestLogLevel, JobSpec, PipelineSpec, ReplayReport } from '../compute/types';
 
 declare global {
   interface Window {
@@ -10,6 +10,17 @@ declare global {
     __UICP_STATE_STORE__?: Map<'window' | 'workspace' | 'global', Map<string, unknown>>;
     uicpComputeCall?: (spec: JobSpec) => Promise<void>;
     uicpComputeCancel?: (jobId: string) => Promise<void>;
+    uicpComputePause?: (jobId: string) => Promise<void>;
+    uicpComputeResume?: (jobId: string) => Promise<void>;
+    uicpComputePipeline?: (spec: PipelineSpec) => Promise<void>;
+    uicpComputeReplay?: (jobId: string) => Promise<ReplayReport>;
+    uicpComputeJobLogs?: (jobId: string, minLevel?: GuestLogLevel) => Promise<GuestLogEntry[]>;
+    uicpAppletSessionOpen?: (windowId: string, spec: JobSpec) => Promise<void>;
+    uicpAppletSessionCall?: (windowId: string, spec: JobSpec) => Promise<unknown>;
+    uicpAppletSessionDispose?: (
+      windowId: string,
+      options?: { workspaceId?: string; clearState?: boolean },
+    ) => Promise<boolean>;
   }
 }
 